@@ -1,6 +1,8 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use tui_textarea::{CursorMove, TextArea};
-use tui_textarea_bench::{dummy_terminal, TerminalExt, LOREM};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use tui_textarea::{CursorMove, LineRope, TextArea, TextBuffer};
+use tui_textarea_bench::{dummy_terminal, large_lorem_lines, TerminalExt, LOREM, SEED};
 
 #[inline]
 fn run(textarea: &TextArea<'_>) {
@@ -16,6 +18,46 @@ fn run(textarea: &TextArea<'_>) {
     }
 }
 
+// Join lines at random positions of a large document. This compares `Vec<String>` and `LineRope` buffers.
+#[inline]
+fn run_large<B: TextBuffer>(textarea: &mut TextArea<'_, B>) -> usize {
+    let mut rng = SmallRng::from_seed(SEED);
+    let mut term = dummy_terminal();
+    for _ in 0..1000 {
+        let row = rng.gen_range(1..textarea.buffer().len() as u16);
+        textarea.move_cursor(CursorMove::Jump(row, 0));
+        textarea.delete_newline();
+    }
+    // Render only once since rendering costs the same regardless of the buffer
+    term.draw_textarea(textarea);
+    textarea.buffer().len()
+}
+
+fn bench_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("delete::large");
+    let lines = large_lorem_lines();
+
+    let vec = TextArea::new(lines.clone());
+    group.bench_function("vec", |b| {
+        b.iter_batched_ref(
+            || vec.clone(),
+            |t| std::hint::black_box(run_large(t)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let rope = TextArea::with_buffer(LineRope::from_lines(lines));
+    group.bench_function("rope", |b| {
+        b.iter_batched_ref(
+            || rope.clone(),
+            |t| std::hint::black_box(run_large(t)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
 fn bench(c: &mut Criterion) {
     let mut lines = vec![];
     for _ in 0..10 {
@@ -25,5 +67,5 @@ fn bench(c: &mut Criterion) {
     c.bench_function("delete::line", |b| b.iter(|| run(&textarea)));
 }
 
-criterion_group!(delete_line, bench);
+criterion_group!(delete_line, bench, bench_large);
 criterion_main!(delete_line);
@@ -1,8 +1,8 @@
-use criterion::{criterion_group, criterion_main, Criterion, SamplingMode};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, SamplingMode};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
-use tui_textarea::{CursorMove, Input, Key, TextArea};
-use tui_textarea_bench::{dummy_terminal, TerminalExt, LOREM, SEED};
+use tui_textarea::{CursorMove, Input, Key, LineRope, TextArea, TextBuffer};
+use tui_textarea_bench::{dummy_terminal, large_lorem_lines, TerminalExt, LOREM, SEED};
 
 #[inline]
 fn random_lorem(repeat: usize) -> usize {
@@ -39,6 +39,50 @@ fn random_lorem(repeat: usize) -> usize {
     textarea.lines().len()
 }
 
+// Insert new lines at random positions of a large document. This compares `Vec<String>` and `LineRope` buffers.
+#[inline]
+fn random_large<B: TextBuffer>(textarea: &mut TextArea<'_, B>) -> usize {
+    let mut rng = SmallRng::from_seed(SEED);
+    let mut term = dummy_terminal();
+
+    for line in LOREM.iter().cycle().take(1000) {
+        let row = rng.gen_range(0..textarea.buffer().len() as u16);
+        textarea.move_cursor(CursorMove::Jump(row, 0));
+        textarea.insert_newline();
+        textarea.move_cursor(CursorMove::Up);
+        textarea.insert_str(line);
+    }
+    // Render only once since rendering costs the same regardless of the buffer
+    term.draw_textarea(textarea);
+
+    textarea.buffer().len()
+}
+
+fn bench_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert::large");
+    let lines = large_lorem_lines();
+
+    let vec = TextArea::new(lines.clone());
+    group.bench_function("vec", |b| {
+        b.iter_batched_ref(
+            || vec.clone(),
+            |t| std::hint::black_box(random_large(t)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let rope = TextArea::with_buffer(LineRope::from_lines(lines));
+    group.bench_function("rope", |b| {
+        b.iter_batched_ref(
+            || rope.clone(),
+            |t| std::hint::black_box(random_large(t)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
 fn bench(c: &mut Criterion) {
     let mut group = c.benchmark_group("insert::random");
 
@@ -63,5 +107,5 @@ fn bench(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(insert_random, bench);
+criterion_group!(insert_random, bench, bench_large);
 criterion_main!(insert_random);
@@ -7,7 +7,7 @@ use ratatui::layout::{Position, Size};
 use ratatui::prelude::backend::ClearType;
 use ratatui::Terminal;
 use std::io;
-use tui_textarea::{CursorMove, TextArea};
+use tui_textarea::{CursorMove, TextArea, TextBuffer};
 
 pub const LOREM: &[&str] = &[
     "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do",
@@ -137,16 +137,31 @@ pub fn dummy_terminal() -> Terminal<DummyBackend> {
 }
 
 pub trait TerminalExt {
-    fn draw_textarea(&mut self, textarea: &TextArea<'_>);
+    fn draw_textarea<B: TextBuffer>(&mut self, textarea: &TextArea<'_, B>);
 }
 
 impl TerminalExt for Terminal<DummyBackend> {
     #[inline]
-    fn draw_textarea(&mut self, textarea: &TextArea<'_>) {
+    fn draw_textarea<B: TextBuffer>(&mut self, textarea: &TextArea<'_, B>) {
         self.draw(|f| f.render_widget(textarea, f.area())).unwrap();
     }
 }
 
+// --- Large document helpers ---
+
+/// Number of lines in the large document used for comparing text buffers. Rows must fit in `u16` for
+/// `CursorMove::Jump`.
+pub const LARGE_LINES: usize = 50_000;
+
+pub fn large_lorem_lines() -> Vec<String> {
+    LOREM
+        .iter()
+        .cycle()
+        .take(LARGE_LINES)
+        .map(|s| s.to_string())
+        .collect()
+}
+
 // --- Cursor benchmark helpers ---
 
 #[derive(Clone, Copy)]
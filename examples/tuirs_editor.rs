@@ -87,6 +87,19 @@ impl SearchBox<'_> {
         };
         self.textarea.set_block(b);
     }
+
+    fn set_status(&mut self, textarea: &TextArea<'_>) {
+        let count = textarea.search_match_count();
+        let title = match textarea.search_match_index() {
+            Some(i) if textarea.search_wrapped() => {
+                format!("Search: {}/{} (wrapped)", i + 1, count)
+            }
+            Some(i) => format!("Search: {}/{}", i + 1, count),
+            None => format!("Search: {} matches", count),
+        };
+        self.textarea
+            .set_block(Block::default().borders(Borders::ALL).title(title));
+    }
 }
 
 struct Buffer<'a> {
@@ -263,7 +276,9 @@ impl Editor<'_> {
                         ..
                     }
                     | Input { key: Key::Down, .. } => {
-                        if !textarea.search_forward(false) {
+                        if textarea.search_forward(false) {
+                            self.search.set_status(textarea);
+                        } else {
                             self.search.set_error(Some("Pattern not found"));
                         }
                     }
@@ -280,7 +295,9 @@ impl Editor<'_> {
                         ..
                     }
                     | Input { key: Key::Up, .. } => {
-                        if !textarea.search_back(false) {
+                        if textarea.search_back(false) {
+                            self.search.set_status(textarea);
+                        } else {
                             self.search.set_error(Some("Pattern not found"));
                         }
                     }
@@ -299,8 +316,10 @@ impl Editor<'_> {
                     }
                     input => {
                         if let Some(query) = self.search.input(input) {
-                            let maybe_err = textarea.set_search_pattern(query).err();
-                            self.search.set_error(maybe_err);
+                            match textarea.set_search_pattern(query) {
+                                Ok(()) if !query.is_empty() => self.search.set_status(textarea),
+                                res => self.search.set_error(res.err()),
+                            }
                         }
                     }
                 }
@@ -0,0 +1,608 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{Index, IndexMut, Range};
+
+/// Storage of text lines which [`TextArea`](crate::TextArea) operates on.
+///
+/// [`TextArea`](crate::TextArea) stores its text in `Vec<String>` by default. Inserting or removing lines in the
+/// middle of a vector moves all lines after them, so editing a very large document can be slow. This trait allows
+/// replacing the storage with another data structure such as [`LineRope`] via
+/// [`TextArea::with_buffer`](crate::TextArea::with_buffer).
+///
+/// Each line is stored as [`String`] without a newline character. Rows are 0-base. Implementations can assume that
+/// rows passed to the methods are in bounds and may panic otherwise, as [`Vec`] does.
+/// ```
+/// use tui_textarea::{LineRope, TextArea, TextBuffer};
+///
+/// let buffer = LineRope::from_lines(vec!["hello".to_string(), "world".to_string()]);
+/// let mut textarea = TextArea::with_buffer(buffer);
+/// textarea.insert_str("hi, ");
+/// assert_eq!(textarea.buffer()[0], "hi, hello");
+/// assert_eq!(textarea.into_lines(), ["hi, hello", "world"]);
+/// ```
+pub trait TextBuffer: Index<usize, Output = String> + IndexMut<usize> + Clone + fmt::Debug {
+    /// Create a buffer with the given lines.
+    fn from_lines(lines: Vec<String>) -> Self;
+
+    /// Convert the buffer into the lines.
+    fn into_lines(self) -> Vec<String>;
+
+    /// Return the number of lines in the buffer.
+    fn len(&self) -> usize;
+
+    /// Return if the buffer contains no line.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the line at the row, or `None` if the row is out of bounds.
+    fn get(&self, row: usize) -> Option<&String> {
+        (row < self.len()).then(|| &self[row])
+    }
+
+    /// Iterate all lines in the buffer from the first line.
+    fn iter(&self) -> impl Iterator<Item = &String>;
+
+    /// Insert the lines before the row. When the row is equal to the number of lines, the lines are appended.
+    fn insert_lines<I: IntoIterator<Item = String>>(&mut self, row: usize, lines: I);
+
+    /// Remove the lines in the range of rows and return them.
+    fn remove_lines(&mut self, rows: Range<usize>) -> Vec<String>;
+
+    /// Insert the line before the row.
+    fn insert(&mut self, row: usize, line: String) {
+        self.insert_lines(row, [line]);
+    }
+
+    /// Remove the line at the row and return it.
+    fn remove(&mut self, row: usize) -> String {
+        self.remove_lines(row..row + 1).pop().unwrap()
+    }
+
+    /// Append the line to the end of the buffer.
+    fn push(&mut self, line: String) {
+        self.insert(self.len(), line);
+    }
+}
+
+impl TextBuffer for Vec<String> {
+    fn from_lines(lines: Vec<String>) -> Self {
+        lines
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        self
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, row: usize) -> Option<&String> {
+        <[String]>::get(self, row)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &String> {
+        <[String]>::iter(self)
+    }
+
+    fn insert_lines<I: IntoIterator<Item = String>>(&mut self, row: usize, lines: I) {
+        self.splice(row..row, lines);
+    }
+
+    fn remove_lines(&mut self, rows: Range<usize>) -> Vec<String> {
+        self.drain(rows).collect()
+    }
+
+    fn insert(&mut self, row: usize, line: String) {
+        Vec::insert(self, row, line);
+    }
+
+    fn remove(&mut self, row: usize) -> String {
+        Vec::remove(self, row)
+    }
+
+    fn push(&mut self, line: String) {
+        Vec::push(self, line);
+    }
+}
+
+// Maximum number of lines stored in one node of `LineRope`.
+const MAX_CHUNK_LINES: usize = 64;
+
+type Tree = Option<Box<Node>>;
+
+#[derive(Clone)]
+struct Node {
+    lines: Vec<String>,
+    len: usize, // Number of lines in this subtree
+    priority: u64,
+    left: Tree,
+    right: Tree,
+}
+
+impl Node {
+    fn new(lines: Vec<String>, priority: u64) -> Box<Self> {
+        Box::new(Self {
+            len: lines.len(),
+            lines,
+            priority,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update(&mut self) {
+        self.len = tree_len(&self.left) + self.lines.len() + tree_len(&self.right);
+    }
+}
+
+fn tree_len(tree: &Tree) -> usize {
+    tree.as_ref().map_or(0, |n| n.len)
+}
+
+fn merge(left: Tree, right: Tree) -> Tree {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut l), Some(mut r)) => {
+            if l.priority >= r.priority {
+                l.right = merge(l.right.take(), Some(r));
+                l.update();
+                Some(l)
+            } else {
+                r.left = merge(Some(l), r.left.take());
+                r.update();
+                Some(r)
+            }
+        }
+    }
+}
+
+// Split the tree into the first `at` lines and the rest. A chunk containing the split point is divided into two nodes.
+fn split(tree: Tree, at: usize, seed: &mut u64) -> (Tree, Tree) {
+    let Some(mut node) = tree else {
+        return (None, None);
+    };
+    let left_len = tree_len(&node.left);
+    if at <= left_len {
+        let (l, r) = split(node.left.take(), at, seed);
+        node.left = r;
+        node.update();
+        (l, Some(node))
+    } else if at >= left_len + node.lines.len() {
+        let (l, r) = split(node.right.take(), at - left_len - node.lines.len(), seed);
+        node.right = l;
+        node.update();
+        (Some(node), r)
+    } else {
+        let rest = node.lines.split_off(at - left_len);
+        let right = node.right.take();
+        node.update();
+        let rest = Node::new(rest, next_priority(seed));
+        (Some(node), merge(Some(rest), right))
+    }
+}
+
+// Insert the line in an existing chunk when it has room. Otherwise the line is returned back.
+fn insert_in_chunk(tree: &mut Tree, row: usize, line: String) -> Option<String> {
+    let Some(node) = tree else {
+        return Some(line);
+    };
+    let left_len = tree_len(&node.left);
+    let rejected = if row < left_len {
+        insert_in_chunk(&mut node.left, row, line)
+    } else if row - left_len <= node.lines.len() {
+        if node.lines.len() >= MAX_CHUNK_LINES {
+            return Some(line);
+        }
+        node.lines.insert(row - left_len, line);
+        None
+    } else {
+        insert_in_chunk(&mut node.right, row - left_len - node.lines.len(), line)
+    };
+    if rejected.is_none() {
+        node.len += 1;
+    }
+    rejected
+}
+
+// Remove the line from its chunk unless the chunk would become empty.
+fn remove_from_chunk(tree: &mut Tree, row: usize) -> Option<String> {
+    let node = tree.as_mut()?;
+    let left_len = tree_len(&node.left);
+    let removed = if row < left_len {
+        remove_from_chunk(&mut node.left, row)
+    } else if row - left_len < node.lines.len() {
+        if node.lines.len() == 1 {
+            return None;
+        }
+        Some(node.lines.remove(row - left_len))
+    } else {
+        remove_from_chunk(&mut node.right, row - left_len - node.lines.len())
+    };
+    if removed.is_some() {
+        node.len -= 1;
+    }
+    removed
+}
+
+fn collect_lines(tree: Tree, out: &mut Vec<String>) {
+    if let Some(node) = tree {
+        let Node {
+            lines, left, right, ..
+        } = *node;
+        collect_lines(left, out);
+        out.extend(lines);
+        collect_lines(right, out);
+    }
+}
+
+// xorshift64 is enough to keep the tree balanced. A fixed seed makes the tree shape deterministic.
+fn next_priority(seed: &mut u64) -> u64 {
+    let mut x = *seed;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    *seed = x;
+    x
+}
+
+const INITIAL_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// [`TextBuffer`] implementation backed by a rope of lines for editing large documents.
+///
+/// Lines are split into small chunks which are stored in a balanced binary tree. Accessing a line, inserting lines,
+/// and removing lines take logarithmic time to the number of lines while they take linear time with `Vec<String>`.
+/// On the other hand, iterating lines is a bit slower than `Vec<String>`.
+/// ```
+/// use tui_textarea::{LineRope, TextBuffer};
+///
+/// let mut rope: LineRope = (0..10000).map(|i| i.to_string()).collect();
+/// rope.insert(5000, "inserted".to_string());
+/// assert_eq!(rope[5000], "inserted");
+/// assert_eq!(rope[5001], "5000");
+/// assert_eq!(rope.len(), 10001);
+///
+/// let removed = rope.remove_lines(1..9999);
+/// assert_eq!(removed.len(), 9998);
+/// assert_eq!(rope.into_lines(), ["0", "9998", "9999"]);
+/// ```
+#[derive(Clone)]
+pub struct LineRope {
+    root: Tree,
+    seed: u64,
+}
+
+impl LineRope {
+    /// Create an empty rope.
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            seed: INITIAL_SEED,
+        }
+    }
+
+    fn build(&mut self, lines: Vec<String>) -> Tree {
+        let mut tree = None;
+        let mut lines = lines.into_iter().peekable();
+        while lines.peek().is_some() {
+            let chunk = lines.by_ref().take(MAX_CHUNK_LINES / 2).collect();
+            let node = Node::new(chunk, next_priority(&mut self.seed));
+            tree = merge(tree, Some(node));
+        }
+        tree
+    }
+
+    fn node_at(&self, mut row: usize) -> Option<(&Node, usize)> {
+        let mut tree = &self.root;
+        while let Some(node) = tree {
+            let left_len = tree_len(&node.left);
+            if row < left_len {
+                tree = &node.left;
+                continue;
+            }
+            row -= left_len;
+            if row < node.lines.len() {
+                return Some((node, row));
+            }
+            row -= node.lines.len();
+            tree = &node.right;
+        }
+        None
+    }
+
+    fn node_at_mut(&mut self, mut row: usize) -> Option<(&mut Node, usize)> {
+        let mut tree = &mut self.root;
+        while let Some(node) = tree {
+            let left_len = tree_len(&node.left);
+            if row < left_len {
+                tree = &mut node.left;
+                continue;
+            }
+            row -= left_len;
+            if row < node.lines.len() {
+                return Some((node, row));
+            }
+            row -= node.lines.len();
+            tree = &mut node.right;
+        }
+        None
+    }
+}
+
+impl Default for LineRope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for LineRope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for LineRope {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for LineRope {}
+
+impl Index<usize> for LineRope {
+    type Output = String;
+
+    fn index(&self, row: usize) -> &String {
+        match self.node_at(row) {
+            Some((node, i)) => &node.lines[i],
+            None => panic!("row {} is out of bounds (len: {})", row, self.len()),
+        }
+    }
+}
+
+impl IndexMut<usize> for LineRope {
+    fn index_mut(&mut self, row: usize) -> &mut String {
+        let len = self.len();
+        match self.node_at_mut(row) {
+            Some((node, i)) => &mut node.lines[i],
+            None => panic!("row {} is out of bounds (len: {})", row, len),
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for LineRope {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::from_lines(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl TextBuffer for LineRope {
+    fn from_lines(lines: Vec<String>) -> Self {
+        let mut rope = Self::new();
+        rope.root = rope.build(lines);
+        rope
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        let mut lines = Vec::with_capacity(self.len());
+        collect_lines(self.root, &mut lines);
+        lines
+    }
+
+    fn len(&self) -> usize {
+        tree_len(&self.root)
+    }
+
+    fn get(&self, row: usize) -> Option<&String> {
+        self.node_at(row).map(|(node, i)| &node.lines[i])
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &String> {
+        Iter::new(self)
+    }
+
+    fn insert_lines<I: IntoIterator<Item = String>>(&mut self, row: usize, lines: I) {
+        let len = self.len();
+        assert!(row <= len, "row {} is out of bounds (len: {})", row, len);
+
+        let mut lines: Vec<_> = lines.into_iter().collect();
+        if lines.len() == 1 {
+            let Some(line) = insert_in_chunk(&mut self.root, row, lines.pop().unwrap()) else {
+                return;
+            };
+            lines.push(line);
+        } else if lines.is_empty() {
+            return;
+        }
+
+        let (left, right) = split(self.root.take(), row, &mut self.seed);
+        let middle = self.build(lines);
+        self.root = merge(merge(left, middle), right);
+    }
+
+    fn remove_lines(&mut self, rows: Range<usize>) -> Vec<String> {
+        let len = self.len();
+        assert!(
+            rows.start <= rows.end && rows.end <= len,
+            "rows {:?} are out of bounds (len: {})",
+            rows,
+            len,
+        );
+
+        if rows.len() == 1 {
+            if let Some(line) = remove_from_chunk(&mut self.root, rows.start) {
+                return vec![line];
+            }
+        } else if rows.is_empty() {
+            return vec![];
+        }
+
+        let (left, rest) = split(self.root.take(), rows.start, &mut self.seed);
+        let (middle, right) = split(rest, rows.len(), &mut self.seed);
+        self.root = merge(left, right);
+        let mut removed = Vec::with_capacity(rows.len());
+        collect_lines(middle, &mut removed);
+        removed
+    }
+}
+
+// In-order iterator over the lines in a rope.
+struct Iter<'a> {
+    stack: Vec<&'a Node>,
+    chunk: std::slice::Iter<'a, String>,
+    remaining: usize,
+}
+
+impl<'a> Iter<'a> {
+    fn new(rope: &'a LineRope) -> Self {
+        let mut iter = Self {
+            stack: vec![],
+            chunk: [].iter(),
+            remaining: rope.len(),
+        };
+        iter.push_left(rope.root.as_deref());
+        iter
+    }
+
+    fn push_left(&mut self, mut tree: Option<&'a Node>) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.chunk.next() {
+                self.remaining -= 1;
+                return Some(line);
+            }
+            let node = self.stack.pop()?;
+            self.chunk = node.lines.as_slice().iter();
+            self.push_left(node.right.as_deref());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+impl FusedIterator for Iter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(range: Range<usize>) -> Vec<String> {
+        range.map(|i| i.to_string()).collect()
+    }
+
+    fn check(rope: &LineRope, expected: &[String]) {
+        assert_eq!(rope.len(), expected.len());
+        assert_eq!(
+            rope.iter().collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        for (i, line) in expected.iter().enumerate() {
+            assert_eq!(&rope[i], line, "row {i}");
+        }
+        assert_eq!(rope.get(expected.len()), None);
+        assert_eq!(rope.clone().into_lines(), expected);
+    }
+
+    #[test]
+    fn from_lines() {
+        for len in [0, 1, 31, 32, 33, 64, 65, 1000] {
+            let expected = lines(0..len);
+            let rope = LineRope::from_lines(expected.clone());
+            check(&rope, &expected);
+        }
+    }
+
+    #[test]
+    fn insert_and_remove_lines() {
+        let mut rope = LineRope::from_lines(lines(0..500));
+        let mut expected = lines(0..500);
+
+        // Deterministic sequence of edits at various positions mixing single line and multi-line edits
+        let mut seed = 42;
+        for i in 0..2000 {
+            let r = next_priority(&mut seed) as usize;
+            let row = r % (expected.len() + 1);
+            match i % 5 {
+                0 | 1 => {
+                    let line = format!("i{i}");
+                    rope.insert(row, line.clone());
+                    expected.insert(row, line);
+                }
+                2 => {
+                    let added: Vec<_> = (0..r % 100).map(|j| format!("c{i}-{j}")).collect();
+                    rope.insert_lines(row, added.clone());
+                    expected.splice(row..row, added);
+                }
+                3 if row < expected.len() => {
+                    assert_eq!(rope.remove(row), expected.remove(row));
+                }
+                _ => {
+                    let end = (row + r % 80).min(expected.len());
+                    let removed = rope.remove_lines(row..end);
+                    let want: Vec<_> = expected.drain(row..end).collect();
+                    assert_eq!(removed, want);
+                }
+            }
+            assert_eq!(rope.len(), expected.len(), "edit {i}");
+        }
+        check(&rope, &expected);
+    }
+
+    #[test]
+    fn remove_all_lines() {
+        let mut rope = LineRope::from_lines(lines(0..100));
+        assert_eq!(rope.remove_lines(0..100), lines(0..100));
+        check(&rope, &[]);
+        rope.push("a".to_string());
+        check(&rope, &["a".to_string()]);
+        assert_eq!(rope.remove(0), "a");
+        check(&rope, &[]);
+    }
+
+    #[test]
+    fn modify_line() {
+        let mut rope = LineRope::from_lines(lines(0..100));
+        rope[70].push('!');
+        assert_eq!(rope[70], "70!");
+        assert_eq!(rope.len(), 100);
+    }
+
+    #[test]
+    fn debug_and_eq() {
+        let rope: LineRope = ["a", "b"].into_iter().collect();
+        assert_eq!(format!("{:?}", rope), r#"["a", "b"]"#);
+        assert_eq!(rope, LineRope::from_lines(vec!["a".into(), "b".into()]));
+        assert_ne!(rope, LineRope::from_lines(vec!["a".into()]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let rope = LineRope::from_lines(lines(0..10));
+        let _ = &rope[10];
+    }
+
+    #[test]
+    fn vec_buffer() {
+        let mut v = lines(0..5);
+        TextBuffer::insert_lines(&mut v, 2, ["a".to_string(), "b".to_string()]);
+        assert_eq!(v, ["0", "1", "a", "b", "2", "3", "4"]);
+        assert_eq!(TextBuffer::remove_lines(&mut v, 1..3), ["1", "a"]);
+        assert_eq!(v, ["0", "b", "2", "3", "4"]);
+        assert_eq!(TextBuffer::iter(&v).count(), 5);
+    }
+}
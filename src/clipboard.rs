@@ -0,0 +1,346 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Clipboard shared by [`crate::TextArea`] and the host system. By default, a textarea only has its internal yank
+/// buffer. When a clipboard is set with [`crate::TextArea::set_clipboard`], text yanked by [`crate::TextArea::copy`]
+/// and [`crate::TextArea::cut`] is also written to the clipboard, and [`crate::TextArea::paste`] inserts the text
+/// read from the clipboard when it was changed by other applications. Text yanked by other deletions such as
+/// [`crate::TextArea::delete_line_by_end`] is only kept in the internal yank buffer.
+///
+/// This crate provides the following implementations:
+///
+/// - [`Osc52Clipboard`]: Write text to the terminal's clipboard via OSC 52 escape sequence
+/// - [`CommandClipboard`]: Run external commands such as `pbcopy`/`pbpaste` or `xclip`
+/// - [`MemoryClipboard`]: In-memory clipboard mainly for testing
+///
+/// ```
+/// use std::io;
+/// use tui_textarea::{Clipboard, TextArea};
+///
+/// // Clipboard which keeps text in upper case
+/// #[derive(Default)]
+/// struct UpperCase(String);
+///
+/// impl Clipboard for UpperCase {
+///     fn set_text(&mut self, text: &str) -> io::Result<()> {
+///         self.0 = text.to_uppercase();
+///         Ok(())
+///     }
+///     fn get_text(&mut self) -> io::Result<Option<String>> {
+///         Ok(Some(self.0.clone()))
+///     }
+/// }
+///
+/// let mut textarea = TextArea::from(["hello"]);
+/// textarea.set_clipboard(UpperCase::default());
+/// textarea.select_all();
+/// textarea.cut();
+/// textarea.paste();
+/// assert_eq!(textarea.lines(), ["HELLO"]);
+/// ```
+pub trait Clipboard {
+    /// Write the text to the clipboard. Lines in the text are separated with `\n`.
+    fn set_text(&mut self, text: &str) -> io::Result<()>;
+
+    /// Read the text from the clipboard. Return `Ok(None)` when the clipboard cannot be read. In the case, the
+    /// textarea's internal yank buffer is used instead.
+    fn get_text(&mut self) -> io::Result<Option<String>>;
+}
+
+// Clipboard shared by clones of the textarea. It remembers the text last written or read by the textarea to know
+// whether other applications changed the clipboard.
+#[derive(Clone)]
+pub(crate) struct SharedClipboard {
+    inner: Arc<Mutex<dyn Clipboard + Send>>,
+    last: Option<String>,
+}
+
+impl SharedClipboard {
+    pub fn new(clipboard: impl Clipboard + Send + 'static) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(clipboard)),
+            last: None,
+        }
+    }
+
+    fn lock(&self) -> io::Result<MutexGuard<'_, dyn Clipboard + Send + 'static>> {
+        self.inner
+            .lock()
+            .map_err(|_| io::Error::other("clipboard is poisoned by a panic in other thread"))
+    }
+
+    pub fn set_text(&mut self, text: &str) -> io::Result<()> {
+        self.lock()?.set_text(text)?;
+        self.last = Some(text.to_string());
+        Ok(())
+    }
+
+    // Read the text only when it differs from the text last written or read by this textarea
+    pub fn get_changed_text(&mut self) -> io::Result<Option<String>> {
+        let Some(text) = self.lock()?.get_text()? else {
+            return Ok(None);
+        };
+        if self.last.as_ref() == Some(&text) {
+            return Ok(None);
+        }
+        self.last = Some(text.clone());
+        Ok(Some(text))
+    }
+}
+
+impl fmt::Debug for SharedClipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedClipboard").finish_non_exhaustive()
+    }
+}
+
+/// In-memory [`Clipboard`]. Clones of this clipboard share the same content. This is useful to test the clipboard
+/// integration of your application without accessing the system clipboard.
+/// ```
+/// use tui_textarea::{MemoryClipboard, TextArea};
+///
+/// let clipboard = MemoryClipboard::default();
+/// let mut textarea = TextArea::from(["hello"]);
+/// textarea.set_clipboard(clipboard.clone());
+///
+/// textarea.select_all();
+/// textarea.copy();
+/// assert_eq!(clipboard.text().as_deref(), Some("hello"));
+///
+/// // Text copied by other applications is pasted
+/// clipboard.set("world");
+/// textarea.paste();
+/// assert_eq!(textarea.lines(), ["helloworld"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryClipboard {
+    text: Arc<Mutex<Option<String>>>,
+}
+
+impl MemoryClipboard {
+    /// Get the text in the clipboard. `None` is returned when nothing was written yet.
+    pub fn text(&self) -> Option<String> {
+        self.text.lock().unwrap().clone()
+    }
+
+    /// Set the text to the clipboard as if it was copied by other applications.
+    pub fn set(&self, text: impl Into<String>) {
+        *self.text.lock().unwrap() = Some(text.into());
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn set_text(&mut self, text: &str) -> io::Result<()> {
+        self.set(text);
+        Ok(())
+    }
+
+    fn get_text(&mut self) -> io::Result<Option<String>> {
+        Ok(self.text())
+    }
+}
+
+/// [`Clipboard`] to write text to the terminal's clipboard with [OSC 52][osc52] escape sequence. This works over SSH
+/// as long as the terminal supports OSC 52. Since reading the clipboard requires the terminal's response, this
+/// clipboard is write-only and [`crate::TextArea::paste`] inserts the textarea's internal yank buffer.
+///
+/// The escape sequence is written to stdout by default. Use [`Osc52Clipboard::with_writer`] to write it to other
+/// destinations.
+/// ```
+/// use tui_textarea::{Clipboard, Osc52Clipboard};
+///
+/// let mut clipboard = Osc52Clipboard::with_writer(vec![]);
+/// clipboard.set_text("hello").unwrap();
+/// assert_eq!(clipboard.get_ref(), b"\x1b]52;c;aGVsbG8=\x07");
+/// ```
+///
+/// [osc52]: https://invisible-island.net/xterm/ctlseqs/ctlseqs.html#h3-Operating-System-Commands
+#[derive(Debug)]
+pub struct Osc52Clipboard<W: Write = io::Stdout> {
+    writer: W,
+}
+
+impl Osc52Clipboard {
+    /// Create a new clipboard writing the escape sequence to stdout.
+    pub fn new() -> Self {
+        Self::with_writer(io::stdout())
+    }
+}
+
+impl Default for Osc52Clipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> Osc52Clipboard<W> {
+    /// Create a new clipboard writing the escape sequence to the writer.
+    pub fn with_writer(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Get the reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W: Write> Clipboard for Osc52Clipboard<W> {
+    fn set_text(&mut self, text: &str) -> io::Result<()> {
+        write!(self.writer, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+        self.writer.flush()
+    }
+
+    fn get_text(&mut self) -> io::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(TABLE[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// [`Clipboard`] to run external commands to access the system clipboard. The text is written to the stdin of the copy
+/// command and read from the stdout of the paste command. When the paste command is empty, the clipboard is write-only
+/// and [`crate::TextArea::paste`] inserts the textarea's internal yank buffer.
+/// ```no_run
+/// use tui_textarea::{CommandClipboard, TextArea};
+///
+/// let mut textarea = TextArea::default();
+///
+/// // macOS
+/// textarea.set_clipboard(CommandClipboard::new(["pbcopy"], ["pbpaste"]));
+/// // X11
+/// textarea.set_clipboard(CommandClipboard::new(
+///     ["xclip", "-selection", "clipboard"],
+///     ["xclip", "-selection", "clipboard", "-o"],
+/// ));
+/// // Wayland
+/// textarea.set_clipboard(CommandClipboard::new(["wl-copy"], ["wl-paste", "--no-newline"]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommandClipboard {
+    copy: Vec<String>,
+    paste: Vec<String>,
+}
+
+impl CommandClipboard {
+    /// Create a new clipboard with the copy command and the paste command. The first element of each command is the
+    /// program and the rest are its arguments.
+    pub fn new(
+        copy: impl IntoIterator<Item = impl Into<String>>,
+        paste: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            copy: copy.into_iter().map(Into::into).collect(),
+            paste: paste.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn command(args: &[String]) -> Option<Command> {
+        let (program, args) = args.split_first()?;
+        let mut cmd = Command::new(program);
+        cmd.args(args);
+        Some(cmd)
+    }
+}
+
+fn check_status(status: std::process::ExitStatus, program: &str) -> io::Result<()> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("{program} exited with {status}")))
+    }
+}
+
+impl Clipboard for CommandClipboard {
+    fn set_text(&mut self, text: &str) -> io::Result<()> {
+        let Some(mut cmd) = Self::command(&self.copy) else {
+            return Ok(());
+        };
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        // Close stdin to notify EOF. Wait for the child even on error not to leave a zombie process
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(text.as_bytes()),
+            None => Ok(()),
+        };
+        let status = child.wait()?;
+        written?;
+        check_status(status, &self.copy[0])
+    }
+
+    fn get_text(&mut self) -> io::Result<Option<String>> {
+        let Some(mut cmd) = Self::command(&self.paste) else {
+            return Ok(None);
+        };
+        let output = cmd.stdin(Stdio::null()).stderr(Stdio::null()).output()?;
+        check_status(output.status, &self.paste[0])?;
+        String::from_utf8(output.stdout)
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_base64() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+            ("あ\n", "44GCCg=="),
+        ] {
+            assert_eq!(base64(input.as_bytes()), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn poisoned_shared_clipboard() {
+        struct Panicking;
+        impl Clipboard for Panicking {
+            fn set_text(&mut self, _text: &str) -> io::Result<()> {
+                panic!("oops");
+            }
+            fn get_text(&mut self) -> io::Result<Option<String>> {
+                Ok(None)
+            }
+        }
+
+        let mut clipboard = SharedClipboard::new(Panicking);
+        let mut cloned = clipboard.clone();
+        let joined = std::thread::spawn(move || cloned.set_text("a")).join();
+        assert!(joined.is_err());
+        assert!(clipboard.set_text("a").is_err());
+        assert!(clipboard.get_changed_text().is_err());
+    }
+}
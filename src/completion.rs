@@ -0,0 +1,192 @@
+use crate::ratatui::buffer::Buffer;
+use crate::ratatui::layout::Rect;
+use crate::ratatui::style::{Color, Modifier, Style};
+use crate::ratatui::widgets::Widget;
+use std::fmt;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthStr as _;
+
+/// Provider of completion candidates for [`TextArea`](crate::TextArea). The provider is queried with the word before
+/// the cursor by [`TextArea::complete`](crate::TextArea::complete) and while the completion popup is open.
+///
+/// Closures taking the prefix and returning candidates implement this trait. [`WordList`] is a provider with a static
+/// list of words.
+/// ```
+/// use tui_textarea::TextArea;
+///
+/// let mut textarea = TextArea::from(["fo"]);
+/// textarea.set_completion_provider(|prefix: &str| {
+///     ["foo", "foobar", "bar"]
+///         .into_iter()
+///         .filter(|w| w.starts_with(prefix))
+///         .map(String::from)
+///         .collect()
+/// });
+///
+/// textarea.move_cursor(tui_textarea::CursorMove::End);
+/// assert!(textarea.complete());
+/// assert_eq!(textarea.completion_candidates(), ["foo", "foobar"]);
+/// ```
+pub trait CompletionProvider {
+    /// Return the candidates completing the prefix. The prefix is the word before the cursor and may be empty. The
+    /// candidates are shown in the returned order.
+    fn complete(&self, prefix: &str) -> Vec<String>;
+}
+
+impl<F: Fn(&str) -> Vec<String>> CompletionProvider for F {
+    fn complete(&self, prefix: &str) -> Vec<String> {
+        self(prefix)
+    }
+}
+
+/// [`CompletionProvider`] with a static list of words. Words starting with the prefix are suggested in the order of
+/// the list. The word which is equal to the prefix is not suggested since completing it changes nothing.
+/// ```
+/// use tui_textarea::{CompletionProvider, WordList};
+///
+/// let words = WordList::new(["select", "set", "from"]);
+/// assert_eq!(words.complete("se"), ["select", "set"]);
+/// assert_eq!(words.complete("set"), Vec::<String>::new());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordList(Vec<String>);
+
+impl WordList {
+    /// Create a new provider with the words.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self(words.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for WordList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl CompletionProvider for WordList {
+    fn complete(&self, prefix: &str) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|w| w.len() > prefix.len() && w.starts_with(prefix))
+            .cloned()
+            .collect()
+    }
+}
+
+// Completion provider shared by clones of the textarea
+#[derive(Clone)]
+pub(crate) struct SharedProvider(Arc<dyn CompletionProvider + Send + Sync>);
+
+impl SharedProvider {
+    pub fn new(provider: impl CompletionProvider + Send + Sync + 'static) -> Self {
+        Self(Arc::new(provider))
+    }
+
+    pub fn complete(&self, prefix: &str) -> Vec<String> {
+        self.0.complete(prefix)
+    }
+}
+
+impl fmt::Debug for SharedProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedProvider")
+    }
+}
+
+// Ongoing completion. `start` is the position of the prefix being completed. The prefix ends at the cursor.
+#[derive(Clone, Debug)]
+pub(crate) struct Completion {
+    pub candidates: Vec<String>,
+    pub selected: usize,
+    pub start: (usize, usize),
+}
+
+impl Completion {
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.candidates.len();
+    }
+
+    pub fn select_prev(&mut self) {
+        let len = self.candidates.len();
+        self.selected = (self.selected + len - 1) % len;
+    }
+
+    pub fn selected(&self) -> &str {
+        &self.candidates[self.selected]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PopupStyle {
+    pub style: Style,
+    pub selected: Style,
+    pub height: u16,
+}
+
+impl Default for PopupStyle {
+    fn default() -> Self {
+        Self {
+            style: Style::default().bg(Color::DarkGray),
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            height: 8,
+        }
+    }
+}
+
+// Popup listing the completion candidates. The popup is placed below the screen position of the prefix, or above it
+// when there is no space below.
+pub(crate) struct CompletionPopup<'a> {
+    pub candidates: &'a [String],
+    pub selected: usize,
+    pub anchor: Option<(u16, u16)>,
+    pub style: PopupStyle,
+}
+
+impl CompletionPopup<'_> {
+    fn rect(&self, area: Rect) -> Option<Rect> {
+        let (x, y) = self.anchor?;
+        let right = area.x.saturating_add(area.width);
+        let bottom = area.y.saturating_add(area.height);
+        if self.candidates.is_empty() || x < area.x || y < area.y || x >= right || y >= bottom {
+            return None;
+        }
+
+        // One column of padding on each side so that the candidates are aligned with the prefix
+        let max_width = self.candidates.iter().map(|c| c.width()).max().unwrap_or(0);
+        let width = (max_width as u16).saturating_add(2).min(area.width);
+        let height = (self.candidates.len() as u16).min(self.style.height);
+        let below = bottom - y - 1;
+        let above = y - area.y;
+        let (y, height) = if height <= below || below >= above {
+            (y + 1, height.min(below))
+        } else {
+            (y - height.min(above), height.min(above))
+        };
+        let x = x.saturating_sub(1).max(area.x).min(right - width);
+        (height > 0).then_some(Rect::new(x, y, width, height))
+    }
+}
+
+impl Widget for CompletionPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(rect) = self.rect(area) else {
+            return;
+        };
+
+        // Scroll the list so that the selected candidate is visible
+        let height = rect.height as usize;
+        let top = (self.selected + 1).saturating_sub(height);
+        let width = rect.width as usize;
+        for (i, candidate) in self.candidates[top..].iter().take(height).enumerate() {
+            let style = if top + i == self.selected {
+                self.style.style.patch(self.style.selected)
+            } else {
+                self.style.style
+            };
+            let y = rect.y + i as u16;
+            buf.set_stringn(rect.x, y, " ".repeat(width), width, style);
+            buf.set_stringn(rect.x + 1, y, candidate, width.saturating_sub(2), style);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::buffer::TextBuffer;
 use crate::widget::Viewport;
 use crate::word::{
     find_word_inclusive_end_forward, find_word_start_backward, find_word_start_forward,
@@ -262,7 +263,7 @@ impl CursorMove {
     pub(crate) fn next_cursor(
         &self,
         (row, col): (usize, usize),
-        lines: &[String],
+        lines: &impl TextBuffer,
         viewport: &Viewport,
         wrapped: Option<&[WrappedLine]>,
     ) -> Option<(usize, usize)> {
@@ -0,0 +1,104 @@
+use crate::history::LineChange;
+use crate::ratatui::style::{Color, Modifier, Style};
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Severity of [`Diagnostic`]. Severities are ordered from [`Severity::Hint`] (the lowest) to [`Severity::Error`] (the
+/// highest).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Severity {
+    /// Hint to improve the text.
+    Hint,
+    /// Informational message.
+    Info,
+    /// Warning.
+    Warning,
+    /// Error.
+    Error,
+}
+
+impl Severity {
+    pub(crate) const ALL: [Self; 4] = [Self::Hint, Self::Info, Self::Warning, Self::Error];
+
+    pub(crate) fn default_style(self) -> Style {
+        let color = match self {
+            Self::Hint => Color::Cyan,
+            Self::Info => Color::Blue,
+            Self::Warning => Color::Yellow,
+            Self::Error => Color::Red,
+        };
+        Style::default()
+            .fg(color)
+            .add_modifier(Modifier::UNDERLINED)
+    }
+}
+
+/// Diagnostic attached to a range of the text by [`TextArea::add_diagnostic`](crate::TextArea::add_diagnostic). The
+/// range is rendered with the style of its severity and the message can be shown after the end of the line. Positions
+/// are 0-base `(row, col)` pairs like the cursor position and `end` is exclusive.
+/// ```
+/// use tui_textarea::{Diagnostic, Severity};
+///
+/// let d = Diagnostic::new((0, 7), (0, 12), Severity::Error, "unknown column `nmae`");
+/// assert_eq!(d.start, (0, 7));
+/// assert_eq!(d.end, (0, 12));
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    /// Start position of the range.
+    pub start: (usize, usize),
+    /// End position of the range (exclusive).
+    pub end: (usize, usize),
+    /// Severity of the diagnostic.
+    pub severity: Severity,
+    /// Message of the diagnostic.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Create a new diagnostic for the range from `start` to `end`.
+    pub fn new(
+        start: (usize, usize),
+        end: (usize, usize),
+        severity: Severity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn contains(&self, pos: (usize, usize)) -> bool {
+        // Empty range contains the position at the range
+        self.start <= pos && (pos < self.end || pos == self.start)
+    }
+
+    // Shift the rows of the diagnostic placed after the edited lines. Diagnostics overlapping with the edit are kept
+    // as-is since they are usually updated by the application after the edit.
+    pub(crate) fn shift(&mut self, change: LineChange) {
+        let LineChange {
+            row,
+            removed,
+            inserted,
+        } = change;
+        if self.start.0 >= row + removed {
+            self.start.0 = self.start.0 + inserted - removed;
+            self.end.0 = self.end.0 + inserted - removed;
+        }
+    }
+}
+
+// Style of the message shown after the end of the line
+pub(crate) fn message_style(style: Style) -> Style {
+    style
+        .remove_modifier(Modifier::UNDERLINED)
+        .add_modifier(Modifier::DIM)
+}
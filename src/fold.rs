@@ -0,0 +1,223 @@
+use crate::buffer::TextBuffer;
+use crate::history::LineChange;
+use crate::wrap::WrappedLine;
+
+// Folded ranges of rows. Each range `(start, end)` is inclusive and the lines `start + 1..=end` are hidden behind the
+// line at `start`. Ranges are sorted by their start rows. Ranges can be nested but never partially overlap.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Folds(Vec<(usize, usize)>);
+
+impl Folds {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn ranges(&self) -> &[(usize, usize)] {
+        &self.0
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn add(&mut self, start: usize, end: usize) -> bool {
+        if start >= end || self.0.contains(&(start, end)) {
+            return false;
+        }
+        // Folds partially overlapping with the new fold are removed
+        self.0.retain(|&(s, e)| {
+            let nested = start <= s && e <= end || s <= start && end <= e;
+            let disjoint = e < start || end < s;
+            nested || disjoint
+        });
+        let i = self
+            .0
+            .partition_point(|&(s, e)| s < start || s == start && e > end);
+        self.0.insert(i, (start, end));
+        true
+    }
+
+    // Remove the innermost fold containing the row
+    pub fn remove_at(&mut self, row: usize) -> bool {
+        let innermost = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, (s, e))| *s <= row && row <= *e)
+            .min_by_key(|(_, (s, e))| e - s)
+            .map(|(i, _)| i);
+        if let Some(i) = innermost {
+            self.0.remove(i);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn contains(&self, row: usize) -> bool {
+        self.0.iter().any(|&(s, e)| s <= row && row <= e)
+    }
+
+    // Shift or resize folds following the edit. Folds which the edit partially overlaps are removed.
+    pub fn update(&mut self, change: LineChange) {
+        let LineChange {
+            row,
+            removed,
+            inserted,
+        } = change;
+        let last = row + removed - 1;
+        self.0.retain_mut(|(s, e)| {
+            if last < *s {
+                *s = *s + inserted - removed;
+                *e = *e + inserted - removed;
+            } else if *s <= row && last <= *e {
+                *e = *e + inserted - removed;
+            } else if row <= *e {
+                return false;
+            }
+            *s < *e
+        });
+    }
+
+    // Outermost folds which are closed on the screen. Folds hiding the cursor row are open.
+    fn closed(&self, cursor_row: usize) -> Vec<(usize, usize)> {
+        let mut closed: Vec<(usize, usize)> = vec![];
+        for &(s, e) in &self.0 {
+            if s < cursor_row && cursor_row <= e {
+                continue;
+            }
+            if let Some(&(_, prev_end)) = closed.last() {
+                if s <= prev_end {
+                    continue; // Nested in the previous fold
+                }
+            }
+            closed.push((s, e));
+        }
+        closed
+    }
+
+    // Remove visual rows hidden by the closed folds and mark the last visual row of each fold's first line
+    pub fn apply(&self, rows: Vec<WrappedLine>, cursor_row: usize) -> Vec<WrappedLine> {
+        let closed = self.closed(cursor_row);
+        if closed.is_empty() {
+            return rows;
+        }
+        let mut folds = closed.into_iter().peekable();
+        let mut visible = Vec::with_capacity(rows.len());
+        for mut row in rows {
+            while folds.peek().is_some_and(|&(_, e)| e < row.row) {
+                folds.next();
+            }
+            match folds.peek() {
+                Some(&(s, _)) if s < row.row => continue,
+                Some(&(s, e)) if s == row.row && row.last_in_row => row.folded = e - s + 1,
+                _ => {}
+            }
+            visible.push(row);
+        }
+        visible
+    }
+}
+
+// Width of leading whitespaces. `None` for blank lines.
+fn indent_width(line: &str, tab_len: u8) -> Option<usize> {
+    let mut width = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => width += 1,
+            '\t' => width += tab_len.max(1) as usize,
+            c if c.is_whitespace() => width += 1,
+            _ => return Some(width),
+        }
+    }
+    None
+}
+
+// Range of the line and the following lines indented deeper than the line. Blank lines in the region are included but
+// trailing blank lines are not.
+pub(crate) fn indent_region(
+    lines: &impl TextBuffer,
+    row: usize,
+    tab_len: u8,
+) -> Option<(usize, usize)> {
+    let base = indent_width(&lines[row], tab_len)?;
+    let mut end = row;
+    for r in row + 1..lines.len() {
+        match indent_width(&lines[r], tab_len) {
+            Some(w) if w > base => end = r,
+            Some(_) => break,
+            None => {}
+        }
+    }
+    (end > row).then_some((row, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_remove_folds() {
+        let mut f = Folds::default();
+        assert!(f.add(2, 5));
+        assert!(f.add(3, 4));
+        assert!(f.add(0, 8));
+        assert!(!f.add(0, 8));
+        assert!(!f.add(1, 1));
+        assert_eq!(f.ranges(), [(0, 8), (2, 5), (3, 4)]);
+
+        // Partially overlapping fold replaces existing folds
+        assert!(f.add(4, 6));
+        assert_eq!(f.ranges(), [(0, 8), (4, 6)]);
+
+        assert!(f.remove_at(5));
+        assert_eq!(f.ranges(), [(0, 8)]);
+        assert!(f.remove_at(0));
+        assert!(!f.remove_at(0));
+    }
+
+    #[test]
+    fn update_folds_on_edit() {
+        let tests = [
+            // (row, removed, inserted), expected
+            ((0, 1, 2), &[(3, 5), (8, 9)][..]),
+            ((1, 1, 1), &[(2, 4), (7, 8)][..]),
+            ((2, 1, 3), &[(2, 6), (9, 10)][..]),
+            ((4, 1, 2), &[(2, 5), (8, 9)][..]),
+            ((3, 2, 1), &[(2, 3), (6, 7)][..]),
+            ((1, 2, 1), &[(6, 7)][..]),
+            ((4, 2, 1), &[(6, 7)][..]),
+            ((5, 1, 2), &[(2, 4), (8, 9)][..]),
+            ((7, 2, 1), &[(2, 4)][..]),
+        ];
+        for ((row, removed, inserted), expected) in tests {
+            let mut f = Folds::default();
+            f.add(2, 4);
+            f.add(7, 8);
+            f.update(LineChange {
+                row,
+                removed,
+                inserted,
+            });
+            assert_eq!(f.ranges(), expected, "{row}, {removed}, {inserted}");
+        }
+    }
+
+    #[test]
+    fn indent_regions() {
+        let lines = vec![
+            "fn main() {".to_string(),
+            "    if x {".to_string(),
+            "\t  y();".to_string(),
+            "".to_string(),
+            "    }".to_string(),
+            "".to_string(),
+            "}".to_string(),
+        ];
+        assert_eq!(indent_region(&lines, 0, 4), Some((0, 4)));
+        assert_eq!(indent_region(&lines, 1, 4), Some((1, 2)));
+        assert_eq!(indent_region(&lines, 2, 4), None);
+        assert_eq!(indent_region(&lines, 3, 4), None);
+        assert_eq!(indent_region(&lines, 6, 4), None);
+    }
+}
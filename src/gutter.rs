@@ -0,0 +1,280 @@
+use crate::history::LineChange;
+use crate::ratatui::style::{Color, Style};
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthChar as _;
+use unicode_width::UnicodeWidthStr as _;
+
+/// Specify how line numbers are shown in the gutter. See
+/// [`TextArea::set_line_numbers`](crate::TextArea::set_line_numbers).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LineNumbers {
+    /// Show the 1-base line number of each line (default).
+    #[default]
+    Absolute,
+    /// Show the distance from the cursor line. The cursor line shows `0` like Vim's `relativenumber` option.
+    Relative,
+    /// Show the distance from the cursor line, but the cursor line shows its line number like Vim's `number` and
+    /// `relativenumber` options both enabled.
+    Hybrid,
+}
+
+impl LineNumbers {
+    pub(crate) fn number(self, row: usize, cursor_row: usize) -> usize {
+        match self {
+            Self::Absolute => row + 1,
+            Self::Hybrid if row == cursor_row => row + 1,
+            Self::Relative | Self::Hybrid => row.abs_diff(cursor_row),
+        }
+    }
+}
+
+/// Sign shown in the gutter at the left of a line. Signs are placed by
+/// [`TextArea::set_sign`](crate::TextArea::set_sign) to mark lines with errors, warnings, changes in VCS, and so on.
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tui_textarea::Sign;
+///
+/// let bookmark = Sign::new("*", Style::default().fg(Color::Cyan));
+/// assert_eq!(bookmark.text, "*");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sign {
+    /// Text of the sign. Usually one or two characters.
+    pub text: String,
+    /// Style of the sign.
+    pub style: Style,
+}
+
+impl Sign {
+    /// Create a new sign with the text and the style.
+    pub fn new(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+
+    /// Sign for lines with errors. `E` in red.
+    pub fn error() -> Self {
+        Self::new("E", Style::default().fg(Color::Red))
+    }
+
+    /// Sign for lines with warnings. `W` in yellow.
+    pub fn warning() -> Self {
+        Self::new("W", Style::default().fg(Color::Yellow))
+    }
+
+    /// Sign for lines added in VCS. `+` in green.
+    pub fn added() -> Self {
+        Self::new("+", Style::default().fg(Color::Green))
+    }
+
+    /// Sign for lines modified in VCS. `~` in blue.
+    pub fn modified() -> Self {
+        Self::new("~", Style::default().fg(Color::Blue))
+    }
+
+    /// Sign for lines next to removed lines in VCS. `-` in red.
+    pub fn removed() -> Self {
+        Self::new("-", Style::default().fg(Color::Red))
+    }
+}
+
+/// Label shown in the line number column of the gutter instead of line numbers. Closures taking the 0-base row of the
+/// line and the 0-base row of the cursor and returning the label implement this trait. See
+/// [`TextArea::set_line_label`](crate::TextArea::set_line_label).
+/// ```
+/// use ratatui::style::Style;
+/// use tui_textarea::TextArea;
+///
+/// let mut textarea = TextArea::from(["a", "b", "c"]);
+/// textarea.set_line_number_style(Style::default());
+///
+/// // Show line numbers in hexadecimal
+/// textarea.set_line_label(2, |row: usize, _cursor: usize| format!("{:x}", row + 1));
+/// ```
+pub trait LineLabel {
+    /// Return the label of the line at `row`.
+    fn label(&self, row: usize, cursor_row: usize) -> String;
+}
+
+impl<F: Fn(usize, usize) -> String> LineLabel for F {
+    fn label(&self, row: usize, cursor_row: usize) -> String {
+        self(row, cursor_row)
+    }
+}
+
+// Custom line label with the width of the label column. The labeler is shared by clones of the textarea.
+#[derive(Clone)]
+pub(crate) struct CustomLabel {
+    pub width: u8,
+    labeler: Arc<dyn LineLabel + Send + Sync>,
+}
+
+impl CustomLabel {
+    pub fn new(width: u8, labeler: impl LineLabel + Send + Sync + 'static) -> Self {
+        Self {
+            width,
+            labeler: Arc::new(labeler),
+        }
+    }
+
+    // Label truncated to fit in the column
+    pub fn label(&self, row: usize, cursor_row: usize) -> String {
+        let label = self.labeler.label(row, cursor_row);
+        if label.width() <= self.width as usize {
+            return label;
+        }
+        let mut width = 0;
+        label
+            .chars()
+            .take_while(|c| {
+                width += c.width().unwrap_or(0);
+                width <= self.width as usize
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for CustomLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomLabel")
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+// Widths of the columns in the gutter. `line_number` is `None` when line numbers are not shown.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct GutterWidth {
+    pub signs: u8,
+    pub line_number: Option<u8>,
+}
+
+impl GutterWidth {
+    // Total width including margins. The line number column has one margin on each side. When only signs are shown,
+    // one margin is put between the signs and the text.
+    pub fn total(self) -> usize {
+        let signs = self.signs as usize;
+        match self.line_number {
+            Some(width) => signs + width as usize + 2,
+            None if signs > 0 => signs + 1,
+            None => 0,
+        }
+    }
+}
+
+// Signs placed at rows. Signs follow edits of the text like folds.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Signs(BTreeMap<usize, Sign>);
+
+impl Signs {
+    pub fn get(&self, row: usize) -> Option<&Sign> {
+        self.0.get(&row)
+    }
+
+    pub fn insert(&mut self, row: usize, sign: Sign) -> Option<Sign> {
+        self.0.insert(row, sign)
+    }
+
+    pub fn remove(&mut self, row: usize) -> Option<Sign> {
+        self.0.remove(&row)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    // Width of the sign column. The column is hidden when no sign is placed.
+    pub fn width(&self) -> u8 {
+        self.0
+            .values()
+            .map(|s| s.text.width().min(u8::MAX as usize) as u8)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Signs in the replaced lines stay when the line still exists after the edit. Signs after the edit are shifted.
+    pub fn update(&mut self, change: LineChange) {
+        let LineChange {
+            row,
+            removed,
+            inserted,
+        } = change;
+        if removed == inserted || self.0.range(row..).next().is_none() {
+            return;
+        }
+        let mut after = self.0.split_off(&row);
+        let following = after.split_off(&(row + removed));
+        for (r, sign) in after {
+            if r < row + inserted {
+                self.0.insert(r, sign);
+            }
+        }
+        for (r, sign) in following {
+            self.0.insert(r + inserted - removed, sign);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_number_modes() {
+        let tests = [
+            (LineNumbers::Absolute, [1, 2, 3, 4]),
+            (LineNumbers::Relative, [2, 1, 0, 1]),
+            (LineNumbers::Hybrid, [2, 1, 3, 1]),
+        ];
+        for (mode, want) in tests {
+            let have: Vec<_> = (0..4).map(|row| mode.number(row, 2)).collect();
+            assert_eq!(have, want, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn update_signs_on_edit() {
+        let tests = [
+            // (row, removed, inserted), expected
+            ((0, 1, 2), &[2, 3, 4, 5][..]),
+            ((2, 1, 3), &[1, 2, 5, 6][..]),
+            ((1, 2, 1), &[1, 2, 3][..]),
+            ((2, 2, 1), &[1, 2, 3][..]),
+            ((0, 3, 1), &[1, 2][..]),
+            ((5, 1, 2), &[1, 2, 3, 4][..]),
+            ((2, 1, 1), &[1, 2, 3, 4][..]),
+        ];
+        for ((row, removed, inserted), expected) in tests {
+            let mut s = Signs::default();
+            for r in [1, 2, 3, 4] {
+                s.insert(r, Sign::error());
+            }
+            s.update(LineChange {
+                row,
+                removed,
+                inserted,
+            });
+            let rows: Vec<_> = s.0.keys().copied().collect();
+            assert_eq!(rows, expected, "{row}, {removed}, {inserted}");
+        }
+    }
+
+    #[test]
+    fn truncate_custom_label() {
+        let label = CustomLabel::new(3, |row: usize, _: usize| format!("L{row}"));
+        assert_eq!(label.label(5, 0), "L5");
+        assert_eq!(label.label(1234, 0), "L12");
+        let label = CustomLabel::new(3, |_: usize, _: usize| "あいう".to_string());
+        assert_eq!(label.label(0, 0), "あ");
+    }
+}
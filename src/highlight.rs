@@ -8,6 +8,7 @@ use std::cmp::Ordering;
 #[cfg(feature = "tuirs")]
 use tui::text::Spans as Line;
 use unicode_width::UnicodeWidthChar as _;
+use unicode_width::UnicodeWidthStr as _;
 
 enum Boundary {
     Cursor(Style),
@@ -15,6 +16,10 @@ enum Boundary {
     #[cfg(feature = "search")]
     Search(Style),
     Custom(Style, u8), // style, priority
+    Syntax(Style),
+    SyntaxEnd,
+    Diagnostic(Style),
+    DiagnosticEnd,
     End,
 }
 
@@ -27,7 +32,9 @@ impl Boundary {
                 Boundary::Search(_) => 20,
                 Boundary::Select(_) => 10,
                 Boundary::Custom(_, p) => *p,
-                Boundary::End => 0,
+                Boundary::Diagnostic(_) => 2,
+                Boundary::Syntax(_) => 1,
+                Boundary::SyntaxEnd | Boundary::DiagnosticEnd | Boundary::End => 0,
             }
         }
         rank(self).cmp(&rank(other))
@@ -40,7 +47,11 @@ impl Boundary {
             #[cfg(feature = "search")]
             Boundary::Search(s) => Some(*s),
             Boundary::Custom(s, _) => Some(*s),
-            Boundary::End => None,
+            Boundary::Syntax(_)
+            | Boundary::SyntaxEnd
+            | Boundary::Diagnostic(_)
+            | Boundary::DiagnosticEnd
+            | Boundary::End => None,
         }
     }
 }
@@ -107,6 +118,9 @@ pub struct LineHighlighter<'a> {
     mask: Option<char>,
     select_at_end: bool,
     select_style: Style,
+    search_at_end: Option<Style>,
+    suffix: Vec<Span<'a>>,
+    virtual_texts: Vec<(usize, Span<'a>)>,
 }
 
 impl<'a> LineHighlighter<'a> {
@@ -128,20 +142,44 @@ impl<'a> LineHighlighter<'a> {
             mask,
             select_at_end: false,
             select_style,
+            search_at_end: None,
+            suffix: vec![],
+            virtual_texts: vec![],
         }
     }
 
-    pub fn line_number(&mut self, row: usize, lnum_len: u8, style: Style) {
-        let pad = spaces(lnum_len - num_digits(row + 1) + 1);
+    pub fn line_number(&mut self, num: usize, lnum_len: u8, style: Style) {
+        let pad = spaces(lnum_len - num_digits(num) + 1);
         self.spans
-            .push(Span::styled(format!("{}{} ", pad, row + 1), style));
+            .push(Span::styled(format!("{}{} ", pad, num), style));
+    }
+
+    // Label in the line number column. The label must fit in the width
+    pub fn line_label(&mut self, label: &str, width: u8, style: Style) {
+        let pad = spaces(width.saturating_sub(label.width() as u8) + 1);
+        self.spans
+            .push(Span::styled(format!("{}{} ", pad, label), style));
     }
 
     pub fn line_number_placeholder(&mut self, lnum_len: u8, style: Style) {
         self.spans.push(Span::styled(spaces(lnum_len + 2), style));
     }
 
+    // Sign padded to the width of the sign column
+    pub fn sign(&mut self, text: &str, width: u8, style: Style) {
+        let pad = spaces(width.saturating_sub(text.width() as u8));
+        self.spans.push(Span::styled(text.to_string(), style));
+        if !pad.is_empty() {
+            self.spans.push(Span::raw(pad));
+        }
+    }
+
     pub fn cursor_line(&mut self, cursor_col: usize, style: Style) {
+        self.cursor(cursor_col);
+        self.style_begin = style;
+    }
+
+    pub fn cursor(&mut self, cursor_col: usize) {
         if let Some((start, c)) = self.line.char_indices().nth(cursor_col) {
             self.boundaries
                 .push((Boundary::Cursor(self.cursor_style), start));
@@ -149,7 +187,6 @@ impl<'a> LineHighlighter<'a> {
         } else {
             self.cursor_at_end = true;
         }
-        self.style_begin = style;
     }
 
     pub fn set_line_style(&mut self, style: Style) {
@@ -166,6 +203,55 @@ impl<'a> LineHighlighter<'a> {
         }
     }
 
+    // Highlight the newline at the end of line when a match continues to the next line
+    #[cfg(feature = "search")]
+    pub fn search_at_end(&mut self, style: Style) {
+        self.search_at_end = Some(style);
+    }
+
+    // Marker appended after the line which is the placeholder of folded lines
+    pub fn fold_marker(&mut self, marker: String, style: Style) {
+        self.suffix.push(Span::styled(marker, style));
+    }
+
+    // Text rendered before the byte offset without being a part of the line
+    pub fn virtual_text(&mut self, offset: usize, text: &str, style: Style) {
+        self.virtual_texts
+            .push((offset, Span::styled(text.to_string(), style)));
+    }
+
+    // Message of the diagnostic shown after the end of the line
+    pub fn diagnostic_message(&mut self, message: &str, style: Style) {
+        self.suffix.push(Span::raw("  "));
+        self.suffix.push(Span::styled(message.to_string(), style));
+    }
+
+    // Underline styles of diagnostics. Ranges can overlap and later ranges are put on top of earlier ones.
+    pub fn diagnostics(&mut self, ranges: &[(usize, usize, Style)]) {
+        let mut points: Vec<usize> = ranges.iter().flat_map(|&(s, e, _)| [s, e]).collect();
+        points.sort_unstable();
+        points.dedup();
+        let mut segments: Vec<(usize, usize, Style)> = vec![];
+        for w in points.windows(2) {
+            let (start, end) = (w[0], w[1]);
+            let top = ranges
+                .iter()
+                .rev()
+                .find(|&&(s, e, _)| s <= start && end <= e);
+            let Some(&(_, _, style)) = top else {
+                continue;
+            };
+            match segments.last_mut() {
+                Some((_, e, s)) if *e == start && *s == style => *e = end,
+                _ => segments.push((start, end, style)),
+            }
+        }
+        for (start, end, style) in segments {
+            self.boundaries.push((Boundary::Diagnostic(style), start));
+            self.boundaries.push((Boundary::DiagnosticEnd, end));
+        }
+    }
+
     // Shared code for selection and custom highlights
     fn multiline_highlight(
         &mut self,
@@ -244,6 +330,15 @@ impl<'a> LineHighlighter<'a> {
         );
     }
 
+    pub fn syntax(&mut self, spans: impl Iterator<Item = (usize, usize, Style)>) {
+        for (start, end, style) in spans {
+            if start < end {
+                self.boundaries.push((Boundary::Syntax(style), start));
+                self.boundaries.push((Boundary::SyntaxEnd, end));
+            }
+        }
+    }
+
     pub fn into_spans(self) -> Line<'a> {
         let Self {
             line,
@@ -256,19 +351,51 @@ impl<'a> LineHighlighter<'a> {
             mask,
             select_at_end,
             select_style,
+            search_at_end,
+            suffix,
+            mut virtual_texts,
         } = self;
+        let end_style = if cursor_at_end {
+            Some(cursor_style)
+        } else if search_at_end.is_some() {
+            search_at_end
+        } else {
+            select_at_end.then_some(select_style)
+        };
         let mut builder = DisplayTextBuilder::new(tab_len, mask);
 
-        if boundaries.is_empty() {
-            let built = builder.build(line);
-            if !built.is_empty() {
-                spans.push(Span::styled(built, style_begin));
+        // Virtual texts keep the line style such as cursor line
+        virtual_texts.sort_by_key(|(offset, _)| *offset);
+        let mut virtual_texts = virtual_texts
+            .into_iter()
+            .map(|(offset, span)| {
+                (
+                    offset,
+                    Span::styled(span.content, style_begin.patch(span.style)),
+                )
+            })
+            .peekable();
+        let mut push_text = |spans: &mut Vec<Span<'a>>, start: usize, end: usize, style: Style| {
+            let mut start = start;
+            while let Some((offset, span)) = virtual_texts.next_if(|(o, _)| *o < end) {
+                if start < offset {
+                    spans.push(Span::styled(builder.build(&line[start..offset]), style));
+                    start = offset;
+                }
+                spans.push(span);
             }
-            if cursor_at_end {
-                spans.push(Span::styled(" ", cursor_style));
-            } else if select_at_end {
-                spans.push(Span::styled(" ", select_style));
+            if start < end {
+                spans.push(Span::styled(builder.build(&line[start..end]), style));
             }
+        };
+
+        if boundaries.is_empty() {
+            push_text(&mut spans, 0, line.len(), style_begin);
+            if let Some(style) = end_style {
+                spans.push(Span::styled(" ", style));
+            }
+            spans.extend(virtual_texts.map(|(_, span)| span));
+            spans.extend(suffix);
             return Line::from(spans);
         }
 
@@ -277,33 +404,52 @@ impl<'a> LineHighlighter<'a> {
             o => o,
         });
 
+        // Syntax highlight and diagnostics are the base layer under other highlights. It keeps the line style such as
+        // cursor line.
+        let base = |syntax: Option<Style>, diagnostic: Option<Style>| {
+            let style = syntax.map_or(style_begin, |s| style_begin.patch(s));
+            diagnostic.map_or(style, |s| style.patch(s))
+        };
+        let mut syntax = None;
+        let mut diagnostic = None;
+        let mut overlay = None;
         let mut style = style_begin;
         let mut start = 0;
         let mut stack = vec![];
 
         for (next_boundary, end) in boundaries {
             if start < end {
-                spans.push(Span::styled(builder.build(&line[start..end]), style));
+                push_text(&mut spans, start, end, style);
             }
 
-            style = if let Some(s) = next_boundary.style() {
-                stack.push(style);
-                s
-            } else {
-                stack.pop().unwrap_or(style_begin)
-            };
+            match next_boundary {
+                Boundary::Syntax(s) => syntax = Some(s),
+                Boundary::SyntaxEnd => syntax = None,
+                Boundary::Diagnostic(s) => diagnostic = Some(s),
+                Boundary::DiagnosticEnd => diagnostic = None,
+                b => {
+                    if let Some(s) = b.style() {
+                        stack.push(overlay);
+                        overlay = Some(s);
+                    } else {
+                        overlay = stack.pop().flatten();
+                    }
+                }
+            }
+            style = overlay.unwrap_or_else(|| base(syntax, diagnostic));
             start = end;
         }
 
         if start != line.len() {
-            spans.push(Span::styled(builder.build(&line[start..]), style));
+            push_text(&mut spans, start, line.len(), style);
         }
 
-        if cursor_at_end {
-            spans.push(Span::styled(" ", cursor_style));
-        } else if select_at_end {
-            spans.push(Span::styled(" ", select_style));
+        if let Some(style) = end_style {
+            spans.push(Span::styled(" ", style));
         }
+        // Virtual texts at the end of line are rendered after the cursor
+        spans.extend(virtual_texts.map(|(_, span)| span));
+        spans.extend(suffix);
 
         Line::from(spans)
     }
@@ -315,7 +461,6 @@ mod tests {
     use super::*;
     use crate::ratatui::style::Color;
     use std::fmt::Debug;
-    use unicode_width::UnicodeWidthStr as _;
 
     fn build(text: &'static str, tab: u8, mask: Option<char>) -> Cow<'static, str> {
         DisplayTextBuilder::new(tab, mask).build(text)
@@ -425,6 +570,7 @@ mod tests {
     const SEL: Style = Style::new().bg(Color::Blue);
     const LINE: Style = Style::new().bg(Color::Gray);
     const LNUM: Style = Style::new().bg(Color::Yellow);
+    const SYN: Style = Style::new().fg(Color::Magenta);
 
     #[test]
     fn into_spans_normal_line() {
@@ -463,18 +609,90 @@ mod tests {
     #[test]
     fn into_spans_line_number() {
         let tests = [
-            (0, 1, &[(" 1 ", LNUM)][..]),
-            (123, 3, &[(" 124 ", LNUM)][..]),
-            (123, 5, &[("   124 ", LNUM)][..]),
+            (1, 1, &[(" 1 ", LNUM)][..]),
+            (124, 3, &[(" 124 ", LNUM)][..]),
+            (124, 5, &[("   124 ", LNUM)][..]),
         ];
         for test in tests {
-            let (row, len, want) = test;
+            let (num, len, want) = test;
             let mut lh = LineHighlighter::new("", CUR, 4, None, SEL);
-            lh.line_number(row, len, LNUM);
+            lh.line_number(num, len, LNUM);
             assert_spans(lh, want, test);
         }
     }
 
+    #[test]
+    fn into_spans_virtual_text() {
+        let mut lh = LineHighlighter::new("abc", CUR, 4, None, SEL);
+        lh.set_line_style(LINE);
+        lh.virtual_text(3, "!", LNUM);
+        lh.virtual_text(1, "<>", LNUM);
+        lh.selection(0, 0, 0, 0, 2);
+        assert_spans(
+            lh,
+            &[
+                ("a", SEL),
+                ("<>", LINE.patch(LNUM)),
+                ("b", SEL),
+                ("c", LINE),
+                ("!", LINE.patch(LNUM)),
+            ],
+            (),
+        );
+    }
+
+    #[test]
+    fn into_spans_gutter() {
+        let mut lh = LineHighlighter::new("", CUR, 4, None, SEL);
+        lh.sign("E", 2, SEL);
+        lh.line_label("0x1", 4, LNUM);
+        assert_spans(lh, &[("E", SEL), (" ", DEFAULT), ("  0x1 ", LNUM)], ());
+    }
+
+    #[test]
+    fn into_spans_diagnostics() {
+        const WARN: Style = Style::new().fg(Color::Yellow);
+        const ERR: Style = Style::new().fg(Color::Red);
+        let tests = [
+            (
+                &[(1, 3, ERR)][..],
+                &[("a", DEFAULT), ("bc", ERR), ("def", DEFAULT)][..],
+            ),
+            // Later range is put on top of earlier range
+            (
+                &[(0, 4, WARN), (2, 6, ERR)][..],
+                &[("ab", WARN), ("cdef", ERR)][..],
+            ),
+            (
+                &[(0, 6, ERR), (2, 3, WARN)][..],
+                &[("ab", ERR), ("c", WARN), ("def", ERR)][..],
+            ),
+        ];
+        for test in tests {
+            let (ranges, want) = test;
+            let mut lh = LineHighlighter::new("abcdef", CUR, 4, None, SEL);
+            lh.diagnostics(ranges);
+            assert_spans(lh, want, test);
+        }
+
+        // Diagnostic is put on top of syntax highlight, and selection is put on top of them
+        let mut lh = LineHighlighter::new("abcdef", CUR, 4, None, SEL);
+        lh.syntax([(0, 6, LINE)].into_iter());
+        lh.diagnostics(&[(1, 5, ERR)]);
+        lh.selection(0, 0, 3, 0, 4);
+        assert_spans(
+            lh,
+            &[
+                ("a", LINE),
+                ("bc", LINE.patch(ERR)),
+                ("d", SEL),
+                ("e", LINE.patch(ERR)),
+                ("f", LINE),
+            ],
+            (),
+        );
+    }
+
     #[cfg(feature = "search")]
     #[test]
     fn into_spans_search() {
@@ -540,6 +758,29 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "search")]
+    #[test]
+    fn into_spans_search_at_end() {
+        let mut lh = LineHighlighter::new("abc", CUR, 4, None, SEL);
+        lh.search([(1, 3)].into_iter(), SEARCH);
+        lh.search_at_end(SEARCH);
+        assert_spans(
+            lh,
+            &[("a", DEFAULT), ("bc", SEARCH), (" ", SEARCH)],
+            "match",
+        );
+
+        let mut lh = LineHighlighter::new("", CUR, 4, None, SEL);
+        lh.search_at_end(SEARCH);
+        assert_spans(lh, &[(" ", SEARCH)], "empty line");
+
+        // Cursor at end of line is prioritized
+        let mut lh = LineHighlighter::new("abc", CUR, 4, None, SEL);
+        lh.cursor(3);
+        lh.search_at_end(SEARCH);
+        assert_spans(lh, &[("abc", DEFAULT), (" ", CUR)], "cursor");
+    }
+
     #[test]
     fn into_spans_selection() {
         let tests = [
@@ -653,6 +894,38 @@ mod tests {
                 },
                 &[("a", LINE), ("b", CUR), ("c", LINE)][..],
             ),
+            (
+                "syntax on cursor line",
+                {
+                    let mut lh = LineHighlighter::new("abcd", CUR, 4, None, SEL);
+                    lh.cursor_line(0, LINE);
+                    lh.syntax([(0, 2, SYN), (2, 3, SYN)].into_iter());
+                    lh
+                },
+                &[
+                    ("a", CUR),
+                    ("b", LINE.patch(SYN)),
+                    ("c", LINE.patch(SYN)),
+                    ("d", LINE),
+                ][..],
+            ),
+            (
+                "selection crossing syntax",
+                {
+                    let mut lh = LineHighlighter::new("abcdef", CUR, 4, None, SEL);
+                    lh.syntax([(0, 2, SYN), (3, 5, SYN)].into_iter());
+                    lh.selection(0, 0, 1, 0, 4);
+                    lh
+                },
+                &[
+                    ("a", SYN),
+                    ("b", SEL),
+                    ("c", SEL),
+                    ("d", SEL),
+                    ("e", SYN),
+                    ("f", DEFAULT),
+                ][..],
+            ),
         ];
 
         for (what, lh, want) in tests {
@@ -1,7 +1,73 @@
+use crate::buffer::TextBuffer;
 use crate::util::Pos;
-use std::collections::VecDeque;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::mem;
+use std::time::SystemTime;
+
+// Lines `row..row + removed` were replaced with lines `row..row + inserted` by an edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineChange {
+    pub row: usize,
+    pub removed: usize,
+    pub inserted: usize,
+}
+
+/// Change of the text made by an edit, undo, or redo. Applying the changes in order to a copy of the text reproduces
+/// the content of [`TextArea`](crate::TextArea). Positions are 0-based `(row, col)` where `col` is counted in
+/// characters. See [`TextArea::take_changes`](crate::TextArea::take_changes) to get the changes.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextChange {
+    /// Start position of the changed range.
+    pub start: (usize, usize),
+    /// End position of the removed range before the change. This is the same as `start` when no text was removed.
+    pub end: (usize, usize),
+    /// Text removed from the range. Lines are separated with `\n`.
+    pub removed: String,
+    /// Text inserted at the start position. Lines are separated with `\n`.
+    pub inserted: String,
+}
+
+impl TextChange {
+    pub(crate) fn new(
+        start: (usize, usize),
+        end: (usize, usize),
+        removed: String,
+        inserted: String,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            removed,
+            inserted,
+        }
+    }
+
+    /// End position of the inserted text after the change.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["ab"]);
+    /// textarea.set_change_tracking(true);
+    /// textarea.move_cursor(tui_textarea::CursorMove::End);
+    /// textarea.insert_str("c\nde");
+    ///
+    /// let changes = textarea.take_changes();
+    /// assert_eq!(changes[0].inserted_end(), (1, 2));
+    /// ```
+    pub fn inserted_end(&self) -> (usize, usize) {
+        let (row, col) = self.start;
+        match self.inserted.rsplit_once('\n') {
+            Some((head, last)) => (row + head.matches('\n').count() + 1, last.chars().count()),
+            None => (row, col + self.inserted.chars().count()),
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EditKind {
     InsertChar(char),
     DeleteChar(char),
@@ -14,7 +80,7 @@ pub enum EditKind {
 }
 
 impl EditKind {
-    pub(crate) fn apply(&self, lines: &mut Vec<String>, before: &Pos, after: &Pos) {
+    pub(crate) fn apply(&self, lines: &mut impl TextBuffer, before: &Pos, after: &Pos) {
         match self {
             EditKind::InsertChar(c) => {
                 lines[before.row].insert(before.offset, *c);
@@ -53,15 +119,15 @@ impl EditKind {
                 lines.insert(next_row, last_line);
 
                 // Handle middle lines of chunk
-                lines.splice(next_row..next_row, c[1..c.len() - 1].iter().cloned());
+                lines.insert_lines(next_row, c[1..c.len() - 1].iter().cloned());
             }
             EditKind::DeleteChunk(c) => {
                 debug_assert!(c.len() > 1, "Chunk size must be > 1: {:?}", c);
 
                 // Remove middle lines of chunk
                 let mut last_line = lines
-                    .drain(after.row + 1..after.row + c.len())
-                    .next_back()
+                    .remove_lines(after.row + 1..after.row + c.len())
+                    .pop()
                     .unwrap();
                 // Remove last line of chunk
                 last_line.drain(..c[c.len() - 1].len());
@@ -74,6 +140,24 @@ impl EditKind {
         }
     }
 
+    pub(crate) fn line_change(&self, before: &Pos, after: &Pos) -> LineChange {
+        let (row, removed, inserted) = match self {
+            EditKind::InsertChar(_)
+            | EditKind::DeleteChar(_)
+            | EditKind::InsertStr(_)
+            | EditKind::DeleteStr(_) => (before.row, 1, 1),
+            EditKind::InsertNewline => (before.row, 1, 2),
+            EditKind::DeleteNewline => (before.row - 1, 2, 1),
+            EditKind::InsertChunk(c) => (before.row, 1, c.len()),
+            EditKind::DeleteChunk(c) => (after.row, c.len(), 1),
+        };
+        LineChange {
+            row,
+            removed,
+            inserted,
+        }
+    }
+
     fn invert(&self) -> Self {
         use EditKind::*;
         match self.clone() {
@@ -90,10 +174,11 @@ impl EditKind {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Edit {
-    kind: EditKind,
-    before: Pos,
-    after: Pos,
+    pub kind: EditKind,
+    pub before: Pos,
+    pub after: Pos,
 }
 
 impl Edit {
@@ -105,12 +190,34 @@ impl Edit {
         }
     }
 
-    pub fn redo(&self, lines: &mut Vec<String>) {
+    pub fn redo(&self, lines: &mut impl TextBuffer) {
         self.kind.apply(lines, &self.before, &self.after);
     }
 
-    pub fn undo(&self, lines: &mut Vec<String>) {
-        self.kind.invert().apply(lines, &self.after, &self.before); // Undo is redo of inverted edit
+    // Undo is redo of the inverted edit
+    pub fn inverted(&self) -> Self {
+        Self::new(self.kind.invert(), self.after.clone(), self.before.clone())
+    }
+
+    pub fn line_change(&self) -> LineChange {
+        self.kind.line_change(&self.before, &self.after)
+    }
+
+    // Deletions remove the text from the `after` position to the `before` position
+    pub fn text_change(&self) -> TextChange {
+        use EditKind::*;
+        let before = (self.before.row, self.before.col);
+        let after = (self.after.row, self.after.col);
+        match &self.kind {
+            InsertChar(c) => TextChange::new(before, before, String::new(), c.to_string()),
+            DeleteChar(c) => TextChange::new(after, before, c.to_string(), String::new()),
+            InsertNewline => TextChange::new(before, before, String::new(), "\n".to_string()),
+            DeleteNewline => TextChange::new(after, before, "\n".to_string(), String::new()),
+            InsertStr(s) => TextChange::new(before, before, String::new(), s.clone()),
+            DeleteStr(s) => TextChange::new(after, before, s.clone(), String::new()),
+            InsertChunk(c) => TextChange::new(before, before, String::new(), c.join("\n")),
+            DeleteChunk(c) => TextChange::new(after, before, c.join("\n"), String::new()),
+        }
     }
 
     pub fn cursor_before(&self) -> (usize, usize) {
@@ -122,55 +229,650 @@ impl Edit {
     }
 }
 
+// The position points to a character boundary of an existing line
+fn pos_in_text(lines: &[String], pos: &Pos) -> bool {
+    lines.get(pos.row).is_some_and(|line| {
+        line.is_char_boundary(pos.offset) && pos.col <= line[..pos.offset].chars().count()
+    })
+}
+
+impl Edit {
+    // Apply the edit only when its positions and the deleted text match the text. Returns `false` when the edit
+    // doesn't match. The text may be modified in the case
+    fn checked_redo(&self, lines: &mut Vec<String>) -> bool {
+        use EditKind::*;
+        let (before, after) = (&self.before, &self.after);
+        if !pos_in_text(lines, before) {
+            return false;
+        }
+        let rest = |row: usize, offset: usize| lines.get(row).and_then(|l| l.get(offset..));
+        let matched = match &self.kind {
+            InsertChar(_) | InsertNewline | InsertStr(_) => true,
+            InsertChunk(c) => c.len() > 1,
+            DeleteChar(c) => {
+                before.row == after.row
+                    && rest(after.row, after.offset).is_some_and(|s| s.starts_with(*c))
+            }
+            DeleteNewline => before.row > 0,
+            DeleteStr(s) => {
+                rest(after.row, after.offset).is_some_and(|r| r.starts_with(s.as_str()))
+            }
+            DeleteChunk(c) => {
+                let last = after.row + c.len().max(2) - 1;
+                c.len() > 1
+                    && last < lines.len()
+                    && rest(after.row, after.offset) == Some(c[0].as_str())
+                    && lines[after.row + 1..last] == c[1..c.len() - 1]
+                    && lines[last].starts_with(c[c.len() - 1].as_str())
+            }
+        };
+        if !matched {
+            return false;
+        }
+        self.redo(lines);
+        pos_in_text(lines, after)
+    }
+}
+
+/// Policy to group consecutive edits into one undo step. Edits grouped together are undone and redone at once. Only
+/// consecutive insertions or deletions of single characters at adjacent positions are grouped. Other edits such as
+/// inserting a newline or pasting text, moving the cursor, mouse clicks, and changing the selection always end the
+/// group. The default policy is [`UndoGrouping::Word`].
+///
+/// This type is marked as `#[non_exhaustive]` since more policies may be supported in the future.
+/// ```
+/// use tui_textarea::{TextArea, UndoGrouping};
+///
+/// let mut textarea = TextArea::default();
+/// for c in "hello world".chars() {
+///     textarea.insert_char(c);
+/// }
+///
+/// // Undo by words
+/// textarea.undo();
+/// assert_eq!(textarea.lines(), ["hello "]);
+///
+/// // Undo each character separately
+/// textarea.set_undo_grouping(UndoGrouping::None);
+/// textarea.insert_char('w');
+/// textarea.insert_char('o');
+/// textarea.undo();
+/// assert_eq!(textarea.lines(), ["hello w"]);
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UndoGrouping {
+    /// Undo every edit separately.
+    None,
+    /// Undo consecutive character edits by words. A group ends when a character other than whitespace is typed or
+    /// deleted after whitespace.
+    #[default]
+    Word,
+    /// Undo consecutive character edits in a line at once.
+    Line,
+}
+
+impl UndoGrouping {
+    fn coalesces(self, prev: &Edit, next: &Edit) -> bool {
+        let at = |p: &Pos, q: &Pos| p.row == q.row && p.col == q.col;
+        let adjacent = match (&prev.kind, &next.kind) {
+            (EditKind::InsertChar(_), EditKind::InsertChar(_)) => at(&next.before, &prev.after),
+            // Deleting characters backward or forward
+            (EditKind::DeleteChar(_), EditKind::DeleteChar(_)) => {
+                at(&next.before, &prev.after) || at(&next.after, &prev.after)
+            }
+            _ => false,
+        };
+        adjacent && self.coalesces_chars(prev, next)
+    }
+
+    // Edits at other cursors in the same row shift the columns, so only rows are compared for edits at multiple
+    // cursors. Moving the cursors closes the group instead
+    fn coalesces_at_cursors(self, prev: &Edit, next: &Edit) -> bool {
+        prev.after.row == next.before.row && self.coalesces_chars(prev, next)
+    }
+
+    fn coalesces_chars(self, prev: &Edit, next: &Edit) -> bool {
+        let chars = match (&prev.kind, &next.kind) {
+            (EditKind::InsertChar(p), EditKind::InsertChar(n))
+            | (EditKind::DeleteChar(p), EditKind::DeleteChar(n)) => (*p, *n),
+            _ => return false,
+        };
+        match self {
+            Self::None => false,
+            Self::Word => !chars.0.is_whitespace() || chars.1.is_whitespace(),
+            Self::Line => true,
+        }
+    }
+}
+
+/// State of the text in the undo history. See [`TextArea::undo_states`](crate::TextArea::undo_states).
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UndoState {
+    /// Sequence number of the state. States are numbered in the order they were created. The original text is `0`.
+    pub seq: usize,
+    /// Sequence number of the state this state was derived from. This is `None` for the oldest state, which cannot be
+    /// undone.
+    pub parent: Option<usize>,
+    /// Time when the state was created.
+    pub time: SystemTime,
+    /// Whether the text is currently in this state.
+    pub current: bool,
+}
+
+// State of the text in the undo tree. The edits transform the parent state into this state
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Node {
+    pub parent: Option<usize>,
+    // Children in the order of creation
+    pub children: Vec<usize>,
+    // Child redone next. This is the child created or undone last
+    pub redo: Option<usize>,
+    pub edits: Vec<Edit>,
+    pub time: SystemTime,
+}
+
+impl Node {
+    pub fn new(parent: Option<usize>, edits: Vec<Edit>) -> Self {
+        Self {
+            parent,
+            children: vec![],
+            redo: None,
+            edits,
+            time: SystemTime::now(),
+        }
+    }
+}
+
+// Check the states form a tree rooted at `root` and every edit can be undone and redone from the text in the current
+// state. The edits are replayed on a copy of the text. Returns the reason when the states are broken
+pub fn validate_tree(
+    nodes: &BTreeMap<usize, Node>,
+    root: usize,
+    current: usize,
+    lines: &impl TextBuffer,
+) -> Result<(), &'static str> {
+    if nodes.get(&root).is_none_or(|n| n.parent.is_some()) {
+        return Err("broken root state");
+    }
+    if !nodes.contains_key(&current) {
+        return Err("current state out of range");
+    }
+    for (&seq, node) in nodes {
+        if seq != root {
+            let linked = node
+                .parent
+                .and_then(|p| nodes.get(&p))
+                .is_some_and(|p| p.children.contains(&seq));
+            if !linked || node.edits.is_empty() {
+                return Err("broken tree");
+            }
+        }
+        if node.redo.is_some_and(|r| !node.children.contains(&r)) {
+            return Err("broken redo child");
+        }
+        let children_linked = node
+            .children
+            .iter()
+            .all(|c| nodes.get(c).is_some_and(|c| c.parent == Some(seq)));
+        if !children_linked {
+            return Err("broken tree");
+        }
+    }
+
+    // Undo from the current state to the root, then visit all states from the root. Each state is entered by redoing
+    // its edits and left by undoing them. States reached twice or not reached mean a cycle
+    let mut text: Vec<String> = lines.iter().cloned().collect();
+    let mut seq = current;
+    while seq != root {
+        let node = &nodes[&seq];
+        if !node
+            .edits
+            .iter()
+            .rev()
+            .all(|e| e.inverted().checked_redo(&mut text))
+        {
+            return Err("edit does not match text");
+        }
+        seq = node.parent.unwrap();
+    }
+    let mut visited = 0;
+    let mut stack = vec![(root, true)];
+    while let Some((seq, enter)) = stack.pop() {
+        let node = &nodes[&seq];
+        if !enter {
+            if !node
+                .edits
+                .iter()
+                .rev()
+                .all(|e| e.inverted().checked_redo(&mut text))
+            {
+                return Err("edit does not match text");
+            }
+            continue;
+        }
+        visited += 1;
+        if visited > nodes.len() {
+            return Err("broken tree");
+        }
+        if !node.edits.iter().all(|e| e.checked_redo(&mut text)) {
+            return Err("edit does not match text");
+        }
+        stack.push((seq, false));
+        stack.extend(node.children.iter().map(|&c| (c, true)));
+    }
+    if visited != nodes.len() {
+        return Err("broken tree");
+    }
+    Ok(())
+}
+
+fn is_char_edit(edit: &Edit) -> bool {
+    matches!(edit.kind, EditKind::InsertChar(_) | EditKind::DeleteChar(_))
+}
+
+// Edits are stored as a tree of states keyed by sequence numbers. Without the tree mode, the redo branch is removed on
+// a new edit so the tree is always a line. Ongoing groups are not serialized. A restored history starts a new group
+// on the next edit
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct History {
-    index: usize,
     max_items: usize,
-    edits: VecDeque<Edit>,
+    nodes: BTreeMap<usize, Node>,
+    // The oldest state. Its edits were dropped so it cannot be undone
+    root: usize,
+    current: usize,
+    next_seq: usize,
+    // Keep the redo branches on a new edit
+    tree: bool,
+    grouping: UndoGrouping,
+    // Depth of nested groups started by `begin_group`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    depth: usize,
+    // The last group was started by `begin_group` and is still open
+    #[cfg_attr(feature = "serde", serde(skip))]
+    group_open: bool,
+    // The last group consists of character edits at this number of cursors which may be coalesced with the next
+    // edits. 0 means the next edit starts a new group
+    #[cfg_attr(feature = "serde", serde(skip))]
+    coalescing: usize,
+    // The state and the coalescing before the ongoing group of edits at multiple cursors started
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cursors_group: Option<(usize, usize)>,
 }
 
 impl History {
     pub fn new(max_items: usize) -> Self {
         Self {
-            index: 0,
             max_items,
-            edits: VecDeque::new(),
+            nodes: BTreeMap::from([(0, Node::new(None, vec![]))]),
+            root: 0,
+            current: 0,
+            next_seq: 1,
+            tree: false,
+            grouping: UndoGrouping::default(),
+            depth: 0,
+            group_open: false,
+            coalescing: 0,
+            cursors_group: None,
+        }
+    }
+
+    // Remove all edits keeping the configuration
+    pub fn reset(&mut self, max_items: usize) {
+        *self = Self {
+            tree: self.tree,
+            grouping: self.grouping,
+            ..Self::new(max_items)
+        };
+    }
+
+    pub fn set_grouping(&mut self, grouping: UndoGrouping) {
+        self.grouping = grouping;
+        self.coalescing = 0;
+    }
+
+    pub fn grouping(&self) -> UndoGrouping {
+        self.grouping
+    }
+
+    // Disabling the tree mode removes the branches other than the current one
+    pub fn set_tree(&mut self, enabled: bool) {
+        self.tree = enabled;
+        if enabled {
+            return;
+        }
+        let mut keep = vec![self.current];
+        let mut seq = self.current;
+        while let Some(parent) = self.nodes[&seq].parent {
+            keep.push(parent);
+            seq = parent;
+        }
+        let mut seq = self.current;
+        while let Some(child) = self.nodes[&seq].redo {
+            keep.push(child);
+            seq = child;
+        }
+        self.nodes.retain(|seq, _| keep.contains(seq));
+        for node in self.nodes.values_mut() {
+            node.children = node.redo.into_iter().collect();
+        }
+    }
+
+    pub fn tree(&self) -> bool {
+        self.tree
+    }
+
+    // Edits pushed until the matching `end_group` call are undone or redone as one step. Groups can be nested.
+    pub fn begin_group(&mut self) {
+        if self.depth == 0 {
+            self.group_open = false;
+        }
+        self.depth += 1;
+        self.coalescing = 0;
+    }
+
+    pub fn end_group(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            self.group_open = false;
         }
     }
 
+    // Edits at multiple cursors pushed until the matching `end_cursors_group` call are one undo step like
+    // `begin_group`. The step is coalesced with the previous step made at the same number of cursors as if each
+    // cursor were edited separately
+    pub fn begin_cursors_group(&mut self) {
+        if self.depth == 0 {
+            self.cursors_group = Some((self.current, self.coalescing));
+        }
+        self.begin_group();
+    }
+
+    pub fn end_cursors_group(&mut self) {
+        self.end_group();
+        if self.depth > 0 {
+            return;
+        }
+        let Some((start, coalescing)) = self.cursors_group.take() else {
+            return;
+        };
+        if self.current == start || self.current == self.root {
+            return;
+        }
+
+        let edits = &self.nodes[&self.current].edits;
+        if !edits.iter().all(is_char_edit) {
+            return;
+        }
+        let len = edits.len();
+        // The previous state may have been evicted by the new edits
+        let prev = self.nodes.get(&start).filter(|_| start != self.root);
+        let coalesced = prev.is_some_and(|prev| {
+            coalescing == len
+                && prev
+                    .edits
+                    .iter()
+                    .skip(prev.edits.len() - len)
+                    .zip(edits)
+                    .all(|(p, n)| self.grouping.coalesces_at_cursors(p, n))
+        });
+        self.coalescing = len;
+        if !coalesced {
+            return;
+        }
+
+        // Move the edits to the previous state. It has no other children since it was the last edited state
+        let seq = self.current;
+        let node = self.nodes.remove(&seq).unwrap();
+        let prev = self.nodes.get_mut(&start).unwrap();
+        prev.edits.extend(node.edits);
+        prev.children.retain(|&c| c != seq);
+        prev.redo = None;
+        self.current = start;
+    }
+
+    // Stop coalescing character edits so that the next edit starts a new undo step. Unlike `close_groups`, groups
+    // started by `begin_group` are kept open
+    pub fn close_coalescing(&mut self) {
+        self.coalescing = 0;
+    }
+
+    // Close all ongoing groups so that the next edit starts a new undo step
+    pub fn close_groups(&mut self) {
+        self.depth = 0;
+        self.group_open = false;
+        self.coalescing = 0;
+    }
+
     pub fn push(&mut self, edit: Edit) {
         if self.max_items == 0 {
             return;
         }
 
-        if self.edits.len() == self.max_items {
-            self.edits.pop_front();
-            self.index = self.index.saturating_sub(1);
+        if (self.group_open || self.coalescing == 1) && self.current != self.root {
+            let group = &mut self.nodes.get_mut(&self.current).unwrap().edits;
+            if self.group_open || self.grouping.coalesces(&group[group.len() - 1], &edit) {
+                group.push(edit);
+                return;
+            }
         }
 
-        if self.index < self.edits.len() {
-            self.edits.truncate(self.index);
+        if !self.tree {
+            let children = mem::take(&mut self.nodes.get_mut(&self.current).unwrap().children);
+            for child in children {
+                self.remove_subtree(child);
+            }
         }
 
-        self.index += 1;
-        self.edits.push_back(edit);
+        self.group_open = self.depth > 0;
+        self.coalescing = usize::from(self.depth == 0 && is_char_edit(&edit));
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.nodes
+            .insert(seq, Node::new(Some(self.current), vec![edit]));
+        let parent = self.nodes.get_mut(&self.current).unwrap();
+        parent.children.push(seq);
+        parent.redo = Some(seq);
+        self.current = seq;
+        self.evict();
+    }
+
+    fn remove_subtree(&mut self, seq: usize) {
+        let mut stack = vec![seq];
+        while let Some(seq) = stack.pop() {
+            if let Some(node) = self.nodes.remove(&seq) {
+                stack.extend(node.children);
+            }
+        }
     }
 
-    pub fn redo(&mut self, lines: &mut Vec<String>) -> Option<(usize, usize)> {
-        if self.index == self.edits.len() {
+    // Drop the oldest states until the number of states which can be undone fits the max items. The child of the root
+    // on the path to the current state becomes the new root and the other branches from the old root are removed
+    fn evict(&mut self) {
+        while self.nodes.len() > self.max_items + 1 && self.current != self.root {
+            let mut seq = self.current;
+            while self.nodes[&seq].parent != Some(self.root) {
+                seq = self.nodes[&seq].parent.unwrap();
+            }
+            let old = self.nodes.remove(&self.root).unwrap();
+            for child in old.children {
+                if child != seq {
+                    self.remove_subtree(child);
+                }
+            }
+            let root = self.nodes.get_mut(&seq).unwrap();
+            root.parent = None;
+            root.edits.clear();
+            self.root = seq;
+        }
+    }
+
+    fn step_back(&mut self, lines: &mut impl TextBuffer) -> Option<((usize, usize), Vec<Edit>)> {
+        if self.current == self.root {
             return None;
         }
-        let edit = &self.edits[self.index];
-        edit.redo(lines);
-        self.index += 1;
-        Some(edit.cursor_after())
+        let node = &self.nodes[&self.current];
+        let parent = node.parent.unwrap();
+        let mut applied = Vec::with_capacity(node.edits.len());
+        for edit in node.edits.iter().rev() {
+            let edit = edit.inverted();
+            edit.redo(lines);
+            applied.push(edit);
+        }
+        let cursor = node.edits[0].cursor_before();
+        self.nodes.get_mut(&parent).unwrap().redo = Some(self.current);
+        self.current = parent;
+        Some((cursor, applied))
+    }
+
+    fn step_forward(
+        &mut self,
+        child: usize,
+        lines: &mut impl TextBuffer,
+    ) -> ((usize, usize), Vec<Edit>) {
+        let group = &self.nodes[&child].edits;
+        for edit in group {
+            edit.redo(lines);
+        }
+        let applied = (group[group.len() - 1].cursor_after(), group.clone());
+        self.nodes.get_mut(&self.current).unwrap().redo = Some(child);
+        self.current = child;
+        applied
+    }
+
+    // Returns the cursor position after the redo and the edits applied in order
+    pub fn redo(&mut self, lines: &mut impl TextBuffer) -> Option<((usize, usize), Vec<Edit>)> {
+        let child = self.nodes[&self.current].redo?;
+        self.group_open = false;
+        self.coalescing = 0;
+        Some(self.step_forward(child, lines))
+    }
+
+    // Returns the cursor position after the undo and the inverted edits applied in order
+    pub fn undo(&mut self, lines: &mut impl TextBuffer) -> Option<((usize, usize), Vec<Edit>)> {
+        let applied = self.step_back(lines)?;
+        self.group_open = false;
+        self.coalescing = 0;
+        Some(applied)
+    }
+
+    // Move to the state by undoing until the common ancestor and redoing from it. Returns the cursor position after
+    // the move and the edits applied in order
+    pub fn jump(
+        &mut self,
+        target: usize,
+        lines: &mut impl TextBuffer,
+    ) -> Option<((usize, usize), Vec<Edit>)> {
+        if target == self.current || !self.nodes.contains_key(&target) {
+            return None;
+        }
+        self.group_open = false;
+        self.coalescing = 0;
+
+        let mut path = vec![target];
+        while let Some(parent) = self.nodes[&path[path.len() - 1]].parent {
+            path.push(parent);
+        }
+
+        let mut cursor = (0, 0);
+        let mut applied = vec![];
+        while !path.contains(&self.current) {
+            let (c, edits) = self.step_back(lines).unwrap();
+            cursor = c;
+            applied.extend(edits);
+        }
+        let start = path.iter().position(|&s| s == self.current).unwrap();
+        for &child in path[..start].iter().rev() {
+            let (c, edits) = self.step_forward(child, lines);
+            cursor = c;
+            applied.extend(edits);
+        }
+        Some((cursor, applied))
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    // The state created `steps` states before the current state, or the oldest state
+    pub fn earlier(&self, steps: usize) -> usize {
+        let seq = self.current.saturating_sub(steps);
+        self.nodes
+            .range(..=seq)
+            .next_back()
+            .map_or(self.root, |(&s, _)| s)
+    }
+
+    // The state created `steps` states after the current state, or the newest state
+    pub fn later(&self, steps: usize) -> usize {
+        let seq = self.current.saturating_add(steps);
+        self.nodes
+            .range(seq..)
+            .next()
+            .or_else(|| self.nodes.last_key_value())
+            .map_or(self.root, |(&s, _)| s)
+    }
+
+    // The newest state created at the time, or the oldest state
+    pub fn at_time(&self, time: SystemTime) -> usize {
+        self.nodes
+            .iter()
+            .rev()
+            .find(|(_, n)| n.time <= time)
+            .map_or(self.root, |(&s, _)| s)
+    }
+
+    pub fn current_time(&self) -> SystemTime {
+        self.nodes[&self.current].time
+    }
+
+    pub fn states(&self) -> impl Iterator<Item = UndoState> + '_ {
+        self.nodes.iter().map(|(&seq, node)| UndoState {
+            seq,
+            parent: node.parent,
+            time: node.time,
+            current: seq == self.current,
+        })
+    }
+
+    pub fn nodes(&self) -> &BTreeMap<usize, Node> {
+        &self.nodes
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    // Check the history deserialized from outside is consistent with the text. See `validate_tree`
+    pub fn validate(&self, lines: &impl TextBuffer) -> Result<(), &'static str> {
+        if self
+            .nodes
+            .last_key_value()
+            .is_some_and(|(&s, _)| s >= self.next_seq)
+        {
+            return Err("sequence number out of range");
+        }
+        validate_tree(&self.nodes, self.root, self.current, lines)
     }
 
-    pub fn undo(&mut self, lines: &mut Vec<String>) -> Option<(usize, usize)> {
-        self.index = self.index.checked_sub(1)?;
-        let edit = &self.edits[self.index];
-        edit.undo(lines);
-        Some(edit.cursor_before())
+    // Replace the states keeping the configuration. The states must be a valid tree rooted at `root`. When the states
+    // exceed the max items, the oldest states are dropped
+    pub fn restore(&mut self, nodes: BTreeMap<usize, Node>, root: usize, current: usize) {
+        debug_assert!(nodes.contains_key(&root) && nodes.contains_key(&current));
+        self.next_seq = nodes.last_key_value().map_or(0, |(&s, _)| s) + 1;
+        self.nodes = nodes;
+        self.root = root;
+        self.current = current;
+        self.close_groups();
+        if !self.tree {
+            self.set_tree(false);
+        }
+        self.evict();
     }
 
     pub fn max_items(&self) -> usize {
@@ -181,6 +883,94 @@ impl History {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::buffer::LineRope;
+    use std::time::Duration;
+
+    #[test]
+    fn line_change_of_undo_redo() {
+        let tests = [
+            (EditKind::InsertChar('a'), (1, 0), (1, 1), (1, 1, 1)),
+            (EditKind::InsertNewline, (1, 2), (2, 0), (1, 1, 2)),
+            (EditKind::DeleteNewline, (2, 0), (1, 2), (1, 2, 1)),
+            (
+                EditKind::InsertChunk(vec!["x".into(), "y".into(), "z".into()]),
+                (0, 1),
+                (2, 1),
+                (0, 1, 3),
+            ),
+            (
+                EditKind::DeleteChunk(vec!["b".into(), "c".into()]),
+                (1, 0),
+                (0, 1),
+                (0, 2, 1),
+            ),
+        ];
+
+        for (kind, before, after, (row, removed, inserted)) in tests {
+            let before = Pos::new(before.0, before.1, 0);
+            let after = Pos::new(after.0, after.1, 0);
+            let edit = Edit::new(kind.clone(), before, after);
+            let mut lines = vec!["ab".to_string(), "cd".to_string(), "ef".to_string()];
+            let mut history = History::new(10);
+            history.push(edit);
+
+            let expected = LineChange {
+                row,
+                removed: inserted,
+                inserted: removed,
+            };
+            let (_, edits) = history.undo(&mut lines).unwrap();
+            let changes: Vec<_> = edits.iter().map(Edit::line_change).collect();
+            assert_eq!(changes, [expected], "{kind:?}");
+
+            let expected = LineChange {
+                row,
+                removed,
+                inserted,
+            };
+            let (_, edits) = history.redo(&mut lines).unwrap();
+            let changes: Vec<_> = edits.iter().map(Edit::line_change).collect();
+            assert_eq!(changes, [expected], "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn undo_redo_group() {
+        fn insert(lines: &mut Vec<String>, history: &mut History, c: char, col: usize) {
+            let edit = Edit::new(
+                EditKind::InsertChar(c),
+                Pos::new(0, col, col),
+                Pos::new(0, col + 1, col + 1),
+            );
+            edit.redo(lines);
+            history.push(edit);
+        }
+
+        let mut lines = vec!["ab".to_string()];
+        let mut history = History::new(10);
+
+        history.begin_group();
+        insert(&mut lines, &mut history, 'x', 0);
+        insert(&mut lines, &mut history, 'y', 3);
+        history.end_group();
+        insert(&mut lines, &mut history, 'z', 4);
+        assert_eq!(lines, ["xabyz"]);
+
+        let (cursor, changes) = history.undo(&mut lines).unwrap();
+        assert_eq!(lines, ["xaby"]);
+        assert_eq!(cursor, (0, 4));
+        assert_eq!(changes.len(), 1);
+
+        let (cursor, changes) = history.undo(&mut lines).unwrap();
+        assert_eq!(lines, ["ab"]);
+        assert_eq!(cursor, (0, 0));
+        assert_eq!(changes.len(), 2);
+        assert!(history.undo(&mut lines).is_none());
+
+        let (cursor, _) = history.redo(&mut lines).unwrap();
+        assert_eq!(lines, ["xaby"]);
+        assert_eq!(cursor, (0, 4));
+    }
 
     #[test]
     fn insert_delete_chunk() {
@@ -642,9 +1432,107 @@ mod tests {
             edit.apply(&mut lines, &before_pos, &after_pos);
             assert_eq!(&lines, expected, "{test:?}");
 
-            let edit = EditKind::DeleteChunk(chunk);
+            let edit = EditKind::DeleteChunk(chunk.clone());
             edit.apply(&mut lines, &after_pos, &before_pos);
             assert_eq!(&lines, &before, "{test:?}");
+
+            // Rope buffer should behave the same as `Vec<String>`
+            let mut rope = LineRope::from_lines(lines);
+            EditKind::InsertChunk(chunk.clone()).apply(&mut rope, &before_pos, &after_pos);
+            assert_eq!(rope.clone().into_lines(), expected, "{test:?}");
+            EditKind::DeleteChunk(chunk).apply(&mut rope, &after_pos, &before_pos);
+            assert_eq!(rope.into_lines(), before, "{test:?}");
+        }
+    }
+
+    fn insert_str(lines: &mut Vec<String>, history: &mut History, s: &str) {
+        let col = lines[0].len();
+        let edit = Edit::new(
+            EditKind::InsertStr(s.to_string()),
+            Pos::new(0, col, col),
+            Pos::new(0, col + s.len(), col + s.len()),
+        );
+        edit.redo(lines);
+        history.push(edit);
+    }
+
+    #[test]
+    fn undo_tree_keeps_branches() {
+        let mut lines = vec![String::new()];
+        let mut history = History::new(10);
+        history.set_tree(true);
+        insert_str(&mut lines, &mut history, "a"); // 1
+        history.undo(&mut lines).unwrap();
+        insert_str(&mut lines, &mut history, "b"); // 2
+        insert_str(&mut lines, &mut history, "c"); // 3
+        history.undo(&mut lines).unwrap();
+        insert_str(&mut lines, &mut history, "d"); // 4
+        assert_eq!(lines, ["bd"]);
+
+        for (seq, want) in [(3, "bc"), (1, "a"), (0, ""), (4, "bd"), (2, "b")] {
+            assert!(history.jump(seq, &mut lines).is_some(), "{seq}");
+            assert_eq!(lines, [want], "{seq}");
         }
+
+        // Redo follows the branch visited last
+        history.jump(3, &mut lines).unwrap();
+        history.undo(&mut lines).unwrap();
+        history.redo(&mut lines).unwrap();
+        assert_eq!(lines, ["bc"]);
+
+        // Disabling the tree keeps only the current branch
+        history.undo(&mut lines).unwrap();
+        history.set_tree(false);
+        let seqs: Vec<_> = history.nodes().keys().copied().collect();
+        assert_eq!(seqs, [0, 2, 3]);
+        history.redo(&mut lines).unwrap();
+        assert_eq!(lines, ["bc"]);
+    }
+
+    #[test]
+    fn undo_tree_evicts_oldest_states() {
+        let mut lines = vec![String::new()];
+        let mut history = History::new(3);
+        history.set_tree(true);
+        insert_str(&mut lines, &mut history, "a"); // 1
+        history.undo(&mut lines).unwrap();
+        insert_str(&mut lines, &mut history, "b"); // 2
+        insert_str(&mut lines, &mut history, "c"); // 3
+        insert_str(&mut lines, &mut history, "d"); // 4
+
+        // State 2 became the root and the branch of state 1 was removed
+        let seqs: Vec<_> = history.nodes().keys().copied().collect();
+        assert_eq!(seqs, [2, 3, 4]);
+        assert_eq!(history.root(), 2);
+        history.undo(&mut lines).unwrap();
+        history.undo(&mut lines).unwrap();
+        assert!(history.undo(&mut lines).is_none());
+        assert_eq!(lines, ["b"]);
+    }
+
+    #[test]
+    fn undo_states_by_sequence_and_time() {
+        let mut lines = vec![String::new()];
+        let mut history = History::new(10);
+        for s in ["a", "b", "c"] {
+            insert_str(&mut lines, &mut history, s);
+        }
+        let start = SystemTime::UNIX_EPOCH;
+        for (i, node) in history.nodes.values_mut().enumerate() {
+            node.time = start + Duration::from_secs(i as u64 * 10);
+        }
+
+        assert_eq!(history.earlier(1), 2);
+        assert_eq!(history.earlier(10), 0);
+        assert_eq!(history.later(1), 3);
+
+        assert_eq!(history.at_time(start + Duration::from_secs(15)), 1);
+        assert_eq!(history.at_time(start + Duration::from_secs(20)), 2);
+        assert_eq!(history.at_time(start + Duration::from_secs(100)), 3);
+
+        history.jump(1, &mut lines).unwrap();
+        assert_eq!(history.current_time(), start + Duration::from_secs(10));
+        assert_eq!(history.later(1), 2);
+        assert_eq!(history.earlier(1), 0);
     }
 }
@@ -0,0 +1,103 @@
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Specify how the indentation is inserted on inserting a newline. See
+/// [`TextArea::set_auto_indent`](crate::TextArea::set_auto_indent).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AutoIndent {
+    /// Don't insert any indentation (default).
+    #[default]
+    None,
+    /// Copy the indentation of the current line to the new line.
+    Keep,
+    /// Copy the indentation of the current line and increase it after an opening bracket (`{`, `[`, `(`) or `:`.
+    /// Typing a closing bracket at the head of a line decreases the indentation.
+    Smart,
+}
+
+// Leading whitespaces of the line
+pub(crate) fn leading_indent(line: &str) -> &str {
+    let len = line.len() - line.trim_start_matches([' ', '\t']).len();
+    &line[..len]
+}
+
+// Separator replacing the leading indentation of the next line on joining lines. It is empty when either line is
+// blank or the line already ends with a space
+pub(crate) fn join_separator(line: &str, next: &str) -> &'static str {
+    let blank = |s: &str| s.trim().is_empty();
+    if blank(line) || blank(next) || line.ends_with(' ') {
+        ""
+    } else {
+        " "
+    }
+}
+
+// Return the opening bracket or `:` at the end of the text ignoring trailing whitespaces
+pub(crate) fn block_opener(before: &str) -> Option<char> {
+    before
+        .trim_end()
+        .chars()
+        .next_back()
+        .filter(|c| matches!(c, '{' | '[' | '(' | ':'))
+}
+
+// Return if the text starts with the bracket closing the opener
+pub(crate) fn closes_block(opener: char, after: &str) -> bool {
+    let closer = match opener {
+        '{' => '}',
+        '[' => ']',
+        '(' => ')',
+        _ => return false,
+    };
+    after.trim_start().starts_with(closer)
+}
+
+pub(crate) fn is_closer(c: char) -> bool {
+    matches!(c, '}' | ']' | ')')
+}
+
+// Number of leading whitespaces removed from the indentation by outdenting one level. A tab or spaces until the previous
+// tab stop are removed.
+pub(crate) fn outdent_len(indent: &str, tab_len: u8) -> usize {
+    if indent.ends_with('\t') {
+        return 1;
+    }
+    let spaces = indent.len() - indent.trim_end_matches(' ').len();
+    if spaces == 0 || tab_len == 0 {
+        return spaces.min(1);
+    }
+    let width: usize = indent
+        .chars()
+        .map(|c| if c == '\t' { tab_len as usize } else { 1 })
+        .sum();
+    match width % tab_len as usize {
+        0 => spaces.min(tab_len as usize),
+        rem => spaces.min(rem),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outdent_one_level() {
+        for (indent, tab_len, want) in [
+            ("", 4, 0),
+            ("    ", 4, 4),
+            ("        ", 4, 4),
+            ("      ", 4, 2),
+            ("  ", 4, 2),
+            ("\t", 4, 1),
+            ("\t\t", 4, 1),
+            ("\t  ", 4, 2),
+            ("   ", 0, 1),
+        ] {
+            assert_eq!(outdent_len(indent, tab_len), want, "{indent:?}");
+        }
+    }
+}
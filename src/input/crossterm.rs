@@ -1,14 +1,19 @@
 use super::{Input, Key};
 use crate::crossterm::event::{
-    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
 
 impl From<Event> for Input {
-    /// Convert [`crossterm::event::Event`] into [`Input`].
+    /// Convert [`crossterm::event::Event`] into [`Input`]. Text pasted by bracketed paste is converted into
+    /// [`Key::Pasted`].
     fn from(event: Event) -> Self {
         match event {
             Event::Key(key) => Self::from(key),
             Event::Mouse(mouse) => Self::from(mouse),
+            Event::Paste(text) => Self {
+                key: Key::Pasted(text),
+                ..Self::default()
+            },
             _ => Self::default(),
         }
     }
@@ -25,7 +30,7 @@ impl From<KeyCode> for Key {
             KeyCode::Right => Key::Right,
             KeyCode::Up => Key::Up,
             KeyCode::Down => Key::Down,
-            KeyCode::Tab => Key::Tab,
+            KeyCode::Tab | KeyCode::BackTab => Key::Tab,
             KeyCode::Delete => Key::Delete,
             KeyCode::Home => Key::Home,
             KeyCode::End => Key::End,
@@ -49,7 +54,8 @@ impl From<KeyEvent> for Input {
 
         let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         let alt = key.modifiers.contains(KeyModifiers::ALT);
-        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        // BackTab is reported without Shift modifier on some platforms
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT) || key.code == KeyCode::BackTab;
         let key = Key::from(key.code);
 
         Self {
@@ -73,9 +79,16 @@ impl From<MouseEventKind> for Key {
 }
 
 impl From<MouseEvent> for Input {
-    /// Convert [`crossterm::event::MouseEvent`] into [`Input`].
+    /// Convert [`crossterm::event::MouseEvent`] into [`Input`]. Left button events are converted into
+    /// [`Key::MouseDown`], [`Key::MouseDrag`], and [`Key::MouseUp`] with the terminal position of the mouse.
     fn from(mouse: MouseEvent) -> Self {
-        let key = Key::from(mouse.kind);
+        let (column, row) = (mouse.column, mouse.row);
+        let key = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => Key::MouseDown(column, row),
+            MouseEventKind::Drag(MouseButton::Left) => Key::MouseDrag(column, row),
+            MouseEventKind::Up(MouseButton::Left) => Key::MouseUp(column, row),
+            kind => Key::from(kind),
+        };
         let ctrl = mouse.modifiers.contains(KeyModifiers::CONTROL);
         let alt = mouse.modifiers.contains(KeyModifiers::ALT);
         let shift = mouse.modifiers.contains(KeyModifiers::SHIFT);
@@ -142,6 +155,10 @@ mod tests {
                 ),
                 input(Key::F(1), true, true, true),
             ),
+            (
+                key_event(KeyCode::BackTab, KeyModifiers::empty()),
+                input(Key::Tab, false, false, true),
+            ),
             (
                 key_event(KeyCode::NumLock, KeyModifiers::CONTROL),
                 input(Key::Null, true, false, false),
@@ -181,6 +198,28 @@ mod tests {
                 mouse_event(MouseEventKind::Moved, KeyModifiers::CONTROL),
                 input(Key::Null, true, false, false),
             ),
+            (
+                mouse_event(
+                    MouseEventKind::Down(MouseButton::Left),
+                    KeyModifiers::empty(),
+                ),
+                input(Key::MouseDown(1, 1), false, false, false),
+            ),
+            (
+                mouse_event(MouseEventKind::Drag(MouseButton::Left), KeyModifiers::SHIFT),
+                input(Key::MouseDrag(1, 1), false, false, true),
+            ),
+            (
+                mouse_event(MouseEventKind::Up(MouseButton::Left), KeyModifiers::empty()),
+                input(Key::MouseUp(1, 1), false, false, false),
+            ),
+            (
+                mouse_event(
+                    MouseEventKind::Down(MouseButton::Right),
+                    KeyModifiers::empty(),
+                ),
+                input(Key::Null, false, false, false),
+            ),
         ] {
             assert_eq!(Input::from(from), to, "{:?} -> {:?}", from, to);
         }
@@ -200,6 +239,10 @@ mod tests {
                 )),
                 input(Key::MouseScrollDown, false, false, false),
             ),
+            (
+                Event::Paste("a\nb".into()),
+                input(Key::Pasted("a\nb".into()), false, false, false),
+            ),
             (Event::FocusGained, input(Key::Null, false, false, false)),
         ] {
             assert_eq!(Input::from(from.clone()), to, "{:?} -> {:?}", from, to);
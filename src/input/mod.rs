@@ -6,6 +6,8 @@
 mod crossterm;
 #[cfg(any(feature = "termion", feature = "tuirs-termion"))]
 mod termion;
+#[cfg(any(feature = "termion", feature = "tuirs-termion"))]
+pub use termion::TermionPaste;
 #[cfg(feature = "termwiz")]
 mod termwiz;
 
@@ -18,7 +20,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This type is marked as `#[non_exhaustive]` since more keys may be supported in the future.
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Hash, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Key {
@@ -62,6 +64,15 @@ pub enum Key {
     MouseScrollDown,
     /// Virtual key to scroll up by mouse
     MouseScrollUp,
+    /// Virtual key to press the left mouse button at 0-base (column, row) terminal position
+    MouseDown(u16, u16),
+    /// Virtual key to move the mouse to 0-base (column, row) terminal position while the left button is held
+    MouseDrag(u16, u16),
+    /// Virtual key to release the left mouse button at 0-base (column, row) terminal position
+    MouseUp(u16, u16),
+    /// Text pasted at once by bracketed paste. [`TextArea`](crate::TextArea) inserts the text as a single edit without
+    /// triggering any key shortcut
+    Pasted(String),
     /// An invalid key input (this key is always ignored by [`TextArea`](crate::TextArea))
     #[default]
     Null,
@@ -19,7 +19,8 @@ impl From<KeyEvent> for Input {
     /// when 'Shift + A' is pressed with US keyboard, termion passes `termion::event::Key::Char('A')`. We cannot know
     /// how the 'A' character was input.
     ///
-    /// So the `shift` field of the returned `Input` instance is always `false` except for combinations with arrow keys.
+    /// So the `shift` field of the returned `Input` instance is always `false` except for combinations with arrow keys
+    /// and `BackTab`, which is converted to Shift+Tab.
     /// For example, `termion::event::Key::Char('A')` is converted to `Input { key: Key::Char('A'), shift: false, .. }`.
     fn from(key: KeyEvent) -> Self {
         #[cfg(feature = "termion")]
@@ -39,7 +40,8 @@ impl From<KeyEvent> for Input {
             KeyEvent::ShiftUp
             | KeyEvent::ShiftRight
             | KeyEvent::ShiftDown
-            | KeyEvent::ShiftLeft => (false, false, true),
+            | KeyEvent::ShiftLeft
+            | KeyEvent::BackTab => (false, false, true),
             _ => (false, false, false),
         };
 
@@ -47,6 +49,7 @@ impl From<KeyEvent> for Input {
         let (ctrl, alt, shift) = match key {
             KeyEvent::Ctrl(_) => (true, false, false),
             KeyEvent::Alt(_) => (false, true, false),
+            KeyEvent::BackTab => (false, false, true),
             _ => (false, false, false),
         };
 
@@ -117,12 +120,20 @@ impl From<MouseButton> for Key {
 }
 
 impl From<MouseEvent> for Input {
-    /// Convert [`termion::event::MouseEvent`] into [`Input`].
+    /// Convert [`termion::event::MouseEvent`] into [`Input`]. Left button events are converted into
+    /// [`Key::MouseDown`], [`Key::MouseDrag`], and [`Key::MouseUp`]. termion reports 1-base positions so they are
+    /// converted into 0-base positions.
+    ///
+    /// termion does not report which button is held or released. So `MouseEvent::Hold` and `MouseEvent::Release` are
+    /// always assumed to be the left button.
     fn from(mouse: MouseEvent) -> Self {
-        let key = if let MouseEvent::Press(button, ..) = mouse {
-            Key::from(button)
-        } else {
-            Key::Null
+        let key = match mouse {
+            MouseEvent::Press(MouseButton::Left, x, y) => {
+                Key::MouseDown(x.saturating_sub(1), y.saturating_sub(1))
+            }
+            MouseEvent::Press(button, ..) => Key::from(button),
+            MouseEvent::Hold(x, y) => Key::MouseDrag(x.saturating_sub(1), y.saturating_sub(1)),
+            MouseEvent::Release(x, y) => Key::MouseUp(x.saturating_sub(1), y.saturating_sub(1)),
         };
         Self {
             key,
@@ -133,6 +144,66 @@ impl From<MouseEvent> for Input {
     }
 }
 
+/// Collector of text pasted by bracketed paste for termion. termion does not parse bracketed paste, so pasted text
+/// arrives as key events between the start marker and the end marker. This type collects the key events and
+/// converts them into a single [`Key::Pasted`] input.
+///
+/// To enable bracketed paste in the terminal, write `\x1b[?2004h` to stdout. Write `\x1b[?2004l` to disable it
+/// before exiting.
+/// ```no_run
+/// use std::io::{stdin, stdout, Write};
+/// use termion::input::TermRead;
+/// use tui_textarea::{TermionPaste, TextArea};
+///
+/// let mut textarea = TextArea::default();
+/// let mut paste = TermionPaste::default();
+///
+/// write!(stdout(), "\x1b[?2004h").unwrap();
+/// for event in stdin().events() {
+///     // `None` is returned while pasted text is being collected
+///     if let Some(input) = paste.collect(event.unwrap()) {
+///         textarea.input(input);
+///     }
+/// }
+/// write!(stdout(), "\x1b[?2004l").unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TermionPaste {
+    text: Option<String>,
+}
+
+impl TermionPaste {
+    /// Feed the event. This method returns `None` while collecting pasted text. When the paste ends, the collected text
+    /// is returned as [`Key::Pasted`]. Other events are converted into [`Input`] as-is.
+    pub fn collect(&mut self, event: Event) -> Option<Input> {
+        match (&mut self.text, event) {
+            (None, Event::Unsupported(seq)) if seq == b"\x1b[200~" => {
+                self.text = Some(String::new());
+                None
+            }
+            (Some(_), Event::Unsupported(seq)) if seq == b"\x1b[201~" => {
+                let text = self.text.take().unwrap_or_default();
+                Some(Input {
+                    key: Key::Pasted(text),
+                    ..Input::default()
+                })
+            }
+            (Some(text), Event::Key(KeyEvent::Char(c))) => {
+                text.push(if c == '\r' { '\n' } else { c });
+                None
+            }
+            // Control characters and escape sequences in pasted text are ignored
+            (Some(_), _) => None,
+            (None, event) => Some(Input::from(event)),
+        }
+    }
+
+    /// Return `true` while collecting pasted text.
+    pub fn is_pasting(&self) -> bool {
+        self.text.is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,7 +227,7 @@ mod tests {
             (KeyEvent::Char('\n'), input(Key::Enter, false, false, false)),
             (KeyEvent::Char('\r'), input(Key::Enter, false, false, false)),
             (KeyEvent::F(1), input(Key::F(1), false, false, false)),
-            (KeyEvent::BackTab, input(Key::Tab, false, false, false)),
+            (KeyEvent::BackTab, input(Key::Tab, false, false, true)),
             (KeyEvent::Null, input(Key::Null, false, false, false)),
             #[cfg(feature = "termion")]
             (KeyEvent::ShiftDown, input(Key::Down, false, false, true)),
@@ -184,14 +255,18 @@ mod tests {
             ),
             (
                 MouseEvent::Press(MouseButton::Left, 1, 1),
-                input(Key::Null, false, false, false),
+                input(Key::MouseDown(0, 0), false, false, false),
             ),
             (
-                MouseEvent::Release(1, 1),
-                input(Key::Null, false, false, false),
+                MouseEvent::Release(3, 2),
+                input(Key::MouseUp(2, 1), false, false, false),
+            ),
+            (
+                MouseEvent::Hold(2, 3),
+                input(Key::MouseDrag(1, 2), false, false, false),
             ),
             (
-                MouseEvent::Hold(1, 1),
+                MouseEvent::Press(MouseButton::Right, 1, 1),
                 input(Key::Null, false, false, false),
             ),
         ] {
@@ -218,4 +293,34 @@ mod tests {
             assert_eq!(Input::from(from.clone()), to, "{:?} -> {:?}", from, to);
         }
     }
+
+    #[test]
+    fn collect_pasted_text() {
+        let mut paste = TermionPaste::default();
+        let events = [
+            Event::Key(KeyEvent::Char('a')),
+            Event::Unsupported(b"\x1b[200~".to_vec()),
+            Event::Key(KeyEvent::Char('b')),
+            Event::Key(KeyEvent::Char('\r')),
+            Event::Key(KeyEvent::Char('\t')),
+            Event::Key(KeyEvent::Ctrl('c')),
+            Event::Unsupported(b"\x1b[201~".to_vec()),
+            Event::Key(KeyEvent::Char('c')),
+        ];
+        let mut inputs = vec![];
+        let mut pasting = vec![];
+        for event in events {
+            inputs.extend(paste.collect(event));
+            pasting.push(paste.is_pasting());
+        }
+        assert_eq!(pasting, [false, true, true, true, true, true, false, false]);
+        assert_eq!(
+            inputs,
+            [
+                input(Key::Char('a'), false, false, false),
+                input(Key::Pasted("b\n\t".into()), false, false, false),
+                input(Key::Char('c'), false, false, false),
+            ],
+        );
+    }
 }
@@ -4,12 +4,17 @@ use termwiz::input::{
 };
 
 impl From<InputEvent> for Input {
-    /// Convert [`termwiz::input::InputEvent`] into [`Input`].
+    /// Convert [`termwiz::input::InputEvent`] into [`Input`]. Text pasted by bracketed paste is converted into
+    /// [`Key::Pasted`].
     fn from(input: InputEvent) -> Self {
         match input {
             InputEvent::Key(key) => Self::from(key),
             InputEvent::Mouse(mouse) => Self::from(mouse),
             InputEvent::PixelMouse(mouse) => Self::from(mouse),
+            InputEvent::Paste(text) => Self {
+                key: Key::Pasted(text),
+                ..Self::default()
+            },
             _ => Self::default(),
         }
     }
@@ -76,14 +81,27 @@ impl From<MouseButtons> for Key {
 }
 
 impl From<MouseEvent> for Input {
-    /// Convert [`termwiz::input::MouseEvent`] into [`Input`].
+    /// Convert [`termwiz::input::MouseEvent`] into [`Input`]. termwiz reports 1-base positions so they are converted
+    /// into 0-base positions.
+    ///
+    /// termwiz does not distinguish pressing the left button from moving the mouse while the button is held. Both
+    /// are converted into [`Key::MouseDown`] and [`TextArea`](crate::TextArea) handles the latter as dragging. An
+    /// event without any button is converted into [`Key::MouseUp`].
     fn from(mouse: MouseEvent) -> Self {
         let MouseEvent {
+            x,
+            y,
             mouse_buttons,
             modifiers,
-            ..
         } = mouse;
-        let key = Key::from(mouse_buttons);
+        let (column, row) = (x.saturating_sub(1), y.saturating_sub(1));
+        let key = if mouse_buttons.contains(MouseButtons::LEFT) {
+            Key::MouseDown(column, row)
+        } else if mouse_buttons == MouseButtons::NONE {
+            Key::MouseUp(column, row)
+        } else {
+            Key::from(mouse_buttons)
+        };
         let ctrl = modifiers.contains(Modifiers::CTRL);
         let alt = modifiers.contains(Modifiers::ALT);
         let shift = modifiers.contains(Modifiers::SHIFT);
@@ -220,7 +238,7 @@ mod tests {
                 input(Key::MouseScrollDown, true, true, true),
             ),
             (
-                mouse_event(MouseButtons::LEFT, Modifiers::empty()),
+                mouse_event(MouseButtons::RIGHT, Modifiers::empty()),
                 input(Key::Null, false, false, false),
             ),
         ] {
@@ -231,6 +249,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mouse_button_to_input() {
+        for (from, to) in [
+            (
+                mouse_event(MouseButtons::LEFT, Modifiers::empty()),
+                input(Key::MouseDown(0, 0), false, false, false),
+            ),
+            (
+                mouse_event(MouseButtons::LEFT, Modifiers::SHIFT),
+                input(Key::MouseDown(0, 0), false, false, true),
+            ),
+            (
+                mouse_event(MouseButtons::NONE, Modifiers::empty()),
+                input(Key::MouseUp(0, 0), false, false, false),
+            ),
+        ] {
+            assert_eq!(Input::from(from.clone()), to, "{:?} -> {:?}", from, to);
+        }
+
+        // Positions are not available in pixel mouse events
+        let from = pixel_mouse_event(MouseButtons::LEFT, Modifiers::empty());
+        let to = input(Key::Null, false, false, false);
+        assert_eq!(Input::from(from.clone()), to, "{:?} -> {:?}", from, to);
+    }
+
     #[test]
     fn event_to_input() {
         for (from, to) in [
@@ -251,7 +294,7 @@ mod tests {
             ),
             (
                 InputEvent::Paste("x".into()),
-                input(Key::Null, false, false, false),
+                input(Key::Pasted("x".into()), false, false, false),
             ),
         ] {
             assert_eq!(Input::from(from.clone()), to, "{:?} -> {:?}", from, to);
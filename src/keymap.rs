@@ -0,0 +1,591 @@
+use crate::cursor::CursorMove;
+use crate::input::{Input, Key};
+use crate::scroll::Scrolling;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+/// Editor action which can be bound to key inputs with [`KeyMap`]. Actions are performed by
+/// [`TextArea::perform`](crate::TextArea::perform).
+///
+/// This type is marked as `#[non_exhaustive]` since more actions may be supported in the future.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Action {
+    /// Insert the character. See [`TextArea::insert_char`](crate::TextArea::insert_char).
+    InsertChar(char),
+    /// Insert a newline. See [`TextArea::insert_newline`](crate::TextArea::insert_newline).
+    InsertNewline,
+    /// Insert a tab or spaces. See [`TextArea::insert_tab`](crate::TextArea::insert_tab).
+    InsertTab,
+    /// Indent the selected lines. See [`TextArea::indent_lines`](crate::TextArea::indent_lines).
+    IndentLines,
+    /// Outdent the selected lines. See [`TextArea::outdent_lines`](crate::TextArea::outdent_lines).
+    OutdentLines,
+    /// Delete one character before the cursor. See [`TextArea::delete_char`](crate::TextArea::delete_char).
+    DeleteChar,
+    /// Delete one character next to the cursor. See [`TextArea::delete_next_char`](crate::TextArea::delete_next_char).
+    DeleteNextChar,
+    /// Duplicate the selected lines. See [`TextArea::duplicate_lines`](crate::TextArea::duplicate_lines).
+    DuplicateLines,
+    /// Delete the selected lines. See [`TextArea::delete_lines`](crate::TextArea::delete_lines).
+    DeleteLines,
+    /// Move the selected lines up. See [`TextArea::move_lines_up`](crate::TextArea::move_lines_up).
+    MoveLinesUp,
+    /// Move the selected lines down. See [`TextArea::move_lines_down`](crate::TextArea::move_lines_down).
+    MoveLinesDown,
+    /// Join the selected lines. See [`TextArea::join_lines`](crate::TextArea::join_lines).
+    JoinLines,
+    /// Sort the selected lines. See [`TextArea::sort_lines`](crate::TextArea::sort_lines).
+    SortLines,
+    /// Toggle the line comment of the selected lines. See
+    /// [`TextArea::toggle_comment`](crate::TextArea::toggle_comment).
+    ToggleComment,
+    /// Delete from the cursor until the end of line. See
+    /// [`TextArea::delete_line_by_end`](crate::TextArea::delete_line_by_end).
+    DeleteLineByEnd,
+    /// Delete from the cursor until the head of line. See
+    /// [`TextArea::delete_line_by_head`](crate::TextArea::delete_line_by_head).
+    DeleteLineByHead,
+    /// Delete one word before the cursor. See [`TextArea::delete_word`](crate::TextArea::delete_word).
+    DeleteWord,
+    /// Delete one word next to the cursor. See [`TextArea::delete_next_word`](crate::TextArea::delete_next_word).
+    DeleteNextWord,
+    /// Move the cursor and cancel the current selection. See [`TextArea::move_cursor`](crate::TextArea::move_cursor).
+    Move(CursorMove),
+    /// Move the cursor while extending the selection. Selection starts at the cursor when nothing is selected.
+    Select(CursorMove),
+    /// Scroll the textarea and cancel the current selection. See [`TextArea::scroll`](crate::TextArea::scroll).
+    Scroll(Scrolling),
+    /// Scroll the textarea while extending the selection.
+    SelectScroll(Scrolling),
+    /// Move the cursor while extending the block selection. Block selection starts at the cursor when no block is
+    /// selected. See [`TextArea::start_block_selection`](crate::TextArea::start_block_selection).
+    SelectBlock(CursorMove),
+    /// Undo the last edit. See [`TextArea::undo`](crate::TextArea::undo).
+    Undo,
+    /// Redo the last undone edit. See [`TextArea::redo`](crate::TextArea::redo).
+    Redo,
+    /// Copy the selected text. See [`TextArea::copy`](crate::TextArea::copy).
+    Copy,
+    /// Cut the selected text. See [`TextArea::cut`](crate::TextArea::cut).
+    Cut,
+    /// Paste the yanked text. See [`TextArea::paste`](crate::TextArea::paste).
+    Paste,
+    /// Select the entire text. See [`TextArea::select_all`](crate::TextArea::select_all).
+    SelectAll,
+    /// Select the whole lines. See [`TextArea::select_lines`](crate::TextArea::select_lines).
+    SelectLines,
+    /// Cancel the current selection and remove extra cursors. See
+    /// [`TextArea::cancel_selection`](crate::TextArea::cancel_selection) and
+    /// [`TextArea::clear_extra_cursors`](crate::TextArea::clear_extra_cursors).
+    CancelSelection,
+    /// Add a cursor on the line above. See [`TextArea::add_cursor_above`](crate::TextArea::add_cursor_above).
+    AddCursorAbove,
+    /// Add a cursor on the line below. See [`TextArea::add_cursor_below`](crate::TextArea::add_cursor_below).
+    AddCursorBelow,
+    /// Select the next occurrence of the selected text with a new cursor. See
+    /// [`TextArea::add_next_occurrence`](crate::TextArea::add_next_occurrence).
+    AddNextOccurrence,
+    /// Open the completion popup for the word before the cursor. See
+    /// [`TextArea::complete`](crate::TextArea::complete).
+    TriggerCompletion,
+    /// Application-defined action. [`TextArea`](crate::TextArea) does nothing for this action. Applications can
+    /// handle it by resolving inputs with [`KeyMap::resolve`] by themselves.
+    Custom(String),
+}
+
+/// Key bindings which map key inputs to editor [`Action`]s.
+///
+/// Each binding is a sequence of one or more [`Input`]s. For example, `Ctrl+X Ctrl+S` is a sequence of two inputs.
+/// While the inputs typed so far are a prefix of some sequence, [`KeyMap::resolve`] waits for the next input. When the
+/// typed inputs don't match any binding, they are discarded. Note that binding a sequence makes its prefixes wait for
+/// the next input, so single-key bindings which are prefixes of other sequences are no longer triggered.
+///
+/// Typing a character without Ctrl and Alt modifiers which is not bound inserts the character.
+///
+/// Two presets are available. [`KeyMap::emacs`] is the default key mappings of [`TextArea`](crate::TextArea). See the
+/// table in [the module document](./index.html) for the bindings. [`KeyMap::standard`] provides key mappings of
+/// standard desktop text editors such as Ctrl+C/Ctrl+V/Ctrl+Z and Shift+arrows.
+/// ```
+/// use tui_textarea::{Action, CursorMove, Input, Key, KeyMap, TextArea};
+///
+/// fn ctrl(c: char) -> Input {
+///     Input { key: Key::Char(c), ctrl: true, alt: false, shift: false }
+/// }
+///
+/// let mut keymap = KeyMap::standard();
+/// // Rebind Ctrl+E to move the cursor to the end of line
+/// keymap.bind([ctrl('e')], Action::Move(CursorMove::End));
+/// // Unbind Ctrl+A
+/// keymap.unbind([ctrl('a')]);
+/// // Bind the sequence Ctrl+K Ctrl+U to an action handled by application
+/// keymap.bind([ctrl('k'), ctrl('u')], Action::Custom("upper".to_string()));
+///
+/// let mut textarea = TextArea::from(["hello"]);
+/// textarea.set_key_map(keymap);
+///
+/// textarea.input(ctrl('e'));
+/// assert_eq!(textarea.cursor(), (0, 5));
+///
+/// textarea.input(ctrl('k'));
+/// assert_eq!(textarea.key_map().pending(), [ctrl('k')]);
+/// textarea.input(ctrl('u')); // `TextArea::input` ignores custom actions
+/// assert!(textarea.key_map().pending().is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyMap {
+    bindings: HashMap<Vec<Input>, Action>,
+    prefixes: HashSet<Vec<Input>>,
+    pending: Vec<Input>,
+}
+
+impl Default for KeyMap {
+    /// Create the default key mappings. This is the same as [`KeyMap::emacs`].
+    fn default() -> Self {
+        Self::emacs()
+    }
+}
+
+impl KeyMap {
+    /// Create a key map without any binding. Only inserting characters is available.
+    pub fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            prefixes: HashSet::new(),
+            pending: vec![],
+        }
+    }
+
+    /// Create a key map with Emacs-like key bindings. This is the default key map of [`TextArea`](crate::TextArea).
+    /// See the table in [the module document](./index.html) for the bindings.
+    pub fn emacs() -> Self {
+        let mut m = Self::new();
+        let (t, f) = (Some(true), Some(false));
+        m.preset(Key::Char('m'), t, f, Action::InsertNewline);
+        m.preset(Key::Char('\n'), f, f, Action::InsertNewline);
+        m.preset(Key::Char('\r'), f, f, Action::InsertNewline);
+        m.preset(Key::Enter, None, None, Action::InsertNewline);
+        m.preset_with_shift(&Key::Tab, f, f, true, &Action::OutdentLines);
+        m.preset(Key::Tab, f, f, Action::InsertTab);
+        m.preset(Key::Char('h'), t, f, Action::DeleteChar);
+        m.preset(Key::Backspace, f, f, Action::DeleteChar);
+        m.preset(Key::Char('d'), t, f, Action::DeleteNextChar);
+        m.preset(Key::Delete, f, f, Action::DeleteNextChar);
+        m.preset(Key::Char('k'), t, f, Action::DeleteLineByEnd);
+        m.preset(Key::Char('j'), t, f, Action::DeleteLineByHead);
+        m.preset(Key::Char('w'), t, f, Action::DeleteWord);
+        m.preset(Key::Char('h'), f, t, Action::DeleteWord);
+        m.preset(Key::Backspace, f, t, Action::DeleteWord);
+        m.preset(Key::Delete, f, t, Action::DeleteNextWord);
+        m.preset(Key::Char('d'), f, t, Action::DeleteNextWord);
+        m.preset_move(Key::Char('n'), t, f, CursorMove::Down);
+        m.preset_move(Key::Down, f, f, CursorMove::Down);
+        m.preset_move(Key::Char('p'), t, f, CursorMove::Up);
+        m.preset_move(Key::Up, f, f, CursorMove::Up);
+        m.preset_move(Key::Char('f'), t, f, CursorMove::Forward);
+        m.preset_move(Key::Right, f, f, CursorMove::Forward);
+        m.preset_move(Key::Char('b'), t, f, CursorMove::Back);
+        m.preset_move(Key::Left, f, f, CursorMove::Back);
+        m.preset_move(Key::Char('a'), t, f, CursorMove::Head);
+        m.preset_move(Key::Home, None, None, CursorMove::Head);
+        m.preset_move(Key::Left, t, t, CursorMove::Head);
+        m.preset_move(Key::Char('b'), t, t, CursorMove::Head);
+        m.preset_move(Key::Char('e'), t, f, CursorMove::End);
+        m.preset_move(Key::End, None, None, CursorMove::End);
+        m.preset_move(Key::Right, t, t, CursorMove::End);
+        m.preset_move(Key::Char('f'), t, t, CursorMove::End);
+        m.preset_move(Key::Char('<'), f, t, CursorMove::Top);
+        m.preset_move(Key::Up, t, t, CursorMove::Top);
+        m.preset_move(Key::Char('p'), t, t, CursorMove::Top);
+        m.preset_move(Key::Char('>'), f, t, CursorMove::Bottom);
+        m.preset_move(Key::Down, t, t, CursorMove::Bottom);
+        m.preset_move(Key::Char('n'), t, t, CursorMove::Bottom);
+        m.preset_move(Key::Char('f'), f, t, CursorMove::WordForward);
+        m.preset_move(Key::Right, t, f, CursorMove::WordForward);
+        m.preset_move(Key::Char('b'), f, t, CursorMove::WordBack);
+        m.preset_move(Key::Left, t, f, CursorMove::WordBack);
+        m.preset_move(Key::Char(']'), f, t, CursorMove::ParagraphForward);
+        m.preset_move(Key::Char('n'), f, t, CursorMove::ParagraphForward);
+        m.preset_move(Key::Down, t, f, CursorMove::ParagraphForward);
+        m.preset_move(Key::Char('['), f, t, CursorMove::ParagraphBack);
+        m.preset_move(Key::Char('p'), f, t, CursorMove::ParagraphBack);
+        m.preset_move(Key::Up, t, f, CursorMove::ParagraphBack);
+        m.preset_select_block();
+        m.preset_with_shift(&Key::Up, f, t, false, &Action::MoveLinesUp);
+        m.preset_with_shift(&Key::Down, f, t, false, &Action::MoveLinesDown);
+        m.preset(Key::Char('u'), t, f, Action::Undo);
+        m.preset(Key::Char('r'), t, f, Action::Redo);
+        m.preset(Key::Char('y'), t, f, Action::Paste);
+        m.preset(Key::Paste, None, None, Action::Paste);
+        m.preset(Key::Char('x'), t, f, Action::Cut);
+        m.preset(Key::Cut, None, None, Action::Cut);
+        m.preset(Key::Char('c'), t, f, Action::Copy);
+        m.preset(Key::Copy, None, None, Action::Copy);
+        m.preset_scroll(Key::Char('v'), t, f, Scrolling::PageDown);
+        m.preset_scroll(Key::PageDown, None, None, Scrolling::PageDown);
+        m.preset_scroll(Key::Char('v'), f, t, Scrolling::PageUp);
+        m.preset_scroll(Key::PageUp, None, None, Scrolling::PageUp);
+        m.preset_scroll(Key::MouseScrollDown, None, None, (1, 0).into());
+        m.preset_scroll(Key::MouseScrollUp, None, None, (-1, 0).into());
+        m
+    }
+
+    /// Create a key map with key bindings of standard desktop text editors.
+    ///
+    /// | Mappings                                  | Description                                  |
+    /// |-------------------------------------------|----------------------------------------------|
+    /// | `Enter`                                   | Insert newline                               |
+    /// | `Tab`                                     | Insert tab                                   |
+    /// | `Shift+Tab`                               | Outdent selected lines                       |
+    /// | `Backspace`, `Delete`                     | Delete one character before/next to cursor   |
+    /// | `Ctrl+Backspace`, `Ctrl+Delete`           | Delete one word before/next to cursor        |
+    /// | `←`, `→`, `↑`, `↓`                        | Move cursor by character or line             |
+    /// | `Ctrl+←`, `Ctrl+→`                        | Move cursor by word                          |
+    /// | `Ctrl+↑`, `Ctrl+↓`                        | Move cursor by paragraph                     |
+    /// | `Home`, `End`                             | Move cursor to head/end of line              |
+    /// | `Ctrl+Home`, `Ctrl+End`                   | Move cursor to top/bottom of lines           |
+    /// | `PageUp`, `PageDown`                      | Scroll up/down by page                       |
+    /// | `Shift` + the above movements             | Extend the selection                         |
+    /// | `Shift+Alt` + arrows                      | Extend the block selection                   |
+    /// | `Ctrl+A`                                  | Select all text                              |
+    /// | `Ctrl+C`, `Ctrl+X`, `Ctrl+V`              | Copy, cut, and paste                         |
+    /// | `Ctrl+Z`                                  | Undo                                         |
+    /// | `Ctrl+Y`, `Ctrl+Shift+Z`                  | Redo                                         |
+    /// | `Ctrl+Alt+↑`, `Ctrl+Alt+↓`                | Add cursor above/below                       |
+    /// | `Alt+↑`, `Alt+↓`                          | Move selected lines up/down                  |
+    /// | `Ctrl+L`                                  | Select whole lines                           |
+    /// | `Ctrl+Shift+D`                            | Duplicate selected lines                     |
+    /// | `Ctrl+Shift+K`                            | Delete selected lines                        |
+    /// | `Ctrl+J`                                  | Join selected lines                          |
+    /// | `Ctrl+/`                                  | Toggle line comment                          |
+    /// | `Ctrl+D`                                  | Select next occurrence with new cursor       |
+    /// | `Esc`                                     | Cancel selection and remove extra cursors    |
+    /// | `Ctrl+Space`                              | Open completion popup                        |
+    pub fn standard() -> Self {
+        let mut m = Self::new();
+        let (t, f) = (Some(true), Some(false));
+        m.preset(Key::Up, t, t, Action::AddCursorAbove);
+        m.preset(Key::Down, t, t, Action::AddCursorBelow);
+        m.preset(Key::Enter, f, f, Action::InsertNewline);
+        m.preset_with_shift(&Key::Tab, f, f, true, &Action::OutdentLines);
+        m.preset(Key::Tab, f, f, Action::InsertTab);
+        m.preset(Key::Backspace, f, f, Action::DeleteChar);
+        m.preset(Key::Delete, f, f, Action::DeleteNextChar);
+        m.preset(Key::Backspace, t, f, Action::DeleteWord);
+        m.preset(Key::Delete, t, f, Action::DeleteNextWord);
+        m.preset_move(Key::Left, f, f, CursorMove::Back);
+        m.preset_move(Key::Right, f, f, CursorMove::Forward);
+        m.preset_move(Key::Up, f, f, CursorMove::Up);
+        m.preset_move(Key::Down, f, f, CursorMove::Down);
+        m.preset_move(Key::Left, t, f, CursorMove::WordBack);
+        m.preset_move(Key::Right, t, f, CursorMove::WordForward);
+        m.preset_move(Key::Up, t, f, CursorMove::ParagraphBack);
+        m.preset_move(Key::Down, t, f, CursorMove::ParagraphForward);
+        m.preset_move(Key::Home, f, f, CursorMove::Head);
+        m.preset_move(Key::End, f, f, CursorMove::End);
+        m.preset_move(Key::Home, t, f, CursorMove::Top);
+        m.preset_move(Key::End, t, f, CursorMove::Bottom);
+        m.preset_select_block();
+        m.preset_with_shift(&Key::Up, f, t, false, &Action::MoveLinesUp);
+        m.preset_with_shift(&Key::Down, f, t, false, &Action::MoveLinesDown);
+        m.preset_scroll(Key::PageUp, f, f, Scrolling::PageUp);
+        m.preset_scroll(Key::PageDown, f, f, Scrolling::PageDown);
+        m.preset_scroll(Key::MouseScrollDown, None, None, (1, 0).into());
+        m.preset_scroll(Key::MouseScrollUp, None, None, (-1, 0).into());
+        m.preset(Key::Char('a'), t, f, Action::SelectAll);
+        m.preset(Key::Char('l'), t, f, Action::SelectLines);
+        m.preset(Key::Char('j'), t, f, Action::JoinLines);
+        m.preset(Key::Char('/'), t, f, Action::ToggleComment);
+        for c in ['d', 'D'] {
+            m.bind(
+                [input(Key::Char(c), true, false, true)],
+                Action::DuplicateLines,
+            );
+        }
+        for c in ['k', 'K'] {
+            m.bind(
+                [input(Key::Char(c), true, false, true)],
+                Action::DeleteLines,
+            );
+        }
+        m.preset(Key::Char('d'), t, f, Action::AddNextOccurrence);
+        m.preset(Key::Char(' '), t, f, Action::TriggerCompletion);
+        m.preset(Key::Char('c'), t, f, Action::Copy);
+        m.preset(Key::Copy, None, None, Action::Copy);
+        m.preset(Key::Char('x'), t, f, Action::Cut);
+        m.preset(Key::Cut, None, None, Action::Cut);
+        m.preset(Key::Char('v'), t, f, Action::Paste);
+        m.preset(Key::Paste, None, None, Action::Paste);
+        m.bind([input(Key::Char('z'), true, false, false)], Action::Undo);
+        m.bind([input(Key::Char('y'), true, false, false)], Action::Redo);
+        m.bind([input(Key::Char('z'), true, false, true)], Action::Redo);
+        m.bind([input(Key::Char('Z'), true, false, true)], Action::Redo);
+        m.preset(Key::Esc, f, f, Action::CancelSelection);
+        m
+    }
+
+    // Bind the action to the key with every combination of modifiers which are not specified. Keys which are already
+    // bound are not overwritten so that the earlier bindings take precedence.
+    fn preset(&mut self, key: Key, ctrl: Option<bool>, alt: Option<bool>, action: Action) {
+        for shift in [false, true] {
+            self.preset_with_shift(&key, ctrl, alt, shift, &action);
+        }
+    }
+
+    fn preset_with_shift(
+        &mut self,
+        key: &Key,
+        ctrl: Option<bool>,
+        alt: Option<bool>,
+        shift: bool,
+        action: &Action,
+    ) {
+        fn choices(modifier: Option<bool>) -> &'static [bool] {
+            match modifier {
+                Some(true) => &[true],
+                Some(false) => &[false],
+                None => &[false, true],
+            }
+        }
+
+        for &ctrl in choices(ctrl) {
+            for &alt in choices(alt) {
+                let keys = vec![input(key.clone(), ctrl, alt, shift)];
+                self.bindings.entry(keys).or_insert_with(|| action.clone());
+            }
+        }
+    }
+
+    // Bind cursor movement to the key. Moving the cursor with Shift extends the selection.
+    fn preset_move(&mut self, key: Key, ctrl: Option<bool>, alt: Option<bool>, m: CursorMove) {
+        self.preset_with_shift(&key, ctrl, alt, false, &Action::Move(m));
+        self.preset_with_shift(&key, ctrl, alt, true, &Action::Select(m));
+    }
+
+    // Bind Shift+Alt+arrows to extending the block selection
+    fn preset_select_block(&mut self) {
+        let (t, f) = (Some(true), Some(false));
+        for (key, m) in [
+            (Key::Left, CursorMove::Back),
+            (Key::Right, CursorMove::Forward),
+            (Key::Up, CursorMove::Up),
+            (Key::Down, CursorMove::Down),
+        ] {
+            self.preset_with_shift(&key, f, t, true, &Action::SelectBlock(m));
+        }
+    }
+
+    // Bind scrolling to the key. Scrolling with Shift extends the selection.
+    fn preset_scroll(&mut self, key: Key, ctrl: Option<bool>, alt: Option<bool>, s: Scrolling) {
+        self.preset_with_shift(&key, ctrl, alt, false, &Action::Scroll(s));
+        self.preset_with_shift(&key, ctrl, alt, true, &Action::SelectScroll(s));
+    }
+
+    /// Bind the action to the sequence of inputs. When the sequence was already bound, the previous action is
+    /// replaced and returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the sequence is empty.
+    pub fn bind(
+        &mut self,
+        keys: impl IntoIterator<Item = Input>,
+        action: Action,
+    ) -> Option<Action> {
+        let keys: Vec<_> = keys.into_iter().collect();
+        assert!(!keys.is_empty(), "key sequence must not be empty");
+        for len in 1..keys.len() {
+            self.prefixes.insert(keys[..len].to_vec());
+        }
+        self.pending.clear();
+        self.bindings.insert(keys, action)
+    }
+
+    /// Unbind the sequence of inputs and return the action which was bound to it.
+    pub fn unbind(&mut self, keys: impl IntoIterator<Item = Input>) -> Option<Action> {
+        let keys: Vec<_> = keys.into_iter().collect();
+        let action = self.bindings.remove(&keys)?;
+        if keys.len() > 1 {
+            self.prefixes = self
+                .bindings
+                .keys()
+                .flat_map(|keys| (1..keys.len()).map(|len| keys[..len].to_vec()))
+                .collect();
+        }
+        self.pending.clear();
+        Some(action)
+    }
+
+    /// Get the action bound to the sequence of inputs.
+    /// ```
+    /// use tui_textarea::{Action, Input, Key, KeyMap};
+    ///
+    /// let keymap = KeyMap::emacs();
+    /// let ctrl_u = Input { key: Key::Char('u'), ctrl: true, alt: false, shift: false };
+    /// assert_eq!(keymap.get(&[ctrl_u]), Some(&Action::Undo));
+    /// ```
+    pub fn get(&self, keys: &[Input]) -> Option<&Action> {
+        self.bindings.get(keys)
+    }
+
+    /// Iterate all bindings in arbitrary order.
+    pub fn bindings(&self) -> impl Iterator<Item = (&[Input], &Action)> {
+        self.bindings.iter().map(|(k, a)| (k.as_slice(), a))
+    }
+
+    /// Get the inputs typed so far which are a prefix of some key sequence. This is useful to show the pending key
+    /// sequence to users.
+    pub fn pending(&self) -> &[Input] {
+        &self.pending
+    }
+
+    /// Discard the inputs typed so far.
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Resolve the input into the action bound to it. `None` is returned when the input is waiting for the next input
+    /// of a key sequence or no action is bound to the input.
+    /// ```
+    /// use tui_textarea::{Action, Input, Key, KeyMap};
+    ///
+    /// fn ctrl(c: char) -> Input {
+    ///     Input { key: Key::Char(c), ctrl: true, alt: false, shift: false }
+    /// }
+    ///
+    /// let mut keymap = KeyMap::new();
+    /// keymap.bind([ctrl('x'), ctrl('s')], Action::Custom("save".to_string()));
+    ///
+    /// assert_eq!(keymap.resolve(ctrl('x')), None);
+    /// assert_eq!(keymap.resolve(ctrl('s')), Some(Action::Custom("save".to_string())));
+    ///
+    /// // Unbound character is inserted
+    /// let a = Input { key: Key::Char('a'), ..Default::default() };
+    /// assert_eq!(keymap.resolve(a), Some(Action::InsertChar('a')));
+    /// ```
+    pub fn resolve(&mut self, input: Input) -> Option<Action> {
+        if input.key == Key::Null {
+            return None; // Ignore invalid inputs such as key release events in the middle of a sequence
+        }
+
+        self.pending.push(input);
+        if self.prefixes.contains(&self.pending) {
+            return None;
+        }
+
+        let keys = mem::take(&mut self.pending);
+        if let Some(action) = self.bindings.get(&keys) {
+            return Some(action.clone());
+        }
+        match keys.as_slice() {
+            [
+                Input {
+                    key: Key::Char(c),
+                    ctrl: false,
+                    alt: false,
+                    ..
+                },
+            ] => Some(Action::InsertChar(*c)),
+            _ => None,
+        }
+    }
+}
+
+fn input(key: Key, ctrl: bool, alt: bool, shift: bool) -> Input {
+    Input {
+        key,
+        ctrl,
+        alt,
+        shift,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctrl(c: char) -> Input {
+        input(Key::Char(c), true, false, false)
+    }
+
+    #[test]
+    fn preset_keeps_earlier_bindings() {
+        let m = KeyMap::emacs();
+        // `Home` matches any modifiers, but `Ctrl+Alt+Left` is bound to the same action
+        for (keys, action) in [
+            (
+                input(Key::Home, true, true, false),
+                Action::Move(CursorMove::Head),
+            ),
+            (
+                input(Key::Home, false, false, true),
+                Action::Select(CursorMove::Head),
+            ),
+            (
+                input(Key::Char('h'), true, false, false),
+                Action::DeleteChar,
+            ),
+            (
+                input(Key::Char('h'), false, true, false),
+                Action::DeleteWord,
+            ),
+            (input(Key::Enter, true, true, true), Action::InsertNewline),
+            (input(Key::Tab, false, false, true), Action::OutdentLines),
+        ] {
+            assert_eq!(
+                m.get(std::slice::from_ref(&keys)),
+                Some(&action),
+                "{keys:?}"
+            );
+        }
+        assert_eq!(m.get(&[input(Key::Tab, true, false, false)]), None);
+    }
+
+    #[test]
+    fn resolve_sequence() {
+        let mut m = KeyMap::emacs();
+        m.bind([ctrl('x'), ctrl('s')], Action::Custom("save".into()));
+        assert_eq!(m.resolve(ctrl('x')), None);
+        assert_eq!(m.pending(), [ctrl('x')]);
+        assert_eq!(m.resolve(ctrl('s')), Some(Action::Custom("save".into())));
+        assert!(m.pending().is_empty());
+
+        // Unknown sequence is discarded including the character input
+        assert_eq!(m.resolve(ctrl('x')), None);
+        assert_eq!(m.resolve(input(Key::Char('a'), false, false, false)), None);
+        assert!(m.pending().is_empty());
+        assert_eq!(
+            m.resolve(input(Key::Char('a'), false, false, false)),
+            Some(Action::InsertChar('a')),
+        );
+
+        // Unbinding the sequence makes the prefix available again
+        assert_eq!(
+            m.unbind([ctrl('x'), ctrl('s')]),
+            Some(Action::Custom("save".into())),
+        );
+        assert_eq!(m.resolve(ctrl('x')), Some(Action::Cut));
+    }
+
+    #[test]
+    fn rebind_and_unbind() {
+        let mut m = KeyMap::standard();
+        assert_eq!(
+            m.bind([ctrl('a')], Action::Move(CursorMove::Head)),
+            Some(Action::SelectAll)
+        );
+        assert_eq!(m.resolve(ctrl('a')), Some(Action::Move(CursorMove::Head)));
+        assert_eq!(m.unbind([ctrl('a')]), Some(Action::Move(CursorMove::Head)));
+        assert_eq!(m.unbind([ctrl('a')]), None);
+        assert_eq!(m.resolve(ctrl('a')), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bind_empty_sequence() {
+        KeyMap::new().bind([], Action::Undo);
+    }
+}
@@ -9,15 +9,32 @@ compile_error!(
     "ratatui support and tui-rs support are exclusive. only one of them can be enabled at the same time. see https://github.com/rhysd/tui-textarea#installation"
 );
 
+mod buffer;
+mod clipboard;
+mod completion;
 mod cursor;
+mod diagnostic;
+mod fold;
+mod gutter;
 mod highlight;
 mod history;
+mod indent;
 mod input;
+mod keymap;
+mod mouse;
 mod scroll;
 #[cfg(feature = "search")]
 mod search;
+mod snapshot;
+mod syntax;
 mod textarea;
+mod undo_file;
+mod undo_tree;
 mod util;
+mod validation;
+#[cfg(feature = "vim")]
+mod vim;
+mod virtual_text;
 mod widget;
 mod word;
 mod wrap;
@@ -50,8 +67,30 @@ use termion;
 #[cfg(feature = "tuirs-termion")]
 use termion_15 as termion;
 
+pub use buffer::{LineRope, TextBuffer};
+pub use clipboard::{Clipboard, CommandClipboard, MemoryClipboard, Osc52Clipboard};
+pub use completion::{CompletionProvider, WordList};
 pub use cursor::CursorMove;
+pub use diagnostic::{Diagnostic, Severity};
+pub use gutter::{LineLabel, LineNumbers, Sign};
+pub use history::{TextChange, UndoGrouping, UndoState};
+pub use indent::AutoIndent;
+#[cfg(any(feature = "termion", feature = "tuirs-termion"))]
+pub use input::TermionPaste;
 pub use input::{Input, Key};
+pub use keymap::{Action, KeyMap};
 pub use scroll::Scrolling;
+#[cfg(feature = "search")]
+pub use search::SearchOptions;
+pub use snapshot::TextAreaSnapshot;
+pub use syntax::Highlighter;
+#[cfg(feature = "syntect")]
+pub use syntax::SyntectHighlighter;
 pub use textarea::{TextArea, TextAreaMeasure};
+#[cfg(feature = "search")]
+pub use validation::RegexFilter;
+pub use validation::{DigitsOnly, Insertion, MaxLength, MaxLines, Validation, Validator};
+#[cfg(feature = "vim")]
+pub use vim::{Vim, VimEvent, VimMode, VimOperator, VimRegister};
+pub use virtual_text::VirtualText;
 pub use wrap::WrapMode;
@@ -0,0 +1,65 @@
+use std::time::{Duration, Instant};
+
+// Clicks on the same position within this interval are counted as double-click or triple-click.
+const MULTI_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, Default)]
+pub struct MouseState {
+    last_click: Option<(Instant, (usize, usize))>,
+    clicks: u8,
+    pressed: bool,
+}
+
+impl MouseState {
+    /// Record a press of the left button at the cursor position and return how many times the position was clicked
+    /// successively. The count cycles through 1 (single-click), 2 (double-click), and 3 (triple-click).
+    pub fn press(&mut self, pos: (usize, usize)) -> u8 {
+        let now = Instant::now();
+        self.clicks = match self.last_click {
+            Some((at, prev)) if prev == pos && now.duration_since(at) <= MULTI_CLICK_INTERVAL => {
+                self.clicks % 3 + 1
+            }
+            _ => 1,
+        };
+        self.last_click = Some((now, pos));
+        self.pressed = true;
+        self.clicks
+    }
+
+    pub fn release(&mut self) {
+        self.pressed = false;
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_clicks() {
+        let mut m = MouseState::default();
+        assert_eq!(m.press((0, 0)), 1);
+        assert!(m.is_pressed());
+        m.release();
+        assert!(!m.is_pressed());
+        assert_eq!(m.press((0, 0)), 2);
+        assert_eq!(m.press((0, 0)), 3);
+        assert_eq!(m.press((0, 0)), 1);
+        assert_eq!(m.press((0, 1)), 1);
+    }
+
+    #[test]
+    fn reset_clicks_after_interval() {
+        let mut m = MouseState::default();
+        assert_eq!(m.press((0, 0)), 1);
+        let (at, pos) = m.last_click.unwrap();
+        if let Some(past) = at.checked_sub(MULTI_CLICK_INTERVAL * 2) {
+            m.last_click = Some((past, pos));
+            assert_eq!(m.press((0, 0)), 1);
+        }
+    }
+}
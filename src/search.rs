@@ -1,10 +1,82 @@
+use crate::buffer::TextBuffer;
 use crate::ratatui::style::{Color, Style};
-use regex::Regex;
+use crate::util::Pos;
+use regex::{Captures, Regex, RegexBuilder};
+use std::sync::OnceLock;
+
+/// Options to interpret the query of text search. See [`crate::TextArea::set_search_options`].
+/// ```
+/// use tui_textarea::SearchOptions;
+///
+/// let opts = SearchOptions::default().ignore_case(true).whole_word(true);
+/// assert!(opts.ignore_case && opts.whole_word && !opts.literal);
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SearchOptions {
+    /// Match letters ignoring their cases.
+    pub ignore_case: bool,
+    /// Match only whole words. Matches must start and end at word boundaries.
+    pub whole_word: bool,
+    /// Treat the query as a plain string instead of a regular expression.
+    pub literal: bool,
+    /// Match the query against the entire text where lines are joined with `\n`, instead of each line. Matches can
+    /// span multiple lines and `^`/`$` match at the start/end of each line.
+    pub multi_line: bool,
+}
+
+impl SearchOptions {
+    /// Set if the search ignores cases of letters.
+    pub fn ignore_case(mut self, enabled: bool) -> Self {
+        self.ignore_case = enabled;
+        self
+    }
+
+    /// Set if the search matches only whole words.
+    pub fn whole_word(mut self, enabled: bool) -> Self {
+        self.whole_word = enabled;
+        self
+    }
+
+    /// Set if the query is a plain string instead of a regular expression.
+    pub fn literal(mut self, enabled: bool) -> Self {
+        self.literal = enabled;
+        self
+    }
+
+    /// Set if the query is matched against the entire text instead of each line.
+    pub fn multi_line(mut self, enabled: bool) -> Self {
+        self.multi_line = enabled;
+        self
+    }
+
+    fn build(&self, query: &str) -> Result<Regex, regex::Error> {
+        let pat = if self.literal {
+            regex::escape(query)
+        } else {
+            query.to_string()
+        };
+        let pat = if self.whole_word {
+            format!(r"\b(?:{pat})\b")
+        } else {
+            pat
+        };
+        RegexBuilder::new(&pat)
+            .case_insensitive(self.ignore_case)
+            .multi_line(self.multi_line)
+            .build()
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Search {
     pub pat: Option<Regex>,
     pub style: Style,
+    pub query: String,
+    pub options: SearchOptions,
+    pub wrapped: bool,
+    // Matches in the entire text cached for multi-line search
+    text_matches: OnceLock<Vec<(Pos, Pos)>>,
 }
 
 impl Default for Search {
@@ -12,6 +84,10 @@ impl Default for Search {
         Self {
             pat: None,
             style: Style::default().bg(Color::Blue),
+            query: String::new(),
+            options: SearchOptions::default(),
+            wrapped: false,
+            text_matches: OnceLock::new(),
         }
     }
 }
@@ -26,21 +102,241 @@ impl Search {
         Some(matches)
     }
 
+    // Byte ranges of matches in the row and if the last match continues to the next row
+    pub fn row_matches(
+        &self,
+        lines: &impl TextBuffer,
+        row: usize,
+    ) -> Option<(Vec<(usize, usize)>, bool)> {
+        if !self.options.multi_line {
+            let matches = self.matches(&lines[row])?;
+            return Some((matches.collect(), false));
+        }
+        self.pat.as_ref()?;
+
+        let text_matches = self.text_matches(lines);
+        let first = text_matches.partition_point(|(_, end)| end.row < row);
+        let mut matches = vec![];
+        let mut continues = false;
+        for (start, end) in text_matches[first..]
+            .iter()
+            .take_while(|(s, _)| s.row <= row)
+        {
+            let start = if start.row == row { start.offset } else { 0 };
+            let end = if end.row == row {
+                end.offset
+            } else {
+                continues = true;
+                lines[row].len()
+            };
+            matches.push((start, end));
+        }
+        Some((matches, continues))
+    }
+
+    pub fn invalidate(&mut self) {
+        self.text_matches = OnceLock::new();
+    }
+
+    fn text_matches(&self, lines: &impl TextBuffer) -> &[(Pos, Pos)] {
+        self.text_matches.get_or_init(|| {
+            let mut matches = vec![];
+            self.each_match(lines, |start, end, _| matches.push((start, end)));
+            matches
+        })
+    }
+
     pub fn set_pattern(&mut self, query: &str) -> Result<(), regex::Error> {
+        self.invalidate();
         match &self.pat {
-            Some(r) if r.as_str() == query => {}
+            Some(_) if self.query == query => {}
             _ if query.is_empty() => self.pat = None,
-            _ => self.pat = Some(Regex::new(query)?),
+            _ => self.pat = Some(self.options.build(query)?),
+        }
+        self.query = query.to_string();
+        self.wrapped = false;
+        Ok(())
+    }
+
+    pub fn set_options(&mut self, options: SearchOptions) -> Result<(), regex::Error> {
+        self.invalidate();
+        if self.pat.is_some() && options != self.options {
+            self.pat = Some(options.build(&self.query)?);
+            self.wrapped = false;
         }
+        self.options = options;
         Ok(())
     }
 
+    // Call the function with start and end positions of each match in the buffer
+    fn each_match(&self, lines: &impl TextBuffer, mut f: impl FnMut(Pos, Pos, &Captures<'_>)) {
+        let Some(pat) = &self.pat else {
+            return;
+        };
+
+        if self.options.multi_line {
+            let mut text = String::new();
+            let mut line_starts = Vec::with_capacity(lines.len());
+            for (row, line) in lines.iter().enumerate() {
+                if row > 0 {
+                    text.push('\n');
+                }
+                line_starts.push(text.len());
+                text.push_str(line);
+            }
+            let pos = |offset: usize| {
+                let row = line_starts.partition_point(|&s| s <= offset) - 1;
+                let offset = offset - line_starts[row];
+                Pos::new(row, lines[row][..offset].chars().count(), offset)
+            };
+            for caps in pat.captures_iter(&text) {
+                let m = caps.get(0).unwrap();
+                f(pos(m.start()), pos(m.end()), &caps);
+            }
+            return;
+        }
+
+        for (row, line) in lines.iter().enumerate() {
+            let mut col = 0;
+            let mut byte = 0;
+            for caps in pat.captures_iter(line) {
+                let m = caps.get(0).unwrap();
+                col += line[byte..m.start()].chars().count();
+                let end = col + m.as_str().chars().count();
+                f(
+                    Pos::new(row, col, m.start()),
+                    Pos::new(row, end, m.end()),
+                    &caps,
+                );
+                col = end;
+                byte = m.end();
+            }
+        }
+    }
+
+    // Start and end positions of all matches in the buffer
+    pub fn match_ranges(&self, lines: &impl TextBuffer) -> Vec<((usize, usize), (usize, usize))> {
+        if self.options.multi_line {
+            return self
+                .text_matches(lines)
+                .iter()
+                .map(|(start, end)| ((start.row, start.col), (end.row, end.col)))
+                .collect();
+        }
+        let mut ranges = vec![];
+        self.each_match(lines, |start, end, _| {
+            ranges.push(((start.row, start.col), (end.row, end.col)));
+        });
+        ranges
+    }
+
+    // Search the start of match in the entire text. Matches may span multiple lines.
+    fn search_text(
+        &mut self,
+        lines: &impl TextBuffer,
+        cursor: (usize, usize),
+        match_cursor: bool,
+        forward: bool,
+    ) -> Option<(usize, usize)> {
+        self.pat.as_ref()?;
+        let starts: Vec<_> = self
+            .text_matches(lines)
+            .iter()
+            .map(|(start, _)| (start.row, start.col))
+            .collect();
+        let found = if forward {
+            starts
+                .iter()
+                .find(|&&s| s > cursor || match_cursor && s == cursor)
+        } else {
+            starts
+                .iter()
+                .rev()
+                .find(|&&s| s < cursor || match_cursor && s == cursor)
+        };
+        if let Some(&pos) = found {
+            return Some(pos);
+        }
+        let pos = if forward {
+            starts.first()
+        } else {
+            starts.last()
+        };
+        self.wrapped = pos.is_some();
+        pos.copied()
+    }
+
+    // Start and end positions of all matches in the buffer with the replacement text. `$1` and `${name}` in the
+    // replacement are expanded to the captured groups.
+    pub fn replacements(
+        &self,
+        lines: &impl TextBuffer,
+        replacement: &str,
+    ) -> Vec<(Pos, Pos, String)> {
+        let mut replacements = vec![];
+        self.each_match(lines, |start, end, caps| {
+            let mut text = String::new();
+            caps.expand(replacement, &mut text);
+            replacements.push((start, end, text));
+        });
+        replacements
+    }
+
+    // Start and end positions of the match starting at the position with the replacement text. Unlike
+    // `replacements`, only the template for this match is expanded.
+    pub fn replacement_at(
+        &self,
+        lines: &impl TextBuffer,
+        pos: (usize, usize),
+        replacement: &str,
+    ) -> Option<(Pos, Pos, String)> {
+        let pat = self.pat.as_ref()?;
+        let expand = |caps: &Captures<'_>| {
+            let mut text = String::new();
+            caps.expand(replacement, &mut text);
+            text
+        };
+
+        if self.options.multi_line {
+            let mut found = None;
+            self.each_match(lines, |start, end, caps| {
+                if found.is_none() && (start.row, start.col) == pos {
+                    found = Some((start, end, expand(caps)));
+                }
+            });
+            return found;
+        }
+
+        let (row, col) = pos;
+        let line = &lines[row];
+        let byte = line
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        let caps = pat.captures_at(line, byte)?;
+        let m = caps.get(0).unwrap();
+        if m.start() != byte {
+            return None;
+        }
+        let end = col + m.as_str().chars().count();
+        Some((
+            Pos::new(row, col, byte),
+            Pos::new(row, end, m.end()),
+            expand(&caps),
+        ))
+    }
+
     pub fn forward(
         &mut self,
-        lines: &[String],
+        lines: &impl TextBuffer,
         cursor: (usize, usize),
         match_cursor: bool,
     ) -> Option<(usize, usize)> {
+        self.wrapped = false;
+        if self.options.multi_line {
+            return self.search_text(lines, cursor, match_cursor, true);
+        }
         let pat = if let Some(pat) = &self.pat {
             pat
         } else {
@@ -59,17 +355,20 @@ impl Search {
         }
 
         // Search lines after cursor
-        for (i, line) in lines[row + 1..].iter().enumerate() {
+        for i in row + 1..lines.len() {
+            let line = &lines[i];
             if let Some(m) = pat.find(line) {
                 let col = line[..m.start()].chars().count();
-                return Some((row + 1 + i, col));
+                return Some((i, col));
             }
         }
 
         // Search lines before cursor (wrap)
-        for (i, line) in lines[..row].iter().enumerate() {
+        for i in 0..row {
+            let line = &lines[i];
             if let Some(m) = pat.find(line) {
                 let col = line[..m.start()].chars().count();
+                self.wrapped = true;
                 return Some((i, col));
             }
         }
@@ -84,6 +383,7 @@ impl Search {
             let i = m.start();
             if i <= col_idx {
                 let col = current_line[..i].chars().count();
+                self.wrapped = true;
                 return Some((row, col));
             }
         }
@@ -93,10 +393,14 @@ impl Search {
 
     pub fn back(
         &mut self,
-        lines: &[String],
+        lines: &impl TextBuffer,
         cursor: (usize, usize),
         match_cursor: bool,
     ) -> Option<(usize, usize)> {
+        self.wrapped = false;
+        if self.options.multi_line {
+            return self.search_text(lines, cursor, match_cursor, false);
+        }
         let pat = if let Some(pat) = &self.pat {
             pat
         } else {
@@ -121,7 +425,8 @@ impl Search {
         }
 
         // Search lines before cursor
-        for (i, line) in lines[..row].iter().enumerate().rev() {
+        for i in (0..row).rev() {
+            let line = &lines[i];
             if let Some(m) = pat.find_iter(line).last() {
                 let col = line[..m.start()].chars().count();
                 return Some((i, col));
@@ -129,10 +434,12 @@ impl Search {
         }
 
         // Search lines after cursor (wrap)
-        for (i, line) in lines[row + 1..].iter().enumerate().rev() {
+        for i in (row + 1..lines.len()).rev() {
+            let line = &lines[i];
             if let Some(m) = pat.find_iter(line).last() {
                 let col = line[..m.start()].chars().count();
-                return Some((row + 1 + i, col));
+                self.wrapped = true;
+                return Some((i, col));
             }
         }
 
@@ -144,6 +451,7 @@ impl Search {
                 .last()
             {
                 let col = col + current_line[i..m.start()].chars().count();
+                self.wrapped = true;
                 return Some((row, col));
             }
         }
@@ -167,4 +475,42 @@ mod tests {
         s.set_pattern("").unwrap();
         assert!(s.matches("fo foo bar fooo").is_none());
     }
+
+    #[test]
+    fn build_with_options() {
+        let tests = [
+            (
+                SearchOptions::default(),
+                "a.c",
+                "abc A.C a.c",
+                &[(0, 3), (8, 11)][..],
+            ),
+            (
+                SearchOptions::default().literal(true),
+                "a.c",
+                "abc A.C a.c",
+                &[(8, 11)],
+            ),
+            (
+                SearchOptions::default().literal(true).ignore_case(true),
+                "a.c",
+                "abc A.C a.c",
+                &[(4, 7), (8, 11)],
+            ),
+            (
+                SearchOptions::default().whole_word(true),
+                "foo|bar",
+                "foo foobar bar_ bar",
+                &[(0, 3), (16, 19)],
+            ),
+        ];
+
+        for (opts, query, line, expected) in tests {
+            let mut s = Search::default();
+            s.set_options(opts).unwrap();
+            s.set_pattern(query).unwrap();
+            let m: Vec<_> = s.matches(line).unwrap().collect();
+            assert_eq!(m, expected, "{opts:?} {query:?}");
+        }
+    }
 }
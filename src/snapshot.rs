@@ -0,0 +1,71 @@
+use crate::history::History;
+use crate::indent::AutoIndent;
+use crate::ratatui::style::Style;
+use crate::textarea::YankText;
+use crate::wrap::WrapMode;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of the state of [`TextArea`](crate::TextArea) created by
+/// [`TextArea::snapshot`](crate::TextArea::snapshot). The textarea is restored from the snapshot by
+/// [`TextArea::from_snapshot`](crate::TextArea::from_snapshot).
+///
+/// The snapshot contains the text, the cursor, the selection, the yanked text, the undo/redo history, the scroll
+/// position, and the configuration such as tab length, wrap mode, placeholder, and styles. When `serde` feature is
+/// enabled, the snapshot can be serialized to save the editing session and to resume it later with undo/redo intact.
+///
+/// Values which cannot be serialized such as block, key map, clipboard, validators, syntax highlighter, completion
+/// provider, and decorations like highlights, signs, diagnostics, and virtual texts are not included. Set them again
+/// after restoring the textarea.
+/// ```
+/// use tui_textarea::{TextArea, CursorMove};
+///
+/// let mut textarea = TextArea::default();
+/// textarea.insert_str("hello");
+/// textarea.set_tab_length(2);
+///
+/// let snapshot = textarea.snapshot();
+/// assert_eq!(snapshot.lines(), ["hello"]);
+/// assert_eq!(snapshot.cursor(), (0, 5));
+///
+/// let mut restored = TextArea::from_snapshot(snapshot);
+/// assert_eq!(restored.lines(), ["hello"]);
+/// assert_eq!(restored.tab_length(), 2);
+///
+/// // Undo history is restored
+/// assert!(restored.undo());
+/// assert_eq!(restored.lines(), [""]);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TextAreaSnapshot {
+    pub(crate) lines: Vec<String>,
+    pub(crate) cursor: (usize, usize),
+    pub(crate) selection_start: Option<(usize, usize)>,
+    pub(crate) yank: YankText,
+    pub(crate) history: History,
+    pub(crate) scroll_top: (u16, u16),
+    pub(crate) tab_len: u8,
+    pub(crate) hard_tab_indent: bool,
+    pub(crate) auto_indent: AutoIndent,
+    pub(crate) wrap_mode: WrapMode,
+    pub(crate) placeholder: String,
+    pub(crate) placeholder_style: Style,
+    pub(crate) style: Style,
+    pub(crate) cursor_style: Style,
+    pub(crate) cursor_line_style: Style,
+    pub(crate) line_number_style: Option<Style>,
+    pub(crate) select_style: Style,
+}
+
+impl TextAreaSnapshot {
+    /// Get the lines of the text in the snapshot.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Get the cursor position in the snapshot as 0-base `(row, col)`.
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+}
@@ -0,0 +1,285 @@
+use crate::buffer::TextBuffer;
+use crate::history::LineChange;
+use crate::ratatui::style::Style;
+use std::cmp;
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Mutex, MutexGuard};
+
+/// Highlighter to color text in [`crate::TextArea`] such as syntax highlighting. The highlighter is called for each
+/// visible line on rendering and the results are cached per line. When a line is edited, only the cache of the line
+/// is invalidated so the line is highlighted again on the next rendering.
+///
+/// For highlighters whose result of a line depends on the previous lines (e.g. block comments spanning multiple
+/// lines), return `true` from [`Highlighter::is_stateful`]. See the method document for the details.
+///
+/// The highlighter set to a textarea must implement [`Clone`] since cloning the textarea clones its highlighter so that
+/// the states of stateful highlighters are not shared between the clones.
+///
+/// ```
+/// use std::ops::Range;
+/// use ratatui::style::{Color, Style};
+/// use tui_textarea::{Highlighter, TextArea};
+///
+/// // Highlight numbers in red
+/// #[derive(Clone)]
+/// struct NumberHighlighter;
+///
+/// impl Highlighter for NumberHighlighter {
+///     fn highlight_line(&mut self, _row: usize, line: &str) -> Vec<(Range<usize>, Style)> {
+///         line.char_indices()
+///             .filter(|(_, c)| c.is_ascii_digit())
+///             .map(|(i, _)| (i..i + 1, Style::default().fg(Color::Red)))
+///             .collect()
+///     }
+/// }
+///
+/// let mut textarea = TextArea::from(["let x = 42;"]);
+/// textarea.set_highlighter(NumberHighlighter);
+/// ```
+pub trait Highlighter {
+    /// Highlight the line at the `row` (0-based). The returned vector contains byte ranges in the line and their
+    /// styles. The ranges should not overlap. The style of a range is applied on top of the base style of the line,
+    /// and text selection, search matches, custom highlights, and the cursor are rendered over it.
+    fn highlight_line(&mut self, row: usize, line: &str) -> Vec<(Range<usize>, Style)>;
+
+    /// Notify that the lines from the `row` were modified or their rows were shifted. Stateful highlighters should
+    /// discard their states for the row and later rows. The default implementation does nothing.
+    fn invalidate(&mut self, row: usize) {
+        let _ = row;
+    }
+
+    /// Return `true` when the highlight of a line depends on its previous lines. For stateful highlighters:
+    ///
+    /// - Lines are highlighted in order without skipping any row. When the line at `row` is highlighted, the lines
+    ///   `0..row` were already highlighted since the last [`Highlighter::invalidate`] call with a row smaller than
+    ///   `row`.
+    /// - Editing a line invalidates the cache of the line and all following lines.
+    ///
+    /// The default implementation returns `false`.
+    fn is_stateful(&self) -> bool {
+        false
+    }
+}
+
+type Spans = Vec<(Range<usize>, Style)>;
+
+// Highlighter which can be cloned as a trait object
+trait CloneHighlighter: Highlighter + Send {
+    fn clone_box(&self) -> Box<dyn CloneHighlighter>;
+}
+
+impl<H: Highlighter + Clone + Send + 'static> CloneHighlighter for H {
+    fn clone_box(&self) -> Box<dyn CloneHighlighter> {
+        Box::new(self.clone())
+    }
+}
+
+pub(crate) struct SyntaxHighlight {
+    // Each clone of the textarea owns its highlighter since states of the highlighter depend on the cache
+    highlighter: Mutex<Box<dyn CloneHighlighter>>,
+    stateful: bool,
+    // Highlighted spans indexed by row. For stateful highlighters, all rows in the cache are highlighted.
+    cache: Mutex<Vec<Option<Spans>>>,
+}
+
+impl SyntaxHighlight {
+    pub fn new(highlighter: impl Highlighter + Clone + Send + 'static) -> Self {
+        Self {
+            stateful: highlighter.is_stateful(),
+            highlighter: Mutex::new(Box::new(highlighter)),
+            cache: Mutex::new(vec![]),
+        }
+    }
+
+    fn highlighter(&self) -> MutexGuard<'_, Box<dyn CloneHighlighter>> {
+        // Continue highlighting even if some other thread panicked while highlighting
+        self.highlighter.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn cache(&mut self) -> &mut Vec<Option<Spans>> {
+        self.cache.get_mut().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn invalidate(&mut self, change: LineChange) {
+        let LineChange {
+            row,
+            removed,
+            inserted,
+        } = change;
+        let stateful = self.stateful;
+        let cache = self.cache();
+        if stateful {
+            cache.truncate(row);
+        } else if row < cache.len() {
+            let end = cmp::min(row + removed, cache.len());
+            cache.splice(row..end, (0..inserted).map(|_| None));
+        }
+        self.highlighter().invalidate(row);
+    }
+
+    pub fn invalidate_all(&mut self) {
+        self.cache().clear();
+        self.highlighter().invalidate(0);
+    }
+
+    pub fn spans(&self, row: usize, lines: &impl TextBuffer) -> Spans {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(Some(spans)) = cache.get(row) {
+            return spans.clone();
+        }
+
+        let mut highlighter = self.highlighter();
+        if self.stateful {
+            for r in cache.len()..=row {
+                let spans = highlighter.highlight_line(r, &lines[r]);
+                cache.push(Some(spans));
+            }
+        } else {
+            if cache.len() <= row {
+                cache.resize(row + 1, None);
+            }
+            cache[row] = Some(highlighter.highlight_line(row, &lines[row]));
+        }
+        cache[row].clone().unwrap_or_default()
+    }
+}
+
+impl Clone for SyntaxHighlight {
+    // The cache and the highlighter are cloned together so that the cloned cache matches the cloned states
+    fn clone(&self) -> Self {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let highlighter = self.highlighter().clone_box();
+        Self {
+            highlighter: Mutex::new(highlighter),
+            stateful: self.stateful,
+            cache: Mutex::new(cache.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for SyntaxHighlight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxHighlight")
+            .field("stateful", &self.stateful)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`Highlighter`] implementation based on [syntect](https://docs.rs/syntect), which highlights text with Sublime Text
+/// syntax definitions and color themes. This is available when `syntect` feature is enabled.
+///
+/// ```
+/// use tui_textarea::{SyntectHighlighter, TextArea};
+///
+/// let mut textarea = TextArea::from(["fn main() {", "    println!(\"hello\");", "}"]);
+/// let highlighter = SyntectHighlighter::from_extension("rs").unwrap();
+/// textarea.set_highlighter(highlighter);
+/// ```
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+#[derive(Clone)]
+pub struct SyntectHighlighter {
+    syntaxes: syntect::parsing::SyntaxSet,
+    theme: syntect::highlighting::Theme,
+    // States at the start of each row. The first element is the initial state.
+    states: Vec<(
+        syntect::parsing::ParseState,
+        syntect::highlighting::HighlightState,
+    )>,
+}
+
+#[cfg(feature = "syntect")]
+impl SyntectHighlighter {
+    /// Create a new highlighter for the syntax named `syntax` (e.g. `"Rust"`) in the syntax set with the color theme.
+    /// The syntax set must be loaded for lines with newlines such as [`syntect::parsing::SyntaxSet::load_defaults_newlines`].
+    /// This method returns `None` when the syntax is not found in the syntax set.
+    pub fn new(
+        syntaxes: syntect::parsing::SyntaxSet,
+        syntax: &str,
+        theme: syntect::highlighting::Theme,
+    ) -> Option<Self> {
+        use syntect::highlighting::{HighlightState, Highlighter as ThemeHighlighter};
+        use syntect::parsing::{ParseState, ScopeStack};
+
+        let parse = ParseState::new(syntaxes.find_syntax_by_name(syntax)?);
+        let highlight = HighlightState::new(&ThemeHighlighter::new(&theme), ScopeStack::new());
+        Some(Self {
+            syntaxes,
+            theme,
+            states: vec![(parse, highlight)],
+        })
+    }
+
+    /// Create a new highlighter for the file extension `ext` (e.g. `"rs"`) with the default syntax set and the
+    /// `base16-ocean.dark` color theme. This method returns `None` when no syntax is found for the extension.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        let syntaxes = syntect::parsing::SyntaxSet::load_defaults_newlines();
+        let name = syntaxes.find_syntax_by_extension(ext)?.name.clone();
+        let mut themes = syntect::highlighting::ThemeSet::load_defaults();
+        let theme = themes.themes.remove("base16-ocean.dark")?;
+        Self::new(syntaxes, &name, theme)
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl Highlighter for SyntectHighlighter {
+    fn highlight_line(&mut self, row: usize, line: &str) -> Vec<(Range<usize>, Style)> {
+        use crate::ratatui::style::{Color, Modifier};
+        use syntect::highlighting::{
+            FontStyle, Highlighter as ThemeHighlighter, RangedHighlightIterator,
+        };
+
+        // Lines are highlighted in order so the state of the row is always the last one
+        self.states.truncate(row + 1);
+        let (mut parse, mut highlight) = self.states[self.states.len() - 1].clone();
+
+        let line = format!("{line}\n");
+        let Ok(ops) = parse.parse_line(&line, &self.syntaxes) else {
+            self.states.push((parse, highlight));
+            return vec![];
+        };
+        let highlighter = ThemeHighlighter::new(&self.theme);
+        let len = line.len() - 1;
+        let spans = RangedHighlightIterator::new(&mut highlight, &ops, &line, &highlighter)
+            .filter_map(|(s, _, range)| {
+                let range = range.start..cmp::min(range.end, len);
+                if range.is_empty() {
+                    return None;
+                }
+                let fg = s.foreground;
+                let mut style = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+                if s.font_style.contains(FontStyle::BOLD) {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                if s.font_style.contains(FontStyle::ITALIC) {
+                    style = style.add_modifier(Modifier::ITALIC);
+                }
+                if s.font_style.contains(FontStyle::UNDERLINE) {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                Some((range, style))
+            })
+            .collect();
+
+        self.states.push((parse, highlight));
+        spans
+    }
+
+    fn invalidate(&mut self, row: usize) {
+        self.states.truncate(row + 1);
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl fmt::Debug for SyntectHighlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntectHighlighter")
+            .field("theme", &self.theme.name)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,32 +1,61 @@
+use crate::buffer::TextBuffer;
+use crate::clipboard::{Clipboard, SharedClipboard};
+use crate::completion::{
+    Completion, CompletionPopup, CompletionProvider, PopupStyle, SharedProvider,
+};
 use crate::cursor::CursorMove;
+use crate::diagnostic::{Diagnostic, Severity, message_style};
+use crate::fold::{Folds, indent_region};
+use crate::gutter::{CustomLabel, GutterWidth, LineLabel, LineNumbers, Sign, Signs};
 use crate::highlight::LineHighlighter;
-use crate::history::{Edit, EditKind, History};
+use crate::history::{Edit, EditKind, History, TextChange, UndoGrouping, UndoState};
+use crate::indent::{
+    AutoIndent, block_opener, closes_block, is_closer, join_separator, leading_indent, outdent_len,
+};
 use crate::input::{Input, Key};
+use crate::keymap::{Action, KeyMap};
+use crate::mouse::MouseState;
 use crate::ratatui::layout::{Alignment, Rect};
 use crate::ratatui::style::{Color, Modifier, Style};
 use crate::ratatui::widgets::{Block, Widget};
 use crate::scroll::Scrolling;
 #[cfg(feature = "search")]
-use crate::search::Search;
+use crate::search::{Search, SearchOptions};
+use crate::snapshot::TextAreaSnapshot;
+use crate::syntax::{Highlighter, SyntaxHighlight};
+use crate::undo_file::{read_history, write_history};
+use crate::undo_tree::UndoTreeView;
 use crate::util::{Pos, num_digits, spaces};
+use crate::validation::{Insertion, Validation, Validator, Validators};
+use crate::virtual_text::VirtualText;
 use crate::widget::Viewport;
-use crate::word::{find_word_exclusive_end_forward, find_word_start_backward};
+use crate::word::{
+    find_word_exclusive_end_forward, find_word_prefix_start, find_word_range,
+    find_word_start_backward,
+};
 use crate::wrap::{
-    WrapMode, WrappedLine, cursor_at_visual_row, cursor_visual_row, effective_wrap_width,
-    wrapped_rows,
+    WrapMode, WrappedLine, col_after_display_width, col_at_display_width, cursor_at_visual_row,
+    cursor_visual_row, display_width_at_col, effective_wrap_width, wrapped_rows,
 };
 #[cfg(feature = "ratatui")]
 use ratatui_core::text::Line;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::cmp::{self, Ordering};
 use std::fmt;
+use std::io;
+use std::time::Duration;
 #[cfg(feature = "tuirs")]
 use tui::text::Spans as Line;
 use unicode_width::UnicodeWidthChar as _;
 
 #[derive(Debug, Clone)]
-enum YankText {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) enum YankText {
     Piece(String),
     Chunk(Vec<String>),
+    // Text of each row copied from a block selection
+    Block(Vec<String>),
 }
 
 impl Default for YankText {
@@ -50,11 +79,38 @@ impl From<Vec<String>> for YankText {
     }
 }
 
+impl YankText {
+    // `str::lines` is not available since it strips a newline at end. `\n` and `\r\n` are recognized as newline.
+    fn from_text(text: String) -> Self {
+        text.split('\n')
+            .map(|s| s.strip_suffix('\r').unwrap_or(s).to_string())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
 impl fmt::Display for YankText {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Piece(s) => write!(f, "{}", s),
-            Self::Chunk(ss) => write!(f, "{}", ss.join("\n")),
+            Self::Chunk(ss) | Self::Block(ss) => write!(f, "{}", ss.join("\n")),
+        }
+    }
+}
+
+// Extra cursor for editing text at multiple positions. The selection is ongoing when `selection_start` is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Caret {
+    cursor: (usize, usize),
+    selection_start: Option<(usize, usize)>,
+}
+
+impl Caret {
+    fn range(&self) -> ((usize, usize), (usize, usize)) {
+        match self.selection_start {
+            Some(start) if start > self.cursor => (self.cursor, start),
+            Some(start) => (start, self.cursor),
+            None => (self.cursor, self.cursor),
         }
     }
 }
@@ -128,16 +184,28 @@ pub struct TextAreaMeasure {
 /// }
 /// ```
 #[derive(Clone, Debug)]
-pub struct TextArea<'a> {
-    lines: Vec<String>,
+pub struct TextArea<'a, B = Vec<String>> {
+    lines: B,
     block: Option<Block<'a>>,
     style: Style,
     cursor: (usize, usize), // 0-base
     tab_len: u8,
     hard_tab_indent: bool,
+    auto_indent: AutoIndent,
+    comment_prefix: String,
     history: History,
     cursor_line_style: Style,
     line_number_style: Option<Style>,
+    line_numbers: LineNumbers,
+    line_label: Option<CustomLabel>,
+    signs: Signs,
+    diagnostics: Vec<Diagnostic>,
+    diagnostic_styles: [Style; 4],
+    diagnostic_messages: bool,
+    virtual_texts: Vec<VirtualText>,
+    completion_provider: Option<SharedProvider>,
+    completion: Option<Completion>,
+    completion_style: PopupStyle,
     pub(crate) viewport: Viewport,
     pub(crate) cursor_style: Style,
     yank: YankText,
@@ -151,9 +219,21 @@ pub struct TextArea<'a> {
     pub(crate) placeholder_style: Style,
     mask: Option<char>,
     selection_start: Option<(usize, usize)>,
+    block_selection: bool,
+    cursors: Vec<Caret>,
     select_style: Style,
     custom_highlights: Vec<CustomHighlight>,
+    syntax: Option<SyntaxHighlight>,
+    clipboard: Option<SharedClipboard>,
+    validators: Validators,
+    validation_error: Option<String>,
+    error_style: Style,
+    changes: Option<Vec<TextChange>>,
+    folds: Folds,
+    fold_style: Style,
     measure_cache: Option<(u16, TextAreaMeasure)>,
+    mouse: MouseState,
+    key_map: KeyMap,
 }
 
 /// Convert any iterator whose elements can be converted into [`String`] into [`TextArea`]. Each [`String`] element is
@@ -233,11 +313,53 @@ impl<'a> TextArea<'a> {
     /// let textarea = TextArea::new(lines);
     /// assert_eq!(textarea.lines(), ["hello", "...", "goodbye"]);
     /// ```
-    pub fn new(mut lines: Vec<String>) -> Self {
+    pub fn new(lines: Vec<String>) -> Self {
+        Self::with_buffer(lines)
+    }
+
+    /// Get slice of line texts. This method borrows the content, but not moves. Note that the returned slice will
+    /// never be empty because an empty text means a slice containing one empty line. This is correct since any text
+    /// file must end with a newline.
+    ///
+    /// This method is only available for the default `Vec<String>` buffer. To access lines of any
+    /// [`TextBuffer`](crate::TextBuffer), use [`TextArea::line`] and [`TextArea::iter_lines`].
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// assert_eq!(textarea.lines(), [""]);
+    ///
+    /// textarea.insert_char('a');
+    /// assert_eq!(textarea.lines(), ["a"]);
+    ///
+    /// textarea.insert_newline();
+    /// assert_eq!(textarea.lines(), ["a", ""]);
+    ///
+    /// textarea.insert_char('b');
+    /// assert_eq!(textarea.lines(), ["a", "b"]);
+    /// ```
+    pub fn lines(&'a self) -> &'a [String] {
+        &self.lines
+    }
+}
+
+impl<'a, B: TextBuffer> TextArea<'a, B> {
+    /// Create [`TextArea`] instance with the given text buffer. This is useful to store the text in other data
+    /// structure than `Vec<String>`. For example, [`LineRope`](crate::LineRope) makes editing large documents faster.
+    /// When the buffer contains no line, one empty line is added.
+    /// ```
+    /// use tui_textarea::{LineRope, TextArea, TextBuffer};
+    ///
+    /// let rope: LineRope = (0..100000).map(|i| format!("line {i}")).collect();
+    /// let mut textarea = TextArea::with_buffer(rope);
+    /// textarea.insert_newline();
+    /// assert_eq!(textarea.buffer().len(), 100001);
+    /// assert_eq!(textarea.buffer()[1], "line 0");
+    /// ```
+    pub fn with_buffer(mut lines: B) -> Self {
         if lines.is_empty() {
             lines.push(String::new());
         }
-
         Self {
             lines,
             block: None,
@@ -245,9 +367,21 @@ impl<'a> TextArea<'a> {
             cursor: (0, 0),
             tab_len: 4,
             hard_tab_indent: false,
+            auto_indent: AutoIndent::None,
+            comment_prefix: "//".to_string(),
             history: History::new(50),
             cursor_line_style: Style::default().add_modifier(Modifier::UNDERLINED),
             line_number_style: None,
+            line_numbers: LineNumbers::Absolute,
+            line_label: None,
+            signs: Signs::default(),
+            diagnostics: vec![],
+            diagnostic_styles: Severity::ALL.map(Severity::default_style),
+            diagnostic_messages: true,
+            virtual_texts: vec![],
+            completion_provider: None,
+            completion: None,
+            completion_style: PopupStyle::default(),
             viewport: Viewport::default(),
             cursor_style: Style::default().add_modifier(Modifier::REVERSED),
             yank: YankText::default(),
@@ -261,16 +395,54 @@ impl<'a> TextArea<'a> {
             placeholder_style: Style::default().fg(Color::DarkGray),
             mask: None,
             selection_start: None,
+            block_selection: false,
+            cursors: vec![],
             select_style: Style::default().bg(Color::LightBlue),
             custom_highlights: Default::default(),
+            syntax: None,
+            clipboard: None,
+            validators: Validators::default(),
+            validation_error: None,
+            error_style: Style::default().fg(Color::Red),
+            changes: None,
+            folds: Folds::default(),
+            fold_style: Style::default().fg(Color::DarkGray),
             measure_cache: None,
+            mouse: MouseState::default(),
+            key_map: KeyMap::default(),
         }
     }
 
+    /// Get the text of the line at the 0-based `row`. `None` is returned when the row is out of the text. Unlike
+    /// [`TextArea::lines`], this method is available for any [`TextBuffer`].
+    /// ```
+    /// use tui_textarea::{LineRope, TextArea};
+    ///
+    /// let textarea = TextArea::with_buffer(LineRope::from_iter(["hello", "world"]));
+    /// assert_eq!(textarea.line(1), Some("world"));
+    /// assert_eq!(textarea.line(2), None);
+    /// ```
+    pub fn line(&self, row: usize) -> Option<&str> {
+        self.lines.get(row).map(String::as_str)
+    }
+
+    /// Iterate over the texts of all lines. Like [`TextArea::lines`], at least one line is always yielded. Unlike
+    /// [`TextArea::lines`], this method is available for any [`TextBuffer`].
+    /// ```
+    /// use tui_textarea::{LineRope, TextArea};
+    ///
+    /// let textarea = TextArea::with_buffer(LineRope::from_iter(["hello", "world"]));
+    /// assert_eq!(textarea.iter_lines().collect::<Vec<_>>(), ["hello", "world"]);
+    /// ```
+    pub fn iter_lines(&self) -> impl Iterator<Item = &str> + '_ {
+        self.lines.iter().map(String::as_str)
+    }
+
     /// Handle a key input with default key mappings. For default key mappings, see the table in
     /// [the module document](./index.html).
     /// `crossterm`, `termion`, and `termwiz` features enable conversion from their own key event types into
     /// [`Input`] so this method can take the event values directly.
+    /// Text pasted by bracketed paste ([`Key::Pasted`]) is inserted as a single edit without triggering key mappings.
     /// This method returns if the input modified text contents or not in the textarea.
     /// ```ignore
     /// use tui_textarea::{TextArea, Key, Input};
@@ -305,34 +477,224 @@ impl<'a> TextArea<'a> {
     /// ```
     pub fn input(&mut self, input: impl Into<Input>) -> bool {
         let input = input.into();
+        let cursor = self.cursor;
         let modified = match input {
             Input {
-                key: Key::Char('m'),
-                ctrl: true,
-                alt: false,
+                key: Key::MouseDown(column, row),
+                shift,
                 ..
+            } => {
+                self.mouse_down(column, row, shift);
+                false
             }
-            | Input {
-                key: Key::Char('\n' | '\r'),
-                ctrl: false,
-                alt: false,
+            Input {
+                key: Key::MouseDrag(column, row),
                 ..
+            } => {
+                self.mouse_drag(column, row);
+                false
             }
-            | Input {
-                key: Key::Enter, ..
+            Input {
+                key: Key::MouseUp(..),
+                ..
             } => {
-                self.insert_newline();
-                true
+                self.mouse_up();
+                false
+            }
+            Input {
+                key: Key::Pasted(ref text),
+                ..
+            } => self.insert_pasted(text),
+            _ => match self.completion_input(&input) {
+                Some(modified) => modified,
+                None => match self.key_map.resolve(input.clone()) {
+                    Some(action) => self.perform(action),
+                    None => false,
+                },
+            },
+        };
+        self.update_completion(cursor, modified);
+
+        // Check invariants
+        debug_assert!(!self.lines.is_empty(), "no line after {:?}", input);
+        let (r, c) = self.cursor;
+        debug_assert!(
+            self.lines.len() > r,
+            "cursor {:?} exceeds max lines {} after {:?}",
+            self.cursor,
+            self.lines.len(),
+            input,
+        );
+        debug_assert!(
+            self.lines[r].chars().count() >= c,
+            "cursor {:?} exceeds max col {} at line {:?} after {:?}",
+            self.cursor,
+            self.lines[r].chars().count(),
+            self.lines[r],
+            input,
+        );
+
+        modified
+    }
+
+    /// Perform the editor action. This method returns if the action modified text contents or not in the textarea.
+    /// [`Action::Custom`] does nothing. This method is useful to perform actions resolved by your own [`KeyMap`].
+    /// ```
+    /// use tui_textarea::{Action, CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["hello"]);
+    ///
+    /// textarea.perform(Action::Select(CursorMove::End));
+    /// assert_eq!(textarea.selection_range(), Some(((0, 0), (0, 5))));
+    ///
+    /// assert!(textarea.perform(Action::Cut));
+    /// assert_eq!(textarea.lines(), [""]);
+    /// ```
+    pub fn perform(&mut self, action: Action) -> bool {
+        match action {
+            // Validators may reject the insertion or replace it with an empty text
+            Action::InsertChar(c) => self.try_insert_char(c),
+            Action::InsertNewline => self.try_insert_newline(),
+            Action::InsertTab => self.insert_tab(),
+            Action::IndentLines => self.indent_lines(),
+            Action::OutdentLines => self.outdent_lines(),
+            Action::DuplicateLines => self.duplicate_lines(),
+            Action::DeleteLines => self.delete_lines(),
+            Action::MoveLinesUp => self.move_lines_up(),
+            Action::MoveLinesDown => self.move_lines_down(),
+            Action::JoinLines => self.join_lines(),
+            Action::SortLines => self.sort_lines(),
+            Action::ToggleComment => self.toggle_comment(),
+            Action::DeleteChar => self.delete_char(),
+            Action::DeleteNextChar => self.delete_next_char(),
+            Action::DeleteLineByEnd => self.delete_line_by_end(),
+            Action::DeleteLineByHead => self.delete_line_by_head(),
+            Action::DeleteWord => self.delete_word(),
+            Action::DeleteNextWord => self.delete_next_word(),
+            Action::Move(m) => {
+                self.history.close_coalescing();
+                self.move_cursor_with_shift(m, false);
+                false
+            }
+            Action::Select(m) => {
+                self.history.close_coalescing();
+                self.move_cursor_with_shift(m, true);
+                false
+            }
+            Action::Scroll(s) => {
+                self.scroll_with_shift(s, false);
+                false
+            }
+            Action::SelectScroll(s) => {
+                self.scroll_with_shift(s, true);
+                false
+            }
+            Action::SelectBlock(m) => {
+                if !self.is_block_selecting() {
+                    self.start_block_selection();
+                }
+                self.history.close_coalescing();
+                self.move_cursor_with_shift(m, true);
+                false
+            }
+            Action::Undo => self.undo(),
+            Action::Redo => self.redo(),
+            Action::Copy => {
+                self.copy();
+                false
+            }
+            Action::Cut => self.cut(),
+            Action::Paste => self.paste(),
+            Action::SelectAll => {
+                self.select_all();
+                false
+            }
+            Action::SelectLines => {
+                self.select_lines();
+                false
+            }
+            Action::CancelSelection => {
+                self.cancel_selection();
+                self.clear_extra_cursors();
+                false
+            }
+            Action::AddCursorAbove => {
+                self.add_cursor_above();
+                false
             }
+            Action::AddCursorBelow => {
+                self.add_cursor_below();
+                false
+            }
+            Action::AddNextOccurrence => {
+                self.add_next_occurrence();
+                false
+            }
+            Action::TriggerCompletion => {
+                self.complete();
+                false
+            }
+            Action::Custom(_) => false,
+        }
+    }
+
+    /// Set the key map used by [`TextArea::input`]. The default key map is [`KeyMap::emacs`].
+    /// ```
+    /// use tui_textarea::{Input, Key, KeyMap, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["hello"]);
+    /// textarea.set_key_map(KeyMap::standard());
+    ///
+    /// // Ctrl+A selects all text with the standard key map
+    /// textarea.input(Input { key: Key::Char('a'), ctrl: true, alt: false, shift: false });
+    /// assert!(textarea.is_selecting());
+    /// ```
+    pub fn set_key_map(&mut self, key_map: KeyMap) {
+        self.key_map = key_map;
+    }
+
+    /// Get the key map used by [`TextArea::input`].
+    pub fn key_map(&self) -> &KeyMap {
+        &self.key_map
+    }
+
+    /// Get the mutable reference to the key map used by [`TextArea::input`] to rebind or unbind keys.
+    /// ```
+    /// use tui_textarea::{Action, Input, Key, TextArea};
+    ///
+    /// let mut textarea = TextArea::default();
+    /// let ctrl_z = Input { key: Key::Char('z'), ctrl: true, alt: false, shift: false };
+    /// textarea.key_map_mut().bind([ctrl_z.clone()], Action::Undo);
+    ///
+    /// textarea.insert_str("hello");
+    /// assert!(textarea.input(ctrl_z));
+    /// assert!(textarea.is_empty());
+    /// ```
+    pub fn key_map_mut(&mut self) -> &mut KeyMap {
+        &mut self.key_map
+    }
+
+    /// Handle a key input without default key mappings. This method handles only
+    ///
+    /// - Single character input without modifier keys
+    /// - Tab
+    /// - Enter
+    /// - Backspace
+    /// - Delete
+    /// - Pasted text
+    ///
+    /// This method returns if the input modified text contents or not in the textarea.
+    ///
+    /// This method is useful when you want to define your own key mappings and don't want default key mappings.
+    /// See 'Define your own key mappings' section in [the module document](./index.html).
+    pub fn input_without_shortcuts(&mut self, input: impl Into<Input>) -> bool {
+        match input.into() {
             Input {
                 key: Key::Char(c),
                 ctrl: false,
                 alt: false,
                 ..
-            } => {
-                self.insert_char(c);
-                true
-            }
+            } => self.try_insert_char(c),
             Input {
                 key: Key::Tab,
                 ctrl: false,
@@ -340,1777 +702,3922 @@ impl<'a> TextArea<'a> {
                 ..
             } => self.insert_tab(),
             Input {
-                key: Key::Char('h'),
-                ctrl: true,
-                alt: false,
-                ..
-            }
-            | Input {
                 key: Key::Backspace,
-                ctrl: false,
-                alt: false,
                 ..
             } => self.delete_char(),
             Input {
-                key: Key::Char('d'),
-                ctrl: true,
-                alt: false,
-                ..
-            }
-            | Input {
-                key: Key::Delete,
-                ctrl: false,
-                alt: false,
-                ..
+                key: Key::Delete, ..
             } => self.delete_next_char(),
             Input {
-                key: Key::Char('k'),
-                ctrl: true,
-                alt: false,
-                ..
-            } => self.delete_line_by_end(),
+                key: Key::Enter, ..
+            } => self.try_insert_newline(),
             Input {
-                key: Key::Char('j'),
-                ctrl: true,
-                alt: false,
+                key: Key::Pasted(text),
                 ..
-            } => self.delete_line_by_head(),
+            } => self.insert_pasted(&text),
             Input {
-                key: Key::Char('w'),
-                ctrl: true,
-                alt: false,
-                ..
-            }
-            | Input {
-                key: Key::Char('h'),
-                ctrl: false,
-                alt: true,
+                key: Key::MouseScrollDown,
                 ..
+            } => {
+                self.scroll((1, 0));
+                false
             }
-            | Input {
-                key: Key::Backspace,
-                ctrl: false,
-                alt: true,
-                ..
-            } => self.delete_word(),
             Input {
-                key: Key::Delete,
-                ctrl: false,
-                alt: true,
-                ..
-            }
-            | Input {
-                key: Key::Char('d'),
-                ctrl: false,
-                alt: true,
+                key: Key::MouseScrollUp,
                 ..
-            } => self.delete_next_word(),
-            Input {
-                key: Key::Char('n'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::Down,
-                ctrl: false,
-                alt: false,
-                shift,
             } => {
-                self.move_cursor_with_shift(CursorMove::Down, shift);
+                self.scroll((-1, 0));
                 false
             }
             Input {
-                key: Key::Char('p'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::Up,
-                ctrl: false,
-                alt: false,
+                key: Key::MouseDown(column, row),
                 shift,
+                ..
             } => {
-                self.move_cursor_with_shift(CursorMove::Up, shift);
+                self.mouse_down(column, row, shift);
                 false
             }
             Input {
-                key: Key::Char('f'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::Right,
-                ctrl: false,
-                alt: false,
-                shift,
+                key: Key::MouseDrag(column, row),
+                ..
             } => {
-                self.move_cursor_with_shift(CursorMove::Forward, shift);
+                self.mouse_drag(column, row);
                 false
             }
             Input {
-                key: Key::Char('b'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::Left,
-                ctrl: false,
-                alt: false,
-                shift,
+                key: Key::MouseUp(..),
+                ..
             } => {
-                self.move_cursor_with_shift(CursorMove::Back, shift);
+                self.mouse_up();
                 false
             }
-            Input {
-                key: Key::Char('a'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::Home,
-                shift,
-                ..
-            }
-            | Input {
-                key: Key::Left | Key::Char('b'),
-                ctrl: true,
-                alt: true,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::Head, shift);
-                false
-            }
-            Input {
-                key: Key::Char('e'),
-                ctrl: true,
-                alt: false,
-                shift,
-            }
-            | Input {
-                key: Key::End,
-                shift,
-                ..
-            }
-            | Input {
-                key: Key::Right | Key::Char('f'),
-                ctrl: true,
-                alt: true,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::End, shift);
-                false
-            }
-            Input {
-                key: Key::Char('<'),
-                ctrl: false,
-                alt: true,
-                shift,
-            }
-            | Input {
-                key: Key::Up | Key::Char('p'),
-                ctrl: true,
-                alt: true,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::Top, shift);
-                false
-            }
-            Input {
-                key: Key::Char('>'),
-                ctrl: false,
-                alt: true,
-                shift,
-            }
-            | Input {
-                key: Key::Down | Key::Char('n'),
-                ctrl: true,
-                alt: true,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::Bottom, shift);
-                false
-            }
-            Input {
-                key: Key::Char('f'),
-                ctrl: false,
-                alt: true,
-                shift,
-            }
-            | Input {
-                key: Key::Right,
-                ctrl: true,
-                alt: false,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::WordForward, shift);
-                false
-            }
-            Input {
-                key: Key::Char('b'),
-                ctrl: false,
-                alt: true,
-                shift,
-            }
-            | Input {
-                key: Key::Left,
-                ctrl: true,
-                alt: false,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::WordBack, shift);
-                false
-            }
-            Input {
-                key: Key::Char(']'),
-                ctrl: false,
-                alt: true,
-                shift,
+            _ => false,
+        }
+    }
+
+    // Notify the edit applied to the text buffer to the syntax highlighter and the change tracking
+    fn on_edit_applied(&mut self, edit: &Edit) {
+        if let Some(syntax) = &mut self.syntax {
+            syntax.invalidate(edit.line_change());
+        }
+        if let Some(changes) = &mut self.changes {
+            changes.push(edit.text_change());
+        }
+        let change = edit.line_change();
+        if !self.folds.is_empty() {
+            self.folds.update(change);
+        }
+        self.signs.update(change);
+        for diagnostic in &mut self.diagnostics {
+            diagnostic.shift(change);
+        }
+        for text in &mut self.virtual_texts {
+            text.shift(change);
+        }
+        #[cfg(feature = "search")]
+        self.search.invalidate();
+    }
+
+    fn push_history(&mut self, kind: EditKind, before: Pos, after_offset: usize) {
+        let (row, col) = self.cursor;
+        let after = Pos::new(row, col, after_offset);
+        let edit = Edit::new(kind, before, after);
+        self.on_edit_applied(&edit);
+        self.history.push(edit);
+        self.cursors.clear(); // Edits not applied at each cursor make positions of extra cursors invalid
+        self.reset_measure_cache();
+    }
+
+    /// Insert a single character at current cursor position.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// textarea.insert_char('a');
+    /// assert_eq!(textarea.lines(), ["a"]);
+    /// ```
+    pub fn insert_char(&mut self, c: char) {
+        self.try_insert_char(c);
+    }
+
+    // Insert a character and return if the text was modified. Validators may reject the character or replace it with
+    // an empty text.
+    fn try_insert_char(&mut self, c: char) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.try_insert_char(c));
+        }
+
+        if c == '\n' || c == '\r' {
+            return self.try_insert_newline();
+        }
+
+        if let Some(modified) = self.validate_insertion(c.encode_utf8(&mut [0; 4])) {
+            return modified;
+        }
+
+        let outdent = if is_closer(c) {
+            self.closer_outdent_len()
+        } else {
+            0
+        };
+        if outdent > 0 {
+            // Indentation is removed with the closing bracket as one step
+            let (row, col) = self.cursor;
+            let start = col - outdent; // Indentation consists of ASCII whitespaces
+            self.begin_transaction();
+            self.delete_range(Pos::new(row, start, start), Pos::new(row, col, col), false);
+            self.insert_char_at_cursor(c);
+            self.end_transaction();
+        } else {
+            self.insert_char_at_cursor(c);
+        }
+        true
+    }
+
+    // Number of whitespaces removed before typing a closing bracket at the head of line with `AutoIndent::Smart`
+    fn closer_outdent_len(&self) -> usize {
+        if self.auto_indent != AutoIndent::Smart || self.selection_start.is_some() {
+            return 0;
+        }
+        let (row, col) = self.cursor;
+        let line = &self.lines[row];
+        let before = &line[..line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)];
+        if leading_indent(before).len() != before.len() {
+            return 0;
+        }
+        outdent_len(before, self.tab_len)
+    }
+
+    fn insert_char_at_cursor(&mut self, c: char) {
+        self.delete_selection(false);
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        let i = line
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        line.insert(i, c);
+        self.cursor.1 += 1;
+        self.push_history(
+            EditKind::InsertChar(c),
+            Pos::new(row, col, i),
+            i + c.len_utf8(),
+        );
+    }
+
+    /// Insert a string at current cursor position. This method returns if some text was inserted or not in the textarea.
+    /// Both `\n` and `\r\n` are recognized as newlines but `\r` isn't.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// textarea.insert_str("hello");
+    /// assert_eq!(textarea.lines(), ["hello"]);
+    ///
+    /// textarea.insert_str(", world\ngoodbye, world");
+    /// assert_eq!(textarea.lines(), ["hello, world", "goodbye, world"]);
+    /// ```
+    pub fn insert_str<S: AsRef<str>>(&mut self, s: S) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.insert_str(s.as_ref()));
+        }
+
+        match self.validate_insertion(s.as_ref()) {
+            Some(modified) => modified,
+            None => self.insert_text(s.as_ref()),
+        }
+    }
+
+    // Run the validators on the text about to be inserted at the cursor. `None` is returned when the text is accepted
+    // as-is. Otherwise the text was rejected or the transformed text was inserted, and whether the text was modified is
+    // returned.
+    fn validate_insertion(&mut self, text: &str) -> Option<bool> {
+        if self.validators.is_empty() {
+            self.validation_error = None;
+            return None;
+        }
+
+        let text = text.replace("\r\n", "\n");
+        let (start, end) = self.selection_range().unwrap_or((self.cursor, self.cursor));
+        match self.validate_range(&text, start, end) {
+            Validation::Accept => None,
+            Validation::Replace(text) => Some(self.insert_text(&text)),
+            Validation::Reject(_) => Some(false),
+        }
+    }
+
+    // Run the validators on the text which replaces the range, and update the validation error with the result
+    fn validate_range(
+        &mut self,
+        text: &str,
+        (start_row, start_col): (usize, usize),
+        (end_row, end_col): (usize, usize),
+    ) -> Validation {
+        let offset = |line: &str, col| line.char_indices().nth(col).map_or(line.len(), |(i, _)| i);
+        let before = &self.lines[start_row];
+        let before = &before[..offset(before, start_col)];
+        let after = &self.lines[end_row];
+        let after = &after[offset(after, end_col)..];
+        let insertion = Insertion {
+            text,
+            before,
+            after,
+            line_count: self.lines.len() - (end_row - start_row),
+        };
+
+        let validation = self.validators.validate(&insertion);
+        self.validation_error = match &validation {
+            Validation::Reject(error) => Some(error.clone()),
+            _ => None,
+        };
+        validation
+    }
+
+    // Run the validators on the text which replaces the range in an edit which can't insert transformed text, such as
+    // line operations. `true` is returned only when the text is accepted as-is.
+    fn accepts_range(&mut self, text: &str, start: (usize, usize), end: (usize, usize)) -> bool {
+        if self.validators.is_empty() {
+            self.validation_error = None;
+            return true;
+        }
+        self.validate_range(text, start, end) == Validation::Accept
+    }
+
+    fn insert_text(&mut self, s: &str) -> bool {
+        let modified = self.delete_selection(false);
+        let mut lines: Vec<_> = s
+            .split('\n')
+            .map(|s| s.strip_suffix('\r').unwrap_or(s).to_string())
+            .collect();
+        match lines.len() {
+            0 => modified,
+            1 => self.insert_piece(lines.remove(0)),
+            _ => self.insert_chunk(lines),
+        }
+    }
+
+    // Pasted text replacing the selection is undone as one step. Terminals may send `\r` as newline on paste.
+    fn insert_pasted(&mut self, text: &str) -> bool {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        self.begin_transaction();
+        let modified = self.insert_str(text);
+        self.end_transaction();
+        modified
+    }
+
+    fn insert_chunk(&mut self, chunk: Vec<String>) -> bool {
+        debug_assert!(chunk.len() > 1, "Chunk size must be > 1: {:?}", chunk);
+
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        let i = line
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        let before = Pos::new(row, col, i);
+
+        let (row, col) = (
+            row + chunk.len() - 1,
+            chunk[chunk.len() - 1].chars().count(),
+        );
+        self.cursor = (row, col);
+
+        let end_offset = chunk.last().unwrap().len();
+
+        let edit = EditKind::InsertChunk(chunk);
+        edit.apply(&mut self.lines, &before, &Pos::new(row, col, end_offset));
+
+        self.push_history(edit, before, end_offset);
+        true
+    }
+
+    fn insert_piece(&mut self, s: String) -> bool {
+        if s.is_empty() {
+            return false;
+        }
+
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        debug_assert!(
+            !s.contains('\n'),
+            "string given to TextArea::insert_piece must not contain newline: {:?}",
+            line,
+        );
+
+        let i = line
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        line.insert_str(i, &s);
+        let end_offset = i + s.len();
+
+        self.cursor.1 += s.chars().count();
+        self.push_history(EditKind::InsertStr(s), Pos::new(row, col, i), end_offset);
+        true
+    }
+
+    fn delete_range(&mut self, start: Pos, end: Pos, should_yank: bool) {
+        self.cursor = (start.row, start.col);
+
+        if start.row == end.row {
+            let removed = self.lines[start.row]
+                .drain(start.offset..end.offset)
+                .as_str()
+                .to_string();
+            if should_yank {
+                self.set_yank(removed.clone().into());
             }
-            | Input {
-                key: Key::Char('n'),
-                ctrl: false,
-                alt: true,
-                shift,
+            self.push_history(EditKind::DeleteStr(removed), end, start.offset);
+            return;
+        }
+
+        let mut deleted = vec![
+            self.lines[start.row]
+                .drain(start.offset..)
+                .as_str()
+                .to_string(),
+        ];
+        deleted.extend(self.lines.remove_lines(start.row + 1..end.row));
+        if start.row + 1 < self.lines.len() {
+            let mut last_line = self.lines.remove(start.row + 1);
+            self.lines[start.row].push_str(&last_line[end.offset..]);
+            last_line.truncate(end.offset);
+            deleted.push(last_line);
+        }
+
+        if should_yank {
+            self.set_yank(YankText::Chunk(deleted.clone()));
+        }
+
+        let edit = if deleted.len() == 1 {
+            EditKind::DeleteStr(deleted.remove(0))
+        } else {
+            EditKind::DeleteChunk(deleted)
+        };
+        self.push_history(edit, end, start.offset);
+    }
+
+    /// Delete a string from the current cursor position. The `chars` parameter means number of characters, not a byte
+    /// length of the string. Newlines at the end of lines are counted in the number. This method returns if some text
+    /// was deleted or not.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["🐱🐶🐰🐮"]);
+    /// textarea.move_cursor(CursorMove::Forward);
+    ///
+    /// textarea.delete_str(2);
+    /// assert_eq!(textarea.lines(), ["🐱🐮"]);
+    ///
+    /// let mut textarea = TextArea::from(["🐱", "🐶", "🐰", "🐮"]);
+    /// textarea.move_cursor(CursorMove::Down);
+    ///
+    /// textarea.delete_str(4); // Deletes 🐶, \n, 🐰, \n
+    /// assert_eq!(textarea.lines(), ["🐱", "🐮"]);
+    /// ```
+    pub fn delete_str(&mut self, chars: usize) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_str(chars));
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+        if chars == 0 {
+            return false;
+        }
+
+        let (start_row, start_col) = self.cursor;
+
+        let mut remaining = chars;
+        let mut find_end = move |line: &str| {
+            let mut col = 0usize;
+            for (i, _) in line.char_indices() {
+                if remaining == 0 {
+                    return Some((i, col));
+                }
+                col += 1;
+                remaining -= 1;
             }
-            | Input {
-                key: Key::Down,
-                ctrl: true,
-                alt: false,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::ParagraphForward, shift);
-                false
+            if remaining == 0 {
+                Some((line.len(), col))
+            } else {
+                remaining -= 1;
+                None
             }
-            Input {
-                key: Key::Char('['),
-                ctrl: false,
-                alt: true,
-                shift,
+        };
+
+        let line = &self.lines[start_row];
+        let start_offset = {
+            line.char_indices()
+                .nth(start_col)
+                .map(|(i, _)| i)
+                .unwrap_or(line.len())
+        };
+
+        // First line
+        if let Some((offset_delta, col_delta)) = find_end(&line[start_offset..]) {
+            let end_offset = start_offset + offset_delta;
+            let end_col = start_col + col_delta;
+            let removed = self.lines[start_row]
+                .drain(start_offset..end_offset)
+                .as_str()
+                .to_string();
+            self.set_yank(removed.clone().into());
+            self.push_history(
+                EditKind::DeleteStr(removed),
+                Pos::new(start_row, end_col, end_offset),
+                start_offset,
+            );
+            return true;
+        }
+
+        let mut r = start_row + 1;
+        let mut offset = 0;
+        let mut col = 0;
+
+        while r < self.lines.len() {
+            let line = &self.lines[r];
+            if let Some((o, c)) = find_end(line) {
+                offset = o;
+                col = c;
+                break;
             }
-            | Input {
-                key: Key::Char('p'),
-                ctrl: false,
-                alt: true,
-                shift,
+            r += 1;
+        }
+
+        if r == self.lines.len() {
+            // Clamp exhausted deletions to EOF on the last line so history keeps a valid cursor.
+            r = self.lines.len() - 1;
+            let line = &self.lines[r];
+            offset = line.len();
+            col = line.chars().count();
+        }
+
+        let start = Pos::new(start_row, start_col, start_offset);
+        let end = Pos::new(r, col, offset);
+        self.delete_range(start, end, true);
+        true
+    }
+
+    fn delete_piece(&mut self, col: usize, chars: usize) -> bool {
+        if chars == 0 {
+            return false;
+        }
+
+        #[inline]
+        fn bytes_and_chars(claimed: usize, s: &str) -> (usize, usize) {
+            // Note: `claimed` may be larger than characters in `s` (e.g. usize::MAX)
+            let mut last_col = 0;
+            for (col, (bytes, _)) in s.char_indices().enumerate() {
+                if col == claimed {
+                    return (bytes, claimed);
+                }
+                last_col = col;
             }
-            | Input {
-                key: Key::Up,
-                ctrl: true,
-                alt: false,
-                shift,
-            } => {
-                self.move_cursor_with_shift(CursorMove::ParagraphBack, shift);
-                false
+            (s.len(), last_col + 1)
+        }
+
+        let (row, _) = self.cursor;
+        let line = &mut self.lines[row];
+        if let Some((i, _)) = line.char_indices().nth(col) {
+            let (bytes, chars) = bytes_and_chars(chars, &line[i..]);
+            let removed = line.drain(i..i + bytes).as_str().to_string();
+
+            self.cursor = (row, col);
+            self.push_history(
+                EditKind::DeleteStr(removed.clone()),
+                Pos::new(row, col + chars, i + bytes),
+                i,
+            );
+            self.set_yank(removed.into());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Insert a tab at current cursor position. Note that this method does nothing when the tab length is 0. This
+    /// method returns if a tab string was inserted or not in the textarea.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["hi"]);
+    ///
+    /// textarea.move_cursor(CursorMove::End); // Move to the end of line
+    ///
+    /// textarea.insert_tab();
+    /// assert_eq!(textarea.lines(), ["hi  "]);
+    /// textarea.insert_tab();
+    /// assert_eq!(textarea.lines(), ["hi      "]);
+    /// ```
+    pub fn insert_tab(&mut self) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.insert_tab());
+        }
+
+        if self.tab_len == 0 {
+            return self.delete_selection(false);
+        }
+
+        if self.hard_tab_indent {
+            if let Some(modified) = self.validate_insertion("\t") {
+                return modified;
             }
-            Input {
-                key: Key::Char('u'),
-                ctrl: true,
-                alt: false,
-                ..
-            } => self.undo(),
-            Input {
-                key: Key::Char('r'),
-                ctrl: true,
-                alt: false,
-                ..
-            } => self.redo(),
-            Input {
-                key: Key::Char('y'),
-                ctrl: true,
-                alt: false,
-                ..
+            self.insert_char_at_cursor('\t');
+            return true;
+        }
+
+        // Spaces are inserted at the start of the selection
+        let (row, col) = self
+            .selection_range()
+            .map_or(self.cursor, |(start, _)| start);
+        let width: usize = self.lines[row]
+            .chars()
+            .take(col)
+            .map(|c| c.width().unwrap_or(0))
+            .sum();
+        let len = self.tab_len - (width % self.tab_len as usize) as u8;
+        if let Some(modified) = self.validate_insertion(spaces(len)) {
+            return modified;
+        }
+        self.delete_selection(false);
+        self.insert_piece(spaces(len).to_string())
+    }
+
+    /// Indent the lines in the selection by one level. When nothing is selected, the cursor line is indented. The
+    /// indentation is a hard tab or spaces of the tab length as [`TextArea::indent`]. Empty lines are not indented.
+    /// The edit is undone as one step. This method returns if some line was indented or not.
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "", "b", "c"]);
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::Jump(2, 1));
+    ///
+    /// assert!(textarea.indent_lines());
+    /// assert_eq!(textarea.lines(), ["    a", "", "    b", "c"]);
+    /// assert_eq!(textarea.selection_range(), Some(((0, 4), (2, 5))));
+    /// ```
+    pub fn indent_lines(&mut self) -> bool {
+        let indent = self.indent();
+        let width = indent.len() as isize;
+        let (start, end) = self.selected_rows();
+        for row in start..=end {
+            if !self.lines[row].is_empty() && !self.accepts_range(indent, (row, 0), (row, 0)) {
+                return false;
             }
-            | Input {
-                key: Key::Paste, ..
-            } => self.paste(),
-            Input {
-                key: Key::Char('x'),
-                ctrl: true,
-                alt: false,
-                ..
+        }
+        self.edit_selected_lines(|t, row| {
+            if t.lines[row].is_empty() {
+                return 0;
             }
-            | Input { key: Key::Cut, .. } => self.cut(),
-            Input {
-                key: Key::Char('c'),
-                ctrl: true,
-                alt: false,
-                ..
+            t.cursor = (row, 0);
+            t.insert_piece(indent.to_string());
+            width
+        }) > 0
+    }
+
+    /// Outdent the lines in the selection by one level. When nothing is selected, the cursor line is outdented. A hard
+    /// tab or spaces until the previous tab stop are removed from the indentation of each line. The edit is undone as
+    /// one step. This method returns if some line was outdented or not. This method is bound to `Shift+Tab` in the
+    /// default key mappings.
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["        a", "\tb", "  c"]);
+    /// textarea.select_all();
+    ///
+    /// assert!(textarea.outdent_lines());
+    /// assert_eq!(textarea.lines(), ["    a", "b", "c"]);
+    ///
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["        a", "\tb", "  c"]);
+    /// ```
+    pub fn outdent_lines(&mut self) -> bool {
+        self.edit_selected_lines(|t, row| {
+            let len = outdent_len(leading_indent(&t.lines[row]), t.tab_len);
+            if len > 0 {
+                t.delete_range(Pos::new(row, 0, 0), Pos::new(row, len, len), false);
             }
-            | Input { key: Key::Copy, .. } => {
-                self.copy();
-                false
+            -(len as isize)
+        }) > 0
+    }
+
+    // Edit each line in the selection as one step. `f` returns how many characters were inserted at the head of the
+    // line. The cursor and the selection follow the edits. The number of edited lines is returned.
+    fn edit_selected_lines(&mut self, mut f: impl FnMut(&mut Self, usize) -> isize) -> usize {
+        let (start, end) = self.selected_rows();
+        let (cursor, selection_start) = (self.cursor, self.selection_start.take());
+
+        let mut deltas = Vec::with_capacity(end - start + 1);
+        self.begin_transaction();
+        for row in start..=end {
+            deltas.push(f(self, row));
+        }
+        self.end_transaction();
+
+        let follow = |(row, col): (usize, usize)| -> (usize, usize) {
+            let delta = match row.checked_sub(start) {
+                Some(i) if row <= end => deltas[i],
+                _ => 0,
+            };
+            (row, col.saturating_add_signed(delta))
+        };
+        self.selection_start = selection_start.map(follow);
+        self.cursor = follow(cursor);
+        deltas.iter().filter(|&&d| d != 0).count()
+    }
+
+    // First and last rows of the lines in the selection. The cursor line is returned when nothing is selected
+    fn selected_rows(&self) -> (usize, usize) {
+        match self.selection_range() {
+            // The line where the selection ends at its head is not selected
+            Some(((start, _), (end, 0))) if start < end => (start, end - 1),
+            Some(((start, _), (end, _))) => (start, end),
+            None => (self.cursor.0, self.cursor.0),
+        }
+    }
+
+    // Position at the end of the line
+    fn line_end(&self, row: usize) -> Pos {
+        let line = &self.lines[row];
+        Pos::new(row, line.chars().count(), line.len())
+    }
+
+    /// Select the whole lines in the selection, or the cursor line when nothing is selected. When whole lines are
+    /// already selected, the next line is added to the selection. The cursor moves to the head of the line after the
+    /// selected lines, or to the end of the last line. This method is bound to `Ctrl+L` in [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    ///
+    /// textarea.select_lines();
+    /// assert_eq!(textarea.selection_range(), Some(((0, 0), (1, 0))));
+    /// textarea.select_lines();
+    /// assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 0))));
+    /// textarea.select_lines();
+    /// assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 1))));
+    /// ```
+    pub fn select_lines(&mut self) {
+        let (start, mut end) = self.selected_rows();
+        let last = self.lines.len() - 1;
+        let range_end = |end: usize| {
+            if end < last {
+                (end + 1, 0)
+            } else {
+                (last, self.lines[last].chars().count())
             }
-            Input {
-                key: Key::Char('v'),
-                ctrl: true,
-                alt: false,
-                shift,
+        };
+        if end < last && self.selection_range() == Some(((start, 0), range_end(end))) {
+            end += 1;
+        }
+        self.history.close_coalescing();
+        self.cursors.clear();
+        self.block_selection = false;
+        self.selection_start = Some((start, 0));
+        self.cursor = range_end(end);
+    }
+
+    /// Duplicate the lines in the selection, or the cursor line when nothing is selected. The copy is inserted below
+    /// the lines and the cursor and the selection move to the copy. The edit is undone as one step. This method returns
+    /// `false` when the validators don't accept the copy. This method is bound to `Ctrl+Shift+D` in
+    /// [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b"]);
+    /// textarea.move_cursor(CursorMove::End);
+    ///
+    /// assert!(textarea.duplicate_lines());
+    /// assert_eq!(textarea.lines(), ["a", "a", "b"]);
+    /// assert_eq!(textarea.cursor(), (1, 1));
+    /// ```
+    pub fn duplicate_lines(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        let (cursor, selection_start) = (self.cursor, self.selection_start);
+        let mut chunk = vec![String::new()];
+        chunk.extend((start..=end).map(|row| self.lines[row].clone()));
+
+        let pos = self.line_end(end);
+        if !self.accepts_range(&chunk.join("\n"), (pos.row, pos.col), (pos.row, pos.col)) {
+            return false;
+        }
+        self.cursor = (pos.row, pos.col);
+        self.insert_chunk(chunk);
+
+        let rows = end - start + 1;
+        self.cursor = (cursor.0 + rows, cursor.1);
+        self.selection_start = selection_start.map(|(row, col)| (row + rows, col));
+        true
+    }
+
+    /// Delete the lines in the selection, or the cursor line when nothing is selected. The cursor moves to the line
+    /// after the deleted lines. The deleted text is not yanked. This method returns if some text was deleted or not.
+    /// This method is bound to `Ctrl+Shift+K` in [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    /// textarea.move_cursor(CursorMove::Down);
+    ///
+    /// assert!(textarea.delete_lines());
+    /// assert_eq!(textarea.lines(), ["a", "c"]);
+    /// assert_eq!(textarea.cursor(), (1, 0));
+    ///
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["a", "b", "c"]);
+    /// ```
+    pub fn delete_lines(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        let col = self.cursor.1;
+        self.cancel_selection();
+        // The newline before the lines is deleted when the lines are at the end of the text
+        let (from, to) = if end + 1 < self.lines.len() {
+            (Pos::new(start, 0, 0), Pos::new(end + 1, 0, 0))
+        } else if start > 0 {
+            (self.line_end(start - 1), self.line_end(end))
+        } else {
+            (Pos::new(0, 0, 0), self.line_end(end))
+        };
+        if (from.row, from.offset) == (to.row, to.offset) {
+            return false;
+        }
+        self.delete_range(from, to, false);
+        let row = cmp::min(start, self.lines.len() - 1);
+        self.cursor = (row, cmp::min(col, self.lines[row].chars().count()));
+        true
+    }
+
+    /// Move the lines in the selection, or the cursor line when nothing is selected, up by one line. The cursor and the
+    /// selection follow the lines. The edit is undone as one step. This method returns if the lines were moved or not.
+    /// This method is bound to `Alt+↑` in the default key mappings and [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    /// textarea.move_cursor(CursorMove::Bottom);
+    ///
+    /// assert!(textarea.move_lines_up());
+    /// assert_eq!(textarea.lines(), ["a", "c", "b"]);
+    /// assert_eq!(textarea.cursor(), (1, 0));
+    /// ```
+    pub fn move_lines_up(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        if start == 0 {
+            return false;
+        }
+        let (cursor, selection_start) = (self.cursor, self.selection_start);
+        let line = self.lines[start - 1].clone();
+        let mut moved: Vec<_> = (start..=end).map(|row| self.lines[row].as_str()).collect();
+        moved.push(&line);
+        let end_pos = self.line_end(end);
+        if !self.accepts_range(&moved.join("\n"), (start - 1, 0), (end, end_pos.col)) {
+            return false;
+        }
+
+        // Move the line above the lines to below them
+        self.begin_transaction();
+        self.delete_range(Pos::new(start - 1, 0, 0), Pos::new(start, 0, 0), false);
+        let pos = self.line_end(end - 1);
+        self.cursor = (pos.row, pos.col);
+        self.insert_chunk(vec![String::new(), line]);
+        self.end_transaction();
+
+        // The selection may end at the head of the line below the lines, which is now the moved line
+        let follow = |(row, col): (usize, usize)| {
+            if start <= row && row <= end + 1 {
+                (row - 1, col)
+            } else {
+                (row, col)
             }
-            | Input {
-                key: Key::PageDown,
-                shift,
-                ..
-            } => {
-                self.scroll_with_shift(Scrolling::PageDown, shift);
-                false
+        };
+        self.selection_start = selection_start.map(follow);
+        self.cursor = follow(cursor);
+        true
+    }
+
+    /// Move the lines in the selection, or the cursor line when nothing is selected, down by one line. See
+    /// [`TextArea::move_lines_up`] for the details. This method is bound to `Alt+↓` in the default key mappings and
+    /// [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    ///
+    /// assert!(textarea.move_lines_down());
+    /// assert_eq!(textarea.lines(), ["b", "a", "c"]);
+    /// assert_eq!(textarea.cursor(), (1, 0));
+    /// ```
+    pub fn move_lines_down(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        let last = self.lines.len() - 1;
+        if end >= last {
+            return false;
+        }
+        let (cursor, selection_start) = (self.cursor, self.selection_start);
+        let line = self.lines[end + 1].clone();
+        let mut moved = vec![line.as_str()];
+        moved.extend((start..=end).map(|row| self.lines[row].as_str()));
+        let end_pos = self.line_end(end + 1);
+        if !self.accepts_range(&moved.join("\n"), (start, 0), (end + 1, end_pos.col)) {
+            return false;
+        }
+
+        // Move the line below the lines to above them
+        self.begin_transaction();
+        self.delete_range(self.line_end(end), self.line_end(end + 1), false);
+        self.cursor = (start, 0);
+        self.insert_chunk(vec![line, String::new()]);
+        self.end_transaction();
+
+        // The selection may end at the head of the line below the lines
+        let follow = |(row, col): (usize, usize)| match row {
+            _ if row < start || end + 1 < row => (row, col),
+            _ if row < last => (row + 1, col),
+            _ => (last, self.lines[last].chars().count()),
+        };
+        self.selection_start = selection_start.map(follow);
+        self.cursor = follow(cursor);
+        true
+    }
+
+    /// Join the lines in the selection into one line. When nothing or only one line is selected, the cursor line and
+    /// the next line are joined. The leading whitespaces of the joined lines are replaced with a space, or removed when
+    /// either line is blank or the line already ends with a space. The cursor moves to the first joint. The edit is
+    /// undone as one step. This method returns if some lines were joined or not. This method is bound to `Ctrl+J` in
+    /// [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["fn f() {", "    1", "}"]);
+    /// textarea.select_all();
+    ///
+    /// assert!(textarea.join_lines());
+    /// assert_eq!(textarea.lines(), ["fn f() { 1 }"]);
+    /// assert_eq!(textarea.cursor(), (0, 8));
+    /// ```
+    pub fn join_lines(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        let end = cmp::max(end, start + 1);
+        if end >= self.lines.len() {
+            return false;
+        }
+        let mut joined = self.lines[end].clone();
+        for row in (start..end).rev() {
+            let line = &self.lines[row];
+            let rest = &joined[leading_indent(&joined).len()..];
+            joined = format!("{line}{}{rest}", join_separator(line, &joined));
+        }
+        let end_pos = self.line_end(end);
+        if !self.accepts_range(&joined, (start, 0), (end, end_pos.col)) {
+            return false;
+        }
+        self.cancel_selection();
+
+        // Join from the last line so that the positions of the earlier joints don't change
+        let joint = self.line_end(start);
+        self.begin_transaction();
+        for row in (start..end).rev() {
+            let (line, next) = (&self.lines[row], &self.lines[row + 1]);
+            let indent = leading_indent(next).len();
+            let sep = join_separator(line, next);
+            self.delete_range(self.line_end(row), Pos::new(row + 1, indent, indent), false);
+            self.insert_piece(sep.to_string());
+        }
+        self.end_transaction();
+        self.cursor = (joint.row, joint.col);
+        true
+    }
+
+    /// Sort the lines in the selection in lexicographic order. The cursor and the selection stay at the same positions
+    /// as far as possible. The edit is undone as one step. This method returns if the order of the lines was changed or
+    /// not.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["b", "c", "a"]);
+    /// textarea.select_all();
+    ///
+    /// assert!(textarea.sort_lines());
+    /// assert_eq!(textarea.lines(), ["a", "b", "c"]);
+    /// assert!(!textarea.sort_lines());
+    /// ```
+    pub fn sort_lines(&mut self) -> bool {
+        let (start, end) = self.selected_rows();
+        let lines: Vec<_> = (start..=end).map(|row| self.lines[row].clone()).collect();
+        let mut sorted = lines.clone();
+        sorted.sort();
+        if sorted == lines {
+            return false;
+        }
+        let end_pos = self.line_end(end);
+        if !self.accepts_range(&sorted.join("\n"), (start, 0), (end, end_pos.col)) {
+            return false;
+        }
+        let (cursor, selection_start) = (self.cursor, self.selection_start);
+
+        self.begin_transaction();
+        self.delete_range(Pos::new(start, 0, 0), self.line_end(end), false);
+        self.insert_chunk(sorted);
+        self.end_transaction();
+
+        self.cursor = self.clamp_cursor_to_buffer(cursor);
+        self.selection_start = selection_start.map(|pos| self.clamp_cursor_to_buffer(pos));
+        true
+    }
+
+    /// Toggle the line comment of the lines in the selection, or the cursor line when nothing is selected. When all
+    /// non-blank lines start with the prefix set by [`TextArea::set_comment_prefix`] after their indentation, the
+    /// prefix and a space following it are removed. Otherwise the prefix and a space are inserted at the smallest
+    /// indentation of the lines. Blank lines are not changed. The edit is undone as one step. This method returns if
+    /// some line was changed or not. This method is bound to `Ctrl+/` in [`KeyMap::standard`].
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["if x {", "    f();", "}"]);
+    /// textarea.select_all();
+    ///
+    /// assert!(textarea.toggle_comment());
+    /// assert_eq!(textarea.lines(), ["// if x {", "//     f();", "// }"]);
+    /// assert!(textarea.toggle_comment());
+    /// assert_eq!(textarea.lines(), ["if x {", "    f();", "}"]);
+    /// ```
+    pub fn toggle_comment(&mut self) -> bool {
+        let prefix = self.comment_prefix.clone();
+        let (start, end) = self.selected_rows();
+        let indents: Vec<_> = (start..=end)
+            .filter(|&row| !self.lines[row].trim().is_empty())
+            .map(|row| leading_indent(&self.lines[row]).len())
+            .collect();
+        let Some(&min_indent) = indents.iter().min() else {
+            return false;
+        };
+        if prefix.is_empty() {
+            return false;
+        }
+
+        let commented = (start..=end).all(|row| {
+            let line = &self.lines[row];
+            line.trim().is_empty() || line[leading_indent(line).len()..].starts_with(&prefix)
+        });
+        let changed = if commented {
+            self.edit_selected_lines(|t, row| {
+                let line = &t.lines[row];
+                let indent = leading_indent(line).len();
+                let Some(rest) = line[indent..].strip_prefix(prefix.as_str()) else {
+                    return 0;
+                };
+                let len = prefix.len() + usize::from(rest.starts_with(' '));
+                let chars = line[indent..indent + len].chars().count();
+                let end = Pos::new(row, indent + chars, indent + len);
+                t.delete_range(Pos::new(row, indent, indent), end, false);
+                -(chars as isize)
+            })
+        } else {
+            let comment = format!("{prefix} ");
+            let chars = comment.chars().count() as isize;
+            for row in start..=end {
+                let pos = (row, min_indent);
+                if !self.lines[row].trim().is_empty() && !self.accepts_range(&comment, pos, pos) {
+                    return false;
+                }
             }
-            Input {
-                key: Key::Char('v'),
-                ctrl: false,
-                alt: true,
-                shift,
+            self.edit_selected_lines(|t, row| {
+                if t.lines[row].trim().is_empty() {
+                    return 0;
+                }
+                t.cursor = (row, min_indent);
+                t.insert_piece(comment.clone());
+                chars
+            })
+        };
+        changed > 0
+    }
+
+    /// Insert a newline at current cursor position.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["hi"]);
+    ///
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// textarea.insert_newline();
+    /// assert_eq!(textarea.lines(), ["h", "i"]);
+    /// ```
+    pub fn insert_newline(&mut self) {
+        self.try_insert_newline();
+    }
+
+    // Insert a newline and return if the text was modified
+    fn try_insert_newline(&mut self) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.try_insert_newline());
+        }
+
+        // The indentation is validated with the newline
+        let chunk = self.indented_newline();
+        let text = chunk
+            .as_ref()
+            .map_or_else(|| "\n".to_string(), |c| c.join("\n"));
+        if let Some(modified) = self.validate_insertion(&text) {
+            return modified;
+        }
+
+        self.delete_selection(false);
+        if let Some(chunk) = chunk {
+            let cursor = (self.cursor.0 + 1, chunk[1].chars().count());
+            self.insert_chunk(chunk);
+            self.cursor = cursor;
+            return true;
+        }
+
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        let offset = line
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        let next_line = line[offset..].to_string();
+        line.truncate(offset);
+
+        self.lines.insert(row + 1, next_line);
+        self.cursor = (row + 1, 0);
+        self.push_history(EditKind::InsertNewline, Pos::new(row, col, offset), 0);
+        true
+    }
+
+    // Chunk of a newline followed by the indentation of the new line, which is inserted as one edit. The selection is
+    // replaced with the chunk. `None` is returned when no indentation is necessary.
+    fn indented_newline(&self) -> Option<Vec<String>> {
+        if self.auto_indent == AutoIndent::None {
+            return None;
+        }
+
+        let ((start_row, start_col), (end_row, end_col)) =
+            self.selection_range().unwrap_or((self.cursor, self.cursor));
+        let offset = |line: &str, col| line.char_indices().nth(col).map_or(line.len(), |(i, _)| i);
+        let before = &self.lines[start_row];
+        let before = &before[..offset(before, start_col)];
+        let after = &self.lines[end_row];
+        let after = &after[offset(after, end_col)..];
+        let indent = leading_indent(before).to_string();
+        let opener = match self.auto_indent {
+            AutoIndent::Smart => block_opener(before),
+            _ => None,
+        };
+
+        let chunk = match opener {
+            // Put the closing bracket after the cursor on its own line: `{|}` -> `{\n    |\n}`
+            Some(c) if closes_block(c, after) => {
+                vec![String::new(), format!("{indent}{}", self.indent()), indent]
             }
-            | Input {
-                key: Key::PageUp,
-                shift,
-                ..
-            } => {
-                self.scroll_with_shift(Scrolling::PageUp, shift);
+            Some(_) => vec![String::new(), format!("{indent}{}", self.indent())],
+            None if indent.is_empty() => return None,
+            None => vec![String::new(), indent],
+        };
+        Some(chunk)
+    }
+
+    /// Delete a newline from **head** of current cursor line. This method returns if a newline was deleted or not in
+    /// the textarea. When some text is selected, it is deleted instead.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["hello", "world"]);
+    ///
+    /// textarea.move_cursor(CursorMove::Down);
+    /// textarea.delete_newline();
+    /// assert_eq!(textarea.lines(), ["helloworld"]);
+    /// ```
+    pub fn delete_newline(&mut self) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_newline());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+
+        let (row, _) = self.cursor;
+        if row == 0 {
+            return false;
+        }
+
+        let line = self.lines.remove(row);
+        let prev_line = &mut self.lines[row - 1];
+        let prev_line_end = prev_line.len();
+
+        self.cursor = (row - 1, prev_line.chars().count());
+        prev_line.push_str(&line);
+        self.push_history(EditKind::DeleteNewline, Pos::new(row, 0, 0), prev_line_end);
+        true
+    }
+
+    /// Delete one character before cursor. When the cursor is at head of line, the newline before the cursor will be
+    /// removed. This method returns if some text was deleted or not in the textarea. When some text is selected, it is
+    /// deleted instead.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    ///
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// textarea.delete_char();
+    /// assert_eq!(textarea.lines(), ["bc"]);
+    /// ```
+    pub fn delete_char(&mut self) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_char());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+
+        let (row, col) = self.cursor;
+        if col == 0 {
+            return self.delete_newline();
+        }
+
+        let line = &mut self.lines[row];
+        if let Some((offset, c)) = line.char_indices().nth(col - 1) {
+            line.remove(offset);
+            self.cursor.1 -= 1;
+            self.push_history(
+                EditKind::DeleteChar(c),
+                Pos::new(row, col, offset + c.len_utf8()),
+                offset,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Delete one character next to cursor. When the cursor is at end of line, the newline next to the cursor will be
+    /// removed. This method returns if a character was deleted or not in the textarea.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    ///
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// textarea.delete_next_char();
+    /// assert_eq!(textarea.lines(), ["ac"]);
+    /// ```
+    pub fn delete_next_char(&mut self) -> bool {
+        self.split_block_selection();
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_next_char());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+
+        let before = self.cursor;
+        self.move_cursor_with_shift(CursorMove::Forward, false);
+        if before == self.cursor {
+            return false; // Cursor didn't move, meant no character at next of cursor.
+        }
+
+        self.delete_char()
+    }
+
+    /// Delete string from cursor to end of the line. When the cursor is at end of line, the newline next to the cursor
+    /// is removed. This method returns if some text was deleted or not in the textarea.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["abcde"]);
+    ///
+    /// // Move to 'c'
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// textarea.move_cursor(CursorMove::Forward);
+    ///
+    /// textarea.delete_line_by_end();
+    /// assert_eq!(textarea.lines(), ["ab"]);
+    /// ```
+    pub fn delete_line_by_end(&mut self) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_line_by_end());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+        if self.delete_piece(self.cursor.1, usize::MAX) {
+            return true;
+        }
+        self.delete_next_char() // At the end of the line. Try to delete next line
+    }
+
+    /// Delete string from cursor to head of the line. When the cursor is at head of line, the newline before the cursor
+    /// will be removed. This method returns if some text was deleted or not in the textarea.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["abcde"]);
+    ///
+    /// // Move to 'c'
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// textarea.move_cursor(CursorMove::Forward);
+    ///
+    /// textarea.delete_line_by_head();
+    /// assert_eq!(textarea.lines(), ["cde"]);
+    /// ```
+    pub fn delete_line_by_head(&mut self) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_line_by_head());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+        if self.delete_piece(0, self.cursor.1) {
+            return true;
+        }
+        self.delete_newline()
+    }
+
+    /// Delete a word before cursor. Word boundary appears at spaces, punctuations, and others. For example `fn foo(a)`
+    /// consists of words `fn`, `foo`, `(`, `a`, `)`. When the cursor is at head of line, the newline before the cursor
+    /// will be removed.
+    ///
+    /// This method returns if some text was deleted or not in the textarea.
+    ///
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    ///
+    /// textarea.move_cursor(CursorMove::End);
+    ///
+    /// textarea.delete_word();
+    /// assert_eq!(textarea.lines(), ["aaa bbb "]);
+    /// textarea.delete_word();
+    /// assert_eq!(textarea.lines(), ["aaa "]);
+    /// ```
+    pub fn delete_word(&mut self) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_word());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+        let (r, c) = self.cursor;
+        if let Some(col) = find_word_start_backward(&self.lines[r], c) {
+            self.delete_piece(col, c - col)
+        } else if c > 0 {
+            self.delete_piece(0, c)
+        } else {
+            self.delete_newline()
+        }
+    }
+
+    /// Delete a word next to cursor. Word boundary appears at spaces, punctuations, and others. For example `fn foo(a)`
+    /// consists of words `fn`, `foo`, `(`, `a`, `)`. When the cursor is at end of line, the newline next to the cursor
+    /// will be removed.
+    ///
+    /// This method returns if some text was deleted or not in the textarea.
+    ///
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    ///
+    /// textarea.delete_next_word();
+    /// assert_eq!(textarea.lines(), [" bbb ccc"]);
+    /// textarea.delete_next_word();
+    /// assert_eq!(textarea.lines(), [" ccc"]);
+    /// ```
+    pub fn delete_next_word(&mut self) -> bool {
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.delete_next_word());
+        }
+
+        if self.delete_selection(false) {
+            return true;
+        }
+        let (r, c) = self.cursor;
+        let line = &self.lines[r];
+        if let Some(col) = find_word_exclusive_end_forward(line, c) {
+            self.delete_piece(c, col - c)
+        } else {
+            let end_col = line.chars().count();
+            if c < end_col {
+                self.delete_piece(c, end_col - c)
+            } else if r + 1 < self.lines.len() {
+                self.cursor = (r + 1, 0);
+                self.delete_newline()
+            } else {
                 false
             }
-            Input {
-                key: Key::MouseScrollDown,
-                shift,
-                ..
-            } => {
-                self.scroll_with_shift((1, 0).into(), shift);
-                false
+        }
+    }
+
+    /// Clears the whole content of the text area.
+    /// This will result in an empty text area.
+    /// The position of the cursor does matter for clearing the entire text.
+    ///
+    /// This method returns if some text was deleted or not in the textarea.
+    ///
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["aaa\nbbb ccc"]);
+    ///
+    /// textarea.clear();
+    /// assert!(textarea.is_empty());
+    /// ```
+    pub fn clear(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let all_lines = &self.lines;
+        let summed_up_chars_new_lines: usize = all_lines
+            .iter()
+            .map(|line| line.chars().map(|_| 1usize).sum::<usize>())
+            .sum();
+        let all_chars = all_lines.len() + summed_up_chars_new_lines;
+        self.move_cursor(CursorMove::Jump(0, 0));
+        self.delete_str(all_chars);
+
+        true
+    }
+
+    /// Paste a string previously deleted by [`TextArea::delete_line_by_head`], [`TextArea::delete_line_by_end`],
+    /// [`TextArea::delete_word`], [`TextArea::delete_next_word`]. When a clipboard is set by
+    /// [`TextArea::set_clipboard`] and other applications changed the clipboard, the text in the clipboard is pasted.
+    /// Text copied from a block selection is inserted as a block. See [`TextArea::start_block_selection`] for the
+    /// details. This method returns if some text was inserted or not in the textarea.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    ///
+    /// textarea.delete_next_word();
+    /// textarea.move_cursor(CursorMove::End);
+    /// textarea.paste();
+    /// assert_eq!(textarea.lines(), [" bbb cccaaa"]);
+    /// ```
+    pub fn paste(&mut self) -> bool {
+        // Text killed by deletions after the last copy is kept in the yank buffer unless other applications changed
+        // the clipboard. This also keeps the text copied from a block selection as a block.
+        if let Some(clipboard) = &mut self.clipboard {
+            if let Ok(Some(text)) = clipboard.get_changed_text() {
+                self.yank = YankText::from_text(text);
             }
-            Input {
-                key: Key::MouseScrollUp,
-                shift,
-                ..
-            } => {
-                self.scroll_with_shift((-1, 0).into(), shift);
-                false
+        }
+
+        if !self.cursors.is_empty() {
+            return self.at_each_cursor(|t| t.paste_yank(false));
+        }
+        self.paste_yank(true)
+    }
+
+    // A block is pasted as lines of text at each cursor when `block` is `false`
+    fn paste_yank(&mut self, block: bool) -> bool {
+        // Avoid converting the yanked text into a string when no validator is set
+        if self.validators.is_empty() {
+            self.validation_error = None;
+        } else if let Some(modified) = self.validate_insertion(&self.yank.to_string()) {
+            return modified;
+        }
+        self.delete_selection(false);
+        match self.yank.clone() {
+            YankText::Piece(s) => self.insert_piece(s),
+            YankText::Chunk(c) => self.insert_chunk(c),
+            YankText::Block(b) if block => self.insert_block(b),
+            YankText::Block(b) => self.insert_text(&b.join("\n")),
+        }
+    }
+
+    // Insert each line of the block at the display column of the cursor on the following rows as one step. Rows
+    // shorter than the column are padded with spaces and new rows are appended at the end of the text as needed. The
+    // cursor stays at the top-left of the inserted block.
+    fn insert_block(&mut self, block: Vec<String>) -> bool {
+        let (row, col) = self.cursor;
+        let width = display_width_at_col(&self.lines[row], col, self.tab_len, &[]);
+        let mut modified = false;
+        self.begin_transaction();
+        for (i, text) in block.into_iter().enumerate() {
+            let row = row + i;
+            if row == self.lines.len() {
+                self.cursor = (row - 1, self.lines[row - 1].chars().count());
+                self.insert_chunk(vec![String::new(), String::new()]);
+                modified = true;
             }
-            _ => false,
+            let line = &self.lines[row];
+            let col = col_after_display_width(line, width, self.tab_len);
+            let pad = width.saturating_sub(display_width_at_col(line, col, self.tab_len, &[]));
+            self.cursor = (row, col);
+            modified |= self.insert_piece(format!("{}{}", " ".repeat(pad), text));
+        }
+        self.end_transaction();
+        self.cursor = (row, col);
+        modified
+    }
+
+    /// Start text selection at the cursor position. If text selection is already ongoing, the start position is reset.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    ///
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::WordForward);
+    /// textarea.copy();
+    /// assert_eq!(textarea.yank_text(), "aaa ");
+    /// ```
+    pub fn start_selection(&mut self) {
+        self.history.close_coalescing();
+        self.selection_start = Some(self.cursor);
+        self.block_selection = false;
+        for caret in &mut self.cursors {
+            caret.selection_start = Some(caret.cursor);
+        }
+    }
+
+    /// Stop the current text selection. This method does nothing if text selection is not ongoing.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    ///
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::WordForward);
+    ///
+    /// // Cancel the ongoing text selection
+    /// textarea.cancel_selection();
+    ///
+    /// // As the result, this `copy` call does nothing
+    /// textarea.copy();
+    /// assert_eq!(textarea.yank_text(), "");
+    /// ```
+    pub fn cancel_selection(&mut self) {
+        // Edits clear the empty selection without closing the undo group of typed characters
+        if self.selection_start.is_some() {
+            self.history.close_coalescing();
+        }
+        self.selection_start = None;
+        self.block_selection = false;
+        for caret in &mut self.cursors {
+            caret.selection_start = None;
+        }
+    }
+
+    pub fn custom_highlight(
+        &mut self,
+        range: ((usize, usize), (usize, usize)),
+        style: Style,
+        priority: u8,
+    ) {
+        self.custom_highlights.push(CustomHighlight {
+            range,
+            style,
+            priority,
+        });
+    }
+
+    pub fn clear_custom_highlight(&mut self) {
+        self.custom_highlights.clear();
+    }
+
+    /// Set the [`Highlighter`] to color the text such as syntax highlighting. The highlighter is called for each
+    /// visible line on rendering and its results are cached per line until the line is modified. Highlighting is
+    /// disabled while a mask character is set. The highlighter must be [`Clone`] since cloning the textarea also clones
+    /// the highlighter. Each clone of the textarea keeps its own highlighter state.
+    /// ```
+    /// use std::ops::Range;
+    /// use ratatui::style::{Color, Style};
+    /// use tui_textarea::{Highlighter, TextArea};
+    ///
+    /// // Highlight the `fn` keyword
+    /// #[derive(Clone)]
+    /// struct Keyword;
+    ///
+    /// impl Highlighter for Keyword {
+    ///     fn highlight_line(&mut self, _row: usize, line: &str) -> Vec<(Range<usize>, Style)> {
+    ///         line.match_indices("fn")
+    ///             .map(|(i, s)| (i..i + s.len(), Style::default().fg(Color::Magenta)))
+    ///             .collect()
+    ///     }
+    /// }
+    ///
+    /// let mut textarea = TextArea::from(["fn main() {}"]);
+    /// textarea.set_highlighter(Keyword);
+    /// assert!(textarea.has_highlighter());
+    /// ```
+    pub fn set_highlighter(&mut self, highlighter: impl Highlighter + Clone + Send + 'static) {
+        self.syntax = Some(SyntaxHighlight::new(highlighter));
+    }
+
+    /// Remove the highlighter which was set by [`TextArea::set_highlighter`].
+    pub fn remove_highlighter(&mut self) {
+        self.syntax = None;
+    }
+
+    /// Return if a highlighter is set by [`TextArea::set_highlighter`].
+    pub fn has_highlighter(&self) -> bool {
+        self.syntax.is_some()
+    }
+
+    /// Discard all cached highlights and highlight the lines again on the next rendering. This is useful when the
+    /// highlighter's configuration such as a color theme was changed after it was set.
+    pub fn invalidate_highlights(&mut self) {
+        if let Some(syntax) = &mut self.syntax {
+            syntax.invalidate_all();
+        }
+    }
+
+    /// Select the entire text. Cursor moves to the end of the text buffer. When text selection is already ongoing,
+    /// it is canceled.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["aaa", "bbb", "ccc"]);
+    ///
+    /// textarea.select_all();
+    ///
+    /// // Cut the entire text;
+    /// textarea.cut();
+    ///
+    /// assert_eq!(textarea.lines(), [""]); // Buffer is now empty
+    /// assert_eq!(textarea.yank_text(), "aaa\nbbb\nccc");
+    /// ```
+    pub fn select_all(&mut self) {
+        self.cursors.clear();
+        self.move_cursor(CursorMove::Jump(u16::MAX, u16::MAX));
+        self.selection_start = Some((0, 0));
+        self.block_selection = false;
+    }
+
+    /// Return if text selection is ongoing or not.
+    /// ```
+    /// use tui_textarea::{TextArea};
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// assert!(!textarea.is_selecting());
+    /// textarea.start_selection();
+    /// assert!(textarea.is_selecting());
+    /// textarea.cancel_selection();
+    /// assert!(!textarea.is_selecting());
+    /// ```
+    pub fn is_selecting(&self) -> bool {
+        self.selection_start.is_some()
+    }
+
+    /// Start a block selection at the cursor position. A block selection selects a rectangle of display columns
+    /// between the start position and the cursor across the rows. Wide characters and tabs are selected only when they
+    /// start within the rectangle. Moving the cursor extends the block like [`TextArea::start_selection`], and extra
+    /// cursors are removed.
+    ///
+    /// - [`TextArea::copy`] yanks the text of each row as one line
+    /// - [`TextArea::cut`] and deleting characters remove the rectangle
+    /// - [`TextArea::paste`] inserts the yanked block at the display column of the cursor on the following rows
+    /// - Typing text inserts it at every row. The selected text is replaced with a cursor at each row
+    ///
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["abcd", "efgh", "ijkl"]);
+    /// textarea.move_cursor(CursorMove::Forward);
+    ///
+    /// textarea.start_block_selection();
+    /// textarea.move_cursor(CursorMove::Jump(2, 3));
+    /// assert!(textarea.is_block_selecting());
+    ///
+    /// textarea.cut();
+    /// assert_eq!(textarea.lines(), ["ad", "eh", "il"]);
+    /// assert_eq!(textarea.yank_text(), "bc\nfg\njk");
+    ///
+    /// // Paste the block at the end of the lines
+    /// textarea.move_cursor(CursorMove::End);
+    /// textarea.paste();
+    /// assert_eq!(textarea.lines(), ["adbc", "ehfg", "iljk"]);
+    /// ```
+    pub fn start_block_selection(&mut self) {
+        self.history.close_coalescing();
+        self.cursors.clear();
+        self.selection_start = Some(self.cursor);
+        self.block_selection = true;
+    }
+
+    /// Return if block selection started by [`TextArea::start_block_selection`] is ongoing or not. While the block
+    /// selection is ongoing, [`TextArea::selection_range`] returns the corners of the block.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// textarea.start_block_selection();
+    /// assert!(textarea.is_block_selecting());
+    /// textarea.start_selection();
+    /// assert!(!textarea.is_block_selecting());
+    /// ```
+    pub fn is_block_selecting(&self) -> bool {
+        self.block_selection && self.selection_start.is_some()
+    }
+
+    // Range of rows and range of display columns in the block selection. `None` is returned when no block is selected
+    fn block_bounds(&self) -> Option<((usize, usize), (usize, usize))> {
+        if !self.block_selection {
+            return None;
+        }
+        let start = self.selection_start?;
+        let width = |(row, col): (usize, usize)| {
+            display_width_at_col(&self.lines[row], col, self.tab_len, &[])
+        };
+        let (left, right) = match (width(start), width(self.cursor)) {
+            (s, c) if s <= c => (s, c),
+            (s, c) => (c, s),
         };
+        let rows = match (start.0, self.cursor.0) {
+            (s, c) if s <= c => (s, c),
+            (s, c) => (c, s),
+        };
+        Some((rows, (left, right)))
+    }
 
-        // Check invariants
-        debug_assert!(!self.lines.is_empty(), "no line after {:?}", input);
-        let (r, c) = self.cursor;
-        debug_assert!(
-            self.lines.len() > r,
-            "cursor {:?} exceeds max lines {} after {:?}",
-            self.cursor,
-            self.lines.len(),
-            input,
-        );
-        debug_assert!(
-            self.lines[r].chars().count() >= c,
-            "cursor {:?} exceeds max col {} at line {:?} after {:?}",
-            self.cursor,
-            self.lines[r].chars().count(),
-            self.lines[r],
-            input,
-        );
+    // Caret selecting the characters of the row between the display columns
+    fn block_caret(&self, row: usize, (left, right): (usize, usize)) -> Caret {
+        let line = &self.lines[row];
+        let start = col_after_display_width(line, left, self.tab_len);
+        let end = col_after_display_width(line, right, self.tab_len);
+        Caret {
+            cursor: (row, end),
+            selection_start: (start < end).then_some((row, start)),
+        }
+    }
 
-        modified
+    // Carets selecting the part of each row in the block selection
+    fn block_carets(&self) -> Option<Vec<Caret>> {
+        let ((top, bottom), cols) = self.block_bounds()?;
+        Some(
+            (top..=bottom)
+                .map(|row| self.block_caret(row, cols))
+                .collect(),
+        )
     }
 
-    /// Handle a key input without default key mappings. This method handles only
+    // Replace the block selection with a cursor at each row so that typing edits every row
+    fn split_block_selection(&mut self) {
+        if let Some(carets) = self.block_carets() {
+            let row = self.cursor.0;
+            let carets = carets.into_iter().map(|c| (c, c.cursor.0 == row)).collect();
+            self.set_carets(carets);
+        }
+    }
+
+    /// Add a cursor on the line above the top cursor for editing text at multiple positions. The new cursor becomes the
+    /// primary cursor returned by [`TextArea::cursor`]. Inserting and deleting text, pasting, and moving cursors are
+    /// applied at every cursor. Other edits are applied only at the primary cursor and remove the extra cursors. This
+    /// method returns if a cursor was added or not.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
     ///
-    /// - Single character input without modifier keys
-    /// - Tab
-    /// - Enter
-    /// - Backspace
-    /// - Delete
+    /// let mut textarea = TextArea::from(["abc", "def", "ghi"]);
+    /// textarea.move_cursor(CursorMove::Bottom);
+    /// textarea.move_cursor(CursorMove::Forward);
     ///
-    /// This method returns if the input modified text contents or not in the textarea.
+    /// assert!(textarea.add_cursor_above());
+    /// assert!(textarea.add_cursor_above());
+    /// assert!(!textarea.add_cursor_above());
+    /// assert_eq!(textarea.cursors(), [(0, 1), (1, 1), (2, 1)]);
     ///
-    /// This method is useful when you want to define your own key mappings and don't want default key mappings.
-    /// See 'Define your own key mappings' section in [the module document](./index.html).
-    pub fn input_without_shortcuts(&mut self, input: impl Into<Input>) -> bool {
-        match input.into() {
-            Input {
-                key: Key::Char(c),
-                ctrl: false,
-                alt: false,
-                ..
-            } => {
-                self.insert_char(c);
-                true
-            }
-            Input {
-                key: Key::Tab,
-                ctrl: false,
-                alt: false,
-                ..
-            } => self.insert_tab(),
-            Input {
-                key: Key::Backspace,
-                ..
-            } => self.delete_char(),
-            Input {
-                key: Key::Delete, ..
-            } => self.delete_next_char(),
-            Input {
-                key: Key::Enter, ..
-            } => {
-                self.insert_newline();
-                true
-            }
-            Input {
-                key: Key::MouseScrollDown,
-                ..
-            } => {
-                self.scroll((1, 0));
-                false
-            }
-            Input {
-                key: Key::MouseScrollUp,
-                ..
-            } => {
-                self.scroll((-1, 0));
-                false
-            }
-            _ => false,
+    /// textarea.insert_char('x');
+    /// assert_eq!(textarea.lines(), ["axbc", "dxef", "gxhi"]);
+    ///
+    /// // Edits at all cursors are undone at once
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["abc", "def", "ghi"]);
+    /// ```
+    pub fn add_cursor_above(&mut self) -> bool {
+        let top = self
+            .cursors
+            .first()
+            .map_or(self.cursor, |c| cmp::min(c.cursor, self.cursor));
+        if top.0 == 0 {
+            return false;
+        }
+        self.add_cursor((top.0 - 1, top.1));
+        true
+    }
+
+    /// Add a cursor on the line below the bottom cursor. See [`TextArea::add_cursor_above`] for the details. This
+    /// method returns if a cursor was added or not.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["abc", "de"]);
+    /// textarea.move_cursor(tui_textarea::CursorMove::End);
+    ///
+    /// assert!(textarea.add_cursor_below());
+    /// assert_eq!(textarea.cursors(), [(0, 3), (1, 2)]);
+    /// ```
+    pub fn add_cursor_below(&mut self) -> bool {
+        let bottom = self
+            .cursors
+            .last()
+            .map_or(self.cursor, |c| cmp::max(c.cursor, self.cursor));
+        if bottom.0 + 1 >= self.lines.len() {
+            return false;
         }
+        self.add_cursor((bottom.0 + 1, bottom.1));
+        true
     }
 
-    fn push_history(&mut self, kind: EditKind, before: Pos, after_offset: usize) {
-        let (row, col) = self.cursor;
-        let after = Pos::new(row, col, after_offset);
-        let edit = Edit::new(kind, before, after);
-        self.history.push(edit);
-        self.reset_measure_cache();
+    /// Add a cursor at the position. The position is clamped to the text. The new cursor becomes the primary cursor
+    /// returned by [`TextArea::cursor`]. See [`TextArea::add_cursor_above`] for the details.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["let x = 1;", "f(x);"]);
+    /// textarea.move_cursor(tui_textarea::CursorMove::Jump(0, 4));
+    /// textarea.add_cursor((1, 2));
+    ///
+    /// textarea.delete_next_char();
+    /// textarea.insert_str("y");
+    /// assert_eq!(textarea.lines(), ["let y = 1;", "f(y);"]);
+    /// ```
+    pub fn add_cursor(&mut self, cursor: (usize, usize)) {
+        let cursor = self.clamp_cursor_to_buffer(cursor);
+        let mut carets: Vec<_> = self.carets().map(|(c, _)| (c, false)).collect();
+        carets.push((
+            Caret {
+                cursor,
+                selection_start: None,
+            },
+            true,
+        ));
+        self.set_carets(carets);
     }
 
-    /// Insert a single character at current cursor position.
+    /// Select the next occurrence of the selected text and add a cursor at the end of it. When nothing is selected,
+    /// the word under the cursor is selected instead. The new cursor becomes the primary cursor returned by
+    /// [`TextArea::cursor`]. The search wraps around the text. This method returns if a word or an occurrence was
+    /// selected or not.
     /// ```
     /// use tui_textarea::TextArea;
     ///
-    /// let mut textarea = TextArea::default();
+    /// let mut textarea = TextArea::from(["foo bar", "bar foo"]);
     ///
-    /// textarea.insert_char('a');
-    /// assert_eq!(textarea.lines(), ["a"]);
+    /// assert!(textarea.add_next_occurrence()); // Select the word "foo"
+    /// assert_eq!(textarea.selection_range(), Some(((0, 0), (0, 3))));
+    /// assert!(textarea.add_next_occurrence()); // Select the next "foo"
+    /// assert_eq!(textarea.cursors(), [(0, 3), (1, 7)]);
+    ///
+    /// textarea.insert_str("baz");
+    /// assert_eq!(textarea.lines(), ["baz bar", "bar baz"]);
     /// ```
-    pub fn insert_char(&mut self, c: char) {
-        if c == '\n' || c == '\r' {
-            self.insert_newline();
-            return;
+    pub fn add_next_occurrence(&mut self) -> bool {
+        let Some((start, end)) = self.selection_range() else {
+            let (row, col) = self.cursor;
+            let Some((start, end)) = find_word_range(&self.lines[row], col) else {
+                return false;
+            };
+            self.selection_start = Some((row, start));
+            self.cursor = (row, end);
+            return true;
+        };
+        if start == end {
+            return false;
         }
 
-        self.delete_selection(false);
-        let (row, col) = self.cursor;
-        let line = &mut self.lines[row];
-        let i = line
-            .char_indices()
-            .nth(col)
-            .map(|(i, _)| i)
-            .unwrap_or(line.len());
-        line.insert(i, c);
-        self.cursor.1 += 1;
-        self.push_history(
-            EditKind::InsertChar(c),
-            Pos::new(row, col, i),
-            i + c.len_utf8(),
-        );
+        let text = self.text_in_range(start, end);
+        let carets: Vec<_> = self.carets().map(|(c, _)| c).collect();
+        let last_end = carets.iter().map(|c| c.range().1).max().unwrap();
+        let Some((start, end)) = self.find_text_from(&text, last_end, |range| {
+            !carets.iter().any(|c| c.range() == range)
+        }) else {
+            return false;
+        };
+
+        let mut carets: Vec<_> = carets.into_iter().map(|c| (c, false)).collect();
+        carets.push((
+            Caret {
+                cursor: end,
+                selection_start: Some(start),
+            },
+            true,
+        ));
+        self.set_carets(carets);
+        true
     }
 
-    /// Insert a string at current cursor position. This method returns if some text was inserted or not in the textarea.
-    /// Both `\n` and `\r\n` are recognized as newlines but `\r` isn't.
+    /// Get the positions of all cursors in order of their positions. It contains only the cursor returned by
+    /// [`TextArea::cursor`] unless cursors are added by methods such as [`TextArea::add_cursor_below`].
     /// ```
     /// use tui_textarea::TextArea;
     ///
-    /// let mut textarea = TextArea::default();
+    /// let mut textarea = TextArea::from(["abc", "def"]);
+    /// assert_eq!(textarea.cursors(), [(0, 0)]);
     ///
-    /// textarea.insert_str("hello");
-    /// assert_eq!(textarea.lines(), ["hello"]);
+    /// textarea.add_cursor_below();
+    /// assert_eq!(textarea.cursors(), [(0, 0), (1, 0)]);
+    /// ```
+    pub fn cursors(&self) -> Vec<(usize, usize)> {
+        self.carets().map(|(c, _)| c.cursor).collect()
+    }
+
+    /// Remove all cursors except for the primary cursor returned by [`TextArea::cursor`].
+    /// ```
+    /// use tui_textarea::TextArea;
     ///
-    /// textarea.insert_str(", world\ngoodbye, world");
-    /// assert_eq!(textarea.lines(), ["hello, world", "goodbye, world"]);
+    /// let mut textarea = TextArea::from(["abc", "def"]);
+    /// textarea.add_cursor_below();
+    /// textarea.clear_extra_cursors();
+    /// assert_eq!(textarea.cursors(), [(1, 0)]);
     /// ```
-    pub fn insert_str<S: AsRef<str>>(&mut self, s: S) -> bool {
-        let modified = self.delete_selection(false);
-        let mut lines: Vec<_> = s
-            .as_ref()
-            .split('\n')
-            .map(|s| s.strip_suffix('\r').unwrap_or(s).to_string())
-            .collect();
-        match lines.len() {
-            0 => modified,
-            1 => self.insert_piece(lines.remove(0)),
-            _ => self.insert_chunk(lines),
-        }
+    pub fn clear_extra_cursors(&mut self) {
+        self.cursors.clear();
     }
 
-    fn insert_chunk(&mut self, chunk: Vec<String>) -> bool {
-        debug_assert!(chunk.len() > 1, "Chunk size must be > 1: {:?}", chunk);
+    // All cursors including the primary cursor in order of their positions. The boolean is `true` for the primary one
+    fn carets(&self) -> impl Iterator<Item = (Caret, bool)> + '_ {
+        let primary = Caret {
+            cursor: self.cursor,
+            selection_start: self.selection_start,
+        };
+        let i = self.cursors.partition_point(|c| c.cursor < primary.cursor);
+        self.cursors[..i]
+            .iter()
+            .map(|c| (*c, false))
+            .chain(std::iter::once((primary, true)))
+            .chain(self.cursors[i..].iter().map(|c| (*c, false)))
+    }
 
-        let (row, col) = self.cursor;
-        let line = &mut self.lines[row];
-        let i = line
-            .char_indices()
-            .nth(col)
-            .map(|(i, _)| i)
-            .unwrap_or(line.len());
-        let before = Pos::new(row, col, i);
+    // Set the cursors sorted by their positions. Cursors whose ranges overlap are merged into one.
+    fn set_carets(&mut self, mut carets: Vec<(Caret, bool)>) {
+        carets.sort_by_key(|(c, _)| c.range());
+        let mut merged: Vec<(Caret, bool)> = Vec::with_capacity(carets.len());
+        for (caret, primary) in carets {
+            if let Some((last, last_primary)) = merged.last_mut() {
+                let (start, end) = last.range();
+                let (next_start, next_end) = caret.range();
+                if next_start < end || caret.cursor == last.cursor {
+                    let end = cmp::max(end, next_end);
+                    *last = Caret {
+                        cursor: end,
+                        selection_start: (start != end).then_some(start),
+                    };
+                    *last_primary |= primary;
+                    continue;
+                }
+            }
+            merged.push((caret, primary));
+        }
 
-        let (row, col) = (
-            row + chunk.len() - 1,
-            chunk[chunk.len() - 1].chars().count(),
-        );
-        self.cursor = (row, col);
+        self.cursors.clear();
+        self.block_selection = false;
+        for (caret, primary) in merged {
+            if primary {
+                self.cursor = caret.cursor;
+                self.selection_start = caret.selection_start;
+            } else {
+                self.cursors.push(caret);
+            }
+        }
+    }
 
-        let end_offset = chunk.last().unwrap().len();
+    // Apply the edit or the cursor movement at every cursor. Edits at all cursors are undone at once, and typed
+    // characters are grouped by the undo grouping policy as with a single cursor.
+    fn at_each_cursor(&mut self, mut f: impl FnMut(&mut Self) -> bool) -> bool {
+        let carets: Vec<_> = self.carets().collect();
+        self.cursors.clear();
+
+        // Edit from the last cursor so that each edit doesn't move the cursors which are not processed yet. Processed
+        // cursors are remembered as distances from the end of the text since edits before them don't change the text
+        // after them.
+        let mut processed = Vec::with_capacity(carets.len());
+        let mut modified = false;
+        self.history.begin_cursors_group();
+        for (caret, primary) in carets.into_iter().rev() {
+            self.cursor = caret.cursor;
+            self.selection_start = caret.selection_start;
+            modified |= f(self);
+            let cursor = self.distance_from_end(self.cursor);
+            let selection_start = self.selection_start.map(|p| self.distance_from_end(p));
+            processed.push((cursor, selection_start, primary));
+        }
+        self.history.end_cursors_group();
+
+        let carets = processed
+            .into_iter()
+            .map(|(cursor, selection_start, primary)| {
+                let caret = Caret {
+                    cursor: self.position_from_end(cursor),
+                    selection_start: selection_start.map(|p| self.position_from_end(p)),
+                };
+                (caret, primary)
+            })
+            .collect();
+        self.set_carets(carets);
+        modified
+    }
 
-        let edit = EditKind::InsertChunk(chunk);
-        edit.apply(&mut self.lines, &before, &Pos::new(row, col, end_offset));
+    fn distance_from_end(&self, (row, col): (usize, usize)) -> (usize, usize) {
+        let len = self.lines[row].chars().count();
+        (self.lines.len() - 1 - row, len.saturating_sub(col))
+    }
 
-        self.push_history(edit, before, end_offset);
-        true
+    fn position_from_end(&self, (rows, cols): (usize, usize)) -> (usize, usize) {
+        let row = (self.lines.len() - 1).saturating_sub(rows);
+        let len = self.lines[row].chars().count();
+        (row, len.saturating_sub(cols))
     }
 
-    fn insert_piece(&mut self, s: String) -> bool {
-        if s.is_empty() {
-            return false;
+    fn text_in_range(&self, start: (usize, usize), end: (usize, usize)) -> String {
+        let (so, eo) = (
+            self.line_offset(start.0, start.1),
+            self.line_offset(end.0, end.1),
+        );
+        if start.0 == end.0 {
+            return self.lines[start.0][so..eo].to_string();
+        }
+        let mut text = self.lines[start.0][so..].to_string();
+        for row in start.0 + 1..end.0 {
+            text.push('\n');
+            text.push_str(&self.lines[row]);
         }
+        text.push('\n');
+        text.push_str(&self.lines[end.0][..eo]);
+        text
+    }
 
-        let (row, col) = self.cursor;
-        let line = &mut self.lines[row];
-        debug_assert!(
-            !s.contains('\n'),
-            "string given to TextArea::insert_piece must not contain newline: {:?}",
-            line,
-        );
+    // Find the text from the position wrapping around the end of the text. Matches rejected by `accept` are skipped.
+    fn find_text_from(
+        &self,
+        text: &str,
+        from: (usize, usize),
+        accept: impl Fn(((usize, usize), (usize, usize))) -> bool,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        let joined = self.lines.iter().cloned().collect::<Vec<_>>().join("\n");
+        // Byte offset in the joined text to (row, col)
+        let position = |offset: usize| {
+            let before = &joined[..offset];
+            let row = before.matches('\n').count();
+            let head = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+            (row, before[head..].chars().count())
+        };
+        let from = self
+            .lines
+            .iter()
+            .take(from.0)
+            .map(|l| l.len() + 1)
+            .sum::<usize>()
+            + self.line_offset(from.0, from.1);
+
+        let after = joined[from..].match_indices(text).map(|(i, _)| from + i);
+        let before = joined[..from].match_indices(text).map(|(i, _)| i);
+        after
+            .chain(before)
+            .map(|i| (position(i), position(i + text.len())))
+            .find(|range| accept(*range))
+    }
 
-        let i = line
-            .char_indices()
+    // Move the cursor to the position without the `u16` limit of `CursorMove::Jump`. Ongoing selection is kept.
+    #[cfg(feature = "vim")]
+    pub(crate) fn jump_to(&mut self, cursor: (usize, usize)) {
+        self.cursor = self.clamp_cursor_to_buffer(cursor);
+    }
+
+    // Select the range from `start` to `end`. The cursor is put at `end`.
+    #[cfg(feature = "vim")]
+    pub(crate) fn select_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        self.selection_start = Some(self.clamp_cursor_to_buffer(start));
+        self.cursor = self.clamp_cursor_to_buffer(end);
+    }
+
+    fn line_offset(&self, row: usize, col: usize) -> usize {
+        let line = self
+            .lines
+            .get(row)
+            .unwrap_or(&self.lines[self.lines.len() - 1]);
+        line.char_indices()
             .nth(col)
             .map(|(i, _)| i)
-            .unwrap_or(line.len());
-        line.insert_str(i, &s);
-        let end_offset = i + s.len();
+            .unwrap_or(line.len())
+    }
+
+    fn clamp_cursor_to_buffer(&mut self, cursor: (usize, usize)) -> (usize, usize) {
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+            return (0, 0);
+        }
+
+        let (row, col) = cursor;
+        let row = row.min(self.lines.len() - 1);
+        let col = col.min(self.lines[row].chars().count());
+        (row, col)
+    }
 
-        self.cursor.1 += s.chars().count();
-        self.push_history(EditKind::InsertStr(s), Pos::new(row, col, i), end_offset);
-        true
+    fn reset_measure_cache(&mut self) {
+        self.measure_cache = None;
     }
 
-    fn delete_range(&mut self, start: Pos, end: Pos, should_yank: bool) {
-        self.cursor = (start.row, start.col);
+    /// Set the style used for text selection. The default style is light blue.
+    /// ```
+    /// use tui_textarea::TextArea;
+    /// use ratatui::style::{Style, Color};
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// // Change the selection color from the default to Red
+    /// textarea.set_selection_style(Style::default().bg(Color::Red));
+    /// assert_eq!(textarea.selection_style(), Style::default().bg(Color::Red));
+    /// ```
+    pub fn set_selection_style(&mut self, style: Style) {
+        self.select_style = style;
+    }
 
-        if start.row == end.row {
-            let removed = self.lines[start.row]
-                .drain(start.offset..end.offset)
-                .as_str()
-                .to_string();
-            if should_yank {
-                self.yank = removed.clone().into();
-            }
-            self.push_history(EditKind::DeleteStr(removed), end, start.offset);
-            return;
-        }
+    /// Get the style used for text selection.
+    /// ```
+    /// use tui_textarea::TextArea;
+    /// use ratatui::style::{Style, Color};
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// assert_eq!(textarea.selection_style(), Style::default().bg(Color::LightBlue));
+    /// ```
+    pub fn selection_style(&mut self) -> Style {
+        self.select_style
+    }
 
-        let mut deleted = vec![
-            self.lines[start.row]
-                .drain(start.offset..)
-                .as_str()
-                .to_string(),
-        ];
-        deleted.extend(self.lines.drain(start.row + 1..end.row));
-        if start.row + 1 < self.lines.len() {
-            let mut last_line = self.lines.remove(start.row + 1);
-            self.lines[start.row].push_str(&last_line[end.offset..]);
-            last_line.truncate(end.offset);
-            deleted.push(last_line);
-        }
+    fn selection_positions(&self) -> Option<(Pos, Pos)> {
+        self.selection_positions_of(self.selection_start?, self.cursor)
+    }
 
-        if should_yank {
-            self.yank = YankText::Chunk(deleted.clone());
+    fn selection_positions_of(
+        &self,
+        (sr, sc): (usize, usize),
+        (er, ec): (usize, usize),
+    ) -> Option<(Pos, Pos)> {
+        let (so, eo) = (self.line_offset(sr, sc), self.line_offset(er, ec));
+        let s = Pos::new(sr, sc, so);
+        let e = Pos::new(er, ec, eo);
+        match (sr, so).cmp(&(er, eo)) {
+            Ordering::Less => Some((s, e)),
+            Ordering::Equal => None,
+            Ordering::Greater => Some((e, s)),
         }
+    }
 
-        let edit = if deleted.len() == 1 {
-            EditKind::DeleteStr(deleted.remove(0))
-        } else {
-            EditKind::DeleteChunk(deleted)
-        };
-        self.push_history(edit, end, start.offset);
+    fn take_selection_positions(&mut self) -> Option<(Pos, Pos)> {
+        let range = self.selection_positions();
+        self.cancel_selection();
+        range
     }
 
-    /// Delete a string from the current cursor position. The `chars` parameter means number of characters, not a byte
-    /// length of the string. Newlines at the end of lines are counted in the number. This method returns if some text
-    /// was deleted or not.
+    /// Copy the selection text to the yank buffer. When nothing is selected, this method does nothing.
+    /// To get the yanked text, use [`TextArea::yank_text`].
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
+    /// use tui_textarea::{TextArea, Key, Input, CursorMove};
     ///
-    /// let mut textarea = TextArea::from(["🐱🐶🐰🐮"]);
-    /// textarea.move_cursor(CursorMove::Forward);
+    /// let mut textarea = TextArea::from(["Hello World"]);
     ///
-    /// textarea.delete_str(2);
-    /// assert_eq!(textarea.lines(), ["🐱🐮"]);
+    /// // Start text selection at 'W'
+    /// textarea.move_cursor(CursorMove::WordForward);
+    /// textarea.start_selection();
     ///
-    /// let mut textarea = TextArea::from(["🐱", "🐶", "🐰", "🐮"]);
-    /// textarea.move_cursor(CursorMove::Down);
+    /// // Select the word "World" and copy the selected text
+    /// textarea.move_cursor(CursorMove::End);
+    /// textarea.copy();
     ///
-    /// textarea.delete_str(4); // Deletes 🐶, \n, 🐰, \n
-    /// assert_eq!(textarea.lines(), ["🐱", "🐮"]);
+    /// assert_eq!(textarea.yank_text(), "World");
+    /// assert_eq!(textarea.lines(), ["Hello World"]); // Text does not change
     /// ```
-    pub fn delete_str(&mut self, chars: usize) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
-        if chars == 0 {
-            return false;
-        }
-
-        let (start_row, start_col) = self.cursor;
-
-        let mut remaining = chars;
-        let mut find_end = move |line: &str| {
-            let mut col = 0usize;
-            for (i, _) in line.char_indices() {
-                if remaining == 0 {
-                    return Some((i, col));
-                }
-                col += 1;
-                remaining -= 1;
+    pub fn copy(&mut self) {
+        if let Some(carets) = self.block_carets() {
+            self.cancel_selection();
+            let block = carets
+                .iter()
+                .map(|c| {
+                    let (start, end) = c.range();
+                    self.text_in_range(start, end)
+                })
+                .collect::<Vec<_>>();
+            if block.iter().any(|s| !s.is_empty()) {
+                self.set_yank(YankText::Block(block));
+                self.write_yank_to_clipboard();
             }
-            if remaining == 0 {
-                Some((line.len(), col))
+            return;
+        }
+        if let Some((start, end)) = self.take_selection_positions() {
+            if start.row == end.row {
+                let yank = self.lines[start.row][start.offset..end.offset].to_string();
+                self.set_yank(yank.into());
             } else {
-                remaining -= 1;
-                None
+                let mut chunk = vec![self.lines[start.row][start.offset..].to_string()];
+                chunk.extend((start.row + 1..end.row).map(|row| self.lines[row].clone()));
+                chunk.push(self.lines[end.row][..end.offset].to_string());
+                self.set_yank(YankText::Chunk(chunk));
             }
-        };
+            self.write_yank_to_clipboard();
+        }
+    }
 
-        let line = &self.lines[start_row];
-        let start_offset = {
-            line.char_indices()
-                .nth(start_col)
-                .map(|(i, _)| i)
-                .unwrap_or(line.len())
-        };
+    /// Cut the selected text and place it in the yank buffer. This method returns whether the text was modified.
+    /// The cursor will move to the start position of the text selection.
+    /// To get the yanked text, use [`TextArea::yank_text`].
+    /// ```
+    /// use tui_textarea::{TextArea, Key, Input, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["Hello World"]);
+    ///
+    /// // Start text selection at 'W'
+    /// textarea.move_cursor(CursorMove::WordForward);
+    /// textarea.start_selection();
+    ///
+    /// // Select the word "World" and copy the selected text
+    /// textarea.move_cursor(CursorMove::End);
+    /// textarea.cut();
+    ///
+    /// assert_eq!(textarea.yank_text(), "World");
+    /// assert_eq!(textarea.lines(), ["Hello "]);
+    /// ```
+    pub fn cut(&mut self) -> bool {
+        let modified = self.delete_selection(true);
+        if modified {
+            self.write_yank_to_clipboard();
+        }
+        modified
+    }
 
-        // First line
-        if let Some((offset_delta, col_delta)) = find_end(&line[start_offset..]) {
-            let end_offset = start_offset + offset_delta;
-            let end_col = start_col + col_delta;
-            let removed = self.lines[start_row]
-                .drain(start_offset..end_offset)
-                .as_str()
-                .to_string();
-            self.yank = removed.clone().into();
-            self.push_history(
-                EditKind::DeleteStr(removed),
-                Pos::new(start_row, end_col, end_offset),
-                start_offset,
-            );
+    fn delete_selection(&mut self, should_yank: bool) -> bool {
+        if let Some(carets) = self.block_carets() {
+            return self.delete_block(carets, should_yank);
+        }
+        if let Some((s, e)) = self.take_selection_positions() {
+            self.delete_range(s, e, should_yank);
             return true;
         }
+        false
+    }
 
-        let mut r = start_row + 1;
-        let mut offset = 0;
-        let mut col = 0;
-
-        while r < self.lines.len() {
-            let line = &self.lines[r];
-            if let Some((o, c)) = find_end(line) {
-                offset = o;
-                col = c;
-                break;
+    // Delete the text selected at each row of the block as one step. The cursor moves to the top-left of the block
+    fn delete_block(&mut self, carets: Vec<Caret>, should_yank: bool) -> bool {
+        self.cancel_selection();
+        let top_left = carets[0].range().0;
+        let mut block = Vec::with_capacity(carets.len());
+        self.begin_transaction();
+        for caret in carets {
+            let (start, end) = caret.range();
+            block.push(self.text_in_range(start, end));
+            if let Some((s, e)) = self.selection_positions_of(start, end) {
+                self.delete_range(s, e, false);
             }
-            r += 1;
         }
+        self.end_transaction();
+        self.cursor = top_left;
 
-        if r == self.lines.len() {
-            // Clamp exhausted deletions to EOF on the last line so history keeps a valid cursor.
-            r = self.lines.len() - 1;
-            let line = &self.lines[r];
-            offset = line.len();
-            col = line.chars().count();
+        let modified = block.iter().any(|s| !s.is_empty());
+        if should_yank && modified {
+            self.set_yank(YankText::Block(block));
         }
+        modified
+    }
 
-        let start = Pos::new(start_row, start_col, start_offset);
-        let end = Pos::new(r, col, offset);
-        self.delete_range(start, end, true);
-        true
+    /// Move the cursor to the position specified by the [`CursorMove`] parameter. For each kind of cursor moves, see
+    /// the document of [`CursorMove`].
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["abc", "def"]);
+    ///
+    /// textarea.move_cursor(CursorMove::Forward);
+    /// assert_eq!(textarea.cursor(), (0, 1));
+    /// textarea.move_cursor(CursorMove::Down);
+    /// assert_eq!(textarea.cursor(), (1, 1));
+    /// ```
+    pub fn move_cursor(&mut self, m: CursorMove) {
+        // Moving the cursor closes the undo group of typed characters even if the cursor comes back to the same place
+        self.history.close_coalescing();
+        self.move_cursor_with_shift(m, self.selection_start.is_some());
     }
 
-    fn delete_piece(&mut self, col: usize, chars: usize) -> bool {
-        if chars == 0 {
-            return false;
+    fn move_cursor_with_shift(&mut self, m: CursorMove, shift: bool) {
+        if !self.cursors.is_empty() {
+            self.at_each_cursor(|t| {
+                t.move_cursor_with_shift(m, shift);
+                false
+            });
+            return;
         }
 
-        #[inline]
-        fn bytes_and_chars(claimed: usize, s: &str) -> (usize, usize) {
-            // Note: `claimed` may be larger than characters in `s` (e.g. usize::MAX)
-            let mut last_col = 0;
-            for (col, (bytes, _)) in s.char_indices().enumerate() {
-                if col == claimed {
-                    return (bytes, claimed);
+        let (_, _, width, _) = self.viewport.rect();
+        let wrapped_storage = if self.wrap_mode == WrapMode::None || width > 0 {
+            self.visual_rows(width)
+        } else {
+            None
+        };
+        let wrapped_ref = wrapped_storage.as_deref();
+
+        let next = if m == CursorMove::InViewport && wrapped_ref.is_some() {
+            self.cursor_in_wrapped_viewport()
+        } else {
+            m.next_cursor(self.cursor, &self.lines, &self.viewport, wrapped_ref)
+        };
+
+        if let Some(cursor) = next {
+            if shift {
+                if self.selection_start.is_none() {
+                    self.start_selection();
                 }
-                last_col = col;
+            } else {
+                self.cancel_selection();
             }
-            (s.len(), last_col + 1)
+            self.cursor = cursor;
         }
+    }
 
-        let (row, _) = self.cursor;
-        let line = &mut self.lines[row];
-        if let Some((i, _)) = line.char_indices().nth(col) {
-            let (bytes, chars) = bytes_and_chars(chars, &line[i..]);
-            let removed = line.drain(i..i + bytes).as_str().to_string();
-
-            self.cursor = (row, col);
-            self.push_history(
-                EditKind::DeleteStr(removed.clone()),
-                Pos::new(row, col + chars, i + bytes),
-                i,
-            );
-            self.yank = removed.into();
-            true
-        } else {
-            false
+    // Rows on the screen when lines are soft-wrapped or folded. `None` is returned when each line is rendered in one
+    // row without any folded lines.
+    pub(crate) fn visual_rows(&self, width: u16) -> Option<Vec<WrappedLine>> {
+        if self.wrap_mode == WrapMode::None && self.folds.is_empty() {
+            return None;
         }
+        let wrap_width = effective_wrap_width(width, self.gutter_width().total());
+        let rows = wrapped_rows(
+            &self.lines,
+            self.wrap_mode,
+            wrap_width,
+            self.tab_len,
+            &self.virtual_texts,
+        );
+        Some(self.folds.apply(rows, self.cursor.0))
     }
 
-    /// Insert a tab at current cursor position. Note that this method does nothing when the tab length is 0. This
-    /// method returns if a tab string was inserted or not in the textarea.
-    /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["hi"]);
-    ///
-    /// textarea.move_cursor(CursorMove::End); // Move to the end of line
-    ///
-    /// textarea.insert_tab();
-    /// assert_eq!(textarea.lines(), ["hi  "]);
-    /// textarea.insert_tab();
-    /// assert_eq!(textarea.lines(), ["hi      "]);
-    /// ```
-    pub fn insert_tab(&mut self) -> bool {
-        let modified = self.delete_selection(false);
-        if self.tab_len == 0 {
-            return modified;
+    fn cursor_in_wrapped_viewport(&self) -> Option<(usize, usize)> {
+        let (row_top, _, width, height) = self.viewport.rect();
+        if height == 0 {
+            return Some(self.cursor);
         }
 
-        if self.hard_tab_indent {
-            self.insert_char('\t');
-            return true;
+        let Some(rows) = self.visual_rows(width) else {
+            return Some(self.cursor);
+        };
+        if rows.is_empty() {
+            return Some(self.cursor);
         }
 
-        let (row, col) = self.cursor;
-        let width: usize = self.lines[row]
-            .chars()
-            .take(col)
-            .map(|c| c.width().unwrap_or(0))
-            .sum();
-        let len = self.tab_len - (width % self.tab_len as usize) as u8;
-        self.insert_piece(spaces(len).to_string())
+        let cursor_visual = cursor_visual_row(&rows, self.cursor);
+        let row_top = row_top as usize;
+        let row_bottom = row_top + height.saturating_sub(1) as usize;
+        let target_visual = cursor_visual.clamp(row_top, row_bottom);
+
+        Some(cursor_at_visual_row(
+            &self.lines,
+            &rows,
+            self.cursor,
+            target_visual,
+        ))
     }
 
-    /// Insert a newline at current cursor position.
+    /// Undo the last modification. This method returns if the undo modified text contents or not in the textarea.
     /// ```
     /// use tui_textarea::{TextArea, CursorMove};
     ///
-    /// let mut textarea = TextArea::from(["hi"]);
+    /// let mut textarea = TextArea::from(["abc def"]);
     ///
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// textarea.insert_newline();
-    /// assert_eq!(textarea.lines(), ["h", "i"]);
+    /// textarea.delete_next_word();
+    /// assert_eq!(textarea.lines(), [" def"]);
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["abc def"]);
     /// ```
-    pub fn insert_newline(&mut self) {
-        self.delete_selection(false);
-
-        let (row, col) = self.cursor;
-        let line = &mut self.lines[row];
-        let offset = line
-            .char_indices()
-            .nth(col)
-            .map(|(i, _)| i)
-            .unwrap_or(line.len());
-        let next_line = line[offset..].to_string();
-        line.truncate(offset);
-
-        self.lines.insert(row + 1, next_line);
-        self.cursor = (row + 1, 0);
-        self.push_history(EditKind::InsertNewline, Pos::new(row, col, offset), 0);
+    pub fn undo(&mut self) -> bool {
+        let moved = self.history.undo(&mut self.lines);
+        self.on_history_moved(moved)
     }
 
-    /// Delete a newline from **head** of current cursor line. This method returns if a newline was deleted or not in
-    /// the textarea. When some text is selected, it is deleted instead.
+    /// Redo the last undo change. This method returns if the redo modified text contents or not in the textarea.
     /// ```
     /// use tui_textarea::{TextArea, CursorMove};
     ///
-    /// let mut textarea = TextArea::from(["hello", "world"]);
+    /// let mut textarea = TextArea::from(["abc def"]);
     ///
-    /// textarea.move_cursor(CursorMove::Down);
-    /// textarea.delete_newline();
-    /// assert_eq!(textarea.lines(), ["helloworld"]);
+    /// textarea.delete_next_word();
+    /// assert_eq!(textarea.lines(), [" def"]);
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["abc def"]);
+    /// textarea.redo();
+    /// assert_eq!(textarea.lines(), [" def"]);
     /// ```
-    pub fn delete_newline(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
+    pub fn redo(&mut self) -> bool {
+        let moved = self.history.redo(&mut self.lines);
+        self.on_history_moved(moved)
+    }
 
-        let (row, _) = self.cursor;
-        if row == 0 {
+    // Update the state after undo, redo, or moving in the undo tree
+    fn on_history_moved(&mut self, moved: Option<((usize, usize), Vec<Edit>)>) -> bool {
+        let Some((cursor, edits)) = moved else {
             return false;
+        };
+        for edit in &edits {
+            self.on_edit_applied(edit);
         }
+        self.cursors.clear();
+        self.cancel_selection();
+        self.cursor = self.clamp_cursor_to_buffer(cursor);
+        self.reset_measure_cache();
+        true
+    }
 
-        let line = self.lines.remove(row);
-        let prev_line = &mut self.lines[row - 1];
-        let prev_line_end = prev_line.len();
+    // Widths of the gutter columns at the left of the text
+    pub(crate) fn gutter_width(&self) -> GutterWidth {
+        let line_number = self.line_number_style.map(|_| match &self.line_label {
+            Some(label) => label.width,
+            None => num_digits(self.lines.len()),
+        });
+        GutterWidth {
+            signs: self.signs.width(),
+            line_number,
+        }
+    }
 
-        self.cursor = (row - 1, prev_line.chars().count());
-        prev_line.push_str(&line);
-        self.push_history(EditKind::DeleteNewline, Pos::new(row, 0, 0), prev_line_end);
-        true
+    // Underline ranges of diagnostics and show the message after the end of the line
+    fn highlight_diagnostics(
+        &self,
+        hl: &mut LineHighlighter<'_>,
+        line: &str,
+        wrapped: &WrappedLine,
+    ) {
+        let row = wrapped.row;
+        let mut diagnostics: Vec<_> = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.start.0 <= row && row <= d.end.0)
+            .collect();
+        if diagnostics.is_empty() {
+            return;
+        }
+        // Diagnostics with higher severity are put on top
+        diagnostics.sort_by_key(|d| d.severity);
+
+        let offset = |col| line.char_indices().nth(col).map_or(line.len(), |(i, _)| i);
+        let mut ranges = vec![];
+        for d in &diagnostics {
+            let start = if d.start.0 == row {
+                offset(d.start.1)
+            } else {
+                0
+            };
+            let end = if d.end.0 != row {
+                line.len()
+            } else if d.start == d.end {
+                // Highlight the character at the position of the empty range
+                line[start..]
+                    .chars()
+                    .next()
+                    .map_or(start, |c| start + c.len_utf8())
+            } else {
+                offset(d.end.1)
+            };
+            let start = cmp::max(start, wrapped.start_byte);
+            let end = cmp::min(end, wrapped.end_byte);
+            if start < end {
+                let style = self.diagnostic_style(d.severity);
+                ranges.push((start - wrapped.start_byte, end - wrapped.start_byte, style));
+            }
+        }
+        hl.diagnostics(&ranges);
+
+        if self.diagnostic_messages && wrapped.last_in_row {
+            let shown = diagnostics
+                .iter()
+                .rev()
+                .find(|d| d.start.0 == row && !d.message.is_empty());
+            if let Some(d) = shown {
+                let style = message_style(self.diagnostic_style(d.severity));
+                hl.diagnostic_message(&d.message, style);
+            }
+        }
     }
 
-    /// Delete one character before cursor. When the cursor is at head of line, the newline before the cursor will be
-    /// removed. This method returns if some text was deleted or not in the textarea. When some text is selected, it is
-    /// deleted instead.
-    /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["abc"]);
-    ///
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// textarea.delete_char();
-    /// assert_eq!(textarea.lines(), ["bc"]);
-    /// ```
-    pub fn delete_char(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
+    pub(crate) fn line_spans<'b>(
+        &'b self,
+        line: &'b str,
+        row: usize,
+        gutter: GutterWidth,
+    ) -> Line<'b> {
+        let wrapped = WrappedLine {
+            row,
+            start_byte: 0,
+            end_byte: line.len(),
+            start_col: 0,
+            end_col: line.chars().count(),
+            first_in_row: true,
+            last_in_row: true,
+            folded: 0,
+        };
+        self.line_spans_segment(line, &wrapped, gutter)
+    }
+
+    pub(crate) fn line_spans_segment<'b>(
+        &'b self,
+        line: &'b str,
+        wrapped: &WrappedLine,
+        gutter: GutterWidth,
+    ) -> Line<'b> {
+        let fragment = &line[wrapped.start_byte..wrapped.end_byte];
+        let mut hl = LineHighlighter::new(
+            fragment,
+            self.cursor_style,
+            self.tab_len,
+            self.mask,
+            self.select_style,
+        );
+
+        if gutter.signs > 0 {
+            // Margin between signs and text when line numbers are not shown
+            let width = gutter.signs + u8::from(gutter.line_number.is_none());
+            match self.signs.get(wrapped.row) {
+                Some(sign) if wrapped.first_in_row => hl.sign(&sign.text, width, sign.style),
+                _ => hl.sign("", width, Style::default()),
+            }
         }
 
-        let (row, col) = self.cursor;
-        if col == 0 {
-            return self.delete_newline();
+        if let (Some(style), Some(width)) = (self.line_number_style, gutter.line_number) {
+            let cursor_row = self.cursor.0;
+            if !wrapped.first_in_row {
+                hl.line_number_placeholder(width, style);
+            } else if let Some(label) = &self.line_label {
+                hl.line_label(&label.label(wrapped.row, cursor_row), width, style);
+            } else {
+                let num = self.line_numbers.number(wrapped.row, cursor_row);
+                hl.line_number(num, width, style);
+            }
         }
 
-        let line = &mut self.lines[row];
-        if let Some((offset, c)) = line.char_indices().nth(col - 1) {
-            line.remove(offset);
-            self.cursor.1 -= 1;
-            self.push_history(
-                EditKind::DeleteChar(c),
-                Pos::new(row, col, offset + c.len_utf8()),
-                offset,
-            );
-            true
-        } else {
-            false
+        if wrapped.row == self.cursor.0 {
+            hl.set_line_style(self.cursor_line_style);
+            let cursor_col = self.cursor.1;
+            let in_segment = if wrapped.last_in_row {
+                wrapped.start_col <= cursor_col && cursor_col <= wrapped.end_col
+            } else {
+                wrapped.start_col <= cursor_col && cursor_col < wrapped.end_col
+            };
+            if in_segment {
+                hl.cursor_line(cursor_col - wrapped.start_col, self.cursor_line_style);
+            }
+        }
+
+        if !self.diagnostics.is_empty() {
+            self.highlight_diagnostics(&mut hl, line, wrapped);
+        }
+
+        for text in self.virtual_texts.iter().filter(|t| t.pos.0 == wrapped.row) {
+            // Text at the end of a wrapped segment is rendered at the start of the next segment
+            let col = cmp::min(text.pos.1, line.chars().count());
+            let in_segment = if wrapped.last_in_row {
+                wrapped.start_col <= col && col <= wrapped.end_col
+            } else {
+                wrapped.start_col <= col && col < wrapped.end_col
+            };
+            if in_segment {
+                let offset = fragment
+                    .char_indices()
+                    .nth(col - wrapped.start_col)
+                    .map_or(fragment.len(), |(i, _)| i);
+                hl.virtual_text(offset, &text.text, text.style);
+            }
+        }
+
+        if let (Some(syntax), None) = (&self.syntax, self.mask) {
+            let spans = syntax.spans(wrapped.row, &self.lines);
+            hl.syntax(spans.into_iter().filter_map(|(range, style)| {
+                let start = cmp::max(range.start, wrapped.start_byte);
+                let end = cmp::min(range.end, wrapped.end_byte);
+                (start < end).then(|| (start - wrapped.start_byte, end - wrapped.start_byte, style))
+            }));
+        }
+
+        #[cfg(feature = "search")]
+        if let Some((matches, continues)) = self.search.row_matches(&self.lines, wrapped.row) {
+            if continues && wrapped.last_in_row {
+                hl.search_at_end(self.search.style);
+            }
+            let clipped = matches
+                .into_iter()
+                .filter_map(|(start, end)| {
+                    let start = cmp::max(start, wrapped.start_byte);
+                    let end = cmp::min(end, wrapped.end_byte);
+                    (start < end).then_some((start - wrapped.start_byte, end - wrapped.start_byte))
+                })
+                .collect::<Vec<_>>();
+            if !clipped.is_empty() {
+                hl.search(clipped.into_iter(), self.search.style);
+            }
+        }
+
+        for caret in &self.cursors {
+            let (row, col) = caret.cursor;
+            if row == wrapped.row
+                && wrapped.start_col <= col
+                && (col < wrapped.end_col || wrapped.last_in_row && col == wrapped.end_col)
+            {
+                hl.cursor(col - wrapped.start_col);
+            }
+        }
+
+        let carets = match self.block_bounds() {
+            Some(((top, bottom), cols)) if top <= wrapped.row && wrapped.row <= bottom => {
+                vec![self.block_caret(wrapped.row, cols)]
+            }
+            Some(_) => vec![],
+            None => self.carets().map(|(c, _)| c).collect(),
+        };
+        let selections = carets
+            .into_iter()
+            .filter_map(|caret| self.selection_positions_of(caret.selection_start?, caret.cursor));
+        for (start, end) in selections {
+            if wrapped.first_in_row && wrapped.last_in_row {
+                hl.selection(wrapped.row, start.row, start.offset, end.row, end.offset);
+            } else if start.row <= wrapped.row && wrapped.row <= end.row {
+                let start_off = if start.row == wrapped.row {
+                    start.offset
+                } else {
+                    0
+                };
+                let end_off = if end.row == wrapped.row {
+                    end.offset
+                } else {
+                    line.len()
+                };
+                let clipped_start = cmp::max(start_off, wrapped.start_byte);
+                let clipped_end = cmp::min(end_off, wrapped.end_byte);
+                let select_at_end =
+                    wrapped.last_in_row && clipped_end == wrapped.end_byte && wrapped.row < end.row;
+                hl.selection_segment(
+                    clipped_start.saturating_sub(wrapped.start_byte),
+                    clipped_end.saturating_sub(wrapped.start_byte),
+                    select_at_end,
+                );
+            }
         }
-    }
 
-    /// Delete one character next to cursor. When the cursor is at end of line, the newline next to the cursor will be
-    /// removed. This method returns if a character was deleted or not in the textarea.
-    /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["abc"]);
-    ///
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// textarea.delete_next_char();
-    /// assert_eq!(textarea.lines(), ["ac"]);
-    /// ```
-    pub fn delete_next_char(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
+        if wrapped.folded > 0 {
+            let marker = format!(" ··· {} lines", wrapped.folded);
+            hl.fold_marker(marker, self.fold_style);
         }
 
-        let before = self.cursor;
-        self.move_cursor_with_shift(CursorMove::Forward, false);
-        if before == self.cursor {
-            return false; // Cursor didn't move, meant no character at next of cursor.
+        for CustomHighlight {
+            range: ((start_row, start_offset), (end_row, end_offset)),
+            style,
+            priority,
+        } in &self.custom_highlights
+        {
+            if *start_row <= wrapped.row && wrapped.row <= *end_row {
+                let start_off = if *start_row == wrapped.row {
+                    *start_offset
+                } else {
+                    0
+                };
+                let end_off = if *end_row == wrapped.row {
+                    *end_offset
+                } else {
+                    line.len()
+                };
+                let clipped_start = cmp::max(start_off, wrapped.start_byte);
+                let clipped_end = cmp::min(end_off, wrapped.end_byte);
+                if clipped_start < clipped_end {
+                    hl.custom(
+                        wrapped.row,
+                        (
+                            (wrapped.row, clipped_start - wrapped.start_byte),
+                            (wrapped.row, clipped_end - wrapped.start_byte),
+                        ),
+                        *style,
+                        *priority,
+                    );
+                }
+            }
         }
 
-        self.delete_char()
+        hl.into_spans()
     }
 
-    /// Delete string from cursor to end of the line. When the cursor is at end of line, the newline next to the cursor
-    /// is removed. This method returns if some text was deleted or not in the textarea.
-    /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["abcde"]);
+    /// Build a ratatui (or tui-rs) widget to render the current state of the textarea. The widget instance returned
+    /// from this method can be rendered with [`ratatui_core::terminal::Frame::render_widget`].
     ///
-    /// // Move to 'c'
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// textarea.move_cursor(CursorMove::Forward);
+    /// This method was deprecated at v0.5.3 and is no longer necessary. Instead you can directly pass `&TextArea`
+    /// reference to the `Frame::render_widget` method call.
+    /// ```no_run
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::Terminal;
+    /// # use ratatui::widgets::Widget as _;
+    /// # use ratatui::backend::CrosstermBackend;
+    /// # use tui_textarea::TextArea;
+    /// #
+    /// # let backend = CrosstermBackend::new(std::io::stdout());
+    /// # let mut term = Terminal::new(backend).unwrap();
+    /// # let textarea = TextArea::default();
+    /// #
+    /// # #[allow(deprecated)]
+    /// # term.draw(|f| {
+    /// #   let rect = Rect {
+    /// #       x: 0,
+    /// #       y: 0,
+    /// #       width: 24,
+    /// #       height: 8,
+    /// #   };
+    /// // v0.5.2 or earlier
+    /// f.render_widget(textarea.widget(), rect);
     ///
-    /// textarea.delete_line_by_end();
-    /// assert_eq!(textarea.lines(), ["ab"]);
+    /// // v0.5.3 or later
+    /// f.render_widget(&textarea, rect);
+    /// # }).unwrap();
     /// ```
-    pub fn delete_line_by_end(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
-        if self.delete_piece(self.cursor.1, usize::MAX) {
-            return true;
-        }
-        self.delete_next_char() // At the end of the line. Try to delete next line
+    #[deprecated(
+        since = "0.5.3",
+        note = "calling this method is no longer necessary on rendering a textarea. pass &TextArea reference to `Frame::render_widget` method call directly"
+    )]
+    pub fn widget(&'a self) -> impl Widget + 'a {
+        self
     }
 
-    /// Delete string from cursor to head of the line. When the cursor is at head of line, the newline before the cursor
-    /// will be removed. This method returns if some text was deleted or not in the textarea.
+    /// Set the style of textarea. By default, textarea is not styled.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["abcde"]);
-    ///
-    /// // Move to 'c'
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// textarea.move_cursor(CursorMove::Forward);
+    /// use ratatui::style::{Style, Color};
+    /// use tui_textarea::TextArea;
     ///
-    /// textarea.delete_line_by_head();
-    /// assert_eq!(textarea.lines(), ["cde"]);
+    /// let mut textarea = TextArea::default();
+    /// let style = Style::default().fg(Color::Red);
+    /// textarea.set_style(style);
+    /// assert_eq!(textarea.style(), style);
     /// ```
-    pub fn delete_line_by_head(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
-        if self.delete_piece(0, self.cursor.1) {
-            return true;
-        }
-        self.delete_newline()
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
     }
 
-    /// Delete a word before cursor. Word boundary appears at spaces, punctuations, and others. For example `fn foo(a)`
-    /// consists of words `fn`, `foo`, `(`, `a`, `)`. When the cursor is at head of line, the newline before the cursor
-    /// will be removed.
-    ///
-    /// This method returns if some text was deleted or not in the textarea.
-    ///
-    /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
-    ///
-    /// textarea.move_cursor(CursorMove::End);
-    ///
-    /// textarea.delete_word();
-    /// assert_eq!(textarea.lines(), ["aaa bbb "]);
-    /// textarea.delete_word();
-    /// assert_eq!(textarea.lines(), ["aaa "]);
-    /// ```
-    pub fn delete_word(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
-        let (r, c) = self.cursor;
-        if let Some(col) = find_word_start_backward(&self.lines[r], c) {
-            self.delete_piece(col, c - col)
-        } else if c > 0 {
-            self.delete_piece(0, c)
-        } else {
-            self.delete_newline()
-        }
+    /// Get the current style of textarea.
+    pub fn style(&self) -> Style {
+        self.style
     }
 
-    /// Delete a word next to cursor. Word boundary appears at spaces, punctuations, and others. For example `fn foo(a)`
-    /// consists of words `fn`, `foo`, `(`, `a`, `)`. When the cursor is at end of line, the newline next to the cursor
-    /// will be removed.
-    ///
-    /// This method returns if some text was deleted or not in the textarea.
-    ///
+    /// Set the block of textarea. By default, no block is set.
     /// ```
     /// use tui_textarea::TextArea;
+    /// use ratatui::widgets::{Block, Borders};
     ///
-    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
-    ///
-    /// textarea.delete_next_word();
-    /// assert_eq!(textarea.lines(), [" bbb ccc"]);
-    /// textarea.delete_next_word();
-    /// assert_eq!(textarea.lines(), [" ccc"]);
+    /// let mut textarea = TextArea::default();
+    /// let block = Block::default().borders(Borders::ALL).title("Block Title");
+    /// textarea.set_block(block);
+    /// assert!(textarea.block().is_some());
     /// ```
-    pub fn delete_next_word(&mut self) -> bool {
-        if self.delete_selection(false) {
-            return true;
-        }
-        let (r, c) = self.cursor;
-        let line = &self.lines[r];
-        if let Some(col) = find_word_exclusive_end_forward(line, c) {
-            self.delete_piece(c, col - c)
-        } else {
-            let end_col = line.chars().count();
-            if c < end_col {
-                self.delete_piece(c, end_col - c)
-            } else if r + 1 < self.lines.len() {
-                self.cursor = (r + 1, 0);
-                self.delete_newline()
-            } else {
-                false
-            }
-        }
+    pub fn set_block(&mut self, block: Block<'a>) {
+        self.block = Some(block);
+        self.reset_measure_cache();
     }
 
-    /// Clears the whole content of the text area.
-    /// This will result in an empty text area.
-    /// The position of the cursor does matter for clearing the entire text.
-    ///
-    /// This method returns if some text was deleted or not in the textarea.
-    ///
+    /// Remove the block of textarea which was set by [`TextArea::set_block`].
     /// ```
     /// use tui_textarea::TextArea;
+    /// use ratatui::widgets::{Block, Borders};
     ///
-    /// let mut textarea = TextArea::from(["aaa\nbbb ccc"]);
-    ///
-    /// textarea.clear();
-    /// assert!(textarea.is_empty());
+    /// let mut textarea = TextArea::default();
+    /// let block = Block::default().borders(Borders::ALL).title("Block Title");
+    /// textarea.set_block(block);
+    /// textarea.remove_block();
+    /// assert!(textarea.block().is_none());
     /// ```
-    pub fn clear(&mut self) -> bool {
-        if self.is_empty() {
-            return false;
-        }
-
-        let all_lines = self.lines();
-        let summed_up_chars_new_lines: usize = all_lines
-            .iter()
-            .map(|line| line.chars().map(|_| 1usize).sum::<usize>())
-            .sum();
-        let all_chars = all_lines.len() + summed_up_chars_new_lines;
-        self.move_cursor(CursorMove::Jump(0, 0));
-        self.delete_str(all_chars);
+    pub fn remove_block(&mut self) {
+        self.block = None;
+        self.reset_measure_cache();
+    }
 
-        true
+    /// Get the block of textarea if exists.
+    pub fn block<'s>(&'s self) -> Option<&'s Block<'a>> {
+        self.block.as_ref()
     }
 
-    /// Paste a string previously deleted by [`TextArea::delete_line_by_head`], [`TextArea::delete_line_by_end`],
-    /// [`TextArea::delete_word`], [`TextArea::delete_next_word`]. This method returns if some text was inserted or not
-    /// in the textarea.
+    /// Add a validator called before inserting text by [`TextArea::insert_char`], [`TextArea::insert_str`],
+    /// [`TextArea::insert_tab`], [`TextArea::insert_newline`], and [`TextArea::paste`]. The validator can accept,
+    /// transform, or reject the text. When multiple validators are added, they are called in order and each validator
+    /// receives the text transformed by the previous ones. See [`Validator`] for the details.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
+    /// use tui_textarea::{DigitsOnly, MaxLength, TextArea};
     ///
-    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.add_validator(DigitsOnly);
+    /// textarea.add_validator(MaxLength(4));
     ///
-    /// textarea.delete_next_word();
-    /// textarea.move_cursor(CursorMove::End);
-    /// textarea.paste();
-    /// assert_eq!(textarea.lines(), [" bbb cccaaa"]);
+    /// textarea.insert_str("12-34-56");
+    /// assert_eq!(textarea.lines(), ["1234"]);
+    ///
+    /// // Rejected insertion sets the validation error
+    /// textarea.insert_char('7');
+    /// assert_eq!(textarea.lines(), ["1234"]);
+    /// assert!(textarea.validation_error().is_some());
     /// ```
-    pub fn paste(&mut self) -> bool {
-        self.delete_selection(false);
-        match self.yank.clone() {
-            YankText::Piece(s) => self.insert_piece(s),
-            YankText::Chunk(c) => self.insert_chunk(c),
-        }
+    pub fn add_validator(&mut self, validator: impl Validator + Send + Sync + 'static) {
+        self.validators.push(validator);
     }
 
-    /// Start text selection at the cursor position. If text selection is already ongoing, the start position is reset.
+    /// Remove all validators added by [`TextArea::add_validator`]. The current validation error is also cleared.
+    pub fn clear_validators(&mut self) {
+        self.validators.clear();
+        self.validation_error = None;
+    }
+
+    /// Get the error message of the last rejected insertion. The error is cleared when the next insertion is accepted.
+    /// While the error is set, the border of the block is rendered with the style set by
+    /// [`TextArea::set_error_style`].
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
+    /// use tui_textarea::{DigitsOnly, TextArea};
     ///
-    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.add_validator(DigitsOnly);
     ///
-    /// textarea.start_selection();
-    /// textarea.move_cursor(CursorMove::WordForward);
-    /// textarea.copy();
-    /// assert_eq!(textarea.yank_text(), "aaa ");
+    /// textarea.insert_char('a');
+    /// assert_eq!(textarea.validation_error(), Some("Only digits are allowed"));
+    /// textarea.insert_char('1');
+    /// assert_eq!(textarea.validation_error(), None);
     /// ```
-    pub fn start_selection(&mut self) {
-        self.selection_start = Some(self.cursor);
+    pub fn validation_error(&self) -> Option<&str> {
+        self.validation_error.as_deref()
     }
 
-    /// Stop the current text selection. This method does nothing if text selection is not ongoing.
+    /// Set the validation error manually. This is useful to show an error found by validating the whole text, for
+    /// example on submitting a form. The error is cleared when the next insertion is accepted.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["aaa bbb ccc"]);
-    ///
-    /// textarea.start_selection();
-    /// textarea.move_cursor(CursorMove::WordForward);
-    ///
-    /// // Cancel the ongoing text selection
-    /// textarea.cancel_selection();
+    /// use tui_textarea::TextArea;
     ///
-    /// // As the result, this `copy` call does nothing
-    /// textarea.copy();
-    /// assert_eq!(textarea.yank_text(), "");
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_validation_error("Required");
+    /// assert_eq!(textarea.validation_error(), Some("Required"));
+    /// textarea.clear_validation_error();
+    /// assert_eq!(textarea.validation_error(), None);
     /// ```
-    pub fn cancel_selection(&mut self) {
-        self.selection_start = None;
+    pub fn set_validation_error(&mut self, error: impl Into<String>) {
+        self.validation_error = Some(error.into());
     }
 
-    pub fn custom_highlight(
-        &mut self,
-        range: ((usize, usize), (usize, usize)),
-        style: Style,
-        priority: u8,
-    ) {
-        self.custom_highlights.push(CustomHighlight {
-            range,
-            style,
-            priority,
-        });
+    /// Clear the validation error.
+    pub fn clear_validation_error(&mut self) {
+        self.validation_error = None;
     }
 
-    pub fn clear_custom_highlight(&mut self) {
-        self.custom_highlights.clear();
+    /// Set the style of the block's border while a validation error is set. The default style is red foreground.
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// let style = Style::default().fg(Color::Magenta);
+    /// textarea.set_error_style(style);
+    /// assert_eq!(textarea.error_style(), style);
+    /// ```
+    pub fn set_error_style(&mut self, style: Style) {
+        self.error_style = style;
     }
 
-    /// Select the entire text. Cursor moves to the end of the text buffer. When text selection is already ongoing,
-    /// it is canceled.
+    /// Get the style of the block's border while a validation error is set.
+    pub fn error_style(&self) -> Style {
+        self.error_style
+    }
+
+    /// Set the length of tab character. Setting 0 disables tab inputs.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["aaa", "bbb", "ccc"]);
+    /// use tui_textarea::{TextArea, Input, Key};
     ///
-    /// textarea.select_all();
+    /// let mut textarea = TextArea::default();
+    /// let tab_input = Input { key: Key::Tab, ctrl: false, alt: false, shift: false };
     ///
-    /// // Cut the entire text;
-    /// textarea.cut();
+    /// textarea.set_tab_length(8);
+    /// textarea.input(tab_input.clone());
+    /// assert_eq!(textarea.lines(), ["        "]);
     ///
-    /// assert_eq!(textarea.lines(), [""]); // Buffer is now empty
-    /// assert_eq!(textarea.yank_text(), "aaa\nbbb\nccc");
+    /// textarea.set_tab_length(2);
+    /// textarea.input(tab_input);
+    /// assert_eq!(textarea.lines(), ["          "]);
     /// ```
-    pub fn select_all(&mut self) {
-        self.move_cursor(CursorMove::Jump(u16::MAX, u16::MAX));
-        self.selection_start = Some((0, 0));
+    pub fn set_tab_length(&mut self, len: u8) {
+        self.tab_len = len;
+        self.reset_measure_cache();
     }
 
-    /// Return if text selection is ongoing or not.
+    /// Get how many spaces are used for representing tab character. The default value is 4.
+    pub fn tab_length(&self) -> u8 {
+        self.tab_len
+    }
+
+    /// Set if a hard tab is used or not for indent. When `true` is set, typing a tab key inserts a hard tab instead of
+    /// spaces. By default, hard tab is disabled.
     /// ```
-    /// use tui_textarea::{TextArea};
+    /// use tui_textarea::TextArea;
     ///
     /// let mut textarea = TextArea::default();
     ///
-    /// assert!(!textarea.is_selecting());
-    /// textarea.start_selection();
-    /// assert!(textarea.is_selecting());
-    /// textarea.cancel_selection();
-    /// assert!(!textarea.is_selecting());
+    /// textarea.set_hard_tab_indent(true);
+    /// textarea.insert_tab();
+    /// assert_eq!(textarea.lines(), ["\t"]);
     /// ```
-    pub fn is_selecting(&self) -> bool {
-        self.selection_start.is_some()
+    pub fn set_hard_tab_indent(&mut self, enabled: bool) {
+        self.hard_tab_indent = enabled;
     }
 
-    fn line_offset(&self, row: usize, col: usize) -> usize {
-        let line = self
-            .lines
-            .get(row)
-            .unwrap_or(&self.lines[self.lines.len() - 1]);
-        line.char_indices()
-            .nth(col)
-            .map(|(i, _)| i)
-            .unwrap_or(line.len())
+    /// Get if a hard tab is used for indent or not.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// assert!(!textarea.hard_tab_indent());
+    /// textarea.set_hard_tab_indent(true);
+    /// assert!(textarea.hard_tab_indent());
+    /// ```
+    pub fn hard_tab_indent(&self) -> bool {
+        self.hard_tab_indent
     }
 
-    fn clamp_cursor_to_buffer(&mut self, cursor: (usize, usize)) -> (usize, usize) {
-        if self.lines.is_empty() {
-            self.lines.push(String::new());
-            return (0, 0);
-        }
-
-        let (row, col) = cursor;
-        let row = row.min(self.lines.len() - 1);
-        let col = col.min(self.lines[row].chars().count());
-        (row, col)
+    /// Set how the indentation is inserted on inserting a newline. See [`AutoIndent`] for the options. By default,
+    /// auto-indent is disabled. The indentation uses a hard tab or spaces as [`TextArea::indent`].
+    /// ```
+    /// use tui_textarea::{AutoIndent, TextArea};
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_auto_indent(AutoIndent::Smart);
+    ///
+    /// textarea.insert_str("fn main() {");
+    /// textarea.insert_newline();
+    /// textarea.insert_str("foo();");
+    /// textarea.insert_newline();
+    /// textarea.insert_char('}');
+    /// assert_eq!(textarea.lines(), ["fn main() {", "    foo();", "}"]);
+    /// ```
+    pub fn set_auto_indent(&mut self, auto_indent: AutoIndent) {
+        self.auto_indent = auto_indent;
     }
 
-    fn reset_measure_cache(&mut self) {
-        self.measure_cache = None;
+    /// Get how the indentation is inserted on inserting a newline.
+    pub fn auto_indent(&self) -> AutoIndent {
+        self.auto_indent
     }
 
-    /// Set the style used for text selection. The default style is light blue.
+    /// Get a string for indent. It consists of spaces by default. When hard tab is enabled, it is a tab character.
     /// ```
     /// use tui_textarea::TextArea;
-    /// use ratatui::style::{Style, Color};
     ///
     /// let mut textarea = TextArea::default();
     ///
-    /// // Change the selection color from the default to Red
-    /// textarea.set_selection_style(Style::default().bg(Color::Red));
-    /// assert_eq!(textarea.selection_style(), Style::default().bg(Color::Red));
+    /// assert_eq!(textarea.indent(), "    ");
+    /// textarea.set_tab_length(2);
+    /// assert_eq!(textarea.indent(), "  ");
+    /// textarea.set_hard_tab_indent(true);
+    /// assert_eq!(textarea.indent(), "\t");
     /// ```
-    pub fn set_selection_style(&mut self, style: Style) {
-        self.select_style = style;
+    pub fn indent(&self) -> &'static str {
+        if self.hard_tab_indent {
+            "\t"
+        } else {
+            spaces(self.tab_len)
+        }
     }
 
-    /// Get the style used for text selection.
+    /// Set the prefix of line comments inserted and removed by [`TextArea::toggle_comment`]. The default prefix is
+    /// `//`. Setting an empty string disables toggling comments.
     /// ```
     /// use tui_textarea::TextArea;
-    /// use ratatui::style::{Style, Color};
+    ///
+    /// let mut textarea = TextArea::from(["x = 1"]);
+    /// textarea.set_comment_prefix("#");
+    /// assert_eq!(textarea.comment_prefix(), "#");
+    ///
+    /// textarea.toggle_comment();
+    /// assert_eq!(textarea.lines(), ["# x = 1"]);
+    /// ```
+    pub fn set_comment_prefix(&mut self, prefix: impl Into<String>) {
+        self.comment_prefix = prefix.into();
+    }
+
+    /// Get the prefix of line comments set by [`TextArea::set_comment_prefix`].
+    pub fn comment_prefix(&self) -> &str {
+        &self.comment_prefix
+    }
+
+    /// Set how many modifications are remembered for undo/redo. Setting 0 disables undo/redo.
+    pub fn set_max_histories(&mut self, max: usize) {
+        self.history.reset(max);
+    }
+
+    /// Get how many modifications are remembered for undo/redo. The default value is 50.
+    pub fn max_histories(&self) -> usize {
+        self.history.max_items()
+    }
+
+    /// Set the policy to group consecutive edits into one undo step. See [`UndoGrouping`] for the details.
+    /// ```
+    /// use tui_textarea::{TextArea, UndoGrouping};
     ///
     /// let mut textarea = TextArea::default();
+    /// textarea.set_undo_grouping(UndoGrouping::Line);
+    /// assert_eq!(textarea.undo_grouping(), UndoGrouping::Line);
     ///
-    /// assert_eq!(textarea.selection_style(), Style::default().bg(Color::LightBlue));
+    /// for c in "hello world".chars() {
+    ///     textarea.insert_char(c);
+    /// }
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), [""]);
     /// ```
-    pub fn selection_style(&mut self) -> Style {
-        self.select_style
+    pub fn set_undo_grouping(&mut self, grouping: UndoGrouping) {
+        self.history.set_grouping(grouping);
     }
 
-    fn selection_positions(&self) -> Option<(Pos, Pos)> {
-        let (sr, sc) = self.selection_start?;
-        let (er, ec) = self.cursor;
-        let (so, eo) = (self.line_offset(sr, sc), self.line_offset(er, ec));
-        let s = Pos::new(sr, sc, so);
-        let e = Pos::new(er, ec, eo);
-        match (sr, so).cmp(&(er, eo)) {
-            Ordering::Less => Some((s, e)),
-            Ordering::Equal => None,
-            Ordering::Greater => Some((e, s)),
-        }
+    /// Get the policy to group consecutive edits into one undo step. The default value is [`UndoGrouping::Word`].
+    pub fn undo_grouping(&self) -> UndoGrouping {
+        self.history.grouping()
     }
 
-    fn take_selection_positions(&mut self) -> Option<(Pos, Pos)> {
-        let range = self.selection_positions();
-        self.cancel_selection();
-        range
+    /// Begin a transaction. All edits until the matching [`TextArea::end_transaction`] call are undone and redone as
+    /// one step. This is useful to make a change consisting of multiple edits atomic, such as formatting text.
+    /// Transactions can be nested. Edits are grouped until the outermost transaction ends.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b"]);
+    ///
+    /// textarea.begin_transaction();
+    /// textarea.insert_str("// ");
+    /// textarea.move_cursor(CursorMove::Down);
+    /// textarea.move_cursor(CursorMove::Head);
+    /// textarea.insert_str("// ");
+    /// textarea.end_transaction();
+    /// assert_eq!(textarea.lines(), ["// a", "// b"]);
+    ///
+    /// // Both edits are undone at once
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["a", "b"]);
+    /// ```
+    pub fn begin_transaction(&mut self) {
+        self.history.begin_group();
     }
 
-    /// Copy the selection text to the yank buffer. When nothing is selected, this method does nothing.
-    /// To get the yanked text, use [`TextArea::yank_text`].
+    /// End the transaction started by [`TextArea::begin_transaction`]. Calling this method without any ongoing
+    /// transaction does nothing.
+    pub fn end_transaction(&mut self) {
+        self.history.end_group();
+    }
+
+    /// Save the undo/redo history to the writer in a compact binary format. The saved history is keyed to the hash
+    /// of the current text, so [`TextArea::load_undo_history`] restores it only when the text is the same. This is
+    /// useful to keep undo/redo after reopening a file. Write the history after saving the text to the file.
     /// ```
-    /// use tui_textarea::{TextArea, Key, Input, CursorMove};
+    /// use tui_textarea::TextArea;
     ///
-    /// let mut textarea = TextArea::from(["Hello World"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("hello");
     ///
-    /// // Start text selection at 'W'
-    /// textarea.move_cursor(CursorMove::WordForward);
-    /// textarea.start_selection();
+    /// let mut file = vec![];
+    /// textarea.save_undo_history(&mut file).unwrap();
     ///
-    /// // Select the word "World" and copy the selected text
-    /// textarea.move_cursor(CursorMove::End);
-    /// textarea.copy();
+    /// // Reopen the text
+    /// let mut textarea = TextArea::from(["hello"]);
+    /// assert!(textarea.load_undo_history(file.as_slice()).unwrap());
+    /// assert!(textarea.undo());
+    /// assert_eq!(textarea.lines(), [""]);
+    /// ```
+    pub fn save_undo_history(&self, writer: impl io::Write) -> io::Result<()> {
+        write_history(writer, &self.history, &self.lines)
+    }
+
+    /// Load the undo/redo history saved by [`TextArea::save_undo_history`] from the reader. This method returns
+    /// `Ok(true)` when the history was restored. When the history was saved for other text or by an incompatible
+    /// version of this crate, this method returns `Ok(false)` and the current history is kept. Broken data causes an
+    /// error with [`io::ErrorKind::InvalidData`].
     ///
-    /// assert_eq!(textarea.yank_text(), "World");
-    /// assert_eq!(textarea.lines(), ["Hello World"]); // Text does not change
+    /// The maximum number of histories and the undo grouping of the textarea are kept. When the loaded history is
+    /// longer than [`TextArea::max_histories`], the oldest edits are dropped.
     /// ```
-    pub fn copy(&mut self) {
-        if let Some((start, end)) = self.take_selection_positions() {
-            if start.row == end.row {
-                self.yank = self.lines[start.row][start.offset..end.offset]
-                    .to_string()
-                    .into();
-                return;
-            }
-            let mut chunk = vec![self.lines[start.row][start.offset..].to_string()];
-            chunk.extend(self.lines[start.row + 1..end.row].iter().cloned());
-            chunk.push(self.lines[end.row][..end.offset].to_string());
-            self.yank = YankText::Chunk(chunk);
-        }
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("hello");
+    /// let mut file = vec![];
+    /// textarea.save_undo_history(&mut file).unwrap();
+    ///
+    /// // The text was modified outside
+    /// let mut textarea = TextArea::from(["goodbye"]);
+    /// assert!(!textarea.load_undo_history(file.as_slice()).unwrap());
+    /// assert!(!textarea.undo());
+    /// ```
+    pub fn load_undo_history(&mut self, reader: impl io::Read) -> io::Result<bool> {
+        let Some((nodes, root, current)) = read_history(reader, &self.lines)? else {
+            return Ok(false);
+        };
+        self.history.restore(nodes, root, current);
+        Ok(true)
     }
 
-    /// Cut the selected text and place it in the yank buffer. This method returns whether the text was modified.
-    /// The cursor will move to the start position of the text selection.
-    /// To get the yanked text, use [`TextArea::yank_text`].
+    /// Enable or disable the undo tree. By default, an edit made after undo removes the undone edits so they cannot
+    /// be redone anymore. When the undo tree is enabled, the undone edits are kept as another branch of the history.
+    /// The states of the text in all branches can be listed with [`TextArea::undo_states`] and restored with
+    /// [`TextArea::undo_jump`], [`TextArea::undo_earlier`], or [`TextArea::undo_earlier_by`]. Disabling the undo tree
+    /// removes all branches except for the current one.
     /// ```
-    /// use tui_textarea::{TextArea, Key, Input, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["Hello World"]);
+    /// use tui_textarea::TextArea;
     ///
-    /// // Start text selection at 'W'
-    /// textarea.move_cursor(CursorMove::WordForward);
-    /// textarea.start_selection();
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_undo_tree(true);
     ///
-    /// // Select the word "World" and copy the selected text
-    /// textarea.move_cursor(CursorMove::End);
-    /// textarea.cut();
+    /// textarea.insert_str("hello");
+    /// textarea.undo();
+    /// textarea.insert_str("goodbye");
+    /// assert_eq!(textarea.undo_branches(), [1, 2]);
     ///
-    /// assert_eq!(textarea.yank_text(), "World");
-    /// assert_eq!(textarea.lines(), ["Hello "]);
+    /// // Restore the text of the first branch
+    /// textarea.undo_jump(1);
+    /// assert_eq!(textarea.lines(), ["hello"]);
     /// ```
-    pub fn cut(&mut self) -> bool {
-        self.delete_selection(true)
+    pub fn set_undo_tree(&mut self, enabled: bool) {
+        self.history.set_tree(enabled);
     }
 
-    fn delete_selection(&mut self, should_yank: bool) -> bool {
-        if let Some((s, e)) = self.take_selection_positions() {
-            self.delete_range(s, e, should_yank);
-            return true;
-        }
-        false
+    /// Return if the undo tree is enabled. See [`TextArea::set_undo_tree`].
+    pub fn undo_tree(&self) -> bool {
+        self.history.tree()
     }
 
-    /// Move the cursor to the position specified by the [`CursorMove`] parameter. For each kind of cursor moves, see
-    /// the document of [`CursorMove`].
+    /// Get all states of the text in the undo history in the order they were created. The first state is the oldest
+    /// one which cannot be undone, and the current state has [`UndoState::current`] set. Without the undo tree, the
+    /// states are a line from the oldest state to the newest state which can be redone.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
+    /// use tui_textarea::TextArea;
     ///
-    /// let mut textarea = TextArea::from(["abc", "def"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("a");
+    /// textarea.insert_str("b");
+    /// textarea.undo();
     ///
-    /// textarea.move_cursor(CursorMove::Forward);
-    /// assert_eq!(textarea.cursor(), (0, 1));
-    /// textarea.move_cursor(CursorMove::Down);
-    /// assert_eq!(textarea.cursor(), (1, 1));
+    /// let states = textarea.undo_states();
+    /// assert_eq!(states.len(), 3);
+    /// assert_eq!(states[2].parent, Some(1));
+    /// assert!(states[1].current);
     /// ```
-    pub fn move_cursor(&mut self, m: CursorMove) {
-        self.move_cursor_with_shift(m, self.selection_start.is_some());
+    pub fn undo_states(&self) -> Vec<UndoState> {
+        self.history.states().collect()
     }
 
-    fn move_cursor_with_shift(&mut self, m: CursorMove, shift: bool) {
-        let wrapped_storage;
-        let wrapped_ref = if self.wrap_mode != WrapMode::None {
-            let (_, _, width, _) = self.viewport.rect();
-            if width > 0 {
-                let line_number_len = self.line_number_style.map(|_| num_digits(self.lines.len()));
-                let wrap_width = effective_wrap_width(width, line_number_len);
-                wrapped_storage =
-                    wrapped_rows(&self.lines, self.wrap_mode, wrap_width, self.tab_len);
-                Some(wrapped_storage.as_slice())
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        let next = if m == CursorMove::InViewport && self.wrap_mode != WrapMode::None {
-            self.cursor_in_wrapped_viewport()
-        } else {
-            m.next_cursor(self.cursor, &self.lines, &self.viewport, wrapped_ref)
-        };
-
-        if let Some(cursor) = next {
-            if shift {
-                if self.selection_start.is_none() {
-                    self.start_selection();
-                }
-            } else {
-                self.cancel_selection();
-            }
-            self.cursor = cursor;
-        }
+    /// Get the sequence numbers of the newest states in the branches of the undo tree. Each branch ends with one of
+    /// the states. See [`TextArea::set_undo_tree`] for an example.
+    pub fn undo_branches(&self) -> Vec<usize> {
+        self.history
+            .nodes()
+            .iter()
+            .filter(|(_, node)| node.children.is_empty())
+            .map(|(&seq, _)| seq)
+            .collect()
     }
 
-    fn cursor_in_wrapped_viewport(&self) -> Option<(usize, usize)> {
-        let (row_top, _, width, height) = self.viewport.rect();
-        if height == 0 {
-            return Some(self.cursor);
-        }
-
-        let line_number_len = self.line_number_style.map(|_| num_digits(self.lines.len()));
-        let wrap_width = effective_wrap_width(width, line_number_len);
-        let rows = wrapped_rows(&self.lines, self.wrap_mode, wrap_width, self.tab_len);
-        if rows.is_empty() {
-            return Some(self.cursor);
-        }
-
-        let cursor_visual = cursor_visual_row(&rows, self.cursor);
-        let row_top = row_top as usize;
-        let row_bottom = row_top + height.saturating_sub(1) as usize;
-        let target_visual = cursor_visual.clamp(row_top, row_bottom);
-
-        Some(cursor_at_visual_row(
-            &self.lines,
-            &rows,
-            self.cursor,
-            target_visual,
-        ))
+    /// Get the sequence number of the current state of the text. States are numbered in the order they were created.
+    /// The original text is `0`.
+    pub fn undo_seq(&self) -> usize {
+        self.history.current()
     }
 
-    /// Undo the last modification. This method returns if the undo modified text contents or not in the textarea.
+    /// Restore the state of the text with the sequence number by undoing and redoing the edits between the current
+    /// state and the state. The state can be in any branch of the undo tree. This method returns if the text was
+    /// modified or not. Nothing happens when the state does not exist.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
+    /// use tui_textarea::TextArea;
     ///
-    /// let mut textarea = TextArea::from(["abc def"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("a");
+    /// textarea.insert_str("b");
+    /// textarea.insert_str("c");
     ///
-    /// textarea.delete_next_word();
-    /// assert_eq!(textarea.lines(), [" def"]);
-    /// textarea.undo();
-    /// assert_eq!(textarea.lines(), ["abc def"]);
+    /// assert!(textarea.undo_jump(1));
+    /// assert_eq!(textarea.lines(), ["a"]);
+    /// assert!(textarea.undo_jump(3));
+    /// assert_eq!(textarea.lines(), ["abc"]);
+    /// assert!(!textarea.undo_jump(10));
     /// ```
-    pub fn undo(&mut self) -> bool {
-        if let Some(cursor) = self.history.undo(&mut self.lines) {
-            self.cancel_selection();
-            self.cursor = self.clamp_cursor_to_buffer(cursor);
-            self.reset_measure_cache();
-            true
-        } else {
-            false
-        }
+    pub fn undo_jump(&mut self, seq: usize) -> bool {
+        let moved = self.history.jump(seq, &mut self.lines);
+        self.on_history_moved(moved)
     }
 
-    /// Redo the last undo change. This method returns if the redo modified text contents or not in the textarea.
+    /// Restore the state of the text created `steps` states before the current state, like `g-` in Vim. Unlike
+    /// [`TextArea::undo`], this method moves across branches of the undo tree in the order the states were created.
+    /// This method returns if the text was modified or not.
     /// ```
-    /// use tui_textarea::{TextArea, CursorMove};
-    ///
-    /// let mut textarea = TextArea::from(["abc def"]);
+    /// use tui_textarea::TextArea;
     ///
-    /// textarea.delete_next_word();
-    /// assert_eq!(textarea.lines(), [" def"]);
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_undo_tree(true);
+    /// textarea.insert_str("one");
     /// textarea.undo();
-    /// assert_eq!(textarea.lines(), ["abc def"]);
-    /// textarea.redo();
-    /// assert_eq!(textarea.lines(), [" def"]);
+    /// textarea.insert_str("two");
+    ///
+    /// // Go back to the undone branch
+    /// textarea.undo_earlier(1);
+    /// assert_eq!(textarea.lines(), ["one"]);
+    /// textarea.undo_earlier(1);
+    /// assert_eq!(textarea.lines(), [""]);
+    /// textarea.undo_later(2);
+    /// assert_eq!(textarea.lines(), ["two"]);
     /// ```
-    pub fn redo(&mut self) -> bool {
-        if let Some(cursor) = self.history.redo(&mut self.lines) {
-            self.cancel_selection();
-            self.cursor = self.clamp_cursor_to_buffer(cursor);
-            self.reset_measure_cache();
-            true
-        } else {
-            false
-        }
+    pub fn undo_earlier(&mut self, steps: usize) -> bool {
+        self.undo_jump(self.history.earlier(steps))
     }
 
-    pub(crate) fn line_spans<'b>(&'b self, line: &'b str, row: usize, lnum_len: u8) -> Line<'b> {
-        let wrapped = WrappedLine {
-            row,
-            start_byte: 0,
-            end_byte: line.len(),
-            start_col: 0,
-            end_col: line.chars().count(),
-            first_in_row: true,
-            last_in_row: true,
-        };
-        self.line_spans_segment(line, &wrapped, lnum_len)
+    /// Restore the state of the text created `steps` states after the current state, like `g+` in Vim. See
+    /// [`TextArea::undo_earlier`].
+    pub fn undo_later(&mut self, steps: usize) -> bool {
+        self.undo_jump(self.history.later(steps))
     }
 
-    pub(crate) fn line_spans_segment<'b>(
-        &'b self,
-        line: &'b str,
-        wrapped: &WrappedLine,
-        lnum_len: u8,
-    ) -> Line<'b> {
-        let fragment = &line[wrapped.start_byte..wrapped.end_byte];
-        let mut hl = LineHighlighter::new(
-            fragment,
-            self.cursor_style,
-            self.tab_len,
-            self.mask,
-            self.select_style,
-        );
-
-        if let Some(style) = self.line_number_style {
-            if wrapped.first_in_row {
-                hl.line_number(wrapped.row, lnum_len, style);
-            } else {
-                hl.line_number_placeholder(lnum_len, style);
-            }
-        }
-
-        if wrapped.row == self.cursor.0 {
-            hl.set_line_style(self.cursor_line_style);
-            let cursor_col = self.cursor.1;
-            let in_segment = if wrapped.last_in_row {
-                wrapped.start_col <= cursor_col && cursor_col <= wrapped.end_col
-            } else {
-                wrapped.start_col <= cursor_col && cursor_col < wrapped.end_col
-            };
-            if in_segment {
-                hl.cursor_line(cursor_col - wrapped.start_col, self.cursor_line_style);
-            }
-        }
+    /// Restore the state of the text at the time before the duration from the current state was created, like
+    /// `:earlier 30s` in Vim. When no state was created at the time, the oldest state is restored. This method returns
+    /// if the text was modified or not.
+    /// ```
+    /// use std::time::Duration;
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("hello");
+    /// textarea.insert_str(" world");
+    ///
+    /// textarea.undo_earlier_by(Duration::from_secs(60));
+    /// assert_eq!(textarea.lines(), [""]);
+    /// textarea.undo_later_by(Duration::from_secs(60));
+    /// assert_eq!(textarea.lines(), ["hello world"]);
+    /// ```
+    pub fn undo_earlier_by(&mut self, duration: Duration) -> bool {
+        let seq = match self.history.current_time().checked_sub(duration) {
+            Some(time) => self.history.at_time(time),
+            None => self.history.earlier(usize::MAX),
+        };
+        self.undo_jump(seq)
+    }
 
-        #[cfg(feature = "search")]
-        if let Some(matches) = self.search.matches(line) {
-            let clipped = matches
-                .filter_map(|(start, end)| {
-                    let start = cmp::max(start, wrapped.start_byte);
-                    let end = cmp::min(end, wrapped.end_byte);
-                    (start < end).then_some((start - wrapped.start_byte, end - wrapped.start_byte))
-                })
-                .collect::<Vec<_>>();
-            if !clipped.is_empty() {
-                hl.search(clipped.into_iter(), self.search.style);
-            }
-        }
+    /// Restore the state of the text at the time after the duration from the current state was created, like
+    /// `:later 30s` in Vim. See [`TextArea::undo_earlier_by`].
+    pub fn undo_later_by(&mut self, duration: Duration) -> bool {
+        let seq = match self.history.current_time().checked_add(duration) {
+            Some(time) => self.history.at_time(time),
+            None => self.history.later(usize::MAX),
+        };
+        self.undo_jump(seq)
+    }
 
-        if let Some((start, end)) = self.selection_positions() {
-            if wrapped.first_in_row && wrapped.last_in_row {
-                hl.selection(wrapped.row, start.row, start.offset, end.row, end.offset);
-            } else if start.row <= wrapped.row && wrapped.row <= end.row {
-                let start_off = if start.row == wrapped.row {
-                    start.offset
-                } else {
-                    0
-                };
-                let end_off = if end.row == wrapped.row {
-                    end.offset
-                } else {
-                    line.len()
-                };
-                let clipped_start = cmp::max(start_off, wrapped.start_byte);
-                let clipped_end = cmp::min(end_off, wrapped.end_byte);
-                let select_at_end =
-                    wrapped.last_in_row && clipped_end == wrapped.end_byte && wrapped.row < end.row;
-                hl.selection_segment(
-                    clipped_start.saturating_sub(wrapped.start_byte),
-                    clipped_end.saturating_sub(wrapped.start_byte),
-                    select_at_end,
-                );
-            }
+    /// Get a widget to render the undo tree. This is useful to show a history browser next to the textarea. Each row
+    /// shows a state with its sequence number and how long ago it was created. The newest branch continues in the
+    /// same column and older branches are nested below it. The current state is marked with `@` and highlighted with
+    /// the reversed textarea style.
+    /// ```
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Widget;
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_undo_tree(true);
+    /// textarea.insert_str("a");
+    /// textarea.undo();
+    /// textarea.insert_str("b");
+    ///
+    /// let area = Rect::new(0, 0, 20, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// textarea.undo_tree_widget().render(area, &mut buf);
+    /// // o 0 (0s ago)
+    /// // ├─o 1 (0s ago)
+    /// // @ 2 (0s ago)
+    /// ```
+    pub fn undo_tree_widget(&self) -> impl Widget + '_ {
+        UndoTreeView {
+            nodes: self.history.nodes(),
+            root: self.history.root(),
+            current: self.history.current(),
+            style: self.style,
         }
+    }
 
-        for CustomHighlight {
-            range: ((start_row, start_offset), (end_row, end_offset)),
-            style,
-            priority,
-        } in &self.custom_highlights
-        {
-            if *start_row <= wrapped.row && wrapped.row <= *end_row {
-                let start_off = if *start_row == wrapped.row {
-                    *start_offset
-                } else {
-                    0
-                };
-                let end_off = if *end_row == wrapped.row {
-                    *end_offset
-                } else {
-                    line.len()
-                };
-                let clipped_start = cmp::max(start_off, wrapped.start_byte);
-                let clipped_end = cmp::min(end_off, wrapped.end_byte);
-                if clipped_start < clipped_end {
-                    hl.custom(
-                        wrapped.row,
-                        (
-                            (wrapped.row, clipped_start - wrapped.start_byte),
-                            (wrapped.row, clipped_end - wrapped.start_byte),
-                        ),
-                        *style,
-                        *priority,
-                    );
-                }
-            }
+    /// Enable or disable tracking changes of the text. While enabled, every modification of the text including undo,
+    /// redo, and [`TextArea::set_lines`] is recorded as [`TextChange`] and can be taken with
+    /// [`TextArea::take_changes`]. This is useful to sync the text with other data such as language servers or
+    /// external buffers. Disabling the tracking discards the changes not taken yet. By default, the tracking is
+    /// disabled.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// assert!(!textarea.change_tracking());
+    /// textarea.set_change_tracking(true);
+    /// assert!(textarea.change_tracking());
+    /// ```
+    pub fn set_change_tracking(&mut self, enabled: bool) {
+        if !enabled {
+            self.changes = None;
+        } else if self.changes.is_none() {
+            self.changes = Some(vec![]);
         }
+    }
 
-        hl.into_spans()
+    /// Return if changes of the text are tracked. See [`TextArea::set_change_tracking`].
+    pub fn change_tracking(&self) -> bool {
+        self.changes.is_some()
     }
 
-    /// Build a ratatui (or tui-rs) widget to render the current state of the textarea. The widget instance returned
-    /// from this method can be rendered with [`ratatui_core::terminal::Frame::render_widget`].
+    /// Take the changes of the text recorded since the last call. The changes are ordered as they were applied.
+    /// Applying them to a copy of the text in order reproduces the current text. Edits at multiple cursors are
+    /// reported one by one from the last cursor. Empty vector is returned when the change tracking is disabled.
+    /// ```
+    /// use tui_textarea::{TextArea, TextChange};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    /// textarea.set_change_tracking(true);
     ///
-    /// This method was deprecated at v0.5.3 and is no longer necessary. Instead you can directly pass `&TextArea`
-    /// reference to the `Frame::render_widget` method call.
-    /// ```no_run
-    /// # use ratatui::layout::Rect;
-    /// # use ratatui::Terminal;
-    /// # use ratatui::widgets::Widget as _;
-    /// # use ratatui::backend::CrosstermBackend;
-    /// # use tui_textarea::TextArea;
-    /// #
-    /// # let backend = CrosstermBackend::new(std::io::stdout());
-    /// # let mut term = Terminal::new(backend).unwrap();
-    /// # let textarea = TextArea::default();
-    /// #
-    /// # #[allow(deprecated)]
-    /// # term.draw(|f| {
-    /// #   let rect = Rect {
-    /// #       x: 0,
-    /// #       y: 0,
-    /// #       width: 24,
-    /// #       height: 8,
-    /// #   };
-    /// // v0.5.2 or earlier
-    /// f.render_widget(textarea.widget(), rect);
+    /// textarea.insert_char('x');
+    /// textarea.delete_next_char();
+    /// textarea.undo();
     ///
-    /// // v0.5.3 or later
-    /// f.render_widget(&textarea, rect);
-    /// # }).unwrap();
+    /// let changes = textarea.take_changes();
+    /// assert_eq!(changes.len(), 3);
+    /// assert_eq!(changes[0].start, (0, 0));
+    /// assert_eq!(changes[0].inserted, "x");
+    /// assert_eq!((changes[1].start, changes[1].end), ((0, 1), (0, 2)));
+    /// assert_eq!(changes[1].removed, "a");
+    /// // Undo is also reported as a change
+    /// assert_eq!(changes[2].start, (0, 1));
+    /// assert_eq!(changes[2].inserted, "a");
+    ///
+    /// assert!(textarea.take_changes().is_empty());
     /// ```
-    #[deprecated(
-        since = "0.5.3",
-        note = "calling this method is no longer necessary on rendering a textarea. pass &TextArea reference to `Frame::render_widget` method call directly"
-    )]
-    pub fn widget(&'a self) -> impl Widget + 'a {
-        self
+    pub fn take_changes(&mut self) -> Vec<TextChange> {
+        self.changes
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
     }
 
-    /// Set the style of textarea. By default, textarea is not styled.
+    /// Set the style of line at cursor. By default, the cursor line is styled with underline. To stop styling the
+    /// cursor line, set the default style.
     /// ```
     /// use ratatui::style::{Style, Color};
     /// use tui_textarea::TextArea;
     ///
     /// let mut textarea = TextArea::default();
+    ///
     /// let style = Style::default().fg(Color::Red);
-    /// textarea.set_style(style);
-    /// assert_eq!(textarea.style(), style);
+    /// textarea.set_cursor_line_style(style);
+    /// assert_eq!(textarea.cursor_line_style(), style);
+    ///
+    /// // Disable cursor line style
+    /// textarea.set_cursor_line_style(Style::default());
     /// ```
-    pub fn set_style(&mut self, style: Style) {
-        self.style = style;
+    pub fn set_cursor_line_style(&mut self, style: Style) {
+        self.cursor_line_style = style;
     }
 
-    /// Get the current style of textarea.
-    pub fn style(&self) -> Style {
-        self.style
+    /// Get the style of cursor line. By default it is styled with underline.
+    pub fn cursor_line_style(&self) -> Style {
+        self.cursor_line_style
     }
 
-    /// Set the block of textarea. By default, no block is set.
+    /// Set the style of line number. By setting the style with this method, line numbers are drawn in textarea, meant
+    /// that line numbers are disabled by default. If you want to show line numbers but don't want to style them, set
+    /// the default style.
     /// ```
+    /// use ratatui::style::{Style, Color};
     /// use tui_textarea::TextArea;
-    /// use ratatui::widgets::{Block, Borders};
     ///
     /// let mut textarea = TextArea::default();
-    /// let block = Block::default().borders(Borders::ALL).title("Block Title");
-    /// textarea.set_block(block);
-    /// assert!(textarea.block().is_some());
+    ///
+    /// // Show line numbers in dark gray background
+    /// let style = Style::default().bg(Color::DarkGray);
+    /// textarea.set_line_number_style(style);
+    /// assert_eq!(textarea.line_number_style(), Some(style));
     /// ```
-    pub fn set_block(&mut self, block: Block<'a>) {
-        self.block = Some(block);
+    pub fn set_line_number_style(&mut self, style: Style) {
+        self.line_number_style = Some(style);
         self.reset_measure_cache();
     }
 
-    /// Remove the block of textarea which was set by [`TextArea::set_block`].
+    /// Remove the style of line number which was set by [`TextArea::set_line_number_style`]. After calling this
+    /// method, Line numbers will no longer be shown.
     /// ```
+    /// use ratatui::style::{Style, Color};
     /// use tui_textarea::TextArea;
-    /// use ratatui::widgets::{Block, Borders};
     ///
     /// let mut textarea = TextArea::default();
-    /// let block = Block::default().borders(Borders::ALL).title("Block Title");
-    /// textarea.set_block(block);
-    /// textarea.remove_block();
-    /// assert!(textarea.block().is_none());
+    ///
+    /// textarea.set_line_number_style(Style::default().bg(Color::DarkGray));
+    /// textarea.remove_line_number();
+    /// assert_eq!(textarea.line_number_style(), None);
     /// ```
-    pub fn remove_block(&mut self) {
-        self.block = None;
+    pub fn remove_line_number(&mut self) {
+        self.line_number_style = None;
         self.reset_measure_cache();
     }
 
-    /// Get the block of textarea if exists.
-    pub fn block<'s>(&'s self) -> Option<&'s Block<'a>> {
-        self.block.as_ref()
+    /// Get the style of line number if set.
+    pub fn line_number_style(&self) -> Option<Style> {
+        self.line_number_style
     }
 
-    /// Set the length of tab character. Setting 0 disables tab inputs.
+    /// Set how line numbers are shown. By default, absolute line numbers are shown. Line numbers are shown only when
+    /// the style is set by [`TextArea::set_line_number_style`].
     /// ```
-    /// use tui_textarea::{TextArea, Input, Key};
+    /// use ratatui::style::Style;
+    /// use tui_textarea::{LineNumbers, TextArea};
     ///
     /// let mut textarea = TextArea::default();
-    /// let tab_input = Input { key: Key::Tab, ctrl: false, alt: false, shift: false };
+    /// textarea.set_line_number_style(Style::default());
     ///
-    /// textarea.set_tab_length(8);
-    /// textarea.input(tab_input.clone());
-    /// assert_eq!(textarea.lines(), ["        "]);
+    /// // Show the distance from the cursor line like Vim's `relativenumber`
+    /// textarea.set_line_numbers(LineNumbers::Relative);
+    /// assert_eq!(textarea.line_numbers(), LineNumbers::Relative);
+    /// ```
+    pub fn set_line_numbers(&mut self, line_numbers: LineNumbers) {
+        self.line_numbers = line_numbers;
+    }
+
+    /// Get how line numbers are shown.
+    pub fn line_numbers(&self) -> LineNumbers {
+        self.line_numbers
+    }
+
+    /// Set the custom label shown in the line number column instead of line numbers. The labeler is called with the
+    /// 0-base row of each rendered line and the row of the cursor. The column has the fixed `width` and labels are
+    /// right-aligned in it. Labels wider than the column are truncated. The labels are shown only when the style is
+    /// set by [`TextArea::set_line_number_style`].
+    /// ```
+    /// use ratatui::style::Style;
+    /// use tui_textarea::TextArea;
     ///
-    /// textarea.set_tab_length(2);
-    /// textarea.input(tab_input);
-    /// assert_eq!(textarea.lines(), ["          "]);
+    /// let mut textarea = TextArea::from(["SELECT *", "FROM t", "WHERE x"]);
+    /// textarea.set_line_number_style(Style::default());
+    ///
+    /// // Show the cursor line with a marker instead of its number
+    /// textarea.set_line_label(3, |row: usize, cursor_row: usize| {
+    ///     if row == cursor_row { ">".to_string() } else { (row + 1).to_string() }
+    /// });
     /// ```
-    pub fn set_tab_length(&mut self, len: u8) {
-        self.tab_len = len;
+    pub fn set_line_label(&mut self, width: u8, labeler: impl LineLabel + Send + Sync + 'static) {
+        self.line_label = Some(CustomLabel::new(width, labeler));
         self.reset_measure_cache();
     }
 
-    /// Get how many spaces are used for representing tab character. The default value is 4.
-    pub fn tab_length(&self) -> u8 {
-        self.tab_len
+    /// Remove the custom label set by [`TextArea::set_line_label`]. After calling this method, line numbers are shown
+    /// again.
+    pub fn remove_line_label(&mut self) {
+        self.line_label = None;
+        self.reset_measure_cache();
     }
 
-    /// Set if a hard tab is used or not for indent. When `true` is set, typing a tab key inserts a hard tab instead of
-    /// spaces. By default, hard tab is disabled.
+    /// Place the sign at the line of the `row` in the gutter. A sign already placed at the line is replaced and
+    /// returned. The sign column is shown at the left of line numbers while at least one sign is placed. Its width is
+    /// the maximum width of the placed signs. Signs follow the lines on editing the text and a sign is removed with its
+    /// line.
     /// ```
-    /// use tui_textarea::TextArea;
+    /// use tui_textarea::{Sign, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    ///
+    /// textarea.set_sign(1, Sign::error());
+    /// assert_eq!(textarea.sign(1), Some(&Sign::error()));
+    ///
+    /// // Signs follow the edits of lines above them
+    /// textarea.insert_newline();
+    /// assert_eq!(textarea.sign(2), Some(&Sign::error()));
+    /// ```
+    pub fn set_sign(&mut self, row: usize, sign: Sign) -> Option<Sign> {
+        let prev = self.signs.insert(row, sign);
+        self.reset_measure_cache();
+        prev
+    }
+
+    /// Remove the sign placed at the line of the `row` and return it.
+    /// ```
+    /// use tui_textarea::{Sign, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b"]);
+    ///
+    /// textarea.set_sign(0, Sign::added());
+    /// assert_eq!(textarea.remove_sign(0), Some(Sign::added()));
+    /// assert_eq!(textarea.sign(0), None);
+    /// ```
+    pub fn remove_sign(&mut self, row: usize) -> Option<Sign> {
+        let removed = self.signs.remove(row);
+        self.reset_measure_cache();
+        removed
+    }
+
+    /// Remove all signs placed by [`TextArea::set_sign`].
+    pub fn clear_signs(&mut self) {
+        self.signs.clear();
+        self.reset_measure_cache();
+    }
+
+    /// Get the sign placed at the line of the `row`.
+    pub fn sign(&self, row: usize) -> Option<&Sign> {
+        self.signs.get(row)
+    }
+
+    /// Attach the diagnostic to the range of the text. The range is rendered with the style of the diagnostic's
+    /// severity (see [`TextArea::set_diagnostic_style`]). The message is shown after the end of the line where the
+    /// range starts. When multiple diagnostics start at the same line, the message of the most severe one is shown.
+    /// Diagnostics don't change the text or the cursor positions.
+    ///
+    /// Diagnostics after edited lines follow the edits, but diagnostics are not updated when their own lines are
+    /// edited. Update diagnostics with [`TextArea::set_diagnostics`] after checking the new text.
+    /// ```
+    /// use tui_textarea::{Diagnostic, Severity, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["SELECT nmae FROM users"]);
+    ///
+    /// textarea.add_diagnostic(Diagnostic::new((0, 7), (0, 11), Severity::Error, "unknown column"));
+    /// assert_eq!(textarea.diagnostics().len(), 1);
+    /// ```
+    pub fn add_diagnostic(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Replace all diagnostics with the given ones. This is useful to update diagnostics after checking the text.
+    /// ```
+    /// use tui_textarea::{Diagnostic, Severity, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["let x = ;"]);
+    ///
+    /// textarea.set_diagnostics([
+    ///     Diagnostic::new((0, 8), (0, 8), Severity::Error, "expected expression"),
+    ///     Diagnostic::new((0, 4), (0, 5), Severity::Warning, "unused variable"),
+    /// ]);
+    /// assert_eq!(textarea.diagnostics().len(), 2);
+    /// ```
+    pub fn set_diagnostics(&mut self, diagnostics: impl IntoIterator<Item = Diagnostic>) {
+        self.diagnostics = diagnostics.into_iter().collect();
+    }
+
+    /// Remove all diagnostics.
+    pub fn clear_diagnostics(&mut self) {
+        self.diagnostics.clear();
+    }
+
+    /// Get all diagnostics attached to the text.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the most severe diagnostic whose range contains the cursor. This is useful to show the full message of the
+    /// diagnostic under the cursor in a status line.
+    /// ```
+    /// use tui_textarea::{CursorMove, Diagnostic, Severity, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["SELECT nmae FROM users"]);
+    /// textarea.add_diagnostic(Diagnostic::new((0, 7), (0, 11), Severity::Error, "unknown column"));
+    ///
+    /// assert_eq!(textarea.diagnostic_at_cursor(), None);
+    /// textarea.move_cursor(CursorMove::Jump(0, 8));
+    /// assert_eq!(textarea.diagnostic_at_cursor().unwrap().message, "unknown column");
+    /// ```
+    pub fn diagnostic_at_cursor(&self) -> Option<&Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.contains(self.cursor))
+            .max_by_key(|d| d.severity)
+    }
+
+    /// Set the style of ranges of diagnostics with the severity. Messages are rendered in the same style without
+    /// underline and with [`Modifier::DIM`]. By default, ranges are underlined and colored in red for errors, yellow
+    /// for warnings, blue for info, and cyan for hints.
+    /// ```
+    /// use ratatui::style::{Color, Modifier, Style};
+    /// use tui_textarea::{Severity, TextArea};
     ///
     /// let mut textarea = TextArea::default();
     ///
-    /// textarea.set_hard_tab_indent(true);
-    /// textarea.insert_tab();
-    /// assert_eq!(textarea.lines(), ["\t"]);
+    /// let style = Style::default().bg(Color::Red);
+    /// textarea.set_diagnostic_style(Severity::Error, style);
+    /// assert_eq!(textarea.diagnostic_style(Severity::Error), style);
     /// ```
-    pub fn set_hard_tab_indent(&mut self, enabled: bool) {
-        self.hard_tab_indent = enabled;
+    pub fn set_diagnostic_style(&mut self, severity: Severity, style: Style) {
+        self.diagnostic_styles[severity as usize] = style;
     }
 
-    /// Get if a hard tab is used for indent or not.
+    /// Get the style of ranges of diagnostics with the severity.
+    pub fn diagnostic_style(&self, severity: Severity) -> Style {
+        self.diagnostic_styles[severity as usize]
+    }
+
+    /// Set whether messages of diagnostics are shown after the end of lines. The default value is `true`.
     /// ```
     /// use tui_textarea::TextArea;
     ///
     /// let mut textarea = TextArea::default();
     ///
-    /// assert!(!textarea.hard_tab_indent());
-    /// textarea.set_hard_tab_indent(true);
-    /// assert!(textarea.hard_tab_indent());
+    /// textarea.set_diagnostic_messages(false);
+    /// assert!(!textarea.diagnostic_messages());
     /// ```
-    pub fn hard_tab_indent(&self) -> bool {
-        self.hard_tab_indent
+    pub fn set_diagnostic_messages(&mut self, enabled: bool) {
+        self.diagnostic_messages = enabled;
+    }
+
+    /// Get whether messages of diagnostics are shown after the end of lines.
+    pub fn diagnostic_messages(&self) -> bool {
+        self.diagnostic_messages
+    }
+
+    /// Add the virtual text rendered in the line without changing the text. Virtual texts are useful for type hints,
+    /// ghost text of autocompletion, or inline placeholders of form fields. The cursor skips over virtual texts, and
+    /// soft-wrapping and horizontal scrolling take their widths into account.
+    ///
+    /// Virtual texts after edited lines follow the edits, but virtual texts are not updated when their own lines are
+    /// edited. Update them with [`TextArea::set_virtual_texts`] after editing the text.
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_textarea::{TextArea, VirtualText};
+    ///
+    /// let mut textarea = TextArea::from(["let x = 42;"]);
+    ///
+    /// // Show the type hint after the variable name
+    /// let hint = VirtualText::new((0, 5), ": i32", Style::default().fg(Color::DarkGray));
+    /// textarea.add_virtual_text(hint);
+    ///
+    /// // Virtual texts don't change the text
+    /// assert_eq!(textarea.lines(), ["let x = 42;"]);
+    /// ```
+    pub fn add_virtual_text(&mut self, text: VirtualText) {
+        self.virtual_texts.push(text);
+        self.reset_measure_cache();
+    }
+
+    /// Replace all virtual texts with the given ones.
+    /// ```
+    /// use ratatui::style::Style;
+    /// use tui_textarea::{CursorMove, TextArea, VirtualText};
+    ///
+    /// let mut textarea = TextArea::from(["hel"]);
+    /// textarea.move_cursor(CursorMove::End);
+    ///
+    /// // Show the suggestion of autocompletion as ghost text at the cursor
+    /// textarea.set_virtual_texts([VirtualText::new(textarea.cursor(), "lo", Style::default())]);
+    /// assert_eq!(textarea.virtual_texts().len(), 1);
+    /// ```
+    pub fn set_virtual_texts(&mut self, texts: impl IntoIterator<Item = VirtualText>) {
+        self.virtual_texts = texts.into_iter().collect();
+        self.reset_measure_cache();
+    }
+
+    /// Remove all virtual texts.
+    pub fn clear_virtual_texts(&mut self) {
+        self.virtual_texts.clear();
+        self.reset_measure_cache();
+    }
+
+    /// Get all virtual texts.
+    pub fn virtual_texts(&self) -> &[VirtualText] {
+        &self.virtual_texts
+    }
+
+    /// Set the provider of completion candidates. The provider is queried with the word before the cursor by
+    /// [`TextArea::complete`]. See [`CompletionProvider`] for more details.
+    ///
+    /// While the completion popup is open, [`TextArea::input`] handles the following keys to navigate it. Editing the
+    /// word updates the candidates, and moving the cursor or typing a non-word character closes the popup.
+    ///
+    /// | Keys                  | Operation                            |
+    /// |-----------------------|--------------------------------------|
+    /// | `↓`, `Ctrl+N`         | Select the next candidate            |
+    /// | `↑`, `Ctrl+P`         | Select the previous candidate        |
+    /// | `Enter`, `Tab`        | Accept the selected candidate        |
+    /// | `Esc`                 | Close the popup                      |
+    ///
+    /// ```
+    /// use tui_textarea::{CursorMove, Input, Key, TextArea, WordList};
+    ///
+    /// let mut textarea = TextArea::from(["SELECT * FR"]);
+    /// textarea.set_completion_provider(WordList::new(["FROM", "FROZEN"]));
+    /// textarea.move_cursor(CursorMove::End);
+    ///
+    /// assert!(textarea.complete());
+    /// assert_eq!(textarea.completion_candidates(), ["FROM", "FROZEN"]);
+    ///
+    /// // Typing a character narrows the candidates
+    /// textarea.input(Input { key: Key::Char('O'), ..Default::default() });
+    /// textarea.input(Input { key: Key::Char('Z'), ..Default::default() });
+    /// assert_eq!(textarea.completion_candidates(), ["FROZEN"]);
+    ///
+    /// textarea.input(Input { key: Key::Enter, ..Default::default() });
+    /// assert_eq!(textarea.lines(), ["SELECT * FROZEN"]);
+    /// ```
+    pub fn set_completion_provider(
+        &mut self,
+        provider: impl CompletionProvider + Send + Sync + 'static,
+    ) {
+        self.completion_provider = Some(SharedProvider::new(provider));
+        self.completion = None;
+    }
+
+    /// Remove the provider of completion candidates set by [`TextArea::set_completion_provider`]. The completion popup
+    /// is closed.
+    pub fn remove_completion_provider(&mut self) {
+        self.completion_provider = None;
+        self.completion = None;
+    }
+
+    /// Return if the provider of completion candidates is set.
+    pub fn has_completion_provider(&self) -> bool {
+        self.completion_provider.is_some()
+    }
+
+    /// Query the completion provider with the word before the cursor and open the completion popup. The word consists
+    /// of alphanumeric characters and `_` and may be empty. This method returns `true` when some candidates were found.
+    /// When no provider is set or no candidate was found, the popup is closed and `false` is returned.
+    ///
+    /// This method is bound to `Ctrl+Space` in [`KeyMap::standard`]. The default key mappings leave it unbound; bind
+    /// [`Action::TriggerCompletion`] with [`TextArea::key_map_mut`] to trigger it by a key. To show candidates while
+    /// typing, call this method after handling each input.
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea, WordList};
+    ///
+    /// let mut textarea = TextArea::from(["let v = vec.it"]);
+    /// textarea.set_completion_provider(WordList::new(["iter", "iter_mut", "into_iter"]));
+    /// textarea.move_cursor(CursorMove::End);
+    ///
+    /// assert!(textarea.complete());
+    /// assert!(textarea.is_completing());
+    /// assert_eq!(textarea.completion_candidates(), ["iter", "iter_mut"]);
+    /// ```
+    pub fn complete(&mut self) -> bool {
+        self.completion = None;
+        let Some(provider) = &self.completion_provider else {
+            return false;
+        };
+        let (row, col) = self.cursor;
+        let line = &self.lines[row];
+        let start = find_word_prefix_start(line, col);
+        let prefix: String = line.chars().skip(start).take(col - start).collect();
+        let candidates = provider.complete(&prefix);
+        if candidates.is_empty() {
+            return false;
+        }
+        self.completion = Some(Completion {
+            candidates,
+            selected: 0,
+            start: (row, start),
+        });
+        true
+    }
+
+    /// Return if the completion popup is open.
+    pub fn is_completing(&self) -> bool {
+        self.completion.is_some()
+    }
+
+    /// Get the candidates in the completion popup. When the popup is not open, an empty slice is returned.
+    pub fn completion_candidates(&self) -> &[String] {
+        self.completion.as_ref().map_or(&[], |c| &c.candidates)
+    }
+
+    /// Get the index of the selected candidate in the completion popup. When the popup is not open, `None` is returned.
+    /// ```
+    /// use tui_textarea::{TextArea, WordList};
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.set_completion_provider(WordList::new(["a", "b", "c"]));
+    /// assert_eq!(textarea.completion_index(), None);
+    ///
+    /// textarea.complete();
+    /// assert_eq!(textarea.completion_index(), Some(0));
+    /// textarea.select_prev_completion();
+    /// assert_eq!(textarea.completion_index(), Some(2));
+    /// textarea.select_next_completion();
+    /// assert_eq!(textarea.completion_index(), Some(0));
+    /// ```
+    pub fn completion_index(&self) -> Option<usize> {
+        self.completion.as_ref().map(|c| c.selected)
+    }
+
+    /// Select the next candidate in the completion popup. The selection wraps around at the last candidate. This
+    /// method returns `false` when the popup is not open.
+    pub fn select_next_completion(&mut self) -> bool {
+        let Some(completion) = &mut self.completion else {
+            return false;
+        };
+        completion.select_next();
+        true
+    }
+
+    /// Select the previous candidate in the completion popup. The selection wraps around at the first candidate. This
+    /// method returns `false` when the popup is not open.
+    pub fn select_prev_completion(&mut self) -> bool {
+        let Some(completion) = &mut self.completion else {
+            return false;
+        };
+        completion.select_prev();
+        true
     }
 
-    /// Get a string for indent. It consists of spaces by default. When hard tab is enabled, it is a tab character.
+    /// Replace the word before the cursor with the selected candidate and close the completion popup. The replacement
+    /// is undone as one step. This method returns `false` when the popup is not open.
     /// ```
-    /// use tui_textarea::TextArea;
+    /// use tui_textarea::{CursorMove, TextArea, WordList};
     ///
-    /// let mut textarea = TextArea::default();
+    /// let mut textarea = TextArea::from(["hello wo"]);
+    /// textarea.set_completion_provider(WordList::new(["world", "word"]));
+    /// textarea.move_cursor(CursorMove::End);
     ///
-    /// assert_eq!(textarea.indent(), "    ");
-    /// textarea.set_tab_length(2);
-    /// assert_eq!(textarea.indent(), "  ");
-    /// textarea.set_hard_tab_indent(true);
-    /// assert_eq!(textarea.indent(), "\t");
+    /// textarea.complete();
+    /// textarea.select_next_completion();
+    /// assert!(textarea.accept_completion());
+    /// assert_eq!(textarea.lines(), ["hello word"]);
+    /// assert_eq!(textarea.cursor(), (0, 10));
+    /// assert!(!textarea.is_completing());
+    ///
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["hello wo"]);
     /// ```
-    pub fn indent(&self) -> &'static str {
-        if self.hard_tab_indent {
-            "\t"
-        } else {
-            spaces(self.tab_len)
+    pub fn accept_completion(&mut self) -> bool {
+        let Some(completion) = self.completion.take() else {
+            return false;
+        };
+        let (row, col) = self.cursor;
+        let (start_row, start_col) = completion.start;
+        if row != start_row || col < start_col {
+            return false;
         }
+
+        let line = &self.lines[row];
+        let offset = |c| line.char_indices().nth(c).map_or(line.len(), |(i, _)| i);
+        let start = Pos::new(row, start_col, offset(start_col));
+        let end = Pos::new(row, col, offset(col));
+        self.cancel_selection();
+        self.begin_transaction();
+        if start_col < col {
+            self.delete_range(start, end, false);
+        }
+        self.insert_text(completion.selected());
+        self.end_transaction();
+        true
     }
 
-    /// Set how many modifications are remembered for undo/redo. Setting 0 disables undo/redo.
-    pub fn set_max_histories(&mut self, max: usize) {
-        self.history = History::new(max);
+    /// Close the completion popup without changing the text.
+    pub fn cancel_completion(&mut self) {
+        self.completion = None;
     }
 
-    /// Get how many modifications are remembered for undo/redo. The default value is 50.
-    pub fn max_histories(&self) -> usize {
-        self.history.max_items()
+    // Handle the key input to navigate the completion popup. `None` is returned when the input is not for the popup.
+    fn completion_input(&mut self, input: &Input) -> Option<bool> {
+        self.completion.as_ref()?;
+        match input {
+            Input {
+                key: Key::Down,
+                ctrl: false,
+                alt: false,
+                ..
+            }
+            | Input {
+                key: Key::Char('n'),
+                ctrl: true,
+                alt: false,
+                ..
+            } => {
+                self.select_next_completion();
+                Some(false)
+            }
+            Input {
+                key: Key::Up,
+                ctrl: false,
+                alt: false,
+                ..
+            }
+            | Input {
+                key: Key::Char('p'),
+                ctrl: true,
+                alt: false,
+                ..
+            } => {
+                self.select_prev_completion();
+                Some(false)
+            }
+            Input {
+                key: Key::Enter | Key::Tab,
+                ctrl: false,
+                alt: false,
+                ..
+            } => Some(self.accept_completion()),
+            Input { key: Key::Esc, .. } => {
+                self.cancel_completion();
+                Some(false)
+            }
+            _ => None,
+        }
     }
 
-    /// Set the style of line at cursor. By default, the cursor line is styled with underline. To stop styling the
-    /// cursor line, set the default style.
-    /// ```
-    /// use ratatui::style::{Style, Color};
-    /// use tui_textarea::TextArea;
+    // Follow the input handled while the completion popup is open. Editing the word updates the candidates and moving
+    // the cursor closes the popup.
+    fn update_completion(&mut self, cursor: (usize, usize), modified: bool) {
+        let Some(completion) = &self.completion else {
+            return;
+        };
+        if !modified {
+            if self.cursor != cursor {
+                self.completion = None;
+            }
+            return;
+        }
+        let (row, col) = self.cursor;
+        if (row, find_word_prefix_start(&self.lines[row], col)) == completion.start {
+            self.complete();
+        } else {
+            self.completion = None;
+        }
+    }
+
+    /// Get the widget to render the completion popup. The popup is placed below the word being completed, or above it
+    /// when there is no space below, within the area passed to the widget. Usually the area is the whole terminal
+    /// screen so that the popup can overlap other widgets. Render the popup after rendering the textarea since its
+    /// position is calculated from the last rendered viewport. Nothing is rendered when the popup is not open.
+    /// ```no_run
+    /// # use ratatui::backend::CrosstermBackend;
+    /// # use ratatui::Terminal;
+    /// use tui_textarea::{TextArea, WordList};
     ///
     /// let mut textarea = TextArea::default();
-    ///
-    /// let style = Style::default().fg(Color::Red);
-    /// textarea.set_cursor_line_style(style);
-    /// assert_eq!(textarea.cursor_line_style(), style);
-    ///
-    /// // Disable cursor line style
-    /// textarea.set_cursor_line_style(Style::default());
+    /// textarea.set_completion_provider(WordList::new(["hello", "world"]));
+    /// # let mut term = Terminal::new(CrosstermBackend::new(std::io::stdout())).unwrap();
+    ///
+    /// term.draw(|f| {
+    ///     let area = f.area();
+    ///     f.render_widget(&textarea, area);
+    ///     f.render_widget(textarea.completion_widget(), area);
+    /// }).unwrap();
     /// ```
-    pub fn set_cursor_line_style(&mut self, style: Style) {
-        self.cursor_line_style = style;
-    }
-
-    /// Get the style of cursor line. By default it is styled with underline.
-    pub fn cursor_line_style(&self) -> Style {
-        self.cursor_line_style
+    pub fn completion_widget(&self) -> impl Widget + '_ {
+        let completion = self.completion.as_ref();
+        CompletionPopup {
+            candidates: completion.map_or(&[], |c| &c.candidates),
+            selected: completion.map_or(0, |c| c.selected),
+            anchor: completion.and_then(|c| self.pos_to_screen(c.start)),
+            style: self.completion_style,
+        }
     }
 
-    /// Set the style of line number. By setting the style with this method, line numbers are drawn in textarea, meant
-    /// that line numbers are disabled by default. If you want to show line numbers but don't want to style them, set
-    /// the default style.
+    /// Set the style of the completion popup. The default style is dark gray background.
     /// ```
-    /// use ratatui::style::{Style, Color};
+    /// use ratatui::style::{Color, Style};
     /// use tui_textarea::TextArea;
     ///
     /// let mut textarea = TextArea::default();
-    ///
-    /// // Show line numbers in dark gray background
-    /// let style = Style::default().bg(Color::DarkGray);
-    /// textarea.set_line_number_style(style);
-    /// assert_eq!(textarea.line_number_style(), Some(style));
+    /// let style = Style::default().bg(Color::Blue);
+    /// textarea.set_completion_style(style);
+    /// assert_eq!(textarea.completion_style(), style);
     /// ```
-    pub fn set_line_number_style(&mut self, style: Style) {
-        self.line_number_style = Some(style);
-        self.reset_measure_cache();
+    pub fn set_completion_style(&mut self, style: Style) {
+        self.completion_style.style = style;
     }
 
-    /// Remove the style of line number which was set by [`TextArea::set_line_number_style`]. After calling this
-    /// method, Line numbers will no longer be shown.
+    /// Get the style of the completion popup.
+    pub fn completion_style(&self) -> Style {
+        self.completion_style.style
+    }
+
+    /// Set the style of the selected candidate in the completion popup. The style is put on top of the popup style.
+    /// The default style is reversed.
+    pub fn set_completion_selected_style(&mut self, style: Style) {
+        self.completion_style.selected = style;
+    }
+
+    /// Get the style of the selected candidate in the completion popup.
+    pub fn completion_selected_style(&self) -> Style {
+        self.completion_style.selected
+    }
+
+    /// Set the max number of candidates shown in the completion popup at once. The list is scrolled to show the
+    /// selected candidate. The default value is 8.
     /// ```
-    /// use ratatui::style::{Style, Color};
     /// use tui_textarea::TextArea;
     ///
     /// let mut textarea = TextArea::default();
-    ///
-    /// textarea.set_line_number_style(Style::default().bg(Color::DarkGray));
-    /// textarea.remove_line_number();
-    /// assert_eq!(textarea.line_number_style(), None);
+    /// textarea.set_completion_height(4);
+    /// assert_eq!(textarea.completion_height(), 4);
     /// ```
-    pub fn remove_line_number(&mut self) {
-        self.line_number_style = None;
-        self.reset_measure_cache();
+    pub fn set_completion_height(&mut self, height: u16) {
+        self.completion_style.height = height;
     }
 
-    /// Get the style of line number if set.
-    pub fn line_number_style(&self) -> Option<Style> {
-        self.line_number_style
+    /// Get the max number of candidates shown in the completion popup at once.
+    pub fn completion_height(&self) -> u16 {
+        self.completion_style.height
     }
 
     /// Set the placeholder text. The text is set in the textarea when no text is input. Setting a non-empty string `""`
@@ -2246,25 +4753,16 @@ impl<'a> TextArea<'a> {
         self.cursor_style
     }
 
-    /// Get slice of line texts. This method borrows the content, but not moves. Note that the returned slice will
-    /// never be empty because an empty text means a slice containing one empty line. This is correct since any text
-    /// file must end with a newline.
+    /// Get the text buffer of the textarea. It is useful to access the lines when the textarea was created with
+    /// [`TextArea::with_buffer`]. The buffer is never empty as well as [`TextArea::lines`].
     /// ```
-    /// use tui_textarea::TextArea;
-    ///
-    /// let mut textarea = TextArea::default();
-    /// assert_eq!(textarea.lines(), [""]);
-    ///
-    /// textarea.insert_char('a');
-    /// assert_eq!(textarea.lines(), ["a"]);
+    /// use tui_textarea::{TextArea, TextBuffer};
     ///
-    /// textarea.insert_newline();
-    /// assert_eq!(textarea.lines(), ["a", ""]);
-    ///
-    /// textarea.insert_char('b');
-    /// assert_eq!(textarea.lines(), ["a", "b"]);
+    /// let mut textarea = TextArea::from(["hello", "world"]);
+    /// assert_eq!(textarea.buffer().len(), 2);
+    /// assert_eq!(textarea.buffer()[1], "world");
     /// ```
-    pub fn lines(&'a self) -> &'a [String] {
+    pub fn buffer(&self) -> &B {
         &self.lines
     }
 
@@ -2278,15 +4776,132 @@ impl<'a> TextArea<'a> {
             "lines must not be empty; use vec![String::new()] for empty content"
         );
 
+        let lines = B::from_lines(lines);
+        if let Some(changes) = &mut self.changes {
+            let old = &self.lines;
+            let last = old.len() - 1;
+            let end = (last, old[last].chars().count());
+            let removed = old
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("\n");
+            let inserted = lines
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("\n");
+            changes.push(TextChange::new((0, 0), end, removed, inserted));
+        }
         self.lines = lines;
+        #[cfg(feature = "search")]
+        self.search.invalidate();
         self.cursor = self.clamp_cursor_to_buffer(cursor);
-        self.history = History::new(self.history.max_items());
+        self.history.reset(self.history.max_items());
         self.selection_start = None;
+        self.block_selection = false;
+        self.cursors.clear();
         self.custom_highlights.clear();
+        self.folds.clear();
+        self.signs.clear();
+        self.diagnostics.clear();
+        self.virtual_texts.clear();
+        self.completion = None;
+        if let Some(syntax) = &mut self.syntax {
+            syntax.invalidate_all();
+        }
         self.viewport = Viewport::default();
+        self.mouse = MouseState::default();
         self.reset_measure_cache();
     }
 
+    /// Take a snapshot of the textarea state. The snapshot contains the text, the cursor, the selection, the yanked
+    /// text, the undo/redo history, the scroll position, and the configuration. When `serde` feature is enabled, the
+    /// snapshot can be serialized to resume the editing session later. See [`TextAreaSnapshot`] for the details.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_str("hello\nworld");
+    ///
+    /// let snapshot = textarea.snapshot();
+    /// assert_eq!(snapshot.lines(), ["hello", "world"]);
+    /// assert_eq!(snapshot.cursor(), (1, 5));
+    /// ```
+    pub fn snapshot(&self) -> TextAreaSnapshot {
+        // Ongoing transactions are not continued after restoring the snapshot
+        let mut history = self.history.clone();
+        history.close_groups();
+        TextAreaSnapshot {
+            lines: self.lines.iter().cloned().collect(),
+            cursor: self.cursor,
+            selection_start: self.selection_start,
+            yank: self.yank.clone(),
+            history,
+            scroll_top: self.viewport.scroll_top(),
+            tab_len: self.tab_len,
+            hard_tab_indent: self.hard_tab_indent,
+            auto_indent: self.auto_indent,
+            wrap_mode: self.wrap_mode,
+            placeholder: self.placeholder.clone(),
+            placeholder_style: self.placeholder_style,
+            style: self.style,
+            cursor_style: self.cursor_style,
+            cursor_line_style: self.cursor_line_style,
+            line_number_style: self.line_number_style,
+            select_style: self.select_style,
+        }
+    }
+
+    /// Create [`TextArea`] instance from the snapshot taken by [`TextArea::snapshot`]. Values not included in the
+    /// snapshot have their default values. The cursor and the selection are clamped to the text. When the undo/redo
+    /// history is not consistent with the text, for example because the snapshot was modified after it was taken, the
+    /// history is dropped and the restored textarea starts with an empty history.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    /// textarea.insert_char('a');
+    /// textarea.insert_char(' ');
+    /// textarea.insert_char('b');
+    ///
+    /// let mut restored = TextArea::from_snapshot(textarea.snapshot());
+    /// assert_eq!(restored.lines(), ["a b"]);
+    /// assert_eq!(restored.cursor(), (0, 3));
+    ///
+    /// restored.undo();
+    /// assert_eq!(restored.lines(), ["a "]);
+    /// restored.redo();
+    /// assert_eq!(restored.lines(), ["a b"]);
+    /// ```
+    pub fn from_snapshot(snapshot: TextAreaSnapshot) -> Self {
+        let mut textarea = Self::with_buffer(B::from_lines(snapshot.lines));
+        textarea.cursor = textarea.clamp_cursor_to_buffer(snapshot.cursor);
+        textarea.selection_start = snapshot
+            .selection_start
+            .map(|pos| textarea.clamp_cursor_to_buffer(pos));
+        textarea.yank = snapshot.yank;
+        textarea.history = snapshot.history;
+        if textarea.history.validate(&textarea.lines).is_err() {
+            textarea.history.reset(textarea.history.max_items());
+        }
+        textarea
+            .viewport
+            .set_scroll_top(snapshot.scroll_top.0, snapshot.scroll_top.1);
+        textarea.tab_len = snapshot.tab_len;
+        textarea.hard_tab_indent = snapshot.hard_tab_indent;
+        textarea.auto_indent = snapshot.auto_indent;
+        textarea.wrap_mode = snapshot.wrap_mode;
+        textarea.placeholder = snapshot.placeholder;
+        textarea.placeholder_style = snapshot.placeholder_style;
+        textarea.style = snapshot.style;
+        textarea.cursor_style = snapshot.cursor_style;
+        textarea.cursor_line_style = snapshot.cursor_line_style;
+        textarea.line_number_style = snapshot.line_number_style;
+        textarea.select_style = snapshot.select_style;
+        textarea
+    }
+
     /// Convert [`TextArea`] instance into line texts.
     /// ```
     /// use tui_textarea::TextArea;
@@ -2300,6 +4915,20 @@ impl<'a> TextArea<'a> {
     /// assert_eq!(textarea.into_lines(), ["a", "b"]);
     /// ```
     pub fn into_lines(self) -> Vec<String> {
+        self.lines.into_lines()
+    }
+
+    /// Convert [`TextArea`] instance into its text buffer.
+    /// ```
+    /// use tui_textarea::{LineRope, TextArea, TextBuffer};
+    ///
+    /// let mut textarea = TextArea::with_buffer(LineRope::new());
+    /// textarea.insert_str("hello");
+    ///
+    /// let rope = textarea.into_buffer();
+    /// assert_eq!(rope[0], "hello");
+    /// ```
+    pub fn into_buffer(self) -> B {
         self.lines
     }
 
@@ -2410,9 +5039,165 @@ impl<'a> TextArea<'a> {
         self.reset_measure_cache();
     }
 
-    /// Get the current soft-wrap mode.
-    pub fn wrap_mode(&self) -> WrapMode {
-        self.wrap_mode
+    /// Get the current soft-wrap mode.
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.wrap_mode
+    }
+
+    /// Fold the lines from `start` to `end` (inclusive, 0-base). Folded lines are rendered as one row of the line at
+    /// `start` followed by a marker showing the number of the folded lines. Moving the cursor up and down skips the
+    /// folded lines. When the cursor is in the hidden lines, it is moved to the first line of the fold. Folds are kept
+    /// following edits of the text around them. This method returns `false` when the range is empty or already
+    /// folded. Folds partially overlapping with the new fold are removed.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c", "d", "e"]);
+    ///
+    /// assert!(textarea.fold_lines(1, 3));
+    /// assert_eq!(textarea.folds(), [(1, 3)]);
+    ///
+    /// // Cursor skips the folded lines
+    /// textarea.move_cursor(CursorMove::Down);
+    /// assert_eq!(textarea.cursor(), (1, 0));
+    /// textarea.move_cursor(CursorMove::Down);
+    /// assert_eq!(textarea.cursor(), (4, 0));
+    ///
+    /// // Edits above the fold shift it
+    /// textarea.move_cursor(CursorMove::Top);
+    /// textarea.insert_newline();
+    /// assert_eq!(textarea.folds(), [(2, 4)]);
+    /// ```
+    pub fn fold_lines(&mut self, start: usize, end: usize) -> bool {
+        let end = cmp::min(end, self.lines.len().saturating_sub(1));
+        if !self.folds.add(start, end) {
+            return false;
+        }
+        let (row, col) = self.cursor;
+        if start < row && row <= end {
+            let col = cmp::min(col, self.lines[start].chars().count());
+            self.cursor = (start, col);
+        }
+        self.reset_measure_cache();
+        true
+    }
+
+    /// Fold the region of the cursor line and the following lines indented deeper than the cursor line. Blank lines
+    /// in the region are folded together. This method returns `false` when no line follows with deeper indentation.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["fn f() {", "    a();", "", "    b();", "}"]);
+    ///
+    /// assert!(textarea.fold_indent());
+    /// assert_eq!(textarea.folds(), [(0, 3)]);
+    /// ```
+    pub fn fold_indent(&mut self) -> bool {
+        match indent_region(&self.lines, self.cursor.0, self.tab_len) {
+            Some((start, end)) => self.fold_lines(start, end),
+            None => false,
+        }
+    }
+
+    /// Unfold the innermost fold containing the cursor line. This method returns `false` when the cursor line is not
+    /// folded.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    ///
+    /// textarea.fold_lines(0, 2);
+    /// assert!(textarea.unfold());
+    /// assert!(textarea.folds().is_empty());
+    /// assert!(!textarea.unfold());
+    /// ```
+    pub fn unfold(&mut self) -> bool {
+        let removed = self.folds.remove_at(self.cursor.0);
+        if removed {
+            self.reset_measure_cache();
+        }
+        removed
+    }
+
+    /// Unfold all folded lines.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c", "d"]);
+    ///
+    /// textarea.fold_lines(0, 1);
+    /// textarea.fold_lines(2, 3);
+    /// textarea.unfold_all();
+    /// assert!(textarea.folds().is_empty());
+    /// ```
+    pub fn unfold_all(&mut self) {
+        self.folds.clear();
+        self.reset_measure_cache();
+    }
+
+    /// Toggle folding at the cursor. When the cursor line is folded, the innermost fold is unfolded. Otherwise, the
+    /// selected lines are folded when the selection spans multiple lines, or the indented region at the cursor is
+    /// folded (see [`TextArea::fold_indent`]). This method returns `true` when folds are changed.
+    /// ```
+    /// use tui_textarea::{TextArea, CursorMove};
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c"]);
+    ///
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::Down);
+    /// assert!(textarea.toggle_fold());
+    /// assert_eq!(textarea.folds(), [(0, 1)]);
+    ///
+    /// assert!(textarea.toggle_fold());
+    /// assert!(textarea.folds().is_empty());
+    /// ```
+    pub fn toggle_fold(&mut self) -> bool {
+        if self.folds.contains(self.cursor.0) {
+            return self.unfold();
+        }
+        if let Some(((start, _), (end, _))) = self.selection_range() {
+            if start < end {
+                self.cancel_selection();
+                return self.fold_lines(start, end);
+            }
+        }
+        self.fold_indent()
+    }
+
+    /// Get the folded line ranges. Each range is a pair of the first and the last (inclusive) rows. Ranges are sorted
+    /// by their first rows and nested folds are also included.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["a", "b", "c", "d"]);
+    ///
+    /// textarea.fold_lines(1, 2);
+    /// textarea.fold_lines(0, 3);
+    /// assert_eq!(textarea.folds(), [(0, 3), (1, 2)]);
+    /// ```
+    pub fn folds(&self) -> Vec<(usize, usize)> {
+        self.folds.ranges().to_vec()
+    }
+
+    /// Set the style of the marker rendered after the first line of folded lines. By default, the marker is rendered
+    /// in dark gray.
+    /// ```
+    /// use ratatui::style::{Style, Color};
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::default();
+    ///
+    /// let style = Style::default().fg(Color::Blue);
+    /// textarea.set_fold_style(style);
+    /// assert_eq!(textarea.fold_style(), style);
+    /// ```
+    pub fn set_fold_style(&mut self, style: Style) {
+        self.fold_style = style;
+    }
+
+    /// Get the style of the marker of folded lines.
+    pub fn fold_style(&self) -> Style {
+        self.fold_style
     }
 
     /// Set the minimum preferred height in rows.
@@ -2467,7 +5252,10 @@ impl<'a> TextArea<'a> {
     /// assert_eq!(m.preferred_rows, 2);
     /// ```
     pub fn measure(&mut self, width_cols: u16) -> TextAreaMeasure {
-        if let Some((cached_width, cached_result)) = self.measure_cache {
+        // Folds on the screen depend on the cursor position
+        if let (Some((cached_width, cached_result)), true) =
+            (self.measure_cache, self.folds.is_empty())
+        {
             if cached_width == width_cols {
                 return cached_result;
             }
@@ -2507,12 +5295,9 @@ impl<'a> TextArea<'a> {
             return 1;
         }
 
-        let rows = if self.wrap_mode == WrapMode::None {
-            self.lines.len()
-        } else {
-            let line_number_len = self.line_number_style.map(|_| num_digits(self.lines.len()));
-            let wrap_width = effective_wrap_width(width_cols, line_number_len);
-            wrapped_rows(&self.lines, self.wrap_mode, wrap_width, self.tab_len).len()
+        let rows = match self.visual_rows(width_cols) {
+            Some(rows) => rows.len(),
+            None => self.lines.len(),
         };
 
         rows.min(u16::MAX as usize) as u16
@@ -2529,7 +5314,7 @@ impl<'a> TextArea<'a> {
     /// assert!(!textarea.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.lines == [""]
+        self.lines.len() == 1 && self.lines[0].is_empty()
     }
 
     /// Get the yanked text. Text is automatically yanked when deleting strings by [`TextArea::delete_line_by_head`],
@@ -2553,7 +5338,7 @@ impl<'a> TextArea<'a> {
     }
 
     /// Set a yanked text. The text can be inserted by [`TextArea::paste`]. `\n` and `\r\n` are recognized as newline
-    /// but `\r` isn't.
+    /// but `\r` isn't. The text is not written to the clipboard set by [`TextArea::set_clipboard`].
     /// ```
     /// use tui_textarea::TextArea;
     ///
@@ -2564,21 +5349,71 @@ impl<'a> TextArea<'a> {
     /// assert_eq!(textarea.lines(), ["hello", "world"]);
     /// ```
     pub fn set_yank_text(&mut self, text: impl Into<String>) {
-        // `str::lines` is not available since it strips a newline at end
-        let lines: Vec<_> = text
-            .into()
-            .split('\n')
-            .map(|s| s.strip_suffix('\r').unwrap_or(s).to_string())
-            .collect();
-        self.yank = lines.into();
+        self.set_yank(YankText::from_text(text.into()));
+    }
+
+    /// Set a clipboard to share yanked text with the host system. Text yanked by [`TextArea::copy`] and
+    /// [`TextArea::cut`] is also written to the clipboard, and [`TextArea::paste`] inserts the text read from the
+    /// clipboard when other applications changed it. Text yanked by other deletions such as
+    /// [`TextArea::delete_next_word`] is only kept in the yank buffer. When the clipboard cannot be read, the yank
+    /// buffer is inserted instead. Errors from the clipboard are ignored. By default, no clipboard is set and only the internal yank buffer is used. See
+    /// [`Clipboard`] for the details.
+    /// ```
+    /// use tui_textarea::{CursorMove, MemoryClipboard, TextArea};
+    ///
+    /// let clipboard = MemoryClipboard::default();
+    /// let mut textarea = TextArea::from(["hello world"]);
+    /// textarea.set_clipboard(clipboard.clone());
+    /// assert!(textarea.has_clipboard());
+    ///
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::Jump(0, 5));
+    /// textarea.copy();
+    /// assert_eq!(clipboard.text().as_deref(), Some("hello"));
+    ///
+    /// // Deletions don't write to the clipboard
+    /// textarea.delete_next_word();
+    /// assert_eq!(textarea.yank_text(), " world");
+    /// assert_eq!(clipboard.text().as_deref(), Some("hello"));
+    ///
+    /// clipboard.set("goodbye");
+    /// textarea.paste();
+    /// assert_eq!(textarea.lines(), ["hellogoodbye"]);
+    /// assert_eq!(textarea.yank_text(), "goodbye");
+    /// ```
+    pub fn set_clipboard(&mut self, clipboard: impl Clipboard + Send + 'static) {
+        self.clipboard = Some(SharedClipboard::new(clipboard));
+    }
+
+    /// Remove the clipboard which was set by [`TextArea::set_clipboard`]. The yank buffer is still available.
+    pub fn remove_clipboard(&mut self) {
+        self.clipboard = None;
+    }
+
+    /// Return if a clipboard is set by [`TextArea::set_clipboard`].
+    pub fn has_clipboard(&self) -> bool {
+        self.clipboard.is_some()
+    }
+
+    fn set_yank(&mut self, yank: YankText) {
+        self.yank = yank;
+    }
+
+    // Only text explicitly copied or cut is written to the clipboard. Errors from the clipboard are ignored since the
+    // yank buffer is still available
+    fn write_yank_to_clipboard(&mut self) {
+        if let Some(clipboard) = &mut self.clipboard {
+            let _ = clipboard.set_text(&self.yank.to_string());
+        }
     }
 
     /// Set a regular expression pattern for text search. Setting an empty string stops the text search.
     /// When a valid pattern is set, all matches will be highlighted in the textarea. Note that the cursor does not
     /// move. To move the cursor, use [`TextArea::search_forward`] and [`TextArea::search_back`].
     ///
-    /// Grammar of regular expression follows [regex crate](https://docs.rs/regex/latest/regex). Patterns don't match
-    /// to newlines so match passes across no newline.
+    /// Grammar of regular expression follows [regex crate](https://docs.rs/regex/latest/regex). By default, patterns
+    /// are matched against each line so match passes across no newline. To match patterns spanning multiple lines,
+    /// enable `multi_line` option with [`TextArea::set_search_options`].
     ///
     /// When the pattern is invalid, the search pattern will not be updated and an error will be returned.
     ///
@@ -2754,6 +5589,233 @@ impl<'a> TextArea<'a> {
         self.search.style = style;
     }
 
+    /// Set the options to interpret the query of text search. The pattern set by [`TextArea::set_search_pattern`] is
+    /// compiled again with the new options. When the pattern is invalid with the options, the options will not be
+    /// updated and an error will be returned.
+    /// ```
+    /// use tui_textarea::{SearchOptions, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["a.c ABC abc"]);
+    /// textarea.set_search_pattern("a.c").unwrap();
+    /// assert_eq!(textarea.search_match_count(), 2);
+    ///
+    /// // Match the query as a plain string
+    /// textarea.set_search_options(SearchOptions::default().literal(true)).unwrap();
+    /// assert_eq!(textarea.search_match_count(), 1);
+    ///
+    /// // Match ignoring cases of letters
+    /// textarea.set_search_options(SearchOptions::default().ignore_case(true)).unwrap();
+    /// assert_eq!(textarea.search_match_count(), 3);
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn set_search_options(&mut self, options: SearchOptions) -> Result<(), regex::Error> {
+        self.search.set_options(options)
+    }
+
+    /// Get the options of text search set by [`TextArea::set_search_options`]. All options are disabled by default.
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_options(&self) -> SearchOptions {
+        self.search.options
+    }
+
+    /// Get the ranges of all matches of the text search in the entire text. Each range is a pair of the start and end
+    /// positions as `(row, col)`, ordered by their positions. When no text search is ongoing, an empty vector is
+    /// returned.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["foo bar", "foobar"]);
+    /// textarea.set_search_pattern("fo+").unwrap();
+    /// assert_eq!(
+    ///     textarea.search_matches(),
+    ///     [((0, 0), (0, 3)), ((1, 0), (1, 3))],
+    /// );
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_matches(&self) -> Vec<((usize, usize), (usize, usize))> {
+        self.search.match_ranges(&self.lines)
+    }
+
+    /// Get the number of matches of the text search in the entire text. This is useful to show a status like
+    /// "3 of 17" with [`TextArea::search_match_index`].
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_match_count(&self) -> usize {
+        self.search.match_ranges(&self.lines).len()
+    }
+
+    /// Get the 0-based index of the match under the cursor in [`TextArea::search_matches`]. The match under the cursor
+    /// is the one starting at the cursor or containing the cursor. When the cursor is not on any match, `None` is
+    /// returned.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["foo bar foo", "foo"]);
+    /// textarea.set_search_pattern("foo").unwrap();
+    /// assert_eq!(textarea.search_match_index(), Some(0));
+    ///
+    /// textarea.search_forward(false);
+    /// textarea.search_forward(false);
+    /// assert_eq!(textarea.search_match_index(), Some(2));
+    /// assert_eq!(textarea.search_match_count(), 3);
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_match_index(&self) -> Option<usize> {
+        let cursor = self.cursor;
+        self.search
+            .match_ranges(&self.lines)
+            .iter()
+            .position(|&(start, end)| start == cursor || start < cursor && cursor < end)
+    }
+
+    /// Move the cursor to the start of the nth match in [`TextArea::search_matches`]. The index is 0-based. This method
+    /// returns `false` and does not move the cursor when the match does not exist.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["foo bar foo", "foo"]);
+    /// textarea.set_search_pattern("foo").unwrap();
+    ///
+    /// assert!(textarea.search_nth(1));
+    /// assert_eq!(textarea.cursor(), (0, 8));
+    /// assert!(!textarea.search_nth(3));
+    /// assert_eq!(textarea.cursor(), (0, 8));
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_nth(&mut self, index: usize) -> bool {
+        let Some(&(start, _)) = self.search.match_ranges(&self.lines).get(index) else {
+            return false;
+        };
+        self.cursor = start;
+        self.search.wrapped = false;
+        true
+    }
+
+    /// Return if the last [`TextArea::search_forward`] or [`TextArea::search_back`] call wrapped around the text to
+    /// find the match. This is useful to notify users that the search continued from the other end of the text.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["foo", "foo"]);
+    /// textarea.set_search_pattern("foo").unwrap();
+    ///
+    /// textarea.search_forward(false);
+    /// assert_eq!(textarea.cursor(), (1, 0));
+    /// assert!(!textarea.search_wrapped());
+    ///
+    /// textarea.search_forward(false);
+    /// assert_eq!(textarea.cursor(), (0, 0));
+    /// assert!(textarea.search_wrapped());
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search_wrapped(&self) -> bool {
+        self.search.wrapped
+    }
+
+    /// Replace the match of the text search at or after the cursor with the replacement and move the cursor to the end
+    /// of the replaced text. The match is searched as [`TextArea::search_forward`] with `match_cursor` set to `true`, so
+    /// the search wraps around the text and [`TextArea::search_wrapped`] reports it. `$1` or `${name}` in the
+    /// replacement is expanded to the text captured by the group of the pattern. Use `$$` for a literal `$`. See
+    /// [`regex::Captures::expand`] for the details. This method returns if some match was replaced or not.
+    /// ```
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["foo=1, bar=2"]);
+    /// textarea.set_search_pattern(r"(\w+)=(\d)").unwrap();
+    ///
+    /// assert!(textarea.replace_next("$2=$1"));
+    /// assert_eq!(textarea.lines(), ["1=foo, bar=2"]);
+    /// assert!(textarea.replace_next("${2}:$1"));
+    /// assert_eq!(textarea.lines(), ["1=foo, 2:bar"]);
+    /// assert_eq!(textarea.cursor(), (0, 12));
+    ///
+    /// // `false` is returned when no match was found
+    /// assert!(!textarea.replace_next("$1"));
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn replace_next(&mut self, replacement: impl AsRef<str>) -> bool {
+        let Some(pos) = self.search.forward(&self.lines, self.cursor, true) else {
+            return false;
+        };
+        let Some((start, end, text)) =
+            self.search
+                .replacement_at(&self.lines, pos, replacement.as_ref())
+        else {
+            return false;
+        };
+        self.cancel_selection();
+        self.begin_transaction();
+        self.replace_range(start, end, &text);
+        self.end_transaction();
+        true
+    }
+
+    /// Replace all matches of the text search with the replacement as one undoable step. `$1` or `${name}` in the
+    /// replacement is expanded to the text captured by the group of the pattern as well as [`TextArea::replace_next`].
+    /// When `in_selection` is `true`, only matches inside the current selection are replaced. This method returns the
+    /// number of replaced matches.
+    /// ```
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["let a = 1;", "let b = 2;", "let c = 3;"]);
+    /// textarea.set_search_pattern(r"let (\w)").unwrap();
+    ///
+    /// // Replace matches only in the first two lines
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::Down);
+    /// textarea.move_cursor(CursorMove::End);
+    /// assert_eq!(textarea.replace_all("const $1", true), 2);
+    /// assert_eq!(textarea.lines(), ["const a = 1;", "const b = 2;", "let c = 3;"]);
+    ///
+    /// assert_eq!(textarea.replace_all("var $1", false), 1);
+    /// assert_eq!(textarea.lines(), ["const a = 1;", "const b = 2;", "var c = 3;"]);
+    ///
+    /// // Undo all replacements at once
+    /// textarea.undo();
+    /// assert_eq!(textarea.lines(), ["const a = 1;", "const b = 2;", "let c = 3;"]);
+    /// ```
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn replace_all(&mut self, replacement: impl AsRef<str>, in_selection: bool) -> usize {
+        let mut replacements = self.search.replacements(&self.lines, replacement.as_ref());
+        if in_selection {
+            let Some((sel_start, sel_end)) = self.selection_range() else {
+                return 0;
+            };
+            replacements.retain(|(start, end, _)| {
+                sel_start <= (start.row, start.col) && (end.row, end.col) <= sel_end
+            });
+        }
+        if replacements.is_empty() {
+            return 0;
+        }
+
+        self.cancel_selection();
+        self.begin_transaction();
+        // Replace from the last match so that positions of preceding matches are not changed
+        for (start, end, text) in replacements.iter().rev() {
+            self.replace_range(start.clone(), end.clone(), text);
+        }
+        self.end_transaction();
+        replacements.len()
+    }
+
+    #[cfg(feature = "search")]
+    fn replace_range(&mut self, start: Pos, end: Pos, text: &str) {
+        if start.offset != end.offset || start.row != end.row {
+            self.delete_range(start.clone(), end, false);
+        }
+        self.cursor = (start.row, start.col);
+        self.insert_text(text);
+    }
+
     /// Scroll the textarea. See [`Scrolling`] for the argument.
     /// The cursor will not move until it goes out the viewport. When the cursor position is outside the viewport after scroll,
     /// the cursor position will be adjusted to stay in the viewport using the same logic as [`CursorMove::InViewport`].
@@ -2803,6 +5865,316 @@ impl<'a> TextArea<'a> {
         scrolling.scroll(&mut self.viewport);
         self.move_cursor_with_shift(CursorMove::InViewport, shift);
     }
+
+    /// Convert a 0-base (column, row) terminal position into the (row, col) cursor position of the character rendered
+    /// at the position. Line numbers, horizontal scroll, soft-wrapped rows, and wide characters are taken into
+    /// account. When the position is outside the text area or the textarea has not been rendered yet, this method
+    /// returns `None`. Positions below the last line or after the end of a line are clamped to the text.
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::Widget as _;
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["hello", "world"]);
+    ///
+    /// // Render the textarea at (x, y) = (10, 5) in the terminal screen
+    /// let r = Rect { x: 10, y: 5, width: 24, height: 8 };
+    /// let mut b = Buffer::empty(r.clone());
+    /// textarea.render(r, &mut b);
+    ///
+    /// assert_eq!(textarea.screen_to_cursor(12, 6), Some((1, 2)));
+    /// assert_eq!(textarea.screen_to_cursor(30, 6), Some((1, 5)));
+    /// assert_eq!(textarea.screen_to_cursor(0, 0), None);
+    /// ```
+    pub fn screen_to_cursor(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        let (x, y) = self.viewport.origin();
+        let (_, _, width, height) = self.viewport.rect();
+        if column < x || row < y || column - x >= width || row - y >= height {
+            return None;
+        }
+        Some(self.cursor_at_view_offset((column - x) as isize, (row - y) as isize))
+    }
+
+    // Offsets are relative to the top-left corner of the text area. They can be out of the area while dragging.
+    fn cursor_at_view_offset(&self, dx: isize, dy: isize) -> (usize, usize) {
+        if !self.placeholder.is_empty() && self.is_empty() {
+            return (0, 0);
+        }
+
+        let (top_row, top_col, width, _) = self.viewport.rect();
+        let gutter = self.gutter_width();
+
+        // Row of the logical line and the visible segment of the line
+        let rows = self.visual_rows(width);
+        let (row, segment) = if let Some(rows) = rows {
+            let Some(last) = rows.len().checked_sub(1) else {
+                return self.cursor;
+            };
+            let vrow = (top_row as isize + dy).clamp(0, last as isize) as usize;
+            (rows[vrow].row, rows[vrow])
+        } else {
+            let row = (top_row as isize + dy).clamp(0, self.lines.len() as isize - 1) as usize;
+            let line = &self.lines[row];
+            let wrapped = WrappedLine {
+                row,
+                start_byte: 0,
+                end_byte: line.len(),
+                start_col: 0,
+                end_col: line.chars().count(),
+                first_in_row: true,
+                last_in_row: true,
+                folded: 0,
+            };
+            (row, wrapped)
+        };
+
+        let line = &self.lines[row];
+        let fragment = &line[segment.start_byte..segment.end_byte];
+        let mut x = top_col as isize + dx;
+        match self.alignment {
+            Alignment::Left => {}
+            alignment => {
+                let line_width = self.line_spans_segment(line, &segment, gutter).width();
+                let space = (width as usize).saturating_sub(line_width) as isize;
+                x -= if alignment == Alignment::Center {
+                    space / 2
+                } else {
+                    space
+                };
+            }
+        }
+        let x = (x - gutter.total() as isize).max(0) as usize;
+
+        let virt = self.segment_virtual_widths(&segment);
+        let col = if let Some(mask) = self.mask {
+            (x / mask.width().unwrap_or(1).max(1)).min(segment.end_col - segment.start_col)
+        } else {
+            col_at_display_width(fragment, x, self.tab_len, &virt)
+        };
+        let col = segment.start_col + col;
+        let col = if segment.last_in_row {
+            col
+        } else {
+            col.min(segment.end_col.saturating_sub(1)) // Stay in the same visual row
+        };
+        (row, col)
+    }
+
+    /// Convert the cursor position into the 0-base (column, row) terminal position where the cursor is rendered. This
+    /// is the inverse of [`TextArea::screen_to_cursor`] and useful to place popups such as completion candidates or
+    /// tooltips next to the cursor. When the cursor is outside the viewport or the textarea has not been rendered yet,
+    /// this method returns `None`.
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::Widget as _;
+    /// use tui_textarea::{CursorMove, TextArea};
+    ///
+    /// let mut textarea = TextArea::from(["hello", "world"]);
+    /// assert_eq!(textarea.cursor_to_screen(), None);
+    ///
+    /// textarea.move_cursor(CursorMove::Jump(1, 2));
+    /// let r = Rect { x: 10, y: 5, width: 24, height: 8 };
+    /// let mut b = Buffer::empty(r.clone());
+    /// textarea.render(r, &mut b);
+    ///
+    /// assert_eq!(textarea.cursor_to_screen(), Some((12, 6)));
+    /// ```
+    pub fn cursor_to_screen(&self) -> Option<(u16, u16)> {
+        self.pos_to_screen(self.cursor)
+    }
+
+    fn pos_to_screen(&self, pos: (usize, usize)) -> Option<(u16, u16)> {
+        let (x, y) = self.viewport.origin();
+        let (top_row, top_col, width, height) = self.viewport.rect();
+        if width == 0 || height == 0 {
+            return None;
+        }
+        if !self.placeholder.is_empty() && self.is_empty() {
+            return Some((x, y));
+        }
+
+        let gutter = self.gutter_width();
+        let (row, col) = pos;
+        let line = &self.lines[row];
+        let (vrow, segment) = if let Some(rows) = self.visual_rows(width) {
+            let vrow = cursor_visual_row(&rows, pos);
+            (vrow, *rows.get(vrow)?)
+        } else {
+            let wrapped = WrappedLine {
+                row,
+                start_byte: 0,
+                end_byte: line.len(),
+                start_col: 0,
+                end_col: line.chars().count(),
+                first_in_row: true,
+                last_in_row: true,
+                folded: 0,
+            };
+            (row, wrapped)
+        };
+        let dy = vrow
+            .checked_sub(top_row as usize)
+            .filter(|&dy| dy < height as usize)?;
+
+        let fragment = &line[segment.start_byte..segment.end_byte];
+        let col = col.clamp(segment.start_col, segment.end_col) - segment.start_col;
+        let width_before = if let Some(mask) = self.mask {
+            col * mask.width().unwrap_or(1).max(1)
+        } else {
+            let virt = self.segment_virtual_widths(&segment);
+            display_width_at_col(fragment, col, self.tab_len, &virt)
+        };
+        let mut dx = (width_before + gutter.total()) as isize - top_col as isize;
+        match self.alignment {
+            Alignment::Left => {}
+            alignment => {
+                let line_width = self.line_spans_segment(line, &segment, gutter).width();
+                let space = (width as usize).saturating_sub(line_width) as isize;
+                dx += if alignment == Alignment::Center {
+                    space / 2
+                } else {
+                    space
+                };
+            }
+        }
+        if dx < 0 || dx >= width as isize {
+            return None;
+        }
+        Some((x + dx as u16, y + dy as u16))
+    }
+
+    // Character columns relative to the segment and display widths of virtual texts rendered before the characters in
+    // the segment. Virtual texts at the end of the segment are rendered after the cursor.
+    fn segment_virtual_widths(&self, segment: &WrappedLine) -> Vec<(usize, usize)> {
+        self.virtual_texts
+            .iter()
+            .filter(|t| {
+                t.pos.0 == segment.row && segment.start_col <= t.pos.1 && t.pos.1 < segment.end_col
+            })
+            .map(|t| (t.pos.1 - segment.start_col, t.width()))
+            .collect()
+    }
+
+    /// Handle pressing the left mouse button at the 0-base (column, row) terminal position. The cursor moves to the
+    /// clicked character. Clicking the same position twice selects the word at the position, and three times selects
+    /// the whole line. When `extend` is `true`, the selection is extended from the current cursor to the clicked
+    /// position instead (like Shift+Click).
+    ///
+    /// This method returns `true` when the position was inside the text area. Note that the textarea must be rendered
+    /// at least once to know where it is on the screen.
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::Widget as _;
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["hello world"]);
+    /// # let r = Rect { x: 0, y: 0, width: 24, height: 8 };
+    /// # let mut b = Buffer::empty(r.clone());
+    /// # textarea.render(r, &mut b);
+    ///
+    /// // Click 'w'
+    /// assert!(textarea.mouse_down(6, 0, false));
+    /// textarea.mouse_up();
+    /// assert_eq!(textarea.cursor(), (0, 6));
+    ///
+    /// // Double-click selects the word "world"
+    /// textarea.mouse_down(6, 0, false);
+    /// textarea.mouse_up();
+    /// assert_eq!(textarea.selection_range(), Some(((0, 6), (0, 11))));
+    /// ```
+    pub fn mouse_down(&mut self, column: u16, row: u16, extend: bool) -> bool {
+        if self.mouse.is_pressed() {
+            // Some backends such as termwiz report moving the mouse with the button held as pressing the button
+            self.mouse_drag(column, row);
+            return true;
+        }
+
+        let Some(pos) = self.screen_to_cursor(column, row) else {
+            return false;
+        };
+        self.history.close_coalescing();
+        self.cursors.clear();
+
+        if extend {
+            self.mouse.press(pos);
+            if self.selection_start.is_none() {
+                self.start_selection();
+            }
+            self.cursor = pos;
+            return true;
+        }
+
+        match self.mouse.press(pos) {
+            1 => {
+                self.cancel_selection();
+                self.cursor = pos;
+            }
+            2 => {
+                let (row, col) = pos;
+                if let Some((start, end)) = find_word_range(&self.lines[row], col) {
+                    self.selection_start = Some((row, start));
+                    self.cursor = (row, end);
+                }
+            }
+            _ => {
+                let row = pos.0;
+                self.selection_start = Some((row, 0));
+                self.cursor = (row, self.lines[row].chars().count());
+            }
+        }
+        true
+    }
+
+    /// Handle moving the mouse to the 0-base (column, row) terminal position while the left button is held. The text
+    /// from the position where the button was pressed to the current position is selected. When the position is
+    /// outside the text area, the text just outside the viewport is selected so the viewport follows the mouse on the
+    /// next rendering. This method does nothing when the button is not pressed by [`TextArea::mouse_down`].
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::Widget as _;
+    /// use tui_textarea::TextArea;
+    ///
+    /// let mut textarea = TextArea::from(["hello", "world"]);
+    /// # let r = Rect { x: 0, y: 0, width: 24, height: 8 };
+    /// # let mut b = Buffer::empty(r.clone());
+    /// # textarea.render(r, &mut b);
+    ///
+    /// textarea.mouse_down(1, 0, false);
+    /// textarea.mouse_drag(3, 1);
+    /// textarea.mouse_up();
+    /// assert_eq!(textarea.selection_range(), Some(((0, 1), (1, 3))));
+    /// ```
+    pub fn mouse_drag(&mut self, column: u16, row: u16) {
+        if !self.mouse.is_pressed() {
+            return;
+        }
+        let (x, y) = self.viewport.origin();
+        let (_, _, width, height) = self.viewport.rect();
+        // Positions outside the text area are clamped to the next row or column so that the viewport scrolls by one
+        // line on each mouse move
+        let dx = (column as isize - x as isize).clamp(-1, width as isize);
+        let dy = (row as isize - y as isize).clamp(-1, height as isize);
+        let pos = self.cursor_at_view_offset(dx, dy);
+        self.history.close_coalescing();
+        if self.selection_start.is_none() {
+            self.start_selection();
+        }
+        self.cursor = pos;
+    }
+
+    /// Handle releasing the left mouse button. The selection made by [`TextArea::mouse_down`] and
+    /// [`TextArea::mouse_drag`] remains.
+    pub fn mouse_up(&mut self) {
+        self.mouse.release();
+    }
 }
 
 #[cfg(test)]
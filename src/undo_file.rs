@@ -0,0 +1,338 @@
+// Compact binary format of the undo history saved to a file.
+//
+//   magic     "TTAU"
+//   version   u8
+//   hash      u64 LE; FNV-1a hash of the text when the history was saved
+//   root      varint; sequence number of the oldest state
+//   current   varint; sequence number of the current state
+//   redo      varint; redo child of the root state (0 for none, otherwise the sequence number + 1)
+//   time      varint; creation time of the root state in milliseconds since UNIX epoch
+//   states    varint count, followed by each state other than the root in ascending order of sequence numbers
+//   state     varint sequence number, varint parent, varint redo child, varint time, and varint count of edits
+//             followed by each edit
+//   edit      u8 tag, payload, and `before` and `after` positions as (row, col, offset) varints
+//
+// Strings are encoded as varint byte length followed by UTF-8 bytes. A chunk is a varint count followed by strings.
+// The version is bumped whenever the layout changes. Files with other versions are not loaded.
+use crate::buffer::TextBuffer;
+use crate::history::{Edit, EditKind, History, Node, validate_tree};
+use crate::util::Pos;
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 4] = b"TTAU";
+const VERSION: u8 = 2;
+
+// Stable hash of the text which doesn't depend on the platform or the Rust version
+pub fn content_hash(lines: &impl TextBuffer) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let mut hash = OFFSET;
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            hash = (hash ^ b'\n' as u64).wrapping_mul(PRIME);
+        }
+        for &b in line.as_bytes() {
+            hash = (hash ^ b as u64).wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid undo file: {msg}"),
+    )
+}
+
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.0.push(v as u8 | 0x80);
+            v >>= 7;
+        }
+        self.0.push(v as u8);
+    }
+
+    fn usize(&mut self, v: usize) {
+        self.varint(v as u64);
+    }
+
+    fn str(&mut self, s: &str) {
+        self.usize(s.len());
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn chunk(&mut self, c: &[String]) {
+        self.usize(c.len());
+        for s in c {
+            self.str(s);
+        }
+    }
+
+    fn time(&mut self, t: SystemTime) {
+        let millis = t.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        self.varint(millis.try_into().unwrap_or(u64::MAX));
+    }
+
+    fn pos(&mut self, p: &Pos) {
+        self.usize(p.row);
+        self.usize(p.col);
+        self.usize(p.offset);
+    }
+
+    fn edit(&mut self, edit: &Edit) {
+        use EditKind::*;
+        match &edit.kind {
+            InsertChar(c) => {
+                self.0.push(0);
+                self.varint(*c as u64);
+            }
+            DeleteChar(c) => {
+                self.0.push(1);
+                self.varint(*c as u64);
+            }
+            InsertNewline => self.0.push(2),
+            DeleteNewline => self.0.push(3),
+            InsertStr(s) => {
+                self.0.push(4);
+                self.str(s);
+            }
+            DeleteStr(s) => {
+                self.0.push(5);
+                self.str(s);
+            }
+            InsertChunk(c) => {
+                self.0.push(6);
+                self.chunk(c);
+            }
+            DeleteChunk(c) => {
+                self.0.push(7);
+                self.chunk(c);
+            }
+        }
+        self.pos(&edit.before);
+        self.pos(&edit.after);
+    }
+}
+
+struct Decoder<'a>(&'a [u8]);
+
+impl Decoder<'_> {
+    fn u8(&mut self) -> io::Result<u8> {
+        let (&b, rest) = self
+            .0
+            .split_first()
+            .ok_or_else(|| invalid("unexpected end"))?;
+        self.0 = rest;
+        Ok(b)
+    }
+
+    fn bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+        if self.0.len() < len {
+            return Err(invalid("unexpected end"));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> io::Result<u64> {
+        let mut v = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.u8()?;
+            v |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(v);
+            }
+        }
+        Err(invalid("too long integer"))
+    }
+
+    fn usize(&mut self) -> io::Result<usize> {
+        usize::try_from(self.varint()?).map_err(|_| invalid("too large integer"))
+    }
+
+    // Count of items. Each item takes at least one byte so a count larger than the remaining bytes is broken. This
+    // prevents allocating a huge buffer for a broken file
+    fn count(&mut self) -> io::Result<usize> {
+        let len = self.usize()?;
+        if len > self.0.len() {
+            return Err(invalid("too large count"));
+        }
+        Ok(len)
+    }
+
+    fn char(&mut self) -> io::Result<char> {
+        u32::try_from(self.varint()?)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| invalid("invalid character"))
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        let len = self.count()?;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| invalid("invalid UTF-8 string"))
+    }
+
+    fn chunk(&mut self) -> io::Result<Vec<String>> {
+        let len = self.count()?;
+        if len < 2 {
+            return Err(invalid("too short chunk"));
+        }
+        (0..len).map(|_| self.string()).collect()
+    }
+
+    fn time(&mut self) -> io::Result<SystemTime> {
+        UNIX_EPOCH
+            .checked_add(Duration::from_millis(self.varint()?))
+            .ok_or_else(|| invalid("invalid time"))
+    }
+
+    fn pos(&mut self) -> io::Result<Pos> {
+        Ok(Pos::new(self.usize()?, self.usize()?, self.usize()?))
+    }
+
+    fn edit(&mut self) -> io::Result<Edit> {
+        use EditKind::*;
+        let kind = match self.u8()? {
+            0 => InsertChar(self.char()?),
+            1 => DeleteChar(self.char()?),
+            2 => InsertNewline,
+            3 => DeleteNewline,
+            4 => InsertStr(self.string()?),
+            5 => DeleteStr(self.string()?),
+            6 => InsertChunk(self.chunk()?),
+            7 => DeleteChunk(self.chunk()?),
+            _ => return Err(invalid("unknown edit")),
+        };
+        Ok(Edit::new(kind, self.pos()?, self.pos()?))
+    }
+}
+
+pub fn write_history(
+    mut writer: impl Write,
+    history: &History,
+    lines: &impl TextBuffer,
+) -> io::Result<()> {
+    let nodes = history.nodes();
+    let root = history.root();
+    let mut enc = Encoder(Vec::new());
+    enc.0.extend_from_slice(MAGIC);
+    enc.0.push(VERSION);
+    enc.0.extend_from_slice(&content_hash(lines).to_le_bytes());
+    enc.usize(root);
+    enc.usize(history.current());
+    enc.usize(nodes[&root].redo.map_or(0, |s| s + 1));
+    enc.time(nodes[&root].time);
+    enc.usize(nodes.len() - 1);
+    for (&seq, node) in nodes.range(root + 1..) {
+        enc.usize(seq);
+        enc.usize(node.parent.unwrap());
+        enc.usize(node.redo.map_or(0, |s| s + 1));
+        enc.time(node.time);
+        enc.usize(node.edits.len());
+        for edit in &node.edits {
+            enc.edit(edit);
+        }
+    }
+    writer.write_all(&enc.0)
+}
+
+// States of the undo tree, the root, and the current state read from the file
+pub type Tree = (BTreeMap<usize, Node>, usize, usize);
+
+// Returns `None` when the file was saved by other version or for other text
+pub fn read_history(mut reader: impl Read, lines: &impl TextBuffer) -> io::Result<Option<Tree>> {
+    let mut buf = vec![];
+    reader.read_to_end(&mut buf)?;
+    let mut dec = Decoder(&buf);
+    if dec.bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+        return Err(invalid("not an undo file"));
+    }
+    if dec.u8()? != VERSION {
+        return Ok(None);
+    }
+    let hash = u64::from_le_bytes(dec.bytes(8)?.try_into().unwrap());
+    if hash != content_hash(lines) {
+        return Ok(None);
+    }
+
+    let root = dec.usize()?;
+    let current = dec.usize()?;
+    let mut redos = vec![(root, dec.usize()?.checked_sub(1))];
+    let mut nodes = BTreeMap::new();
+    let mut node = Node::new(None, vec![]);
+    node.time = dec.time()?;
+    nodes.insert(root, node);
+
+    let len = dec.count()?;
+    let mut prev = root;
+    for _ in 0..len {
+        let seq = dec.usize()?;
+        let parent = dec.usize()?;
+        if seq <= prev || !nodes.contains_key(&parent) {
+            return Err(invalid("broken tree"));
+        }
+        prev = seq;
+        let redo = dec.usize()?.checked_sub(1);
+        let time = dec.time()?;
+        let len = dec.count()?;
+        if len == 0 {
+            return Err(invalid("empty group"));
+        }
+        let edits = (0..len)
+            .map(|_| dec.edit())
+            .collect::<io::Result<Vec<_>>>()?;
+        let mut node = Node::new(Some(parent), edits);
+        node.time = time;
+        nodes.insert(seq, node);
+        nodes.get_mut(&parent).unwrap().children.push(seq);
+        redos.push((seq, redo));
+    }
+    if !dec.0.is_empty() {
+        return Err(invalid("trailing data"));
+    }
+
+    // Children are known after reading all states
+    for (seq, redo) in redos {
+        let node = nodes.get_mut(&seq).unwrap();
+        if redo.is_some_and(|r| !node.children.contains(&r)) {
+            return Err(invalid("broken redo child"));
+        }
+        node.redo = redo;
+    }
+    // The hash matches but positions of the edits may still be broken. Check them not to panic on undo or redo
+    validate_tree(&nodes, root, current, lines).map_err(invalid)?;
+    Ok(Some((nodes, root, current)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_round_trip() {
+        for v in [0, 1, 0x7f, 0x80, 0x3fff, 0x4000, u32::MAX as u64, u64::MAX] {
+            let mut enc = Encoder(vec![]);
+            enc.varint(v);
+            assert_eq!(Decoder(&enc.0).varint().unwrap(), v, "{v}");
+        }
+        let mut enc = Encoder(vec![]);
+        enc.varint(300);
+        assert_eq!(enc.0, [0xac, 0x02]);
+    }
+
+    #[test]
+    fn hash_distinguishes_line_breaks() {
+        let a = vec!["ab".to_string()];
+        let b = vec!["a".to_string(), "b".to_string()];
+        assert_ne!(content_hash(&a), content_hash(&b));
+        assert_eq!(content_hash(&vec![String::new()]), 0xcbf2_9ce4_8422_2325);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::history::Node;
+use crate::ratatui::buffer::Buffer;
+use crate::ratatui::layout::Rect;
+use crate::ratatui::style::{Modifier, Style};
+use crate::ratatui::widgets::Widget;
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+// Lay out the undo tree as rows of graph prefixes and sequence numbers from the oldest state. The newest child of a
+// state continues in the same column and the older children are nested below it.
+//
+//   o 0
+//   o 1
+//   ├─o 2
+//   │ o 3
+//   o 4
+pub(crate) fn layout(nodes: &BTreeMap<usize, Node>, root: usize) -> Vec<(String, usize)> {
+    let mut rows = vec![];
+    // Branches to lay out with the prefix of the first row and the prefix of the following rows
+    let mut stack = vec![(String::new(), String::new(), root)];
+    while let Some((mut first, prefix, mut seq)) = stack.pop() {
+        loop {
+            rows.push((first, seq));
+            let children = &nodes[&seq].children;
+            let Some((&newest, older)) = children.split_last() else {
+                break;
+            };
+            if older.is_empty() {
+                first = prefix.clone();
+                seq = newest;
+                continue;
+            }
+            // Rows of the older branches come before the newest child. The stack is popped in reverse order
+            stack.push((prefix.clone(), prefix.clone(), newest));
+            let branch = format!("{prefix}│ ");
+            for &child in older.iter().rev() {
+                stack.push((format!("{prefix}├─"), branch.clone(), child));
+            }
+            break;
+        }
+    }
+    rows
+}
+
+fn format_age(now: SystemTime, time: SystemTime) -> String {
+    let secs = now.duration_since(time).unwrap_or_default().as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+// Widget rendering the undo tree. The current state is marked with `@` and highlighted. The rows are scrolled so that
+// the current state is visible.
+pub(crate) struct UndoTreeView<'a> {
+    pub nodes: &'a BTreeMap<usize, Node>,
+    pub root: usize,
+    pub current: usize,
+    pub style: Style,
+}
+
+impl Widget for UndoTreeView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rows = layout(self.nodes, self.root);
+        let height = area.height as usize;
+        let current = rows
+            .iter()
+            .position(|&(_, seq)| seq == self.current)
+            .unwrap_or(0);
+        let top = (current + 1).saturating_sub(height);
+        let width = area.width as usize;
+        let now = SystemTime::now();
+        for (i, (prefix, seq)) in rows[top..].iter().take(height).enumerate() {
+            let (mark, style) = if *seq == self.current {
+                ('@', self.style.add_modifier(Modifier::REVERSED))
+            } else {
+                ('o', self.style)
+            };
+            let age = format_age(now, self.nodes[seq].time);
+            let row = format!("{prefix}{mark} {seq} ({age} ago)");
+            buf.set_stringn(area.x, area.y + i as u16, row, width, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn tree(parents: &[(usize, usize)]) -> BTreeMap<usize, Node> {
+        let mut nodes = BTreeMap::from([(0, Node::new(None, vec![]))]);
+        for &(seq, parent) in parents {
+            nodes.insert(seq, Node::new(Some(parent), vec![]));
+            nodes.get_mut(&parent).unwrap().children.push(seq);
+        }
+        nodes
+    }
+
+    fn lines(nodes: &BTreeMap<usize, Node>) -> Vec<String> {
+        layout(nodes, 0)
+            .into_iter()
+            .map(|(prefix, seq)| format!("{prefix}{seq}"))
+            .collect()
+    }
+
+    #[test]
+    fn layout_line() {
+        assert_eq!(lines(&tree(&[(1, 0), (2, 1)])), ["0", "1", "2"]);
+    }
+
+    #[test]
+    fn layout_branches() {
+        let nodes = tree(&[(1, 0), (2, 1), (3, 2), (4, 1), (5, 4), (6, 2), (7, 1)]);
+        assert_eq!(
+            lines(&nodes),
+            ["0", "1", "├─2", "│ ├─3", "│ 6", "├─4", "│ 5", "7"],
+        );
+    }
+
+    #[test]
+    fn format_ages() {
+        let now = SystemTime::now();
+        for (secs, want) in [
+            (0, "0s"),
+            (59, "59s"),
+            (60, "1m"),
+            (7200, "2h"),
+            (172800, "2d"),
+        ] {
+            assert_eq!(format_age(now, now - Duration::from_secs(secs)), want);
+        }
+        assert_eq!(format_age(now, now + Duration::from_secs(10)), "0s");
+    }
+}
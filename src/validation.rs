@@ -0,0 +1,387 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// Result of [`Validator::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Validation {
+    /// Insert the text as-is.
+    Accept,
+    /// Insert the transformed text instead of the original text. Inserting an empty string deletes the selection only.
+    Replace(String),
+    /// Reject the insertion with the error message. The message is available via
+    /// [`TextArea::validation_error`](crate::TextArea::validation_error).
+    Reject(String),
+}
+
+/// Text about to be inserted into [`TextArea`](crate::TextArea), passed to [`Validator::validate`]. When some text is
+/// selected, the context describes the text after the selection is deleted.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Insertion<'a> {
+    /// Text to be inserted. Lines are separated with `\n`.
+    pub text: &'a str,
+    /// Text before the insertion point in the line.
+    pub before: &'a str,
+    /// Text after the insertion point in the line.
+    pub after: &'a str,
+    /// Number of lines in the textarea before the insertion.
+    pub line_count: usize,
+}
+
+impl<'a> Insertion<'a> {
+    /// Create a new insertion context. This is useful for testing your validators.
+    pub fn new(text: &'a str, before: &'a str, after: &'a str, line_count: usize) -> Self {
+        Self {
+            text,
+            before,
+            after,
+            line_count,
+        }
+    }
+}
+
+/// Validator of text inserted into [`TextArea`](crate::TextArea). Validators are called before the text is inserted by
+/// [`TextArea::insert_char`](crate::TextArea::insert_char), [`TextArea::insert_str`](crate::TextArea::insert_str),
+/// [`TextArea::insert_tab`](crate::TextArea::insert_tab), [`TextArea::insert_newline`](crate::TextArea::insert_newline),
+/// and [`TextArea::paste`](crate::TextArea::paste), and can accept, transform, or reject the text. Line operations such
+/// as [`TextArea::duplicate_lines`](crate::TextArea::duplicate_lines) validate the lines they rewrite and are applied
+/// only when the text is accepted as-is. Deleting text is not validated.
+///
+/// Closures taking [`Insertion`] and returning [`Validation`] implement this trait. This crate also provides the
+/// following filters:
+///
+/// - [`MaxLength`]: Limit the number of characters in a line
+/// - [`MaxLines`]: Limit the number of lines
+/// - [`DigitsOnly`]: Allow only ASCII digits
+/// - `RegexFilter`: Allow only characters matching the regular expression (`search` feature)
+///
+/// ```
+/// use tui_textarea::{Insertion, TextArea, Validation};
+///
+/// let mut textarea = TextArea::default();
+///
+/// // Convert the input to upper case
+/// textarea.add_validator(|i: &Insertion<'_>| Validation::Replace(i.text.to_uppercase()));
+///
+/// textarea.insert_str("hello");
+/// assert_eq!(textarea.lines(), ["HELLO"]);
+/// ```
+pub trait Validator {
+    /// Validate the text about to be inserted.
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation;
+}
+
+impl<F: Fn(&Insertion<'_>) -> Validation> Validator for F {
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        self(insertion)
+    }
+}
+
+// Validators shared by clones of the textarea. They are applied in order and each validator receives the text
+// transformed by the previous validators.
+#[derive(Clone, Default)]
+pub(crate) struct Validators(Vec<Arc<dyn Validator + Send + Sync>>);
+
+impl Validators {
+    pub fn push(&mut self, validator: impl Validator + Send + Sync + 'static) {
+        self.0.push(Arc::new(validator));
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        let mut text = Cow::Borrowed(insertion.text);
+        for validator in &self.0 {
+            let insertion = Insertion {
+                text: &text,
+                ..*insertion
+            };
+            match validator.validate(&insertion) {
+                Validation::Accept => {}
+                Validation::Replace(replaced) => text = Cow::Owned(replaced),
+                rejected @ Validation::Reject(_) => return rejected,
+            }
+        }
+        match text {
+            Cow::Borrowed(_) => Validation::Accept,
+            Cow::Owned(text) if text == insertion.text => Validation::Accept,
+            Cow::Owned(text) => Validation::Replace(text),
+        }
+    }
+}
+
+impl fmt::Debug for Validators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validators").field(&self.0.len()).finish()
+    }
+}
+
+// Remove the characters which do not satisfy the predicate. The insertion is rejected when no character remains.
+fn filter_chars(
+    text: &str,
+    allowed: impl Fn(char) -> bool,
+    error: impl FnOnce() -> String,
+) -> Validation {
+    if text.chars().all(&allowed) {
+        return Validation::Accept;
+    }
+    let filtered: String = text.chars().filter(|&c| allowed(c)).collect();
+    if filtered.is_empty() {
+        Validation::Reject(error())
+    } else {
+        Validation::Replace(filtered)
+    }
+}
+
+/// Filter to limit the number of characters in each line. Text exceeding the limit is truncated, and the insertion is
+/// rejected when nothing can be inserted.
+/// ```
+/// use tui_textarea::{MaxLength, TextArea};
+///
+/// let mut textarea = TextArea::default();
+/// textarea.add_validator(MaxLength(5));
+///
+/// textarea.insert_str("hello world");
+/// assert_eq!(textarea.lines(), ["hello"]);
+///
+/// textarea.insert_char('!');
+/// assert_eq!(textarea.lines(), ["hello"]);
+/// assert!(textarea.validation_error().is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxLength(pub usize);
+
+impl Validator for MaxLength {
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        let max = self.0;
+        let before = insertion.before.chars().count();
+        let after = insertion.after.chars().count();
+        let lines: Vec<_> = insertion.text.split('\n').collect();
+        let last = lines.len() - 1;
+
+        let mut truncated = false;
+        let mut kept = Vec::with_capacity(lines.len());
+        for (i, line) in lines.into_iter().enumerate() {
+            let prefix = if i == 0 { before } else { 0 };
+            let suffix = if i == last { after } else { 0 };
+            let room = max.saturating_sub(prefix + suffix);
+            match line.char_indices().nth(room) {
+                Some((offset, _)) => {
+                    truncated = true;
+                    kept.push(&line[..offset]);
+                }
+                None => kept.push(line),
+            }
+        }
+
+        if !truncated {
+            return Validation::Accept;
+        }
+        let text = kept.join("\n");
+        if text.chars().all(|c| c == '\n') {
+            Validation::Reject(format!("Line must be at most {max} characters"))
+        } else {
+            Validation::Replace(text)
+        }
+    }
+}
+
+/// Filter to limit the number of lines. Lines exceeding the limit are dropped, and the insertion is rejected when
+/// nothing can be inserted. `MaxLines(1)` is useful for single-line inputs since inserting a newline is rejected.
+/// ```
+/// use tui_textarea::{MaxLines, TextArea};
+///
+/// let mut textarea = TextArea::default();
+/// textarea.add_validator(MaxLines(2));
+///
+/// textarea.insert_str("a\nb\nc");
+/// assert_eq!(textarea.lines(), ["a", "b"]);
+///
+/// textarea.insert_newline();
+/// assert_eq!(textarea.lines(), ["a", "b"]);
+/// assert!(textarea.validation_error().is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxLines(pub usize);
+
+impl Validator for MaxLines {
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        let max = self.0;
+        let room = max.saturating_sub(insertion.line_count);
+        let text = insertion.text;
+        match text.match_indices('\n').nth(room) {
+            None => Validation::Accept,
+            Some((0, _)) => Validation::Reject(format!("Text must be at most {max} lines")),
+            Some((offset, _)) => Validation::Replace(text[..offset].to_string()),
+        }
+    }
+}
+
+/// Filter to allow only ASCII digits `0`-`9`. Other characters are removed from the inserted text, and the insertion
+/// is rejected when no digit remains.
+/// ```
+/// use tui_textarea::{DigitsOnly, TextArea};
+///
+/// let mut textarea = TextArea::default();
+/// textarea.add_validator(DigitsOnly);
+///
+/// textarea.insert_str("1,234");
+/// assert_eq!(textarea.lines(), ["1234"]);
+///
+/// textarea.insert_char('a');
+/// assert_eq!(textarea.lines(), ["1234"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DigitsOnly;
+
+impl Validator for DigitsOnly {
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        filter_chars(
+            insertion.text,
+            |c| c.is_ascii_digit(),
+            || "Only digits are allowed".to_string(),
+        )
+    }
+}
+
+/// Filter to allow only characters matching the regular expression. The pattern is matched against each inserted
+/// character. Other characters are removed from the inserted text, and the insertion is rejected when no character
+/// remains. This type is available when `search` feature is enabled.
+/// ```
+/// use tui_textarea::{RegexFilter, TextArea};
+///
+/// let mut textarea = TextArea::default();
+/// textarea.add_validator(RegexFilter::new("[0-9a-fA-F]").unwrap());
+///
+/// textarea.insert_str("0xFF");
+/// assert_eq!(textarea.lines(), ["0FF"]);
+/// ```
+#[cfg(feature = "search")]
+#[derive(Clone, Debug)]
+pub struct RegexFilter {
+    pattern: regex::Regex,
+}
+
+#[cfg(feature = "search")]
+impl RegexFilter {
+    /// Create a new filter with the regular expression pattern. An error is returned when the pattern is invalid.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: regex::Regex::new(pattern)?,
+        })
+    }
+}
+
+#[cfg(feature = "search")]
+impl Validator for RegexFilter {
+    fn validate(&self, insertion: &Insertion<'_>) -> Validation {
+        filter_chars(
+            insertion.text,
+            |c| self.pattern.is_match(c.encode_utf8(&mut [0; 4])),
+            || format!("Only characters matching {} are allowed", self.pattern),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(
+        v: impl Validator,
+        text: &str,
+        before: &str,
+        after: &str,
+        lines: usize,
+    ) -> Validation {
+        v.validate(&Insertion::new(text, before, after, lines))
+    }
+
+    fn replace(s: &str) -> Validation {
+        Validation::Replace(s.to_string())
+    }
+
+    #[test]
+    fn max_length() {
+        use Validation::*;
+        assert_eq!(validate(MaxLength(3), "ab", "", "", 1), Accept);
+        assert_eq!(validate(MaxLength(3), "ab", "a", "", 1), Accept);
+        assert_eq!(validate(MaxLength(3), "ab", "a", "b", 1), replace("a"));
+        assert!(matches!(
+            validate(MaxLength(3), "a", "ab", "c", 1),
+            Reject(_)
+        ));
+        assert_eq!(validate(MaxLength(3), "\n", "abc", "def", 1), Accept);
+        assert_eq!(
+            validate(MaxLength(3), "abcd\nefgh", "", "", 1),
+            replace("abc\nefg")
+        );
+        assert_eq!(validate(MaxLength(3), "x\nyz", "ab", "c", 1), Accept);
+        assert_eq!(
+            validate(MaxLength(2), "x\nyz", "ab", "c", 1),
+            replace("\ny")
+        );
+        assert_eq!(
+            validate(MaxLength(3), "x\nyz", "abc", "", 1),
+            replace("\nyz")
+        );
+        assert_eq!(validate(MaxLength(2), "あい", "", "", 1), Accept);
+    }
+
+    #[test]
+    fn max_lines() {
+        use Validation::*;
+        assert_eq!(validate(MaxLines(2), "a\nb", "", "", 1), Accept);
+        assert_eq!(validate(MaxLines(2), "a\nb\nc", "", "", 1), replace("a\nb"));
+        assert_eq!(validate(MaxLines(2), "a\nb", "", "", 2), replace("a"));
+        assert!(matches!(validate(MaxLines(2), "\nb", "", "", 2), Reject(_)));
+        assert_eq!(validate(MaxLines(1), "abc", "", "", 1), Accept);
+    }
+
+    #[test]
+    fn digits_only() {
+        use Validation::*;
+        assert_eq!(validate(DigitsOnly, "123", "", "", 1), Accept);
+        assert_eq!(validate(DigitsOnly, "1a2\n3", "", "", 1), replace("123"));
+        assert!(matches!(validate(DigitsOnly, "a", "", "", 1), Reject(_)));
+    }
+
+    #[cfg(feature = "search")]
+    #[test]
+    fn regex_filter() {
+        use Validation::*;
+        let filter = RegexFilter::new("[a-c]").unwrap();
+        assert_eq!(validate(filter.clone(), "abc", "", "", 1), Accept);
+        assert_eq!(validate(filter.clone(), "axbyc", "", "", 1), replace("abc"));
+        assert!(matches!(validate(filter, "xyz", "", "", 1), Reject(_)));
+        assert!(RegexFilter::new("(").is_err());
+    }
+
+    #[test]
+    fn chain_validators() {
+        let mut validators = Validators::default();
+        validators.push(DigitsOnly);
+        validators.push(MaxLength(3));
+        let insertion = Insertion::new("1a2b3c4", "", "", 1);
+        assert_eq!(validators.validate(&insertion), replace("123"));
+
+        let insertion = Insertion::new("x", "", "", 1);
+        assert!(matches!(
+            validators.validate(&insertion),
+            Validation::Reject(_)
+        ));
+
+        // Transforming text into the same text is regarded as accepted
+        validators.clear();
+        validators.push(|i: &Insertion<'_>| Validation::Replace(i.text.to_string()));
+        let insertion = Insertion::new("x", "", "", 1);
+        assert_eq!(validators.validate(&insertion), Validation::Accept);
+    }
+}
@@ -0,0 +1,1490 @@
+use crate::buffer::TextBuffer;
+use crate::cursor::CursorMove;
+use crate::input::{Input, Key};
+use crate::scroll::Scrolling;
+use crate::textarea::TextArea;
+use crate::word::find_word_range;
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+use std::mem;
+
+/// Operator waiting for a motion or a text object in [`VimMode::OperatorPending`] mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VimOperator {
+    /// `d` operator
+    Delete,
+    /// `c` operator
+    Change,
+    /// `y` operator
+    Yank,
+}
+
+impl fmt::Display for VimOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delete => write!(f, "d"),
+            Self::Change => write!(f, "c"),
+            Self::Yank => write!(f, "y"),
+        }
+    }
+}
+
+/// Current mode of [`Vim`] emulation. This is useful to change how the textarea is rendered (cursor style, block
+/// title, ...) depending on the mode.
+///
+/// This type is marked as `#[non_exhaustive]` since more modes may be supported in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VimMode {
+    /// Normal mode. This is the initial mode.
+    #[default]
+    Normal,
+    /// Insert mode entered by `i`, `a`, `o`, `c`, ... Inputs are handled by [`TextArea::input`].
+    Insert,
+    /// Character-wise visual mode entered by `v`.
+    Visual,
+    /// Line-wise visual mode entered by `V`.
+    VisualLine,
+    /// Waiting for a motion or a text object after an operator such as `d`.
+    OperatorPending(VimOperator),
+    /// Command-line mode entered by `:`. The command line can be retrieved by [`Vim::command_line`].
+    Command,
+}
+
+impl fmt::Display for VimMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(f, "NORMAL"),
+            Self::Insert => write!(f, "INSERT"),
+            Self::Visual => write!(f, "VISUAL"),
+            Self::VisualLine => write!(f, "VISUAL LINE"),
+            Self::OperatorPending(op) => write!(f, "OPERATOR({op})"),
+            Self::Command => write!(f, "COMMAND"),
+        }
+    }
+}
+
+/// Result of handling an input with [`Vim::input`].
+///
+/// This type is marked as `#[non_exhaustive]` since more events may be supported in the future.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VimEvent {
+    /// The input did not modify the text. It may have moved the cursor, changed the mode, or be waiting for following
+    /// inputs.
+    Nop,
+    /// The input modified the text.
+    Modified,
+    /// A command line was entered with `:`. The string does not contain the leading `:`. Handling the command (`w`,
+    /// `q`, ...) is up to the application.
+    Command(String),
+}
+
+impl From<bool> for VimEvent {
+    fn from(modified: bool) -> Self {
+        if modified { Self::Modified } else { Self::Nop }
+    }
+}
+
+/// Content of a Vim register.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VimRegister {
+    /// Text stored in the register. Lines are joined with `\n`.
+    pub text: String,
+    /// Whether the text was yanked line-wise (e.g. by `yy` or `dd`). Line-wise text is put as new lines.
+    pub linewise: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Pending {
+    #[default]
+    None,
+    Register,
+    G,
+    Find(Find),
+    TextObject {
+        around: bool,
+    },
+    Replace,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Find {
+    Forward,     // f
+    Backward,    // F
+    TillForward, // t
+    TillBack,    // T
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordForward,
+    WordEnd,
+    WordBack,
+    LineHead,
+    FirstNonBlank,
+    LineEnd,
+    Line(usize),
+    ParagraphForward,
+    ParagraphBack,
+    Find(Find, char),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MotionKind {
+    Exclusive,
+    Inclusive,
+    Linewise,
+}
+
+impl Motion {
+    fn kind(self) -> MotionKind {
+        match self {
+            Self::Up | Self::Down | Self::Line(_) => MotionKind::Linewise,
+            Self::WordEnd | Self::LineEnd | Self::Find(Find::Forward | Find::TillForward, _) => {
+                MotionKind::Inclusive
+            }
+            _ => MotionKind::Exclusive,
+        }
+    }
+}
+
+// Target of an operator. The end position of `Chars` is exclusive. `Lines` is an inclusive range of rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Range {
+    Chars((usize, usize), (usize, usize)),
+    Lines(usize, usize),
+}
+
+fn char_len(line: &str) -> usize {
+    line.chars().count()
+}
+
+fn first_non_blank(line: &str) -> usize {
+    line.chars()
+        .position(|c| !c.is_whitespace())
+        .unwrap_or_else(|| char_len(line).saturating_sub(1))
+}
+
+fn is_blank(line: &str) -> bool {
+    line.chars().all(char::is_whitespace)
+}
+
+// Extend the range `[start, end)` in the line to include trailing whitespaces. When there is no trailing whitespace,
+// leading whitespaces are included instead. This is how `aw` and `a"` work.
+fn around_whitespaces(chars: &[char], start: usize, end: usize) -> (usize, usize) {
+    let trailing = chars[end..]
+        .iter()
+        .position(|c| !c.is_whitespace())
+        .unwrap_or(chars.len() - end);
+    if trailing > 0 {
+        return (start, end + trailing);
+    }
+    let leading = chars[..start]
+        .iter()
+        .rev()
+        .position(|c| !c.is_whitespace())
+        .unwrap_or(start);
+    (start - leading, end)
+}
+
+fn word_object(line: &str, col: usize, around: bool) -> Option<(usize, usize)> {
+    let (start, end) = find_word_range(line, col)?;
+    if !around {
+        return Some((start, end));
+    }
+    let chars: Vec<char> = line.chars().collect();
+    if chars[start].is_whitespace() {
+        // `aw` on whitespaces selects the whitespaces and the following word
+        let end = find_word_range(line, end).map_or(end, |(_, e)| e);
+        return Some((start, end));
+    }
+    Some(around_whitespaces(&chars, start, end))
+}
+
+fn quote_object(line: &str, col: usize, quote: char, around: bool) -> Option<(usize, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let quotes: Vec<usize> = (0..chars.len())
+        .filter(|&i| chars[i] == quote && (i == 0 || chars[i - 1] != '\\'))
+        .collect();
+    let (open, close) = quotes
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|&(_, close)| close >= col)?;
+    if around {
+        Some(around_whitespaces(&chars, open, close + 1))
+    } else {
+        Some((open + 1, close))
+    }
+}
+
+fn bracket_object(
+    lines: &impl TextBuffer,
+    (row, col): (usize, usize),
+    open: char,
+    close: char,
+    around: bool,
+) -> Option<Range> {
+    // Find the unmatched open bracket before the cursor. A close bracket under the cursor is inside the object.
+    let mut depth = 0usize;
+    let mut start = None;
+    'outer: for r in (0..=row).rev() {
+        let chars: Vec<char> = lines[r].chars().collect();
+        let upto = if r == row {
+            (col + 1).min(chars.len())
+        } else {
+            chars.len()
+        };
+        for c in (0..upto).rev() {
+            if chars[c] == close && (r, c) != (row, col) {
+                depth += 1;
+            } else if chars[c] == open {
+                if depth == 0 {
+                    start = Some((r, c));
+                    break 'outer;
+                }
+                depth -= 1;
+            }
+        }
+    }
+    let start = start?;
+
+    let mut depth = 0usize;
+    let mut end = None;
+    'outer: for r in start.0..lines.len() {
+        let chars: Vec<char> = lines[r].chars().collect();
+        let from = if r == start.0 { start.1 + 1 } else { 0 };
+        for c in from..chars.len() {
+            if chars[c] == open {
+                depth += 1;
+            } else if chars[c] == close {
+                if depth == 0 {
+                    end = Some((r, c));
+                    break 'outer;
+                }
+                depth -= 1;
+            }
+        }
+    }
+    let end = end?;
+
+    if around {
+        Some(Range::Chars(start, (end.0, end.1 + 1)))
+    } else {
+        Some(Range::Chars((start.0, start.1 + 1), end))
+    }
+}
+
+fn paragraph_object(lines: &impl TextBuffer, row: usize, around: bool) -> Range {
+    let blank = is_blank(&lines[row]);
+    let mut start = row;
+    while start > 0 && is_blank(&lines[start - 1]) == blank {
+        start -= 1;
+    }
+    let mut end = row;
+    while end + 1 < lines.len() && is_blank(&lines[end + 1]) == blank {
+        end += 1;
+    }
+    if around {
+        // `ap` includes the following paragraph of the other kind. When there is none, the preceding one is included
+        if end + 1 < lines.len() {
+            end += 1;
+            while end + 1 < lines.len() && is_blank(&lines[end + 1]) != blank {
+                end += 1;
+            }
+        } else {
+            while start > 0 && is_blank(&lines[start - 1]) != blank {
+                start -= 1;
+            }
+        }
+    }
+    Range::Lines(start, end)
+}
+
+fn find_in_line(line: &str, col: usize, find: Find, target: char) -> Option<usize> {
+    let chars: Vec<char> = line.chars().collect();
+    match find {
+        Find::Forward => (col + 1..chars.len()).find(|&i| chars[i] == target),
+        Find::TillForward => (col + 1..chars.len())
+            .find(|&i| chars[i] == target)
+            .map(|i| i - 1),
+        Find::Backward => (0..col.min(chars.len()))
+            .rev()
+            .find(|&i| chars[i] == target),
+        Find::TillBack => (0..col.min(chars.len()))
+            .rev()
+            .find(|&i| chars[i] == target)
+            .map(|i| i + 1),
+    }
+}
+
+/// Modal Vim emulation layer for [`TextArea`]. This is available when `vim` feature is enabled.
+///
+/// `Vim` keeps the state of the emulation (current mode, pending keys, counts, registers, the last change for `.`
+/// repeat, ...) and drives a [`TextArea`] through its cursor, selection, and editing APIs. Inputs are passed to
+/// [`Vim::input`] instead of [`TextArea::input`]. In insert mode, inputs are handled by [`TextArea::input`] so the
+/// key map of the textarea is used.
+///
+/// Supported features:
+///
+/// - Normal, insert, visual (`v`), visual line (`V`), operator-pending, and command-line (`:`) modes
+/// - Motions: `h`, `j`, `k`, `l`, `w`, `e`, `b`, `0`, `^`, `$`, `gg`, `G`, `{`, `}`, `f`, `F`, `t`, `T`, arrow keys
+/// - Operators `d`, `c`, `y` with motions and text objects, `dd`, `cc`, `yy`, `x`, `X`, `D`, `C`, `s`, `S`, `Y`
+/// - Text objects: `iw`, `aw`, `i"`, `a"`, `i'`, `a'`, `` i` ``, `` a` ``, `i(`, `a(`, `ib`, `i{`, `a{`, `iB`,
+///   `i[`, `a[`, `i<`, `a<`, `ip`, `ap`
+/// - Counts such as `3dw`, `d3w`, `2dd`, `5G`
+/// - Registers: unnamed register `"`, yank register `0`, named registers `"a` to `"z` (`"A` to `"Z` append), black
+///   hole register `"_`
+/// - `p`, `P`, `r`, `J`, `i`, `a`, `I`, `A`, `o`, `O`, `u`, `Ctrl+R`, `g-`, `g+`, `.` repeat
+/// - Scrolling with `Ctrl+E`, `Ctrl+Y`, `Ctrl+D`, `Ctrl+U`, `Ctrl+F`, `Ctrl+B`
+///
+/// ```
+/// use tui_textarea::{Input, Key, TextArea, Vim, VimEvent, VimMode};
+///
+/// let mut textarea = TextArea::from(["hello world foo bar"]);
+/// let mut vim = Vim::new();
+/// let key = |key| Input { key, ..Input::default() };
+///
+/// for c in "2dw".chars() {
+///     vim.input(&mut textarea, key(Key::Char(c)));
+/// }
+/// assert_eq!(textarea.lines(), ["foo bar"]);
+///
+/// // Repeat the last change
+/// vim.input(&mut textarea, key(Key::Char('.')));
+/// assert_eq!(textarea.lines(), [""]);
+///
+/// vim.input(&mut textarea, key(Key::Char('i')));
+/// assert_eq!(vim.mode(), VimMode::Insert);
+/// vim.input(&mut textarea, key(Key::Esc));
+/// assert_eq!(vim.mode(), VimMode::Normal);
+///
+/// // Commands entered with `:` are returned to the application
+/// vim.input(&mut textarea, key(Key::Char(':')));
+/// vim.input(&mut textarea, key(Key::Char('q')));
+/// let event = vim.input(&mut textarea, key(Key::Enter));
+/// assert_eq!(event, VimEvent::Command("q".to_string()));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "vim")))]
+#[derive(Clone, Debug, Default)]
+pub struct Vim {
+    mode: VimMode,
+    pending: Pending,
+    count: Option<usize>,
+    op_count: Option<usize>,
+    register: Option<char>,
+    registers: HashMap<char, VimRegister>,
+    visual_anchor: (usize, usize),
+    command_line: String,
+    // Inputs of the current command to record the last change for `.` repeat
+    keys: Vec<Input>,
+    last_change: Vec<Input>,
+    change: bool,
+    replaying: bool,
+}
+
+impl Vim {
+    /// Create a new Vim emulation state in normal mode.
+    /// ```
+    /// use tui_textarea::{Vim, VimMode};
+    ///
+    /// let vim = Vim::new();
+    /// assert_eq!(vim.mode(), VimMode::Normal);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current mode. Applications can change the cursor style or the block of the textarea based on it.
+    /// ```
+    /// use tui_textarea::{Input, Key, TextArea, Vim, VimMode, VimOperator};
+    ///
+    /// let mut textarea = TextArea::default();
+    /// let mut vim = Vim::new();
+    ///
+    /// vim.input(&mut textarea, Input { key: Key::Char('d'), ..Input::default() });
+    /// assert_eq!(vim.mode(), VimMode::OperatorPending(VimOperator::Delete));
+    /// vim.input(&mut textarea, Input { key: Key::Esc, ..Input::default() });
+    /// assert_eq!(vim.mode(), VimMode::Normal);
+    /// ```
+    pub fn mode(&self) -> VimMode {
+        self.mode
+    }
+
+    /// Get the command line being input in command-line mode. This returns `None` when not in command-line mode.
+    /// ```
+    /// use tui_textarea::{Input, Key, TextArea, Vim};
+    ///
+    /// let mut textarea = TextArea::default();
+    /// let mut vim = Vim::new();
+    ///
+    /// assert_eq!(vim.command_line(), None);
+    /// vim.input(&mut textarea, Input { key: Key::Char(':'), ..Input::default() });
+    /// vim.input(&mut textarea, Input { key: Key::Char('w'), ..Input::default() });
+    /// assert_eq!(vim.command_line(), Some("w"));
+    /// ```
+    pub fn command_line(&self) -> Option<&str> {
+        (self.mode == VimMode::Command).then_some(self.command_line.as_str())
+    }
+
+    /// Get the content of the register. `'"'` is the unnamed register, `'0'` is the yank register, and `'a'` to `'z'`
+    /// are the named registers.
+    /// ```
+    /// use tui_textarea::{Input, Key, TextArea, Vim};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    /// let mut vim = Vim::new();
+    ///
+    /// for c in "\"ayy".chars() {
+    ///     vim.input(&mut textarea, Input { key: Key::Char(c), ..Input::default() });
+    /// }
+    /// let reg = vim.register('a').unwrap();
+    /// assert_eq!(reg.text, "abc");
+    /// assert!(reg.linewise);
+    /// ```
+    pub fn register(&self, name: char) -> Option<&VimRegister> {
+        self.registers.get(&name.to_ascii_lowercase())
+    }
+
+    /// Set the content of the register. For example, the content of the system clipboard can be put with `p` by
+    /// setting it to the unnamed register `'"'`.
+    /// ```
+    /// use tui_textarea::{Input, Key, TextArea, Vim, VimRegister};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    /// let mut vim = Vim::new();
+    ///
+    /// vim.set_register('"', VimRegister { text: "xyz".into(), linewise: true });
+    /// vim.input(&mut textarea, Input { key: Key::Char('p'), ..Input::default() });
+    /// assert_eq!(textarea.lines(), ["abc", "xyz"]);
+    /// ```
+    pub fn set_register(&mut self, name: char, register: VimRegister) {
+        self.registers.insert(name.to_ascii_lowercase(), register);
+    }
+
+    /// Handle an input and update the textarea. The returned [`VimEvent`] tells whether the text was modified or a
+    /// command line was entered.
+    /// ```
+    /// use tui_textarea::{Input, Key, TextArea, Vim, VimEvent};
+    ///
+    /// let mut textarea = TextArea::from(["abc"]);
+    /// let mut vim = Vim::new();
+    ///
+    /// let key = |c| Input { key: Key::Char(c), ..Input::default() };
+    ///
+    /// assert_eq!(vim.input(&mut textarea, key('l')), VimEvent::Nop);
+    /// assert_eq!(textarea.cursor(), (0, 1));
+    /// assert_eq!(vim.input(&mut textarea, key('x')), VimEvent::Modified);
+    /// assert_eq!(textarea.lines(), ["ac"]);
+    /// ```
+    pub fn input<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        input: impl Into<Input>,
+    ) -> VimEvent {
+        let input = input.into();
+        if input.key == Key::Null {
+            return VimEvent::Nop;
+        }
+
+        if !self.replaying {
+            if self.mode == VimMode::Normal && self.is_idle() {
+                self.keys.clear();
+                self.change = false;
+            }
+            self.keys.push(input.clone());
+        }
+
+        let event = match self.mode {
+            VimMode::Insert => self.insert_mode(textarea, input),
+            VimMode::Command => self.command_mode(input),
+            _ => self.normal_mode(textarea, input),
+        };
+
+        if self.mode == VimMode::Normal && self.is_idle() {
+            // Cursor cannot be placed after the last character in normal mode
+            let (row, col) = textarea.cursor();
+            let len = char_len(&textarea.buffer()[row]);
+            if len > 0 && col >= len {
+                textarea.jump_to((row, len - 1));
+            }
+            if !self.replaying && self.change {
+                self.last_change = mem::take(&mut self.keys);
+                self.change = false;
+            }
+        }
+
+        event
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending == Pending::None
+            && self.count.is_none()
+            && self.op_count.is_none()
+            && self.register.is_none()
+    }
+
+    fn reset(&mut self) {
+        self.pending = Pending::None;
+        self.count = None;
+        self.op_count = None;
+        self.register = None;
+        if matches!(self.mode, VimMode::OperatorPending(_)) {
+            self.mode = VimMode::Normal;
+        }
+    }
+
+    fn take_count(&mut self) -> Option<usize> {
+        match (self.count.take(), self.op_count.take()) {
+            (None, None) => None,
+            (c, o) => Some(c.unwrap_or(1).saturating_mul(o.unwrap_or(1))),
+        }
+    }
+
+    fn insert_mode<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        input: Input,
+    ) -> VimEvent {
+        match input {
+            Input { key: Key::Esc, .. }
+            | Input {
+                key: Key::Char('c'),
+                ctrl: true,
+                ..
+            } => {
+                self.mode = VimMode::Normal;
+                textarea.end_transaction();
+                if textarea.cursor().1 > 0 {
+                    textarea.move_cursor(CursorMove::Back);
+                }
+                VimEvent::Nop
+            }
+            input => textarea.input(input).into(),
+        }
+    }
+
+    fn command_mode(&mut self, input: Input) -> VimEvent {
+        match input {
+            Input { key: Key::Esc, .. }
+            | Input {
+                key: Key::Char('c'),
+                ctrl: true,
+                ..
+            } => {
+                self.mode = VimMode::Normal;
+                self.command_line.clear();
+            }
+            Input {
+                key: Key::Enter, ..
+            } => {
+                self.mode = VimMode::Normal;
+                return VimEvent::Command(mem::take(&mut self.command_line));
+            }
+            Input {
+                key: Key::Backspace,
+                ..
+            } => {
+                let popped = self.command_line.pop();
+                if popped.is_none() {
+                    self.mode = VimMode::Normal;
+                }
+            }
+            Input {
+                key: Key::Char(c),
+                ctrl: false,
+                alt: false,
+                ..
+            } => self.command_line.push(c),
+            _ => {}
+        }
+        VimEvent::Nop
+    }
+
+    fn normal_mode<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        input: Input,
+    ) -> VimEvent {
+        let c = match input {
+            Input {
+                key: Key::Char(c),
+                ctrl: false,
+                alt: false,
+                ..
+            } => Some(c),
+            _ => None,
+        };
+
+        match (mem::take(&mut self.pending), c) {
+            (Pending::None, _) => {}
+            (Pending::Register, Some(c)) => {
+                self.register = Some(c);
+                return VimEvent::Nop;
+            }
+            (Pending::G, Some('g')) => {
+                let row = self.take_count().map_or(0, |n| n.saturating_sub(1));
+                return self.motion(textarea, Motion::Line(row), 1);
+            }
+            (Pending::G, Some(c @ ('-' | '+'))) => {
+                let count = self.take_count().unwrap_or(1);
+                self.reset();
+                return if c == '-' {
+                    textarea.undo_earlier(count)
+                } else {
+                    textarea.undo_later(count)
+                }
+                .into();
+            }
+            (Pending::Find(find), Some(c)) => {
+                let count = self.take_count().unwrap_or(1);
+                return self.motion(textarea, Motion::Find(find, c), count);
+            }
+            (Pending::TextObject { around }, Some(c)) => {
+                return self.text_object(textarea, c, around);
+            }
+            (Pending::Replace, Some(c)) => {
+                let count = self.take_count().unwrap_or(1);
+                return self.replace(textarea, c, count);
+            }
+            _ => {
+                self.reset();
+                return VimEvent::Nop;
+            }
+        }
+
+        if input.ctrl && !input.alt {
+            return self.ctrl_key(textarea, input.key);
+        }
+
+        let motion = match input.key {
+            Key::Esc => {
+                self.reset();
+                if matches!(self.mode, VimMode::Visual | VimMode::VisualLine) {
+                    self.mode = VimMode::Normal;
+                    textarea.cancel_selection();
+                }
+                return VimEvent::Nop;
+            }
+            Key::Left => Motion::Left,
+            Key::Right => Motion::Right,
+            Key::Up => Motion::Up,
+            Key::Down => Motion::Down,
+            Key::Home => Motion::LineHead,
+            Key::End => Motion::LineEnd,
+            Key::Char(c) if !input.alt => return self.char_command(textarea, c),
+            _ => {
+                self.reset();
+                return VimEvent::Nop;
+            }
+        };
+        let count = self.take_count().unwrap_or(1);
+        self.motion(textarea, motion, count)
+    }
+
+    fn ctrl_key<B: TextBuffer>(&mut self, textarea: &mut TextArea<'_, B>, key: Key) -> VimEvent {
+        let count = self.take_count().unwrap_or(1);
+        let scrolling = match key {
+            Key::Char('r') if self.mode == VimMode::Normal => {
+                self.reset();
+                let mut modified = false;
+                for _ in 0..count {
+                    modified |= textarea.redo();
+                }
+                return modified.into();
+            }
+            Key::Char('e') => Scrolling::Delta { rows: 1, cols: 0 },
+            Key::Char('y') => Scrolling::Delta { rows: -1, cols: 0 },
+            Key::Char('d') => Scrolling::HalfPageDown,
+            Key::Char('u') => Scrolling::HalfPageUp,
+            Key::Char('f') => Scrolling::PageDown,
+            Key::Char('b') => Scrolling::PageUp,
+            _ => {
+                self.reset();
+                return VimEvent::Nop;
+            }
+        };
+        self.reset();
+        for _ in 0..count {
+            textarea.scroll(scrolling);
+        }
+        self.update_visual(textarea);
+        VimEvent::Nop
+    }
+
+    fn char_command<B: TextBuffer>(&mut self, textarea: &mut TextArea<'_, B>, c: char) -> VimEvent {
+        // Counts
+        if let Some(d) = c.to_digit(10) {
+            let slot = if matches!(self.mode, VimMode::OperatorPending(_)) {
+                &mut self.op_count
+            } else {
+                &mut self.count
+            };
+            if d != 0 || slot.is_some() {
+                *slot = Some(
+                    slot.unwrap_or(0)
+                        .saturating_mul(10)
+                        .saturating_add(d as usize),
+                );
+                return VimEvent::Nop;
+            }
+        }
+
+        // Motions
+        let motion = match c {
+            'h' => Some(Motion::Left),
+            'l' | ' ' => Some(Motion::Right),
+            'j' => Some(Motion::Down),
+            'k' => Some(Motion::Up),
+            'w' => Some(Motion::WordForward),
+            'e' => Some(Motion::WordEnd),
+            'b' => Some(Motion::WordBack),
+            '0' => Some(Motion::LineHead),
+            '^' => Some(Motion::FirstNonBlank),
+            '$' => Some(Motion::LineEnd),
+            '}' => Some(Motion::ParagraphForward),
+            '{' => Some(Motion::ParagraphBack),
+            'G' => {
+                let last = textarea.buffer().len() - 1;
+                let row = self.take_count().map_or(last, |n| n.saturating_sub(1));
+                return self.motion(textarea, Motion::Line(row), 1);
+            }
+            _ => None,
+        };
+        if let Some(motion) = motion {
+            let count = self.take_count().unwrap_or(1);
+            return self.motion(textarea, motion, count);
+        }
+
+        let find = match c {
+            'f' => Some(Find::Forward),
+            'F' => Some(Find::Backward),
+            't' => Some(Find::TillForward),
+            'T' => Some(Find::TillBack),
+            _ => None,
+        };
+        if let Some(find) = find {
+            self.pending = Pending::Find(find);
+            return VimEvent::Nop;
+        }
+        if c == 'g' {
+            self.pending = Pending::G;
+            return VimEvent::Nop;
+        }
+
+        match self.mode {
+            VimMode::OperatorPending(op) => self.operator_pending_command(textarea, op, c),
+            VimMode::Visual | VimMode::VisualLine => self.visual_command(textarea, c),
+            _ => self.normal_command(textarea, c),
+        }
+    }
+
+    fn operator_pending_command<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        op: VimOperator,
+        c: char,
+    ) -> VimEvent {
+        match (op, c) {
+            (_, 'i' | 'a') => {
+                self.pending = Pending::TextObject { around: c == 'a' };
+                VimEvent::Nop
+            }
+            (VimOperator::Delete, 'd') | (VimOperator::Change, 'c') | (VimOperator::Yank, 'y') => {
+                let count = self.take_count().unwrap_or(1);
+                let row = textarea.cursor().0;
+                let last = cmp::min(row + count - 1, textarea.buffer().len() - 1);
+                self.apply(textarea, op, Range::Lines(row, last))
+            }
+            _ => {
+                self.reset();
+                VimEvent::Nop
+            }
+        }
+    }
+
+    fn visual_command<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        c: char,
+    ) -> VimEvent {
+        let op = match c {
+            'i' | 'a' => {
+                self.pending = Pending::TextObject { around: c == 'a' };
+                return VimEvent::Nop;
+            }
+            'v' | 'V' => {
+                let mode = if c == 'v' {
+                    VimMode::Visual
+                } else {
+                    VimMode::VisualLine
+                };
+                self.reset();
+                if self.mode == mode {
+                    self.mode = VimMode::Normal;
+                    textarea.cancel_selection();
+                } else {
+                    self.mode = mode;
+                    textarea.select_range(self.visual_anchor, textarea.cursor());
+                    self.update_visual(textarea);
+                }
+                return VimEvent::Nop;
+            }
+            'o' => {
+                self.reset();
+                let cursor = textarea.cursor();
+                textarea.select_range(cursor, self.visual_anchor);
+                self.visual_anchor = cursor;
+                self.update_visual(textarea);
+                return VimEvent::Nop;
+            }
+            'd' | 'x' => VimOperator::Delete,
+            'c' | 's' => VimOperator::Change,
+            'y' => VimOperator::Yank,
+            _ => {
+                self.reset();
+                return VimEvent::Nop;
+            }
+        };
+
+        self.count = None;
+        let cursor = textarea.cursor();
+        let range = if self.mode == VimMode::VisualLine {
+            let (a, b) = (self.visual_anchor.0, cursor.0);
+            Range::Lines(cmp::min(a, b), cmp::max(a, b))
+        } else {
+            let (start, end) = (
+                cmp::min(self.visual_anchor, cursor),
+                cmp::max(self.visual_anchor, cursor),
+            );
+            Range::Chars(start, Self::next_char(textarea, end)) // Vim's text selection is inclusive
+        };
+        textarea.cancel_selection();
+        let event = self.apply(textarea, op, range);
+        self.change = false; // Changes in visual mode are not repeated by `.`
+        event
+    }
+
+    fn normal_command<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        c: char,
+    ) -> VimEvent {
+        let (row, col) = textarea.cursor();
+        let line_len = char_len(&textarea.buffer()[row]);
+        match c {
+            'd' => self.mode = VimMode::OperatorPending(VimOperator::Delete),
+            'c' => self.mode = VimMode::OperatorPending(VimOperator::Change),
+            'y' => self.mode = VimMode::OperatorPending(VimOperator::Yank),
+            'x' | 'X' | 'D' | 'C' | 's' => {
+                let (op, motion) = match c {
+                    'x' => (VimOperator::Delete, Motion::Right),
+                    'X' => (VimOperator::Delete, Motion::Left),
+                    'D' => (VimOperator::Delete, Motion::LineEnd),
+                    'C' => (VimOperator::Change, Motion::LineEnd),
+                    _ => (VimOperator::Change, Motion::Right),
+                };
+                self.mode = VimMode::OperatorPending(op);
+                let count = self.take_count().unwrap_or(1);
+                return self.motion(textarea, motion, count);
+            }
+            'S' => return self.operator_pending_command(textarea, VimOperator::Change, 'c'),
+            'Y' => return self.operator_pending_command(textarea, VimOperator::Yank, 'y'),
+            'p' | 'P' => {
+                let count = self.take_count().unwrap_or(1);
+                return self.put(textarea, c == 'P', count);
+            }
+            'r' => self.pending = Pending::Replace,
+            'J' => {
+                let count = self.take_count().unwrap_or(1);
+                return self.join(textarea, cmp::max(count, 2) - 1);
+            }
+            'i' | 'a' | 'I' | 'A' | 'o' | 'O' => {
+                self.reset();
+                self.change = true;
+                self.mode = VimMode::Insert;
+                textarea.begin_transaction();
+                match c {
+                    'a' if col < line_len => textarea.move_cursor(CursorMove::Forward),
+                    'I' => {
+                        let col = first_non_blank(&textarea.buffer()[row]);
+                        textarea.jump_to((row, col));
+                    }
+                    'A' => textarea.move_cursor(CursorMove::End),
+                    'o' => {
+                        textarea.move_cursor(CursorMove::End);
+                        textarea.insert_newline();
+                        return VimEvent::Modified;
+                    }
+                    'O' => {
+                        textarea.move_cursor(CursorMove::Head);
+                        textarea.insert_newline();
+                        textarea.move_cursor(CursorMove::Up);
+                        return VimEvent::Modified;
+                    }
+                    _ => {}
+                }
+            }
+            'u' => {
+                let count = self.take_count().unwrap_or(1);
+                self.reset();
+                let mut modified = false;
+                for _ in 0..count {
+                    modified |= textarea.undo();
+                }
+                return modified.into();
+            }
+            '.' => {
+                let count = self.take_count().unwrap_or(1);
+                self.reset();
+                return self.repeat(textarea, count);
+            }
+            ':' => {
+                self.reset();
+                self.mode = VimMode::Command;
+                self.command_line.clear();
+            }
+            '"' => self.pending = Pending::Register,
+            'v' | 'V' => {
+                self.reset();
+                self.visual_anchor = textarea.cursor();
+                textarea.start_selection();
+                if c == 'v' {
+                    self.mode = VimMode::Visual;
+                } else {
+                    self.mode = VimMode::VisualLine;
+                    self.update_visual(textarea);
+                }
+            }
+            _ => self.reset(),
+        }
+        VimEvent::Nop
+    }
+
+    // Move the cursor by the motion. Returns if the cursor moved or not.
+    fn move_by<B: TextBuffer>(
+        textarea: &mut TextArea<'_, B>,
+        motion: Motion,
+        count: usize,
+    ) -> bool {
+        let before = textarea.cursor();
+        for i in 0..count {
+            let (row, col) = textarea.cursor();
+            let line = textarea.buffer()[row].clone();
+            let m = match motion {
+                Motion::Left if col > 0 => CursorMove::Back,
+                Motion::Right if col < char_len(&line) => CursorMove::Forward,
+                Motion::Left | Motion::Right => break,
+                Motion::Up => CursorMove::Up,
+                Motion::Down => CursorMove::Down,
+                Motion::WordForward => CursorMove::WordForward,
+                Motion::WordEnd => CursorMove::WordEnd,
+                Motion::WordBack => CursorMove::WordBack,
+                Motion::LineHead => CursorMove::Head,
+                Motion::LineEnd => {
+                    if i > 0 {
+                        textarea.move_cursor(CursorMove::Down);
+                    }
+                    CursorMove::End
+                }
+                Motion::ParagraphForward => CursorMove::ParagraphForward,
+                Motion::ParagraphBack => CursorMove::ParagraphBack,
+                Motion::FirstNonBlank => {
+                    let col = first_non_blank(&line);
+                    textarea.jump_to((row, col));
+                    break;
+                }
+                Motion::Line(row) => {
+                    let row = cmp::min(row, textarea.buffer().len() - 1);
+                    let col = first_non_blank(&textarea.buffer()[row]);
+                    textarea.jump_to((row, col));
+                    break;
+                }
+                Motion::Find(find, target) => match find_in_line(&line, col, find, target) {
+                    Some(col) => {
+                        textarea.jump_to((row, col));
+                        continue;
+                    }
+                    None => break,
+                },
+            };
+            textarea.move_cursor(m);
+        }
+        textarea.cursor() != before
+    }
+
+    fn motion<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        motion: Motion,
+        count: usize,
+    ) -> VimEvent {
+        let VimMode::OperatorPending(op) = self.mode else {
+            Self::move_by(textarea, motion, count);
+            self.update_visual(textarea);
+            return VimEvent::Nop;
+        };
+
+        // `cw` works like `ce`
+        let motion = if op == VimOperator::Change && motion == Motion::WordForward {
+            let (row, col) = textarea.cursor();
+            match textarea.buffer()[row].chars().nth(col) {
+                Some(c) if !c.is_whitespace() => Motion::WordEnd,
+                _ => motion,
+            }
+        } else {
+            motion
+        };
+
+        let start = textarea.cursor();
+        if !Self::move_by(textarea, motion, count) && !matches!(motion, Motion::Line(_)) {
+            if op == VimOperator::Change {
+                // e.g. `s` and `C` on an empty line start insert mode
+                self.reset();
+                self.mode = VimMode::Insert;
+                textarea.begin_transaction();
+                self.change = true;
+            } else {
+                self.reset();
+            }
+            return VimEvent::Nop;
+        }
+        let mut end = textarea.cursor();
+
+        let range = match motion.kind() {
+            MotionKind::Linewise => {
+                Range::Lines(cmp::min(start.0, end.0), cmp::max(start.0, end.0))
+            }
+            MotionKind::Inclusive => {
+                let (start, end) = (cmp::min(start, end), cmp::max(start, end));
+                Range::Chars(start, Self::next_char(textarea, end))
+            }
+            MotionKind::Exclusive => {
+                if motion == Motion::WordForward && end.0 > start.0 {
+                    // `dw` on the last word of line does not delete the newline
+                    let row = end.0 - 1;
+                    end = (row, char_len(&textarea.buffer()[row]));
+                }
+                Range::Chars(cmp::min(start, end), cmp::max(start, end))
+            }
+        };
+        textarea.jump_to(start);
+        self.apply(textarea, op, range)
+    }
+
+    fn next_char<B: TextBuffer>(
+        textarea: &TextArea<'_, B>,
+        (row, col): (usize, usize),
+    ) -> (usize, usize) {
+        if col < char_len(&textarea.buffer()[row]) {
+            (row, col + 1)
+        } else {
+            (row, col)
+        }
+    }
+
+    fn update_visual<B: TextBuffer>(&self, textarea: &mut TextArea<'_, B>) {
+        if self.mode != VimMode::VisualLine {
+            return;
+        }
+        let anchor = self.visual_anchor.0;
+        let row = textarea.cursor().0;
+        let len = |row: usize| char_len(&textarea.buffer()[row]);
+        let (start, end) = if row >= anchor {
+            ((anchor, 0), (row, len(row)))
+        } else {
+            ((anchor, len(anchor)), (row, 0))
+        };
+        textarea.select_range(start, end);
+    }
+
+    fn text_object<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        c: char,
+        around: bool,
+    ) -> VimEvent {
+        self.take_count();
+        let (row, col) = textarea.cursor();
+        let lines = textarea.buffer();
+        let in_line =
+            |range: Option<(usize, usize)>| range.map(|(s, e)| Range::Chars((row, s), (row, e)));
+        let range = match c {
+            'w' => in_line(word_object(&lines[row], col, around)),
+            '"' | '\'' | '`' => in_line(quote_object(&lines[row], col, c, around)),
+            '(' | ')' | 'b' => bracket_object(lines, (row, col), '(', ')', around),
+            '{' | '}' | 'B' => bracket_object(lines, (row, col), '{', '}', around),
+            '[' | ']' => bracket_object(lines, (row, col), '[', ']', around),
+            '<' | '>' => bracket_object(lines, (row, col), '<', '>', around),
+            'p' => Some(paragraph_object(lines, row, around)),
+            _ => None,
+        };
+        let Some(range) = range else {
+            self.reset();
+            return VimEvent::Nop;
+        };
+
+        match self.mode {
+            VimMode::OperatorPending(op) => self.apply(textarea, op, range),
+            VimMode::Visual | VimMode::VisualLine => {
+                match range {
+                    Range::Chars(start, (row, col)) => {
+                        self.mode = VimMode::Visual;
+                        self.visual_anchor = start;
+                        textarea.select_range(start, (row, col.saturating_sub(1)));
+                    }
+                    Range::Lines(first, last) => {
+                        self.mode = VimMode::VisualLine;
+                        self.visual_anchor = (first, 0);
+                        textarea.jump_to((last, 0));
+                        self.update_visual(textarea);
+                    }
+                }
+                self.reset();
+                VimEvent::Nop
+            }
+            _ => VimEvent::Nop,
+        }
+    }
+
+    fn store_register(&mut self, name: Option<char>, op: VimOperator, register: VimRegister) {
+        match name {
+            Some('_') => return,
+            Some(name) if name.is_ascii_uppercase() => {
+                let reg = self.registers.entry(name.to_ascii_lowercase()).or_default();
+                if reg.linewise || register.linewise {
+                    if !reg.text.is_empty() {
+                        reg.text.push('\n');
+                    }
+                    reg.linewise = true;
+                }
+                reg.text.push_str(&register.text);
+                let reg = reg.clone();
+                self.registers.insert('"', reg);
+                return;
+            }
+            Some(name) if name != '"' => {
+                self.registers.insert(name, register.clone());
+            }
+            _ => {
+                if op == VimOperator::Yank {
+                    self.registers.insert('0', register.clone());
+                }
+            }
+        }
+        self.registers.insert('"', register);
+    }
+
+    fn apply<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        op: VimOperator,
+        range: Range,
+    ) -> VimEvent {
+        let name = self.register.take();
+        self.reset();
+        self.mode = VimMode::Normal;
+        if op != VimOperator::Yank {
+            self.change = true;
+        }
+        if op == VimOperator::Change {
+            // Text deleted by `c` is undone together with the following insertion
+            textarea.begin_transaction();
+        }
+
+        let modified = match range {
+            Range::Chars(start, end) => {
+                textarea.select_range(start, end);
+                let modified = if op == VimOperator::Yank {
+                    textarea.copy();
+                    false
+                } else {
+                    textarea.cut()
+                };
+                let text = if start == end {
+                    String::new()
+                } else {
+                    textarea.yank_text()
+                };
+                self.store_register(
+                    name,
+                    op,
+                    VimRegister {
+                        text,
+                        linewise: false,
+                    },
+                );
+                textarea.jump_to(start);
+                modified
+            }
+            Range::Lines(first, last) => {
+                let lines = textarea.buffer();
+                let text = (first..=last)
+                    .map(|row| lines[row].as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let len = |row: usize| char_len(&lines[row]);
+                let (start, end) = match op {
+                    VimOperator::Yank => ((first, 0), (first, 0)),
+                    VimOperator::Change => ((first, 0), (last, len(last))),
+                    VimOperator::Delete if last + 1 < lines.len() => ((first, 0), (last + 1, 0)),
+                    VimOperator::Delete if first > 0 => {
+                        ((first - 1, len(first - 1)), (last, len(last)))
+                    }
+                    VimOperator::Delete => ((first, 0), (last, len(last))),
+                };
+                self.store_register(
+                    name,
+                    op,
+                    VimRegister {
+                        text,
+                        linewise: true,
+                    },
+                );
+                if start == end {
+                    let col = textarea.cursor().1;
+                    textarea.jump_to((first, col));
+                    false
+                } else {
+                    textarea.select_range(start, end);
+                    let modified = textarea.cut();
+                    if op == VimOperator::Delete {
+                        let row = cmp::min(first, textarea.buffer().len() - 1);
+                        let col = first_non_blank(&textarea.buffer()[row]);
+                        textarea.jump_to((row, col));
+                    }
+                    modified
+                }
+            }
+        };
+
+        if op == VimOperator::Change {
+            self.mode = VimMode::Insert;
+        }
+        modified.into()
+    }
+
+    fn put<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        before: bool,
+        count: usize,
+    ) -> VimEvent {
+        let name = self.register.take().unwrap_or('"');
+        self.reset();
+        let Some(reg) = self.register(name).cloned() else {
+            return VimEvent::Nop;
+        };
+        self.change = true;
+
+        let (row, col) = textarea.cursor();
+        let modified = if reg.linewise {
+            let text = vec![reg.text.as_str(); count].join("\n");
+            let (modified, row) = if before {
+                textarea.jump_to((row, 0));
+                (textarea.insert_str(format!("{text}\n")), row)
+            } else {
+                textarea.move_cursor(CursorMove::End);
+                (textarea.insert_str(format!("\n{text}")), row + 1)
+            };
+            let col = first_non_blank(&textarea.buffer()[row]);
+            textarea.jump_to((row, col));
+            modified
+        } else {
+            if !before && col < char_len(&textarea.buffer()[row]) {
+                textarea.move_cursor(CursorMove::Forward);
+            }
+            let modified = textarea.insert_str(reg.text.repeat(count));
+            if modified && textarea.cursor().1 > 0 {
+                textarea.move_cursor(CursorMove::Back);
+            }
+            modified
+        };
+        modified.into()
+    }
+
+    fn replace<B: TextBuffer>(
+        &mut self,
+        textarea: &mut TextArea<'_, B>,
+        c: char,
+        count: usize,
+    ) -> VimEvent {
+        self.reset();
+        let (row, col) = textarea.cursor();
+        if col + count > char_len(&textarea.buffer()[row]) {
+            return VimEvent::Nop;
+        }
+        self.change = true;
+        textarea.select_range((row, col), (row, col + count));
+        textarea.insert_str(c.to_string().repeat(count));
+        textarea.move_cursor(CursorMove::Back);
+        VimEvent::Modified
+    }
+
+    fn join<B: TextBuffer>(&mut self, textarea: &mut TextArea<'_, B>, count: usize) -> VimEvent {
+        self.reset();
+        let mut modified = false;
+        for _ in 0..count {
+            let row = textarea.cursor().0;
+            let lines = textarea.buffer();
+            if row + 1 >= lines.len() {
+                break;
+            }
+            let (line, next) = (&lines[row], &lines[row + 1]);
+            let len = char_len(line);
+            let indent = next.chars().take_while(|c| c.is_whitespace()).count();
+            let sep = if is_blank(line) || is_blank(next) || line.ends_with(' ') {
+                ""
+            } else {
+                " "
+            };
+            textarea.select_range((row, len), (row + 1, indent));
+            textarea.insert_str(sep);
+            textarea.jump_to((row, len));
+            modified = true;
+        }
+        if modified {
+            self.change = true;
+        }
+        modified.into()
+    }
+
+    fn repeat<B: TextBuffer>(&mut self, textarea: &mut TextArea<'_, B>, count: usize) -> VimEvent {
+        if self.replaying {
+            return VimEvent::Nop;
+        }
+        let keys = self.last_change.clone();
+        let mut modified = false;
+        self.replaying = true;
+        for _ in 0..count {
+            for input in keys.iter().cloned() {
+                modified |= self.input(textarea, input) == VimEvent::Modified;
+            }
+        }
+        self.replaying = false;
+        self.change = false;
+        modified.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_objects() {
+        let tests = [
+            ("foo bar baz", 5, false, Some((4, 7))),
+            ("foo bar baz", 5, true, Some((4, 8))),
+            ("foo bar", 5, true, Some((3, 7))),
+            ("foo  bar", 3, true, Some((3, 8))),
+            ("foo.bar", 3, false, Some((3, 4))),
+            ("", 0, false, None),
+        ];
+        for (line, col, around, want) in tests {
+            assert_eq!(
+                word_object(line, col, around),
+                want,
+                "{:?}",
+                (line, col, around)
+            );
+        }
+    }
+
+    #[test]
+    fn quote_objects() {
+        let tests = [
+            (r#"a "b c" d"#, 4, false, Some((3, 6))),
+            (r#"a "b c" d"#, 4, true, Some((2, 8))),
+            (r#"a "b c" d"#, 0, false, Some((3, 6))),
+            (r#"a "b c""#, 2, true, Some((1, 7))),
+            (r#""a\"b""#, 2, false, Some((1, 5))),
+            (r#""a" b"#, 4, false, None),
+        ];
+        for (line, col, around, want) in tests {
+            assert_eq!(
+                quote_object(line, col, '"', around),
+                want,
+                "{:?}",
+                (line, col, around)
+            );
+        }
+    }
+
+    #[test]
+    fn bracket_objects() {
+        let lines = vec![
+            "f(a, (b), c)".to_string(),
+            "{".to_string(),
+            "  x".to_string(),
+            "}".to_string(),
+        ];
+        let tests = [
+            ((0, 3), '(', ')', false, Some(Range::Chars((0, 2), (0, 11)))),
+            ((0, 3), '(', ')', true, Some(Range::Chars((0, 1), (0, 12)))),
+            ((0, 6), '(', ')', false, Some(Range::Chars((0, 6), (0, 7)))),
+            ((0, 7), '(', ')', false, Some(Range::Chars((0, 6), (0, 7)))),
+            ((0, 9), '(', ')', false, Some(Range::Chars((0, 2), (0, 11)))),
+            ((0, 0), '(', ')', false, None),
+            ((2, 2), '{', '}', false, Some(Range::Chars((1, 1), (3, 0)))),
+            ((3, 0), '{', '}', true, Some(Range::Chars((1, 0), (3, 1)))),
+        ];
+        for (cursor, open, close, around, want) in tests {
+            assert_eq!(
+                bracket_object(&lines, cursor, open, close, around),
+                want,
+                "{:?}",
+                (cursor, open, around)
+            );
+        }
+    }
+
+    #[test]
+    fn paragraph_objects() {
+        let lines: Vec<String> = ["a", "b", "", "", "c", "d"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let tests = [
+            (0, false, Range::Lines(0, 1)),
+            (1, true, Range::Lines(0, 3)),
+            (2, false, Range::Lines(2, 3)),
+            (3, true, Range::Lines(2, 5)),
+            (5, true, Range::Lines(2, 5)),
+        ];
+        for (row, around, want) in tests {
+            assert_eq!(
+                paragraph_object(&lines, row, around),
+                want,
+                "{:?}",
+                (row, around)
+            );
+        }
+    }
+
+    #[test]
+    fn find_char_in_line() {
+        let tests = [
+            (0, Find::Forward, 'c', Some(4)),
+            (0, Find::TillForward, 'c', Some(3)),
+            (4, Find::Backward, 'a', Some(0)),
+            (4, Find::TillBack, 'a', Some(1)),
+            (3, Find::TillForward, 'c', Some(3)),
+            (0, Find::TillForward, 'x', None),
+            (4, Find::Forward, 'c', None),
+        ];
+        for (col, find, c, want) in tests {
+            assert_eq!(
+                find_in_line("abbbc", col, find, c),
+                want,
+                "{:?}",
+                (col, find, c)
+            );
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::history::LineChange;
+use crate::ratatui::style::Style;
+use unicode_width::UnicodeWidthStr as _;
+
+/// Text rendered in a line without being a part of the text, such as type hints, ghost text of autocompletion, or
+/// inline placeholders of form fields. Virtual texts are added by
+/// [`TextArea::add_virtual_text`](crate::TextArea::add_virtual_text). The text is rendered before the character at
+/// the 0-base `(row, col)` position. When the column is at the end of the line, the text is rendered after the end of
+/// the line. The cursor skips over virtual texts and they are not included in [`TextArea::lines`](crate::TextArea::lines).
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tui_textarea::VirtualText;
+///
+/// let hint = VirtualText::new((0, 5), ": i32", Style::default().fg(Color::DarkGray));
+/// assert_eq!(hint.pos, (0, 5));
+/// assert_eq!(hint.text, ": i32");
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VirtualText {
+    /// Position where the text is rendered.
+    pub pos: (usize, usize),
+    /// Text to render. It should not contain newlines.
+    pub text: String,
+    /// Style of the text.
+    pub style: Style,
+}
+
+impl VirtualText {
+    /// Create a new virtual text rendered at the position.
+    pub fn new(pos: (usize, usize), text: impl Into<String>, style: Style) -> Self {
+        Self {
+            pos,
+            text: text.into(),
+            style,
+        }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.text.width()
+    }
+
+    // Shift the row of the virtual text placed after the edited lines. Virtual texts in the edited lines are kept
+    // as-is like diagnostics.
+    pub(crate) fn shift(&mut self, change: LineChange) {
+        let LineChange {
+            row,
+            removed,
+            inserted,
+        } = change;
+        if self.pos.0 >= row + removed {
+            self.pos.0 = self.pos.0 + inserted - removed;
+        }
+    }
+}
+
+// Byte offsets and display widths of the virtual texts in the line sorted by the offsets. Columns beyond the end of
+// the line are clamped.
+pub(crate) fn virtual_widths(texts: &[VirtualText], row: usize, line: &str) -> Vec<(usize, usize)> {
+    let mut widths: Vec<_> = texts
+        .iter()
+        .filter(|t| t.pos.0 == row)
+        .map(|t| {
+            let offset = line
+                .char_indices()
+                .nth(t.pos.1)
+                .map_or(line.len(), |(i, _)| i);
+            (offset, t.width())
+        })
+        .collect();
+    widths.sort_by_key(|&(offset, _)| offset);
+    widths
+}
+
+// Display width of the virtual texts rendered before the character at the column
+pub(crate) fn width_before(texts: &[VirtualText], row: usize, col: usize) -> usize {
+    texts
+        .iter()
+        .filter(|t| t.pos.0 == row && t.pos.1 <= col)
+        .map(VirtualText::width)
+        .sum()
+}
@@ -1,11 +1,12 @@
+use crate::buffer::TextBuffer;
 use crate::ratatui::buffer::Buffer;
 use crate::ratatui::layout::Rect;
 use crate::ratatui::text::{Span, Text};
 use crate::ratatui::widgets::{Paragraph, Widget};
 use crate::textarea::TextArea;
-use crate::util::num_digits;
-use crate::wrap::{WrapMode, cursor_visual_row, effective_wrap_width, wrapped_rows};
-use portable_atomic::{AtomicU64, Ordering};
+use crate::virtual_text::width_before;
+use crate::wrap::{WrapMode, WrappedLine, cursor_visual_row};
+use portable_atomic::{AtomicU32, AtomicU64, Ordering};
 #[cfg(feature = "ratatui")]
 use ratatui_core::text::Line;
 use std::cmp;
@@ -21,13 +22,17 @@ use unicode_width::UnicodeWidthChar;
 // point we stick with using `ratatui::Frame::render_widget` because it is simpler API. Users don't need to
 // manage states of textarea instances separately.
 // https://docs.rs/ratatui/latest/ratatui/terminal/struct.Frame.html#method.render_stateful_widget
+//
+// The second value remembers the screen position of the top-left corner of the text area to map mouse positions to
+// the buffer.
 #[derive(Default, Debug)]
-pub struct Viewport(AtomicU64);
+pub struct Viewport(AtomicU64, AtomicU32);
 
 impl Clone for Viewport {
     fn clone(&self) -> Self {
         let u = self.0.load(Ordering::Relaxed);
-        Viewport(AtomicU64::new(u))
+        let o = self.1.load(Ordering::Relaxed);
+        Viewport(AtomicU64::new(u), AtomicU32::new(o))
     }
 }
 
@@ -59,6 +64,16 @@ impl Viewport {
         )
     }
 
+    pub fn origin(&self) -> (u16, u16) {
+        let u = self.1.load(Ordering::Relaxed);
+        ((u >> 16) as u16, u as u16)
+    }
+
+    fn store_origin(&self, x: u16, y: u16) {
+        self.1
+            .store(((x as u32) << 16) | y as u32, Ordering::Relaxed);
+    }
+
     fn store(&self, row: u16, col: u16, width: u16, height: u16) {
         // Pack four u16 values into one u64 value
         let u =
@@ -66,6 +81,11 @@ impl Viewport {
         self.0.store(u, Ordering::Relaxed);
     }
 
+    pub fn set_scroll_top(&mut self, row: u16, col: u16) {
+        let u = self.0.get_mut();
+        *u = (*u & 0xffff_ffff_0000_0000) | ((row as u64) << 16) | (col as u64);
+    }
+
     pub fn scroll(&mut self, rows: i16, cols: i16) {
         fn apply_scroll(pos: u16, delta: i16) -> u16 {
             if delta >= 0 {
@@ -93,14 +113,15 @@ fn next_scroll_top(prev_top: u16, cursor: u16, len: u16) -> u16 {
     }
 }
 
-impl<'a> TextArea<'a> {
+impl<'a, B: TextBuffer> TextArea<'a, B> {
     fn text_widget(&'a self, top_row: usize, height: usize) -> Text<'a> {
-        let lines_len = self.lines().len();
-        let lnum_len = num_digits(lines_len);
+        let buffer = self.buffer();
+        let lines_len = buffer.len();
+        let gutter = self.gutter_width();
         let bottom_row = cmp::min(top_row + height, lines_len);
         let mut lines = Vec::with_capacity(bottom_row - top_row);
-        for (i, line) in self.lines()[top_row..bottom_row].iter().enumerate() {
-            lines.push(self.line_spans(line.as_str(), top_row + i, lnum_len));
+        for row in top_row..bottom_row {
+            lines.push(self.line_spans(buffer[row].as_str(), row, gutter));
         }
         Text::from(lines)
     }
@@ -113,24 +134,15 @@ impl<'a> TextArea<'a> {
 
     fn wrapped_text_widget(
         &'a self,
+        wrapped: Vec<WrappedLine>,
         prev_top_row: u16,
-        width: u16,
         height: u16,
     ) -> (Text<'a>, u16) {
         if height == 0 {
             return (Text::default(), prev_top_row);
         }
 
-        let lines_len = self.lines().len();
-        let lnum_len = num_digits(lines_len);
-        let line_number_len = self.line_number_style().map(|_| lnum_len);
-        let wrap_width = effective_wrap_width(width, line_number_len);
-        let wrapped = wrapped_rows(
-            self.lines(),
-            self.wrap_mode(),
-            wrap_width,
-            self.tab_length(),
-        );
+        let gutter = self.gutter_width();
         if wrapped.is_empty() {
             return (Text::default(), 0);
         }
@@ -142,8 +154,8 @@ impl<'a> TextArea<'a> {
 
         let mut lines = Vec::with_capacity(bottom.saturating_sub(top));
         for row in &wrapped[top..bottom] {
-            let line = &self.lines()[row.row];
-            lines.push(self.line_spans_segment(line, row, lnum_len));
+            let line = &self.buffer()[row.row];
+            lines.push(self.line_spans_segment(line, row, gutter));
         }
 
         (Text::from(lines), top_row)
@@ -157,28 +169,36 @@ impl<'a> TextArea<'a> {
         let (row, col) = self.cursor();
 
         // Adujst the cursor position due to the width of non-latine characters.
-        let mut cursor = self.lines()[row]
+        let mut cursor = self.buffer()[row]
             .chars()
             .take(col)
             .map(|c| c.width().unwrap_or(0))
             .sum::<usize>() as u16;
 
-        // Adjust the cursor position due to the width of line number.
-        if self.line_number_style().is_some() {
-            let lnum = num_digits(self.lines().len()) as u16 + 2; // `+ 2` for margins
-            if cursor <= lnum {
-                cursor *= 2; // Smoothly slide the line number into the screen on scrolling left
+        // Virtual texts before the cursor shift the cursor position
+        cursor += width_before(self.virtual_texts(), row, col) as u16;
+
+        // Adjust the cursor position due to the width of gutter.
+        let gutter = self.gutter_width().total() as u16;
+        if gutter > 0 {
+            if cursor <= gutter {
+                cursor *= 2; // Smoothly slide the gutter into the screen on scrolling left
             } else {
-                cursor += lnum; // The cursor position is shifted by the line number part
+                cursor += gutter; // The cursor position is shifted by the gutter part
             };
         }
         next_scroll_top(prev_top, cursor, width)
     }
 }
 
-impl Widget for &TextArea<'_> {
+impl<B: TextBuffer> Widget for &TextArea<'_, B> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let Rect { width, height, .. } = if let Some(b) = self.block() {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = if let Some(b) = self.block() {
             b.inner(area)
         } else {
             area
@@ -187,7 +207,16 @@ impl Widget for &TextArea<'_> {
         let (prev_top_row, prev_top_col) = self.viewport.scroll_top();
         let (text, style, top_row, top_col) = if !self.placeholder.is_empty() && self.is_empty() {
             (self.placeholder_widget(), self.placeholder_style, 0, 0)
-        } else if self.wrap_mode() == WrapMode::None {
+        } else if let Some(wrapped) = self.visual_rows(width) {
+            let (text, top_row) = self.wrapped_text_widget(wrapped, prev_top_row, height);
+            // Folded lines without soft-wrapping can still be scrolled horizontally
+            let top_col = if self.wrap_mode() == WrapMode::None {
+                self.scroll_top_col(prev_top_col, width)
+            } else {
+                0
+            };
+            (text, self.style(), top_row, top_col)
+        } else {
             let top_row = self.scroll_top_row(prev_top_row, height);
             let top_col = self.scroll_top_col(prev_top_col, width);
             (
@@ -196,9 +225,6 @@ impl Widget for &TextArea<'_> {
                 top_row,
                 top_col,
             )
-        } else {
-            let (text, top_row) = self.wrapped_text_widget(prev_top_row, width, height);
-            (text, self.style(), top_row, 0)
         };
 
         // To get fine control over the text color and the surrrounding block they have to be rendered separately
@@ -211,9 +237,13 @@ impl Widget for &TextArea<'_> {
             text_area = b.inner(area);
             // ratatui does not need `clone()` call because `Block` implements `WidgetRef` and `&T` implements `Widget`
             // where `T: WidgetRef`. So `b.render` internally calls `b.render_ref` and it doesn't move out `self`.
-            #[cfg(feature = "tuirs")]
-            let b = b.clone();
-            b.render(area, buf)
+            if self.validation_error().is_some() {
+                b.clone().border_style(self.error_style()).render(area, buf);
+            } else {
+                #[cfg(feature = "tuirs")]
+                let b = b.clone();
+                b.render(area, buf);
+            }
         }
         if top_col != 0 {
             inner = inner.scroll((0, top_col));
@@ -221,6 +251,7 @@ impl Widget for &TextArea<'_> {
 
         // Store scroll top position for rendering on the next tick
         self.viewport.store(top_row, top_col, width, height);
+        self.viewport.store_origin(x, y);
 
         inner.render(text_area, buf);
     }
@@ -81,3 +81,31 @@ pub fn find_word_start_backward(line: &str, start_col: usize) -> Option<usize> {
     }
     (cur != CharKind::Space).then_some(0)
 }
+
+pub fn find_word_range(line: &str, col: usize) -> Option<(usize, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let col = col.min(chars.len().checked_sub(1)?);
+    let kind = CharKind::new(chars[col]);
+    let start = chars[..col]
+        .iter()
+        .rposition(|c| CharKind::new(*c) != kind)
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let end = chars[col..]
+        .iter()
+        .position(|c| CharKind::new(*c) != kind)
+        .map(|i| col + i)
+        .unwrap_or(chars.len());
+    Some((start, end))
+}
+
+// Start column of the word which ends at the column. When no word character is before the column, the column itself is
+// returned.
+pub fn find_word_prefix_start(line: &str, col: usize) -> usize {
+    let chars: Vec<char> = line.chars().take(col).collect();
+    chars
+        .iter()
+        .rposition(|c| CharKind::new(*c) != CharKind::Other)
+        .map(|i| i + 1)
+        .unwrap_or(0)
+}
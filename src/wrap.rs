@@ -1,3 +1,5 @@
+use crate::buffer::TextBuffer;
+use crate::virtual_text::{VirtualText, virtual_widths};
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
 #[cfg(feature = "serde")]
@@ -29,6 +31,8 @@ pub(crate) struct WrappedLine {
     pub end_col: usize,
     pub first_in_row: bool,
     pub last_in_row: bool,
+    // Number of lines in the fold when this row is the placeholder of folded lines
+    pub folded: usize,
 }
 
 #[derive(Clone, Copy)]
@@ -37,9 +41,9 @@ struct Chunk {
     end: usize,
 }
 
-pub(crate) fn effective_wrap_width(total_width: u16, line_number_len: Option<u8>) -> usize {
+pub(crate) fn effective_wrap_width(total_width: u16, gutter_width: usize) -> usize {
     let total_width = total_width as usize;
-    let reserved = line_number_len.map(|len| len as usize + 2).unwrap_or(0);
+    let reserved = gutter_width;
     if total_width > reserved {
         total_width - reserved
     } else {
@@ -48,15 +52,21 @@ pub(crate) fn effective_wrap_width(total_width: u16, line_number_len: Option<u8>
 }
 
 pub(crate) fn wrapped_rows(
-    lines: &[String],
+    lines: &impl TextBuffer,
     mode: WrapMode,
     width: usize,
     tab_len: u8,
+    virtual_texts: &[VirtualText],
 ) -> Vec<WrappedLine> {
     let mut rows = Vec::new();
 
     for (row, line) in lines.iter().enumerate() {
-        let ranges = line_ranges(line, mode, width, tab_len);
+        let virt = if virtual_texts.is_empty() || mode == WrapMode::None {
+            vec![]
+        } else {
+            virtual_widths(virtual_texts, row, line)
+        };
+        let ranges = line_ranges(line, mode, width, tab_len, &virt);
         let mut start_col = 0usize;
         for (i, (start_byte, end_byte)) in ranges.iter().copied().enumerate() {
             let end_col = start_col + line[start_byte..end_byte].chars().count();
@@ -68,6 +78,7 @@ pub(crate) fn wrapped_rows(
                 end_col,
                 first_in_row: i == 0,
                 last_in_row: i + 1 == ranges.len(),
+                folded: 0,
             });
             start_col = end_col;
         }
@@ -97,7 +108,7 @@ pub(crate) fn cursor_visual_row(rows: &[WrappedLine], cursor: (usize, usize)) ->
 }
 
 pub(crate) fn cursor_at_visual_row(
-    lines: &[String],
+    lines: &impl TextBuffer,
     rows: &[WrappedLine],
     cursor: (usize, usize),
     visual_row: usize,
@@ -130,11 +141,13 @@ pub(crate) fn cursor_at_visual_row(
     (target.row, col)
 }
 
+// `virt` is the byte offsets and the display widths of virtual texts in the line. See `virtual_widths`.
 pub(crate) fn line_ranges(
     line: &str,
     mode: WrapMode,
     width: usize,
     tab_len: u8,
+    virt: &[(usize, usize)],
 ) -> Vec<(usize, usize)> {
     if mode == WrapMode::None {
         return vec![(0, line.len())];
@@ -145,11 +158,11 @@ pub(crate) fn line_ranges(
         WrapMode::None => vec![(0, line.len())],
         WrapMode::Glyph => {
             let mut chunks = Vec::new();
-            split_range_by_grapheme_width(line, 0, line.len(), width, tab_len, &mut chunks);
+            split_range_by_grapheme_width(line, 0, line.len(), width, tab_len, virt, &mut chunks);
             chunks
         }
-        WrapMode::Word => wrap_word_chunks(line, width, tab_len, false),
-        WrapMode::WordOrGlyph => wrap_word_chunks(line, width, tab_len, true),
+        WrapMode::Word => wrap_word_chunks(line, width, tab_len, virt, false),
+        WrapMode::WordOrGlyph => wrap_word_chunks(line, width, tab_len, virt, true),
     };
 
     if out.is_empty() {
@@ -162,6 +175,7 @@ fn wrap_word_chunks(
     line: &str,
     width: usize,
     tab_len: u8,
+    virt: &[(usize, usize)],
     fallback_to_glyph: bool,
 ) -> Vec<(usize, usize)> {
     let chunks: Vec<_> = UnicodeSegmentation::split_word_bound_indices(line)
@@ -187,7 +201,8 @@ fn wrap_word_chunks(
             seg_start = chunk.start;
         }
 
-        let chunk_width = display_width_from(chunk_text(line, chunk), seg_width, tab_len);
+        let chunk_width = display_width_from(chunk_text(line, chunk), seg_width, tab_len)
+            + virtual_width(virt, chunk.start, chunk.end);
         if seg_width + chunk_width <= width {
             seg_end = chunk.end;
             seg_width += chunk_width;
@@ -203,7 +218,15 @@ fn wrap_word_chunks(
         }
 
         if fallback_to_glyph {
-            split_range_by_grapheme_width(line, chunk.start, chunk.end, width, tab_len, &mut out);
+            split_range_by_grapheme_width(
+                line,
+                chunk.start,
+                chunk.end,
+                width,
+                tab_len,
+                virt,
+                &mut out,
+            );
         } else {
             out.push((chunk.start, chunk.end));
         }
@@ -227,6 +250,7 @@ fn split_range_by_grapheme_width(
     end: usize,
     width: usize,
     tab_len: u8,
+    virt: &[(usize, usize)],
     out: &mut Vec<(usize, usize)>,
 ) {
     let mut segment_start = start;
@@ -239,7 +263,8 @@ fn split_range_by_grapheme_width(
         {
             let grapheme_start = segment_start + offset;
             let grapheme_end = grapheme_start + grapheme.len();
-            let next_width = display_width_to(grapheme, segment_width, tab_len);
+            let next_width = display_width_to(grapheme, segment_width, tab_len)
+                + virtual_width(virt, grapheme_start, grapheme_end);
             let grapheme_width = next_width.saturating_sub(segment_width);
 
             if segment_end != segment_start && segment_width + grapheme_width > width {
@@ -266,6 +291,14 @@ fn split_range_by_grapheme_width(
     }
 }
 
+// Width of the virtual texts rendered before the characters in the byte range
+fn virtual_width(virt: &[(usize, usize)], start: usize, end: usize) -> usize {
+    virt.iter()
+        .filter(|&&(offset, _)| start <= offset && offset < end)
+        .map(|&(_, width)| width)
+        .sum()
+}
+
 #[inline]
 fn chunk_text(line: &str, chunk: Chunk) -> &str {
     &line[chunk.start..chunk.end]
@@ -275,6 +308,54 @@ fn display_width_from(text: &str, start_width: usize, tab_len: u8) -> usize {
     display_width_to(text, start_width, tab_len).saturating_sub(start_width)
 }
 
+/// Return the character column in `text` rendered at the `target` display column. When `target` is beyond the end of
+/// the text, the number of characters is returned. `virt` is the character columns and the display widths of virtual
+/// texts rendered before the characters. Targets on a virtual text point to the character after it.
+pub(crate) fn col_at_display_width(
+    text: &str,
+    target: usize,
+    tab_len: u8,
+    virt: &[(usize, usize)],
+) -> usize {
+    let mut width = 0;
+    for (col, c) in text.chars().enumerate() {
+        width += virtual_width(virt, col, col + 1);
+        let next = display_width_to(c.encode_utf8(&mut [0; 4]), width, tab_len);
+        if target < next {
+            return col;
+        }
+        width = next;
+    }
+    text.chars().count()
+}
+
+/// Return the display width of `text` before the character at `col`. This is the inverse of [`col_at_display_width`].
+/// Virtual texts rendered before the character at `col` are included.
+pub(crate) fn display_width_at_col(
+    text: &str,
+    col: usize,
+    tab_len: u8,
+    virt: &[(usize, usize)],
+) -> usize {
+    let mut width = 0;
+    for (i, c) in text.chars().enumerate().take(col) {
+        width += virtual_width(virt, i, i + 1);
+        width = display_width_to(c.encode_utf8(&mut [0; 4]), width, tab_len);
+    }
+    width + virtual_width(virt, col, col + 1)
+}
+
+/// Return the column of the first character in `text` rendered at or after the `target` display column. Unlike
+/// [`col_at_display_width`], a wide character or a tab covering the target from the left is skipped.
+pub(crate) fn col_after_display_width(text: &str, target: usize, tab_len: u8) -> usize {
+    let col = col_at_display_width(text, target, tab_len, &[]);
+    if col < text.chars().count() && display_width_at_col(text, col, tab_len, &[]) < target {
+        col + 1
+    } else {
+        col
+    }
+}
+
 fn display_width_to(text: &str, mut width: usize, tab_len: u8) -> usize {
     for c in text.chars() {
         if c == '\t' {
@@ -295,12 +376,39 @@ mod tests {
     use super::*;
 
     fn segments(line: &str, mode: WrapMode, width: usize) -> Vec<&str> {
-        line_ranges(line, mode, width, 4)
+        line_ranges(line, mode, width, 4, &[])
             .into_iter()
             .map(|(s, e)| &line[s..e])
             .collect()
     }
 
+    #[test]
+    fn wrap_with_virtual_widths() {
+        let ranges = |mode, virt: &[(usize, usize)]| -> Vec<(usize, usize)> {
+            line_ranges("abcd efgh", mode, 6, 4, virt)
+        };
+        assert_eq!(ranges(WrapMode::Glyph, &[]), [(0, 6), (6, 9)]);
+        assert_eq!(ranges(WrapMode::Glyph, &[(1, 3)]), [(0, 3), (3, 9)]);
+        assert_eq!(ranges(WrapMode::Word, &[(5, 2)]), [(0, 5), (5, 9)]);
+        // Virtual text at the end of line doesn't affect wrapping
+        assert_eq!(ranges(WrapMode::Glyph, &[(9, 10)]), [(0, 6), (6, 9)]);
+
+        assert_eq!(col_at_display_width("abcd", 2, 4, &[]), 2);
+        assert_eq!(col_at_display_width("abcd", 2, 4, &[(1, 2)]), 1);
+        assert_eq!(col_at_display_width("abcd", 3, 4, &[(1, 2)]), 1);
+        assert_eq!(col_at_display_width("abcd", 4, 4, &[(1, 2)]), 2);
+    }
+
+    #[test]
+    fn col_after_wide_chars_and_tabs() {
+        assert_eq!(col_after_display_width("a犬b", 1, 4), 1);
+        assert_eq!(col_after_display_width("a犬b", 2, 4), 2);
+        assert_eq!(col_after_display_width("a犬b", 3, 4), 2);
+        assert_eq!(col_after_display_width("a\tb", 2, 4), 2);
+        assert_eq!(col_after_display_width("a\tb", 4, 4), 2);
+        assert_eq!(col_after_display_width("ab", 5, 4), 2);
+    }
+
     #[test]
     fn word_wrap_keeps_long_word() {
         let have = segments("helloworld", WrapMode::Word, 4);
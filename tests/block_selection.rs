@@ -0,0 +1,185 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::Widget as _;
+use tui_textarea::{Action, CursorMove, Input, Key, KeyMap, MemoryClipboard, TextArea};
+
+fn select_block<'a>(lines: &[&str], start: (u16, u16), end: (u16, u16)) -> TextArea<'a> {
+    let mut textarea = TextArea::from(lines.iter().copied());
+    textarea.move_cursor(CursorMove::Jump(start.0, start.1));
+    textarea.start_block_selection();
+    textarea.move_cursor(CursorMove::Jump(end.0, end.1));
+    assert!(textarea.is_block_selecting());
+    textarea
+}
+
+#[test]
+fn copy_block_with_wide_chars_and_tabs() {
+    let mut textarea = select_block(&["a犬bc", "a\tbc", "abcde"], (0, 1), (2, 3));
+    textarea.copy();
+    assert_eq!(textarea.yank_text(), "犬\n\t\nbc");
+    assert!(!textarea.is_selecting());
+    assert_eq!(textarea.lines(), ["a犬bc", "a\tbc", "abcde"]);
+
+    // Characters starting before the left edge of the block are not selected
+    let mut textarea = select_block(&["abcde", "a犬bc"], (0, 2), (1, 3));
+    textarea.copy();
+    assert_eq!(textarea.yank_text(), "cd\nb");
+
+    // The block is selected from the cursor to the start position
+    let mut textarea = select_block(&["abcd", "abcd"], (1, 3), (0, 1));
+    assert_eq!(textarea.selection_range(), Some(((0, 1), (1, 3))));
+    textarea.copy();
+    assert_eq!(textarea.yank_text(), "bc\nbc");
+}
+
+#[test]
+fn cut_block() {
+    let mut textarea = select_block(&["abcd", "a", "abcd"], (0, 1), (2, 3));
+    assert!(textarea.cut());
+    assert_eq!(textarea.lines(), ["ad", "a", "ad"]);
+    assert_eq!(textarea.yank_text(), "bc\n\nbc");
+    assert_eq!(textarea.cursor(), (0, 1));
+
+    // The rectangle is restored as one step
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["abcd", "a", "abcd"]);
+    assert!(!textarea.undo());
+
+    // Deleting a character removes the rectangle
+    let mut textarea = select_block(&["abcd", "abcd"], (0, 1), (1, 3));
+    assert!(textarea.delete_next_char());
+    assert_eq!(textarea.lines(), ["ad", "ad"]);
+    assert_eq!(textarea.cursors(), [(0, 1), (1, 1)]);
+
+    // Nothing is cut from the empty block
+    let mut textarea = select_block(&["ab", "ab"], (0, 1), (1, 1));
+    assert!(!textarea.cut());
+    assert_eq!(textarea.yank_text(), "");
+}
+
+#[test]
+fn paste_block_at_column() {
+    let mut textarea = select_block(&["12", "34", "", "abcdef"], (0, 0), (1, 2));
+    textarea.copy();
+
+    // Rows are appended at the end of the text
+    textarea.move_cursor(CursorMove::Jump(3, 3));
+    assert!(textarea.paste());
+    assert_eq!(textarea.lines(), ["12", "34", "", "abc12def", "   34"]);
+    assert_eq!(textarea.cursor(), (3, 3));
+
+    // Short rows are padded with spaces
+    textarea.move_cursor(CursorMove::Jump(1, 2));
+    assert!(textarea.paste());
+    assert_eq!(
+        textarea.lines(),
+        ["12", "3412", "  34", "abc12def", "   34"]
+    );
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["12", "34", "", "abc12def", "   34"]);
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["12", "34", "", "abcdef"]);
+
+    // The block is aligned by display columns
+    let mut textarea = select_block(&["xy", "zw", "犬ab", "ab"], (0, 0), (1, 1));
+    textarea.copy();
+    textarea.move_cursor(CursorMove::Jump(2, 1));
+    assert!(textarea.paste());
+    assert_eq!(textarea.lines(), ["xy", "zw", "犬xab", "abz"]);
+
+    // Pasting replaces the block selection
+    let mut textarea = select_block(&["abc", "abc"], (0, 0), (1, 1));
+    textarea.copy();
+    textarea.move_cursor(CursorMove::Jump(0, 1));
+    textarea.start_block_selection();
+    textarea.move_cursor(CursorMove::Jump(1, 3));
+    assert!(textarea.paste());
+    assert_eq!(textarea.lines(), ["aa", "aa"]);
+}
+
+#[test]
+fn type_at_every_row() {
+    let mut textarea = select_block(&["abc", "abc", "abc"], (0, 1), (2, 2));
+    textarea.insert_char('x');
+    assert_eq!(textarea.lines(), ["axc", "axc", "axc"]);
+    assert_eq!(textarea.cursors(), [(0, 2), (1, 2), (2, 2)]);
+    assert_eq!(textarea.cursor(), (2, 2));
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["abc", "abc", "abc"]);
+
+    // Empty block works as a column of cursors
+    let mut textarea = select_block(&["abc", "a", "abc"], (0, 2), (2, 2));
+    assert!(textarea.insert_str("--"));
+    assert_eq!(textarea.lines(), ["ab--c", "a--", "ab--c"]);
+
+    let mut textarea = select_block(&["abc", "abc"], (0, 1), (1, 1));
+    assert!(textarea.delete_char());
+    assert_eq!(textarea.lines(), ["bc", "bc"]);
+}
+
+#[test]
+fn block_selection_with_keys() {
+    let shift_alt = |key| Input {
+        key,
+        ctrl: false,
+        alt: true,
+        shift: true,
+    };
+    for key_map in [KeyMap::emacs(), KeyMap::standard()] {
+        let mut textarea = TextArea::from(["abc", "abc"]);
+        textarea.set_key_map(key_map);
+        textarea.input(shift_alt(Key::Right));
+        textarea.input(shift_alt(Key::Down));
+        assert!(textarea.is_block_selecting());
+        assert_eq!(textarea.selection_range(), Some(((0, 0), (1, 1))));
+        textarea.input(Input {
+            key: Key::Right,
+            ..Default::default()
+        });
+        assert!(!textarea.is_selecting());
+    }
+
+    let mut textarea = TextArea::from(["abc", "abc"]);
+    textarea.perform(Action::SelectBlock(CursorMove::Down));
+    textarea.perform(Action::SelectBlock(CursorMove::End));
+    assert!(textarea.is_block_selecting());
+    assert!(textarea.perform(Action::Cut));
+    assert_eq!(textarea.lines(), ["", ""]);
+}
+
+#[test]
+fn paste_block_through_clipboard() {
+    let clipboard = MemoryClipboard::default();
+    let mut textarea = select_block(&["ab", "cd", "ef"], (0, 1), (1, 2));
+    textarea.set_clipboard(clipboard.clone());
+    textarea.copy();
+    assert_eq!(clipboard.text().as_deref(), Some("b\nd"));
+
+    textarea.move_cursor(CursorMove::Jump(1, 0));
+    assert!(textarea.paste());
+    assert_eq!(textarea.lines(), ["ab", "bcd", "def"]);
+
+    // Text changed by other applications is pasted as lines
+    clipboard.set("x\ny");
+    textarea.move_cursor(CursorMove::Jump(0, 0));
+    assert!(textarea.paste());
+    assert_eq!(textarea.lines(), ["x", "yab", "bcd", "def"]);
+}
+
+#[test]
+fn render_block_selection() {
+    let textarea = select_block(&["abcdef", "a", "a\tb"], (0, 1), (2, 2));
+    let area = Rect::new(0, 0, 6, 3);
+    let mut buf = Buffer::empty(area);
+    (&textarea).render(area, &mut buf);
+
+    let selected = |x, y| buf[(x, y)].bg == Color::LightBlue;
+    assert!(!selected(0, 0));
+    assert!(selected(1, 0));
+    assert!(selected(2, 0));
+    assert!(selected(3, 0));
+    assert!(!selected(4, 0));
+    assert!(!selected(1, 1));
+    assert!(selected(1, 2)); // The tab starting in the block is selected
+}
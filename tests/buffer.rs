@@ -0,0 +1,155 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, Input, Key, LineRope, TextArea, TextBuffer, WrapMode};
+
+fn key(key: Key, ctrl: bool, alt: bool) -> Input {
+    Input {
+        key,
+        ctrl,
+        alt,
+        shift: false,
+    }
+}
+
+fn lines(buffer: &impl TextBuffer) -> Vec<String> {
+    buffer.iter().cloned().collect()
+}
+
+fn render<B: TextBuffer>(textarea: &TextArea<'_, B>) -> Buffer {
+    let area = Rect::new(0, 0, 12, 6);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    buf
+}
+
+#[test]
+fn rope_buffer_behaves_same_as_vec() {
+    let text: Vec<_> = (0..200).map(|i| format!("line {i} foo bar")).collect();
+    let mut vec = TextArea::new(text.clone());
+    let mut rope = TextArea::with_buffer(LineRope::from_lines(text));
+
+    let inputs = [
+        key(Key::Down, false, false),
+        key(Key::Enter, false, false),
+        key(Key::Char('x'), false, false),
+        key(Key::Right, false, true),
+        key(Key::Char('k'), true, false),
+        key(Key::Down, false, false),
+        key(Key::Char('y'), true, false),
+        key(Key::Char('y'), true, false),
+        key(Key::Backspace, false, false),
+        key(Key::Char('h'), true, false),
+        key(Key::PageDown, false, false),
+        key(Key::Char('u'), true, false),
+        key(Key::Char('r'), true, false),
+        key(Key::Delete, false, false),
+        key(Key::Char('w'), true, false),
+        key(Key::Char('u'), true, false),
+        key(Key::Char('u'), true, false),
+        key(Key::Char('u'), true, false),
+        key(Key::Char('r'), true, false),
+        key(Key::Down, false, true),
+        key(Key::Char('j'), true, false),
+        key(Key::Char('m'), true, false),
+    ];
+    for (i, input) in inputs.into_iter().enumerate() {
+        let modified = vec.input(input.clone());
+        assert_eq!(rope.input(input.clone()), modified, "{i}: {input:?}");
+        assert_eq!(lines(rope.buffer()), vec.lines(), "{i}: {input:?}");
+        assert_eq!(rope.cursor(), vec.cursor(), "{i}: {input:?}");
+    }
+
+    // Multi-line edits
+    vec.move_cursor(CursorMove::Jump(10, 3));
+    rope.move_cursor(CursorMove::Jump(10, 3));
+    vec.start_selection();
+    rope.start_selection();
+    vec.move_cursor(CursorMove::Jump(150, 2));
+    rope.move_cursor(CursorMove::Jump(150, 2));
+    assert!(vec.cut());
+    assert!(rope.cut());
+    assert_eq!(lines(rope.buffer()), vec.lines());
+    assert!(vec.insert_str("a\nb\nc"));
+    assert!(rope.insert_str("a\nb\nc"));
+    assert_eq!(lines(rope.buffer()), vec.lines());
+    assert!(vec.paste());
+    assert!(rope.paste());
+    assert_eq!(lines(rope.buffer()), vec.lines());
+    while vec.undo() {
+        assert!(rope.undo());
+        assert_eq!(lines(rope.buffer()), vec.lines());
+        assert_eq!(rope.cursor(), vec.cursor());
+    }
+    assert!(!rope.undo());
+    while vec.redo() {
+        assert!(rope.redo());
+        assert_eq!(lines(rope.buffer()), vec.lines());
+        assert_eq!(rope.cursor(), vec.cursor());
+    }
+
+    assert_eq!(rope.into_lines(), vec.into_lines());
+}
+
+#[test]
+fn read_lines_of_any_buffer() {
+    let text = ["hello", "", "world"];
+    let vec = TextArea::from(text);
+    let rope = TextArea::with_buffer(LineRope::from_iter(text));
+    for row in 0..4 {
+        assert_eq!(rope.line(row), vec.line(row), "{row}");
+    }
+    assert_eq!(rope.line(2), Some("world"));
+    assert_eq!(rope.line(3), None);
+    assert_eq!(rope.iter_lines().collect::<Vec<_>>(), vec.lines());
+
+    let empty = TextArea::with_buffer(LineRope::new());
+    assert_eq!(empty.iter_lines().collect::<Vec<_>>(), [""]);
+}
+
+#[test]
+fn render_rope_buffer() {
+    let text = ["hello", "world", "this is a long line", ""];
+    let mut vec = TextArea::from(text);
+    let mut rope = TextArea::with_buffer(text.into_iter().collect::<LineRope>());
+    assert_eq!(render(&rope), render(&vec));
+
+    vec.set_wrap_mode(WrapMode::Word);
+    rope.set_wrap_mode(WrapMode::Word);
+    vec.move_cursor(CursorMove::Bottom);
+    rope.move_cursor(CursorMove::Bottom);
+    assert_eq!(render(&rope), render(&vec));
+    vec.move_cursor(CursorMove::Up);
+    rope.move_cursor(CursorMove::Up);
+    assert_eq!(rope.cursor(), vec.cursor());
+}
+
+#[test]
+fn empty_rope_buffer() {
+    let mut textarea = TextArea::with_buffer(LineRope::new());
+    assert!(textarea.is_empty());
+    assert_eq!(textarea.buffer().len(), 1);
+    textarea.insert_str("abc");
+    assert!(!textarea.is_empty());
+    assert!(textarea.clear());
+    assert!(textarea.is_empty());
+
+    textarea.set_lines(vec!["x".to_string(), "y".to_string()], (1, 1));
+    assert_eq!(textarea.cursor(), (1, 1));
+    assert_eq!(textarea.into_buffer().into_lines(), ["x", "y"]);
+}
+
+#[cfg(feature = "search")]
+#[test]
+fn search_rope_buffer() {
+    let mut textarea = TextArea::with_buffer(LineRope::from_iter(["foo", "bar", "foo bar"]));
+    textarea.set_search_pattern("bar").unwrap();
+    assert!(textarea.search_forward(false));
+    assert_eq!(textarea.cursor(), (1, 0));
+    assert!(textarea.search_forward(false));
+    assert_eq!(textarea.cursor(), (2, 4));
+    assert!(textarea.search_back(false));
+    assert_eq!(textarea.cursor(), (1, 0));
+    assert!(textarea.search_back(false));
+    assert_eq!(textarea.cursor(), (2, 4));
+}
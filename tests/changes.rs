@@ -0,0 +1,109 @@
+use tui_textarea::{CursorMove, TextArea, TextChange};
+
+// Apply the change to the text as an external buffer would do
+fn apply(text: &mut String, change: &TextChange) {
+    let offset = |(row, col): (usize, usize)| {
+        let line_start: usize = text.split('\n').take(row).map(|l| l.len() + 1).sum();
+        let line = text.split('\n').nth(row).unwrap();
+        line_start + line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)
+    };
+    let start = offset(change.start);
+    let end = offset(change.end);
+    assert_eq!(&text[start..end], change.removed, "{change:?}");
+    text.replace_range(start..end, &change.inserted);
+}
+
+fn assert_replay(t: &mut TextArea<'_>, text: &mut String) {
+    for change in t.take_changes() {
+        apply(text, &change);
+    }
+    assert_eq!(*text, t.lines().join("\n"));
+}
+
+#[test]
+fn replay_changes() {
+    let mut t = TextArea::from(["abc", "déf"]);
+    let mut text = t.lines().join("\n");
+    t.set_change_tracking(true);
+
+    t.insert_str("x\ny");
+    t.move_cursor(CursorMove::End);
+    t.insert_newline();
+    t.insert_char('あ');
+    t.delete_char();
+    t.delete_char();
+    t.move_cursor(CursorMove::Bottom);
+    t.move_cursor(CursorMove::Forward);
+    t.delete_line_by_end();
+    t.delete_newline();
+    assert_replay(&mut t, &mut text);
+
+    while t.undo() {}
+    assert_replay(&mut t, &mut text);
+    while t.redo() {}
+    assert_replay(&mut t, &mut text);
+
+    t.select_all();
+    t.cut();
+    t.paste();
+    t.paste();
+    assert_replay(&mut t, &mut text);
+
+    t.move_cursor(CursorMove::Top);
+    t.move_cursor(CursorMove::Head);
+    t.add_cursor_below();
+    t.add_cursor_below();
+    t.insert_str("- ");
+    assert_replay(&mut t, &mut text);
+
+    t.clear();
+    assert_replay(&mut t, &mut text);
+    t.set_lines(vec!["foo".into(), "bar".into()], (0, 0));
+    assert_replay(&mut t, &mut text);
+}
+
+#[test]
+fn change_of_each_edit() {
+    let mut t = TextArea::from(["ab", "cd"]);
+    t.set_change_tracking(true);
+
+    t.move_cursor(CursorMove::Jump(1, 0));
+    t.delete_newline();
+    t.insert_newline();
+    t.start_selection();
+    t.move_cursor(CursorMove::End);
+    t.cut();
+
+    let change = |start, end, removed: &str, inserted: &str| {
+        (start, end, removed.to_string(), inserted.to_string())
+    };
+    let changes: Vec<_> = t
+        .take_changes()
+        .into_iter()
+        .map(|c| (c.start, c.end, c.removed, c.inserted))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            change((0, 2), (1, 0), "\n", ""),
+            change((0, 2), (0, 2), "", "\n"),
+            change((1, 0), (1, 2), "cd", ""),
+        ],
+    );
+
+    t.set_lines(vec!["x".into()], (0, 0));
+    let changes = t.take_changes();
+    assert_eq!(changes.len(), 1);
+    assert_eq!((changes[0].start, changes[0].end), ((0, 0), (1, 0)));
+    assert_eq!(changes[0].removed, "ab\n");
+    assert_eq!(changes[0].inserted, "x");
+    assert_eq!(changes[0].inserted_end(), (0, 1));
+
+    // Disabling the tracking discards the pending changes
+    t.insert_char('y');
+    t.set_change_tracking(false);
+    t.insert_char('z');
+    assert!(t.take_changes().is_empty());
+    t.set_change_tracking(true);
+    assert!(t.take_changes().is_empty());
+}
@@ -0,0 +1,167 @@
+use std::io;
+use tui_textarea::{Clipboard, CursorMove, MemoryClipboard, Osc52Clipboard, TextArea};
+
+#[test]
+fn copy_and_paste_with_clipboard() {
+    let clipboard = MemoryClipboard::default();
+    let mut t = TextArea::from(["abc", "def"]);
+    t.set_clipboard(clipboard.clone());
+
+    t.start_selection();
+    t.move_cursor(CursorMove::Down);
+    t.copy();
+    assert_eq!(clipboard.text().as_deref(), Some("abc\n"));
+
+    t.move_cursor(CursorMove::End);
+    t.start_selection();
+    t.move_cursor(CursorMove::Head);
+    assert!(t.cut());
+    assert_eq!(clipboard.text().as_deref(), Some("def"));
+    assert_eq!(t.lines(), ["abc", ""]);
+
+    // Text set by other applications is pasted. `\r\n` is recognized as a newline.
+    clipboard.set("x\r\ny");
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["abc", "x", "y"]);
+    assert_eq!(t.yank_text(), "x\ny");
+
+    // Deletions which yank text don't write to the clipboard
+    t.delete_line_by_head();
+    assert_eq!(t.yank_text(), "y");
+    assert_eq!(clipboard.text().as_deref(), Some("x\r\ny"));
+    t.set_yank_text("z");
+    assert_eq!(clipboard.text().as_deref(), Some("x\r\ny"));
+
+    // The killed text is pasted since the clipboard was not changed by other applications
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["abc", "x", "z"]);
+
+    // Clones of the textarea share the clipboard
+    let mut cloned = t.clone();
+    cloned.select_all();
+    cloned.copy();
+    assert_eq!(clipboard.text().as_deref(), Some("abc\nx\nz"));
+
+    t.remove_clipboard();
+    assert!(!t.has_clipboard());
+    t.select_all();
+    t.cut();
+    assert_eq!(t.yank_text(), "abc\nx\nz");
+    assert_eq!(clipboard.text().as_deref(), Some("abc\nx\nz"));
+    t.set_yank_text("v");
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["v"]);
+}
+
+#[test]
+fn kill_without_writing_clipboard() {
+    let mut t = TextArea::from(["abc def"]);
+    t.set_clipboard(WriteOnly::default());
+    t.delete_next_word();
+    t.delete_line_by_end();
+    assert!(t.paste());
+    assert_eq!(t.lines(), [" def"]);
+
+    let clipboard = MemoryClipboard::default();
+    let mut t = TextArea::from(["abc def"]);
+    t.set_clipboard(clipboard.clone());
+    t.clear();
+    assert_eq!(clipboard.text(), None);
+}
+
+#[test]
+fn paste_clipboard_text_at_each_cursor() {
+    let clipboard = MemoryClipboard::default();
+    clipboard.set("x");
+    let mut t = TextArea::from(["a", "b"]);
+    t.set_clipboard(clipboard);
+    t.add_cursor_below();
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["xa", "xb"]);
+}
+
+// Clipboard which cannot be read like OSC 52
+#[derive(Default)]
+struct WriteOnly(Vec<String>);
+
+impl Clipboard for WriteOnly {
+    fn set_text(&mut self, text: &str) -> io::Result<()> {
+        self.0.push(text.to_string());
+        Ok(())
+    }
+
+    fn get_text(&mut self) -> io::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+// Clipboard which always fails
+struct Broken;
+
+impl Clipboard for Broken {
+    fn set_text(&mut self, _text: &str) -> io::Result<()> {
+        Err(io::Error::other("broken"))
+    }
+
+    fn get_text(&mut self) -> io::Result<Option<String>> {
+        Err(io::Error::other("broken"))
+    }
+}
+
+#[test]
+fn fall_back_to_yank_buffer() {
+    let mut t = TextArea::from(["abc"]);
+    t.set_clipboard(WriteOnly::default());
+    t.delete_next_word();
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["abc"]);
+
+    let mut t = TextArea::from(["abc"]);
+    t.set_clipboard(Broken);
+    t.delete_next_word();
+    assert_eq!(t.yank_text(), "abc");
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["abc"]);
+}
+
+#[test]
+fn osc52_clipboard() {
+    let mut clipboard = Osc52Clipboard::with_writer(vec![]);
+    clipboard.set_text("hello\nworld").unwrap();
+    assert_eq!(clipboard.get_ref(), b"\x1b]52;c;aGVsbG8Kd29ybGQ=\x07");
+    assert!(clipboard.get_text().unwrap().is_none());
+}
+
+#[cfg(unix)]
+#[test]
+fn command_clipboard() {
+    use tui_textarea::CommandClipboard;
+
+    let path = std::env::temp_dir().join(format!("tui-textarea-clipboard-{}", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut t = TextArea::from(["abc", "def"]);
+    t.set_clipboard(CommandClipboard::new(
+        ["sh", "-c", &format!("cat > '{path}'")],
+        ["cat", path],
+    ));
+
+    t.select_all();
+    t.copy();
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "abc\ndef");
+
+    std::fs::write(path, "ghi").unwrap();
+    t.move_cursor(CursorMove::Bottom);
+    t.move_cursor(CursorMove::End);
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["abc", "defghi"]);
+
+    // Failing command does not paste anything
+    std::fs::remove_file(path).unwrap();
+    let mut clipboard = CommandClipboard::new(["false"], ["cat", path]);
+    assert!(clipboard.set_text("x").is_err());
+    assert!(clipboard.get_text().is_err());
+
+    // Empty paste command means write-only clipboard
+    let mut clipboard = CommandClipboard::new(["true"], [] as [&str; 0]);
+    assert!(clipboard.get_text().unwrap().is_none());
+}
@@ -0,0 +1,272 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget as _;
+use tui_textarea::{Action, CursorMove, Input, Key, KeyMap, Sign, TextArea, WordList};
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Default::default()
+    }
+}
+
+fn ctrl(c: char) -> Input {
+    Input {
+        key: Key::Char(c),
+        ctrl: true,
+        ..Default::default()
+    }
+}
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    (0..area.height)
+        .map(|y| {
+            let row: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+// Render the textarea in the area and the completion popup over the whole screen
+fn render(textarea: &TextArea<'_>, area: Rect, screen: Rect) -> Buffer {
+    let mut buf = Buffer::empty(screen);
+    textarea.render(area, &mut buf);
+    textarea.completion_widget().render(screen, &mut buf);
+    buf
+}
+
+fn textarea(line: &str, words: &[&str]) -> TextArea<'static> {
+    let mut t = TextArea::from([line.to_string()]);
+    t.set_completion_provider(WordList::new(words.iter().copied()));
+    t.move_cursor(CursorMove::End);
+    t
+}
+
+#[test]
+fn complete_word_before_cursor() {
+    let mut t = textarea("foo.ba", &["bar", "baz", "qux"]);
+    assert!(t.complete());
+    assert_eq!(t.completion_candidates(), ["bar", "baz"]);
+    assert_eq!(t.completion_index(), Some(0));
+
+    // Empty prefix queries the provider with an empty string
+    let mut t = textarea("foo.", &["bar", "baz"]);
+    assert!(t.complete());
+    assert_eq!(t.completion_candidates(), ["bar", "baz"]);
+
+    // No candidate does not open the popup
+    let mut t = textarea("xyz", &["bar"]);
+    assert!(!t.complete());
+    assert!(!t.is_completing());
+
+    // No provider
+    let mut t = TextArea::from(["ba"]);
+    assert!(!t.has_completion_provider());
+    assert!(!t.complete());
+}
+
+#[test]
+fn trigger_with_key_in_emacs_key_map() {
+    let mut t = textarea("ab", &["abc"]);
+    assert!(!t.input(ctrl(' ')));
+    assert!(!t.is_completing());
+
+    t.key_map_mut().bind([ctrl(' ')], Action::TriggerCompletion);
+    assert!(!t.input(ctrl(' ')));
+    assert!(t.is_completing());
+}
+
+#[test]
+fn navigate_and_accept_with_keys() {
+    let mut t = textarea("ab", &["abc", "abd", "abe"]);
+    t.set_key_map(KeyMap::standard());
+    assert!(!t.input(ctrl(' ')));
+    assert!(t.is_completing());
+
+    t.input(key(Key::Down));
+    t.input(ctrl('n'));
+    assert_eq!(t.completion_index(), Some(2));
+    t.input(key(Key::Down));
+    assert_eq!(t.completion_index(), Some(0));
+    t.input(key(Key::Up));
+    t.input(ctrl('p'));
+    assert_eq!(t.completion_index(), Some(1));
+
+    // Navigating the popup doesn't move the cursor
+    assert_eq!(t.cursor(), (0, 2));
+
+    assert!(t.input(key(Key::Enter)));
+    assert_eq!(t.lines(), ["abd"]);
+    assert_eq!(t.cursor(), (0, 3));
+    assert!(!t.is_completing());
+
+    // Enter inserts a newline after the popup was closed
+    t.input(key(Key::Enter));
+    assert_eq!(t.lines(), ["abd", ""]);
+}
+
+#[test]
+fn accept_is_undone_at_once() {
+    let mut t = textarea("let x = valu", &["value"]);
+    t.complete();
+    assert!(t.input(key(Key::Tab)));
+    assert_eq!(t.lines(), ["let x = value"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["let x = valu"]);
+    assert_eq!(t.cursor(), (0, 12));
+    assert!(t.redo());
+    assert_eq!(t.lines(), ["let x = value"]);
+}
+
+#[test]
+fn accept_in_middle_of_line() {
+    let mut t = textarea("f(ar, x)", &["arg"]);
+    t.move_cursor(CursorMove::Jump(0, 4));
+    t.complete();
+    assert!(t.accept_completion());
+    assert_eq!(t.lines(), ["f(arg, x)"]);
+    assert_eq!(t.cursor(), (0, 5));
+    assert!(!t.accept_completion());
+}
+
+#[test]
+fn candidates_follow_typing() {
+    let mut t = textarea("s", &["select", "set", "show"]);
+    t.complete();
+    assert_eq!(t.completion_candidates(), ["select", "set", "show"]);
+
+    t.input(key(Key::Char('e')));
+    assert_eq!(t.completion_candidates(), ["select", "set"]);
+    t.input(key(Key::Char('l')));
+    assert_eq!(t.completion_candidates(), ["select"]);
+
+    t.input(key(Key::Backspace));
+    assert_eq!(t.completion_candidates(), ["select", "set"]);
+
+    // No candidate closes the popup
+    t.input(key(Key::Char('x')));
+    assert!(!t.is_completing());
+}
+
+#[test]
+fn popup_is_closed() {
+    // Typing a non-word character
+    let mut t = textarea("ab", &["abc"]);
+    t.complete();
+    t.input(key(Key::Char(' ')));
+    assert!(!t.is_completing());
+
+    // Moving the cursor
+    let mut t = textarea("ab", &["abc"]);
+    t.complete();
+    t.input(key(Key::Left));
+    assert!(!t.is_completing());
+
+    // Deleting the word start
+    let mut t = textarea("x.a", &["abc"]);
+    t.complete();
+    t.input(key(Key::Backspace));
+    assert!(t.is_completing()); // Empty prefix
+    t.input(key(Key::Backspace));
+    assert!(!t.is_completing());
+
+    // Esc
+    let mut t = textarea("ab", &["abc"]);
+    t.complete();
+    assert!(!t.input(key(Key::Esc)));
+    assert!(!t.is_completing());
+    assert_eq!(t.lines(), ["ab"]);
+
+    // Replacing the text
+    let mut t = textarea("ab", &["abc"]);
+    t.complete();
+    t.set_lines(vec!["x".to_string()], (0, 0));
+    assert!(!t.is_completing());
+}
+
+#[test]
+fn closure_provider() {
+    let mut t = TextArea::from(["n"]);
+    t.set_completion_provider(|prefix: &str| vec![format!("{prefix}1"), format!("{prefix}2")]);
+    t.move_cursor(CursorMove::End);
+    t.complete();
+    t.select_next_completion();
+    t.accept_completion();
+    assert_eq!(t.lines(), ["n2"]);
+
+    t.remove_completion_provider();
+    assert!(!t.complete());
+}
+
+#[test]
+fn render_popup_below_word() {
+    let mut t = textarea("let x = fo", &["foo", "foobar"]);
+    t.set_cursor_style(Style::default());
+    t.set_cursor_line_style(Style::default());
+    t.complete();
+    let screen = Rect::new(0, 0, 20, 5);
+    let buf = render(&t, Rect::new(2, 1, 16, 2), screen);
+    assert_eq!(
+        rows(&buf),
+        ["", "  let x = fo", "          foo", "          foobar", ""],
+    );
+
+    // Selected candidate is reversed and the popup is padded to the widest candidate
+    assert_eq!(buf[(9, 2)].bg, Color::DarkGray);
+    assert_eq!(buf[(16, 2)].bg, Color::DarkGray);
+    assert_eq!(buf[(17, 2)].bg, Color::Reset);
+    assert!(buf[(10, 2)].modifier.contains(Modifier::REVERSED));
+    assert!(!buf[(10, 3)].modifier.contains(Modifier::REVERSED));
+}
+
+#[test]
+fn render_popup_above_word_at_bottom() {
+    let mut t = textarea("ab", &["abc", "abd"]);
+    t.complete();
+    let screen = Rect::new(0, 0, 10, 4);
+    let buf = render(&t, Rect::new(0, 3, 10, 1), screen);
+    assert_eq!(rows(&buf), ["", " abc", " abd", "ab"]);
+}
+
+#[test]
+fn render_popup_scrolled_to_selection() {
+    let mut t = textarea("", &["a", "b", "c", "d"]);
+    t.set_completion_height(2);
+    t.complete();
+    let screen = Rect::new(0, 0, 10, 4);
+    let area = Rect::new(0, 0, 10, 1);
+    assert_eq!(rows(&render(&t, area, screen)), ["", " a", " b", ""]);
+    t.select_prev_completion();
+    assert_eq!(rows(&render(&t, area, screen)), ["", " c", " d", ""]);
+}
+
+#[test]
+fn popup_not_rendered_before_textarea() {
+    let mut t = textarea("ab", &["abc"]);
+    t.complete();
+    let screen = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(screen);
+    t.completion_widget().render(screen, &mut buf);
+    assert_eq!(rows(&buf), ["", "", ""]);
+}
+
+#[test]
+fn cursor_to_screen_with_gutter_and_scroll() {
+    let mut t = TextArea::from(["0123456789abcdef", "x"]);
+    t.set_sign(0, Sign::error());
+    let area = Rect::new(3, 2, 8, 2);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 5));
+    t.render(area, &mut buf);
+    assert_eq!(t.cursor_to_screen(), Some((5, 2)));
+
+    t.move_cursor(CursorMove::End);
+    t.render(area, &mut buf);
+    assert_eq!(t.cursor_to_screen(), Some((10, 2)));
+
+    // The gutter is partially scrolled out
+    t.move_cursor(CursorMove::Down);
+    t.render(area, &mut buf);
+    assert_eq!(t.cursor_to_screen(), Some((4, 3)));
+}
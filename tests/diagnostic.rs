@@ -0,0 +1,168 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, Diagnostic, Severity, TextArea, WrapMode};
+
+fn render(textarea: &TextArea<'_>, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    buf
+}
+
+fn row(buf: &Buffer, y: u16) -> String {
+    let row: String = (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect();
+    row.trim_end().to_string()
+}
+
+fn underlined(buf: &Buffer, y: u16) -> Vec<bool> {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].modifier.contains(Modifier::UNDERLINED))
+        .collect()
+}
+
+fn textarea(lines: &[&str]) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().map(|l| l.to_string()));
+    t.set_cursor_style(Style::default());
+    t.set_cursor_line_style(Style::default());
+    t
+}
+
+#[test]
+fn underline_range_and_show_message() {
+    let mut t = textarea(&["select nmae", "from t"]);
+    t.add_diagnostic(Diagnostic::new((0, 7), (0, 11), Severity::Error, "unknown"));
+    let buf = render(&t, 24, 2);
+
+    assert_eq!(row(&buf, 0), "select nmae  unknown");
+    assert_eq!(row(&buf, 1), "from t");
+    let want: Vec<bool> = (0..24).map(|x| (7..11).contains(&x)).collect();
+    assert_eq!(underlined(&buf, 0), want);
+    assert_eq!(buf[(7, 0)].fg, Color::Red);
+
+    // Message is dimmed without underline
+    let cell = &buf[(13, 0)];
+    assert_eq!(cell.fg, Color::Red);
+    assert!(cell.modifier.contains(Modifier::DIM));
+    assert!(!cell.modifier.contains(Modifier::UNDERLINED));
+
+    // Diagnostics don't change the text and the cursor
+    assert_eq!(t.lines(), ["select nmae", "from t"]);
+    t.move_cursor(CursorMove::End);
+    assert_eq!(t.cursor(), (0, 11));
+
+    t.set_diagnostic_messages(false);
+    let buf = render(&t, 24, 2);
+    assert_eq!(row(&buf, 0), "select nmae");
+}
+
+#[test]
+fn multi_line_and_overlapping_diagnostics() {
+    let mut t = textarea(&["abcd", "efgh"]);
+    t.set_diagnostics([
+        Diagnostic::new((0, 2), (1, 2), Severity::Warning, "warn"),
+        Diagnostic::new((0, 0), (0, 3), Severity::Error, "error"),
+    ]);
+    t.move_cursor(CursorMove::Jump(1, 4));
+    let buf = render(&t, 16, 2);
+
+    // Message of the most severe diagnostic is shown
+    assert_eq!(row(&buf, 0), "abcd  error");
+    assert_eq!(row(&buf, 1), "efgh");
+    let fg: Vec<Color> = (0..4).map(|x| buf[(x, 0)].fg).collect();
+    assert_eq!(fg, [Color::Red, Color::Red, Color::Red, Color::Yellow]);
+    let want: Vec<bool> = (0..16).map(|x| x < 2).collect();
+    assert_eq!(underlined(&buf, 1), want);
+}
+
+#[test]
+fn empty_range_diagnostic() {
+    let mut t = textarea(&["let x = ;"]);
+    t.add_diagnostic(Diagnostic::new(
+        (0, 8),
+        (0, 8),
+        Severity::Error,
+        "expected expression",
+    ));
+    let buf = render(&t, 32, 1);
+    let want: Vec<bool> = (0..32).map(|x| x == 8).collect();
+    assert_eq!(underlined(&buf, 0), want);
+
+    t.move_cursor(CursorMove::Jump(0, 8));
+    assert_eq!(t.diagnostic_at_cursor().unwrap().severity, Severity::Error);
+}
+
+#[test]
+fn diagnostic_at_cursor() {
+    let mut t = textarea(&["abcdef"]);
+    t.set_diagnostics([
+        Diagnostic::new((0, 0), (0, 4), Severity::Hint, "hint"),
+        Diagnostic::new((0, 2), (0, 6), Severity::Warning, "warn"),
+    ]);
+    assert_eq!(t.diagnostic_at_cursor().unwrap().message, "hint");
+    t.move_cursor(CursorMove::Jump(0, 3));
+    assert_eq!(t.diagnostic_at_cursor().unwrap().message, "warn");
+    t.move_cursor(CursorMove::End);
+    assert_eq!(t.diagnostic_at_cursor(), None);
+
+    t.clear_diagnostics();
+    assert!(t.diagnostics().is_empty());
+}
+
+#[test]
+fn diagnostics_follow_edits_above() {
+    let mut t = textarea(&["a", "b", "c"]);
+    t.add_diagnostic(Diagnostic::new((2, 0), (2, 1), Severity::Info, "info"));
+    t.insert_newline();
+    assert_eq!(t.diagnostics()[0].start, (3, 0));
+    t.undo();
+    assert_eq!(t.diagnostics()[0].start, (2, 0));
+
+    t.set_lines(vec!["x".to_string()], (0, 0));
+    assert!(t.diagnostics().is_empty());
+}
+
+#[test]
+fn diagnostic_with_wrap() {
+    let mut t = textarea(&["abcdefgh"]);
+    t.set_wrap_mode(WrapMode::Glyph);
+    t.add_diagnostic(Diagnostic::new((0, 2), (0, 6), Severity::Error, "e"));
+    let buf = render(&t, 4, 2);
+    assert_eq!(row(&buf, 0), "abcd");
+    assert_eq!(row(&buf, 1), "efgh");
+    assert_eq!(underlined(&buf, 0), [false, false, true, true]);
+    assert_eq!(underlined(&buf, 1), [true, true, false, false]);
+}
+
+#[test]
+fn custom_diagnostic_style() {
+    let mut t = textarea(&["abc"]);
+    t.set_diagnostic_style(Severity::Hint, Style::default().bg(Color::Blue));
+    t.add_diagnostic(Diagnostic::new((0, 1), (0, 2), Severity::Hint, ""));
+    let buf = render(&t, 8, 1);
+    assert_eq!(row(&buf, 0), "abc");
+    assert_eq!(buf[(1, 0)].bg, Color::Blue);
+    assert_eq!(buf[(0, 0)].bg, Color::Reset);
+}
+
+#[cfg(feature = "syntect")]
+#[test]
+fn diagnostic_over_syntax_highlight() {
+    use tui_textarea::SyntectHighlighter;
+
+    let mut t = textarea(&["fn main() {}"]);
+    t.set_highlighter(SyntectHighlighter::from_extension("rs").unwrap());
+    t.move_cursor(CursorMove::End);
+    let keyword = render(&t, 16, 1)[(0, 0)].fg;
+
+    t.set_diagnostic_style(
+        Severity::Error,
+        Style::default().add_modifier(Modifier::UNDERLINED),
+    );
+    t.add_diagnostic(Diagnostic::new((0, 0), (0, 2), Severity::Error, ""));
+    let cell = render(&t, 16, 1)[(0, 0)].clone();
+    // Diagnostic style is put on top of the syntax highlight
+    assert_eq!(cell.fg, keyword);
+    assert!(cell.modifier.contains(Modifier::UNDERLINED));
+}
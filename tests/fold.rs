@@ -0,0 +1,196 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, Scrolling, TextArea, WrapMode};
+
+fn render(textarea: &TextArea<'_>, width: u16, height: u16) -> Vec<String> {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    (0..height)
+        .map(|y| {
+            let row: String = (0..width).map(|x| buf[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+fn textarea(lines: &[&str]) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().map(|l| l.to_string()));
+    t.set_cursor_style(Style::default());
+    t.set_cursor_line_style(Style::default());
+    t
+}
+
+#[test]
+fn render_folded_lines() {
+    let mut t = textarea(&["a", "b", "c", "d", "e"]);
+    t.move_cursor(CursorMove::Bottom);
+    assert!(t.fold_lines(1, 3));
+    assert_eq!(render(&t, 16, 4), ["a", "b ··· 3 lines", "e", ""],);
+
+    t.unfold_all();
+    assert_eq!(render(&t, 16, 4), ["b", "c", "d", "e"]);
+}
+
+#[test]
+fn fold_containing_cursor_is_open() {
+    let mut t = textarea(&["a", "b", "c", "d"]);
+    t.fold_lines(0, 2);
+    assert_eq!(render(&t, 16, 3), ["a ··· 3 lines", "d", ""]);
+
+    // Moving the cursor into the hidden lines opens the fold on the screen
+    t.move_cursor(CursorMove::Jump(1, 0));
+    assert_eq!(render(&t, 16, 3), ["a", "b", "c"]);
+    assert_eq!(t.folds(), [(0, 2)]);
+}
+
+#[test]
+fn fold_moves_cursor_out_of_hidden_lines() {
+    let mut t = textarea(&["abc", "defgh", "i"]);
+    t.move_cursor(CursorMove::Jump(1, 4));
+    assert!(t.fold_lines(0, 1));
+    assert_eq!(t.cursor(), (0, 3));
+}
+
+#[test]
+fn cursor_up_and_down_skip_folded_lines() {
+    let mut t = textarea(&["a", "b", "c", "d", "e", "f"]);
+    t.fold_lines(1, 2);
+    t.fold_lines(4, 5);
+
+    let mut rows = vec![];
+    for _ in 0..4 {
+        t.move_cursor(CursorMove::Down);
+        rows.push(t.cursor().0);
+    }
+    assert_eq!(rows, [1, 3, 4, 4]);
+
+    let mut rows = vec![];
+    for _ in 0..4 {
+        t.move_cursor(CursorMove::Up);
+        rows.push(t.cursor().0);
+    }
+    assert_eq!(rows, [3, 1, 0, 0]);
+}
+
+#[test]
+fn nested_folds() {
+    let mut t = textarea(&["a", "b", "c", "d", "e"]);
+    t.fold_lines(1, 2);
+    t.fold_lines(0, 3);
+    t.move_cursor(CursorMove::Bottom);
+    assert_eq!(render(&t, 16, 3), ["a ··· 4 lines", "e", ""]);
+
+    t.move_cursor(CursorMove::Jump(1, 0));
+    assert!(t.unfold());
+    assert_eq!(t.folds(), [(0, 3)]);
+}
+
+#[test]
+fn folds_follow_edits() {
+    let mut t = textarea(&["a", "b", "c", "d", "e"]);
+    t.fold_lines(2, 3);
+
+    // Edits above the fold shift it
+    t.insert_newline();
+    assert_eq!(t.folds(), [(3, 4)]);
+    t.delete_char();
+    assert_eq!(t.folds(), [(2, 3)]);
+
+    // Edits inside the fold resize it
+    t.move_cursor(CursorMove::Jump(2, 1));
+    t.insert_str("x\ny");
+    assert_eq!(t.folds(), [(2, 4)]);
+    t.undo();
+    assert_eq!(t.folds(), [(2, 3)]);
+
+    // Edits below the fold do not affect it
+    t.move_cursor(CursorMove::Bottom);
+    t.insert_newline();
+    assert_eq!(t.folds(), [(2, 3)]);
+
+    // Deleting lines across the fold boundary removes it
+    t.move_cursor(CursorMove::Jump(1, 0));
+    t.start_selection();
+    t.move_cursor(CursorMove::Jump(2, 1));
+    t.cut();
+    assert!(t.folds().is_empty());
+
+    // Replacing the text removes all folds
+    t.fold_lines(0, 1);
+    t.set_lines(vec!["x".to_string(), "y".to_string()], (0, 0));
+    assert!(t.folds().is_empty());
+}
+
+#[test]
+fn fold_indented_region() {
+    let mut t = textarea(&["fn f() {", "    if x {", "        a();", "    }", "}"]);
+    t.move_cursor(CursorMove::Down);
+    assert!(t.fold_indent());
+    assert_eq!(t.folds(), [(1, 2)]);
+    assert!(!t.fold_indent());
+
+    t.move_cursor(CursorMove::Top);
+    assert!(t.toggle_fold());
+    assert_eq!(t.folds(), [(0, 3), (1, 2)]);
+    assert!(t.toggle_fold());
+    assert_eq!(t.folds(), [(1, 2)]);
+
+    t.move_cursor(CursorMove::Bottom);
+    assert!(!t.toggle_fold());
+}
+
+#[test]
+fn folds_with_wrap() {
+    let mut t = textarea(&["abcdefgh", "ij", "kl", "mn"]);
+    t.set_wrap_mode(WrapMode::Glyph);
+    t.move_cursor(CursorMove::Bottom);
+    t.fold_lines(0, 2);
+    // Marker after the last row of the first line is truncated at the edge
+    assert_eq!(render(&t, 4, 4), ["abcd", "efgh", "mn", ""]);
+
+    // Cursor moves through wrapped rows of the first line and skips the hidden lines
+    t.move_cursor(CursorMove::Up);
+    assert_eq!(t.cursor().0, 0);
+    t.move_cursor(CursorMove::Down);
+    assert_eq!(t.cursor().0, 3);
+}
+
+#[test]
+fn scroll_over_folded_lines() {
+    let lines: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+    let mut t = TextArea::from(lines);
+    t.fold_lines(1, 10);
+    render(&t, 16, 4);
+
+    t.scroll(Scrolling::PageDown);
+    render(&t, 16, 4);
+    // Rows on the screen are "0", "1 ···", "11", "12", so one page down starts at "13"
+    assert_eq!(t.cursor().0, 13);
+
+    t.scroll(Scrolling::PageUp);
+    render(&t, 16, 4);
+    assert_eq!(t.cursor().0, 12);
+}
+
+#[test]
+fn click_row_after_fold() {
+    let mut t = textarea(&["a", "b", "c", "d"]);
+    t.fold_lines(0, 2);
+    render(&t, 16, 4);
+    assert_eq!(t.screen_to_cursor(0, 1), Some((3, 0)));
+    assert_eq!(t.screen_to_cursor(10, 0), Some((0, 1)));
+}
+
+#[test]
+fn measure_folded_lines() {
+    let mut t = textarea(&["a", "b", "c", "d"]);
+    t.set_min_rows(1);
+    let before = t.measure(10).preferred_rows;
+    t.fold_lines(0, 2);
+    assert_eq!(t.measure(10).preferred_rows, before - 2);
+    t.unfold_all();
+    assert_eq!(t.measure(10).preferred_rows, before);
+}
@@ -0,0 +1,141 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, LineNumbers, Sign, TextArea, WrapMode};
+
+fn render(textarea: &TextArea<'_>, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    buf
+}
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    (0..area.height)
+        .map(|y| {
+            let row: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+fn textarea(lines: &[&str]) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().map(|l| l.to_string()));
+    t.set_cursor_style(Style::default());
+    t.set_cursor_line_style(Style::default());
+    t
+}
+
+#[test]
+fn relative_line_numbers() {
+    let mut t = textarea(&["a", "b", "c", "d"]);
+    t.set_line_number_style(Style::default());
+    t.move_cursor(CursorMove::Jump(2, 0));
+
+    t.set_line_numbers(LineNumbers::Relative);
+    assert_eq!(rows(&render(&t, 8, 4)), [" 2 a", " 1 b", " 0 c", " 1 d"]);
+
+    t.set_line_numbers(LineNumbers::Hybrid);
+    assert_eq!(rows(&render(&t, 8, 4)), [" 2 a", " 1 b", " 3 c", " 1 d"]);
+
+    t.set_line_numbers(LineNumbers::Absolute);
+    assert_eq!(rows(&render(&t, 8, 4)), [" 1 a", " 2 b", " 3 c", " 4 d"]);
+}
+
+#[test]
+fn custom_line_labels() {
+    let mut t = textarea(&["a", "b", "c"]);
+    t.set_line_number_style(Style::default());
+    t.set_line_label(3, |row: usize, cursor: usize| {
+        if row == cursor {
+            ">".to_string()
+        } else {
+            format!("L{}", row + 1)
+        }
+    });
+    assert_eq!(rows(&render(&t, 8, 3)), ["   > a", "  L2 b", "  L3 c"]);
+
+    // Labels wider than the column are truncated
+    t.set_line_label(2, |row: usize, _: usize| format!("#{row:03}"));
+    assert_eq!(rows(&render(&t, 8, 3)), [" #0 a", " #0 b", " #0 c"]);
+
+    t.remove_line_label();
+    assert_eq!(rows(&render(&t, 8, 3)), [" 1 a", " 2 b", " 3 c"]);
+}
+
+#[test]
+fn render_signs() {
+    let mut t = textarea(&["a", "b", "c"]);
+    t.set_sign(1, Sign::error());
+    let buf = render(&t, 8, 3);
+    assert_eq!(rows(&buf), ["  a", "E b", "  c"]);
+    assert_eq!(buf[(0, 1)].fg, Color::Red);
+
+    // Sign column is put before line numbers
+    t.set_line_number_style(Style::default());
+    t.set_sign(2, Sign::new("!!", Style::default()));
+    assert_eq!(rows(&render(&t, 8, 3)), ["   1 a", "E  2 b", "!! 3 c"]);
+
+    t.clear_signs();
+    assert_eq!(rows(&render(&t, 8, 3)), [" 1 a", " 2 b", " 3 c"]);
+}
+
+#[test]
+fn signs_follow_edits() {
+    let mut t = textarea(&["a", "b", "c", "d"]);
+    t.set_sign(1, Sign::added());
+    t.set_sign(2, Sign::modified());
+    t.set_sign(3, Sign::removed());
+
+    t.insert_newline();
+    assert_eq!(t.sign(2), Some(&Sign::added()));
+    t.undo();
+    assert_eq!(t.sign(1), Some(&Sign::added()));
+
+    // Joining lines removes the sign of the second line
+    t.move_cursor(CursorMove::Jump(2, 0));
+    t.delete_char();
+    assert_eq!(t.sign(1), Some(&Sign::added()));
+    assert_eq!(t.sign(2), Some(&Sign::removed()));
+    assert_eq!(t.sign(3), None);
+
+    t.set_lines(vec!["x".to_string()], (0, 0));
+    assert_eq!(t.sign(1), None);
+}
+
+#[test]
+fn gutter_with_wrap() {
+    let mut t = textarea(&["abcdefgh", "ij"]);
+    t.set_wrap_mode(WrapMode::Glyph);
+    t.set_line_number_style(Style::default());
+    t.set_sign(0, Sign::warning());
+    // Gutter is 4 columns: sign, margin, number, margin
+    assert_eq!(
+        rows(&render(&t, 8, 4)),
+        ["W 1 abcd", "    efgh", "  2 ij", ""],
+    );
+
+    t.move_cursor(CursorMove::Down);
+    assert_eq!(t.cursor(), (0, 4));
+}
+
+#[test]
+fn click_with_signs() {
+    let mut t = textarea(&["abc", "def"]);
+    t.set_sign(0, Sign::error());
+    render(&t, 8, 2);
+    assert_eq!(t.screen_to_cursor(2, 1), Some((1, 0)));
+    assert_eq!(t.screen_to_cursor(4, 0), Some((0, 2)));
+}
+
+#[test]
+fn horizontal_scroll_with_signs() {
+    let mut t = textarea(&["0123456789abcdef"]);
+    t.set_sign(0, Sign::error());
+    t.move_cursor(CursorMove::End);
+    let buf = render(&t, 8, 1);
+    // The cursor at the end of line is visible at the last column
+    assert_eq!(rows(&buf), ["9abcdef"]);
+}
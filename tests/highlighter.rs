@@ -0,0 +1,242 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget as _;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+use tui_textarea::{CursorMove, Highlighter, Input, Key, TextArea};
+
+fn render(textarea: &TextArea<'_>) -> Buffer {
+    let area = Rect::new(0, 0, 12, 6);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    buf
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ..Input::default()
+    }
+}
+
+// Highlight digits in red and record highlighted rows
+#[derive(Clone, Default)]
+struct Digits {
+    rows: Arc<Mutex<Vec<usize>>>,
+    invalidated: Arc<Mutex<Vec<usize>>>,
+    stateful: bool,
+}
+
+impl Digits {
+    fn stateful() -> Self {
+        Self {
+            stateful: true,
+            ..Self::default()
+        }
+    }
+
+    fn take_rows(&self) -> Vec<usize> {
+        std::mem::take(&mut *self.rows.lock().unwrap())
+    }
+
+    fn share(&self) -> Self {
+        Self {
+            rows: Arc::clone(&self.rows),
+            invalidated: Arc::clone(&self.invalidated),
+            stateful: self.stateful,
+        }
+    }
+}
+
+impl Highlighter for Digits {
+    fn highlight_line(&mut self, row: usize, line: &str) -> Vec<(Range<usize>, Style)> {
+        self.rows.lock().unwrap().push(row);
+        line.char_indices()
+            .filter(|(_, c)| c.is_ascii_digit())
+            .map(|(i, _)| (i..i + 1, Style::default().fg(Color::Red)))
+            .collect()
+    }
+
+    fn invalidate(&mut self, row: usize) {
+        self.invalidated.lock().unwrap().push(row);
+    }
+
+    fn is_stateful(&self) -> bool {
+        self.stateful
+    }
+}
+
+#[test]
+fn render_highlighted_spans() {
+    let mut textarea = TextArea::from(["a1b", "22c"]);
+    textarea.set_highlighter(Digits::default());
+    let buf = render(&textarea);
+
+    // Highlight is applied on top of the cursor line style
+    assert_eq!(buf[(0, 0)].fg, Color::Reset); // Cursor
+    assert_eq!(buf[(1, 0)].fg, Color::Red);
+    assert!(buf[(1, 0)].modifier.contains(Modifier::UNDERLINED));
+    assert_eq!(buf[(2, 0)].fg, Color::Reset);
+    assert_eq!(buf[(0, 1)].fg, Color::Red);
+    assert_eq!(buf[(1, 1)].fg, Color::Red);
+    assert!(!buf[(1, 1)].modifier.contains(Modifier::UNDERLINED));
+    assert_eq!(buf[(2, 1)].fg, Color::Reset);
+
+    // Selection is rendered over the highlight
+    textarea.move_cursor(CursorMove::Down);
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::End);
+    let buf = render(&textarea);
+    assert_eq!(buf[(1, 1)].bg, Color::LightBlue);
+    assert_eq!(buf[(1, 1)].fg, Color::Reset);
+    assert_eq!(buf[(1, 0)].fg, Color::Red);
+
+    // Highlight is disabled while masking text
+    textarea.set_mask_char('*');
+    let buf = render(&textarea);
+    assert_eq!(buf[(1, 0)].fg, Color::Reset);
+
+    textarea.clear_mask_char();
+    textarea.remove_highlighter();
+    assert!(!textarea.has_highlighter());
+    let buf = render(&textarea);
+    assert_eq!(buf[(1, 0)].fg, Color::Reset);
+}
+
+#[test]
+fn render_highlighted_spans_with_wrap() {
+    let mut textarea = TextArea::from(["aaaaaaaaaa1 2"]); // Wrapped after the space
+    textarea.set_wrap_mode(tui_textarea::WrapMode::Glyph);
+    textarea.set_highlighter(Digits::default());
+    let buf = render(&textarea);
+    assert_eq!(buf[(10, 0)].fg, Color::Red);
+    assert_eq!(buf[(11, 0)].fg, Color::Reset);
+    assert_eq!(buf[(0, 1)].fg, Color::Red);
+}
+
+#[test]
+fn invalidate_only_edited_lines() {
+    let highlighter = Digits::default();
+    let mut textarea = TextArea::from(["1", "2", "3", "4"]);
+    textarea.set_highlighter(highlighter.share());
+
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [0, 1, 2, 3]);
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [] as [usize; 0]);
+
+    textarea.move_cursor(CursorMove::Down);
+    textarea.insert_char('x');
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [1]);
+
+    textarea.insert_newline();
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [1, 2]);
+
+    textarea.undo();
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [1]);
+
+    textarea.redo();
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [1, 2]);
+
+    textarea.input(key(Key::Backspace));
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [1]);
+    assert_eq!(textarea.lines(), ["1", "x2", "3", "4"]);
+
+    textarea.set_lines(vec!["5".into(), "6".into()], (0, 0));
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [0, 1]);
+
+    textarea.invalidate_highlights();
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [0, 1]);
+}
+
+#[test]
+fn invalidate_following_lines_with_stateful_highlighter() {
+    let highlighter = Digits::stateful();
+    let mut textarea = TextArea::from(["1", "2", "3", "4"]);
+    textarea.set_highlighter(highlighter.share());
+
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [0, 1, 2, 3]);
+
+    textarea.move_cursor(CursorMove::Jump(2, 0));
+    textarea.insert_char('x');
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [2, 3]);
+    assert_eq!(*highlighter.invalidated.lock().unwrap(), [2]);
+
+    textarea.undo();
+    render(&textarea);
+    assert_eq!(highlighter.take_rows(), [2, 3]);
+    assert_eq!(*highlighter.invalidated.lock().unwrap(), [2, 2]);
+}
+
+// Stateful highlighter which checks lines are highlighted in order since the last invalidation
+#[derive(Clone, Default)]
+struct Ordered {
+    next: usize,
+}
+
+impl Highlighter for Ordered {
+    fn highlight_line(&mut self, row: usize, _line: &str) -> Vec<(Range<usize>, Style)> {
+        assert_eq!(row, self.next, "lines must be highlighted in order");
+        self.next += 1;
+        vec![]
+    }
+
+    fn invalidate(&mut self, row: usize) {
+        self.next = self.next.min(row);
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn clones_have_their_own_highlighter_states() {
+    let mut textarea = TextArea::from(["1", "2", "3", "4"]);
+    textarea.set_highlighter(Ordered::default());
+    render(&textarea);
+
+    let mut cloned = textarea.clone();
+    textarea.move_cursor(CursorMove::Jump(1, 0));
+    textarea.insert_char('x');
+    cloned.move_cursor(CursorMove::Jump(3, 0));
+    cloned.insert_char('x');
+
+    // Invalidating the original textarea doesn't affect the states of the clone
+    render(&cloned);
+    render(&textarea);
+}
+
+#[cfg(feature = "syntect")]
+#[test]
+fn syntect_highlighter() {
+    use tui_textarea::SyntectHighlighter;
+
+    let mut textarea = TextArea::from(["/* a", "b */ fn"]);
+    textarea.set_highlighter(SyntectHighlighter::from_extension("rs").unwrap());
+    let buf = render(&textarea);
+
+    // Block comment continues to the next line
+    let comment = buf[(3, 0)].fg;
+    assert!(matches!(comment, Color::Rgb(..)), "{comment:?}");
+    assert_eq!(buf[(0, 1)].fg, comment);
+    assert_ne!(buf[(5, 1)].fg, comment);
+
+    // Closing the comment in the first line changes the highlight of the second line
+    textarea.move_cursor(CursorMove::End);
+    textarea.insert_str(" */");
+    let buf = render(&textarea);
+    assert_ne!(buf[(0, 1)].fg, comment);
+
+    assert!(SyntectHighlighter::from_extension("no-such-extension").is_none());
+}
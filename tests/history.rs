@@ -1,4 +1,4 @@
-use tui_textarea::TextArea;
+use tui_textarea::{Action, CursorMove, Input, Key, TextArea, UndoGrouping};
 
 // Regression test for #4
 #[test]
@@ -8,3 +8,388 @@ fn disable_history() {
     assert!(t.insert_str("hello"));
     assert_eq!(t.lines(), ["hello"]);
 }
+
+fn type_str(t: &mut TextArea<'_>, s: &str) {
+    for c in s.chars() {
+        t.input(Input {
+            key: if c == '\n' { Key::Enter } else { Key::Char(c) },
+            ..Input::default()
+        });
+    }
+}
+
+fn undo_all(t: &mut TextArea<'_>) -> Vec<String> {
+    let mut history = vec![t.lines().join("\n")];
+    while t.undo() {
+        history.push(t.lines().join("\n"));
+    }
+    history
+}
+
+#[test]
+fn undo_grouping_of_typed_chars() {
+    #[rustfmt::skip]
+    let tests: &[(UndoGrouping, &[&str])] = &[
+        (UndoGrouping::None, &["ab c\nd", "ab c\n", "ab c", "ab ", "ab", "a", ""]),
+        (UndoGrouping::Word, &["ab c\nd", "ab c\n", "ab c", "ab ", ""]),
+        (UndoGrouping::Line, &["ab c\nd", "ab c\n", "ab c", ""]),
+    ];
+
+    for (grouping, expected) in tests {
+        let mut t = TextArea::default();
+        t.set_undo_grouping(*grouping);
+        type_str(&mut t, "ab c\nd");
+        assert_eq!(undo_all(&mut t), *expected, "{grouping:?}");
+    }
+}
+
+#[test]
+fn undo_grouping_of_deleted_chars() {
+    let mut t = TextArea::from(["abc def"]);
+    t.move_cursor(CursorMove::End);
+    for _ in 0..5 {
+        t.delete_char();
+    }
+    assert_eq!(t.lines(), ["ab"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["abc"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["abc def"]);
+
+    t.move_cursor(CursorMove::Head);
+    for _ in 0..3 {
+        t.delete_next_char();
+    }
+    assert_eq!(t.lines(), [" def"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["abc def"]);
+
+    // Typing after deleting starts a new group
+    t.move_cursor(CursorMove::Head);
+    t.delete_next_char();
+    t.insert_char('x');
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["bc def"]);
+}
+
+#[test]
+fn undo_grouping_ends_by_cursor_move() {
+    let mut t = TextArea::default();
+    type_str(&mut t, "ab");
+    t.move_cursor(CursorMove::Head);
+    type_str(&mut t, "cd");
+    assert_eq!(undo_all(&mut t), ["cdab", "ab", ""]);
+}
+
+#[test]
+fn undo_grouping_ends_by_cursor_move_and_back() {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget as _;
+
+    let moves: [fn(&mut TextArea<'_>); 4] = [
+        |t| {
+            t.move_cursor(CursorMove::Back);
+            t.move_cursor(CursorMove::Forward);
+        },
+        |t| {
+            t.start_selection();
+            t.cancel_selection();
+        },
+        |t| {
+            t.start_block_selection();
+            t.perform(Action::Move(CursorMove::End));
+        },
+        |t| {
+            t.mouse_down(2, 0, false);
+            t.mouse_drag(2, 0);
+            t.mouse_up();
+        },
+    ];
+    for (i, f) in moves.into_iter().enumerate() {
+        let mut t = TextArea::default();
+        let area = Rect::new(0, 0, 10, 3);
+        (&t).render(area, &mut Buffer::empty(area));
+        type_str(&mut t, "ab");
+        f(&mut t);
+        type_str(&mut t, "cd");
+        assert_eq!(undo_all(&mut t), ["abcd", "ab", ""], "{i}");
+    }
+}
+
+#[test]
+fn undo_redo_transaction() {
+    let mut t = TextArea::default();
+    t.insert_str("a");
+
+    t.begin_transaction();
+    t.insert_newline();
+    t.begin_transaction(); // Nested transaction
+    t.insert_str("b");
+    t.end_transaction();
+    t.insert_char('c');
+    t.end_transaction();
+    assert_eq!(t.lines(), ["a", "bc"]);
+
+    // Transaction is not merged with the following edit
+    t.insert_char('d');
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["a", "bc"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["a"]);
+    assert!(t.redo());
+    assert_eq!(t.lines(), ["a", "bc"]);
+
+    // Unbalanced end does nothing
+    t.end_transaction();
+    t.insert_str("e");
+    t.insert_str("f");
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["a", "bce"]);
+}
+
+#[test]
+fn set_max_histories_keeps_undo_grouping() {
+    let mut t = TextArea::default();
+    t.set_undo_grouping(UndoGrouping::Line);
+    t.set_max_histories(10);
+    assert_eq!(t.undo_grouping(), UndoGrouping::Line);
+}
+
+fn save_history(t: &TextArea<'_>) -> Vec<u8> {
+    let mut file = vec![];
+    t.save_undo_history(&mut file).unwrap();
+    file
+}
+
+#[test]
+fn load_saved_undo_history() {
+    let mut t = TextArea::default();
+    type_str(&mut t, "hello world\nfoo");
+    t.insert_str("a\nb");
+    t.delete_line_by_head();
+    t.undo();
+    let file = save_history(&t);
+
+    let mut r = TextArea::from(t.lines().iter().cloned());
+    assert!(r.load_undo_history(file.as_slice()).unwrap());
+
+    // The undone edit can be redone
+    assert!(r.redo());
+    assert_eq!(r.lines(), ["hello world", "fooa", ""]);
+    assert!(r.undo());
+    assert_eq!(undo_all(&mut r), undo_all(&mut t));
+}
+
+#[test]
+fn load_undo_history_for_other_text() {
+    let mut t = TextArea::default();
+    t.insert_str("hello");
+    let file = save_history(&t);
+
+    for lines in [&["hell"][..], &["hello", ""], &["", "hello"]] {
+        let mut r = TextArea::from(lines.iter().copied());
+        assert!(!r.load_undo_history(file.as_slice()).unwrap(), "{lines:?}");
+        assert!(!r.undo(), "{lines:?}");
+    }
+
+    // The current history is kept
+    let mut r = TextArea::from(["hell"]);
+    r.insert_char('o');
+    assert!(!r.load_undo_history(file.as_slice()).unwrap());
+    assert!(r.undo());
+    assert_eq!(r.lines(), ["hell"]);
+}
+
+#[test]
+fn load_undo_history_of_other_version() {
+    let t = TextArea::default();
+    let mut file = save_history(&t);
+    file[4] += 1;
+    let mut r = TextArea::default();
+    assert!(!r.load_undo_history(file.as_slice()).unwrap());
+}
+
+#[test]
+fn load_broken_undo_history() {
+    let mut t = TextArea::default();
+    t.insert_str("a\nb");
+    t.insert_char('c');
+    let file = save_history(&t);
+
+    // Truncated file
+    for len in [0, 3, 5, 13, file.len() - 1] {
+        let mut r = TextArea::from(["a", "bc"]);
+        let err = r.load_undo_history(&file[..len]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData, "{len}");
+    }
+
+    // Not an undo file
+    let mut r = TextArea::from(["a", "bc"]);
+    let err = r.load_undo_history(&b"hello world"[..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // Trailing data
+    let mut broken = file.clone();
+    broken.push(0);
+    assert!(r.load_undo_history(broken.as_slice()).is_err());
+    assert!(!r.undo());
+
+    // Positions of the last edit `InsertChar('c')` are out of the text though the hash matches
+    let end = file.len();
+    for (i, v) in [(end - 6, 5), (end - 4, 9), (end - 3, 3), (end - 1, 1)] {
+        let mut broken = file.clone();
+        broken[i] = v;
+        let mut r = TextArea::from(["a", "bc"]);
+        let err = r.load_undo_history(broken.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData, "{i}");
+        assert!(!r.undo());
+    }
+}
+
+#[test]
+fn load_undo_history_longer_than_max_histories() {
+    let mut t = TextArea::default();
+    for c in ['a', 'b', 'c'] {
+        t.insert_str(c.to_string());
+    }
+    let file = save_history(&t);
+
+    let mut r = TextArea::from(["abc"]);
+    r.set_max_histories(2);
+    r.set_undo_grouping(UndoGrouping::None);
+    assert!(r.load_undo_history(file.as_slice()).unwrap());
+    assert_eq!(undo_all(&mut r), ["abc", "ab", "a"]);
+    assert_eq!(r.max_histories(), 2);
+    assert_eq!(r.undo_grouping(), UndoGrouping::None);
+}
+
+#[test]
+fn undo_tree_keeps_undone_edits() {
+    let mut t = TextArea::default();
+    assert!(!t.undo_tree());
+    t.set_undo_tree(true);
+    assert!(t.undo_tree());
+
+    type_str(&mut t, "hello");
+    t.undo();
+    type_str(&mut t, "world");
+    t.undo();
+    type_str(&mut t, "foo");
+    assert_eq!(t.undo_branches(), [1, 2, 3]);
+    assert_eq!(t.undo_seq(), 3);
+
+    assert!(t.undo_jump(1));
+    assert_eq!(t.lines(), ["hello"]);
+    assert_eq!(t.cursor(), (0, 5));
+    assert!(t.undo_later(1));
+    assert_eq!(t.lines(), ["world"]);
+    assert!(!t.undo_jump(2));
+
+    // Undo and redo in the branch
+    assert!(t.undo());
+    assert_eq!(t.lines(), [""]);
+    assert!(t.redo());
+    assert_eq!(t.lines(), ["world"]);
+
+    let states = t.undo_states();
+    let parents: Vec<_> = states.iter().map(|s| s.parent).collect();
+    assert_eq!(parents, [None, Some(0), Some(0), Some(0)]);
+    let current: Vec<_> = states.iter().map(|s| s.current).collect();
+    assert_eq!(current, [false, false, true, false]);
+}
+
+#[test]
+fn undo_tree_disabled_removes_undone_edits() {
+    let mut t = TextArea::default();
+    type_str(&mut t, "hello");
+    t.undo();
+    type_str(&mut t, "world");
+    assert_eq!(t.undo_branches(), [2]);
+    assert!(!t.undo_jump(1));
+    assert!(t.undo_earlier(1));
+    assert_eq!(t.lines(), [""]);
+}
+
+#[test]
+fn undo_by_time() {
+    use std::time::Duration;
+
+    let mut t = TextArea::default();
+    t.set_undo_tree(true);
+    type_str(&mut t, "hello");
+    t.undo();
+    type_str(&mut t, "world");
+
+    // All states were created just now
+    assert!(!t.undo_earlier_by(Duration::ZERO));
+    assert!(t.undo_earlier_by(Duration::from_secs(3600)));
+    assert_eq!(t.lines(), [""]);
+    assert!(t.undo_later_by(Duration::from_secs(3600)));
+    assert_eq!(t.lines(), ["world"]);
+    assert!(t.undo_earlier_by(Duration::MAX));
+    assert!(t.undo_later_by(Duration::MAX));
+    assert_eq!(t.lines(), ["world"]);
+}
+
+#[test]
+fn save_and_load_undo_tree() {
+    let mut t = TextArea::default();
+    t.set_undo_tree(true);
+    type_str(&mut t, "hello");
+    t.undo();
+    type_str(&mut t, "world");
+    t.undo_jump(1);
+    let file = save_history(&t);
+
+    let mut r = TextArea::from(["hello"]);
+    r.set_undo_tree(true);
+    assert!(r.load_undo_history(file.as_slice()).unwrap());
+    assert_eq!(r.undo_seq(), 1);
+    assert_eq!(r.undo_branches(), [1, 2]);
+    assert!(r.undo_jump(2));
+    assert_eq!(r.lines(), ["world"]);
+
+    // Without the undo tree, only the current branch is loaded
+    let mut r = TextArea::from(["hello"]);
+    assert!(r.load_undo_history(file.as_slice()).unwrap());
+    assert_eq!(r.undo_branches(), [1]);
+}
+
+#[test]
+fn render_undo_tree() {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget as _;
+
+    let mut t = TextArea::default();
+    t.set_undo_tree(true);
+    for s in ["a", "b"] {
+        t.insert_str(s);
+        t.undo();
+    }
+    t.insert_str("c");
+    t.insert_str("d");
+
+    let rows = |t: &TextArea<'_>, height| {
+        let area = Rect::new(0, 0, 8, height);
+        let mut buf = Buffer::empty(area);
+        t.undo_tree_widget().render(area, &mut buf);
+        (0..height)
+            .map(|y| {
+                let row: String = (0..8).map(|x| buf[(x, y)].symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        rows(&t, 5),
+        ["o 0 (0s", "├─o 1 (0", "├─o 2 (0", "o 3 (0s", "@ 4 (0s"],
+    );
+
+    // Scrolled to the current state
+    t.undo_jump(4);
+    assert_eq!(rows(&t, 2), ["o 3 (0s", "@ 4 (0s"]);
+    t.undo_jump(1);
+    assert_eq!(rows(&t, 2), ["o 0 (0s", "├─@ 1 (0"]);
+}
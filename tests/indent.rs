@@ -0,0 +1,224 @@
+use tui_textarea::{AutoIndent, CursorMove, Input, Key, MaxLength, MaxLines, TextArea};
+
+fn textarea(lines: &[&str], auto_indent: AutoIndent) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().map(|l| l.to_string()));
+    t.set_auto_indent(auto_indent);
+    t
+}
+
+#[test]
+fn no_auto_indent_by_default() {
+    let mut t = TextArea::from(["    foo {"]);
+    assert_eq!(t.auto_indent(), AutoIndent::None);
+    t.move_cursor(CursorMove::End);
+    t.insert_newline();
+    assert_eq!(t.lines(), ["    foo {", ""]);
+    assert_eq!(t.cursor(), (1, 0));
+}
+
+#[test]
+fn keep_indent() {
+    let mut t = textarea(&["  \tfoo {"], AutoIndent::Keep);
+    t.move_cursor(CursorMove::End);
+    t.insert_newline();
+    assert_eq!(t.lines(), ["  \tfoo {", "  \t"]);
+    assert_eq!(t.cursor(), (1, 3));
+
+    // Closing bracket is not outdented
+    t.insert_char('}');
+    assert_eq!(t.lines(), ["  \tfoo {", "  \t}"]);
+
+    // Newline with indentation is undone at once
+    t.undo();
+    t.undo();
+    assert_eq!(t.lines(), ["  \tfoo {"]);
+    assert_eq!(t.cursor(), (0, 8));
+}
+
+#[test]
+fn keep_indent_in_middle_of_line() {
+    let mut t = textarea(&["    foo bar"], AutoIndent::Keep);
+    t.move_cursor(CursorMove::Jump(0, 8));
+    t.insert_newline();
+    assert_eq!(t.lines(), ["    foo ", "    bar"]);
+    assert_eq!(t.cursor(), (1, 4));
+
+    // Cursor inside the indentation copies only the indentation before the cursor
+    let mut t = textarea(&["    foo"], AutoIndent::Keep);
+    t.move_cursor(CursorMove::Jump(0, 2));
+    t.insert_newline();
+    assert_eq!(t.lines(), ["  ", "    foo"]);
+}
+
+#[test]
+fn smart_indent_after_opener() {
+    for (line, want) in [
+        ("fn main() {", "    "),
+        ("let v = [", "    "),
+        ("  call(", "      "),
+        ("key:", "    "),
+        ("if x:  ", "    "),
+        ("foo;", ""),
+    ] {
+        let mut t = textarea(&[line], AutoIndent::Smart);
+        t.move_cursor(CursorMove::End);
+        t.insert_newline();
+        assert_eq!(t.lines(), [line, want], "{line:?}");
+        assert_eq!(t.cursor(), (1, want.len()), "{line:?}");
+    }
+}
+
+#[test]
+fn smart_indent_between_brackets() {
+    let mut t = textarea(&["  f({})"], AutoIndent::Smart);
+    t.move_cursor(CursorMove::Jump(0, 5));
+    t.insert_newline();
+    assert_eq!(t.lines(), ["  f({", "      ", "  })"]);
+    assert_eq!(t.cursor(), (1, 6));
+
+    t.undo();
+    assert_eq!(t.lines(), ["  f({})"]);
+    assert_eq!(t.cursor(), (0, 5));
+}
+
+#[test]
+fn smart_indent_with_hard_tab() {
+    let mut t = textarea(&["\tif x {"], AutoIndent::Smart);
+    t.set_hard_tab_indent(true);
+    t.move_cursor(CursorMove::End);
+    t.insert_newline();
+    assert_eq!(t.lines(), ["\tif x {", "\t\t"]);
+    t.insert_char('}');
+    assert_eq!(t.lines(), ["\tif x {", "\t}"]);
+}
+
+#[test]
+fn outdent_on_closing_bracket() {
+    let mut t = textarea(&["{", "    foo", "    "], AutoIndent::Smart);
+    t.move_cursor(CursorMove::Bottom);
+    t.move_cursor(CursorMove::End);
+    t.insert_char('}');
+    assert_eq!(t.lines(), ["{", "    foo", "}"]);
+    assert_eq!(t.cursor(), (2, 1));
+
+    // Outdent and the bracket are undone at once
+    t.undo();
+    assert_eq!(t.lines(), ["{", "    foo", "    "]);
+
+    // Not outdented when some text is before the cursor
+    let mut t = textarea(&["    x"], AutoIndent::Smart);
+    t.move_cursor(CursorMove::End);
+    t.insert_char(')');
+    assert_eq!(t.lines(), ["    x)"]);
+
+    // Outdented until the previous tab stop
+    let mut t = textarea(&["      "], AutoIndent::Smart);
+    t.move_cursor(CursorMove::End);
+    t.insert_char(']');
+    assert_eq!(t.lines(), ["    ]"]);
+}
+
+#[test]
+fn indent_and_outdent_lines() {
+    let mut t = TextArea::from(["a", "  b", "c"]);
+    t.set_tab_length(2);
+    t.move_cursor(CursorMove::Jump(1, 3));
+    assert!(t.indent_lines());
+    assert_eq!(t.lines(), ["a", "    b", "c"]);
+    assert_eq!(t.cursor(), (1, 5));
+
+    // Selection ending at the head of line doesn't include the line
+    t.move_cursor(CursorMove::Jump(0, 0));
+    t.start_selection();
+    t.move_cursor(CursorMove::Jump(2, 0));
+    assert!(t.indent_lines());
+    assert_eq!(t.lines(), ["  a", "      b", "c"]);
+    assert_eq!(t.selection_range(), Some(((0, 2), (2, 0))));
+
+    assert!(t.outdent_lines());
+    assert!(t.outdent_lines());
+    assert_eq!(t.lines(), ["a", "  b", "c"]);
+    assert_eq!(t.selection_range(), Some(((0, 0), (2, 0))));
+
+    // Each outdent is undone at once
+    t.undo();
+    assert_eq!(t.lines(), ["a", "    b", "c"]);
+    t.undo();
+    assert_eq!(t.lines(), ["  a", "      b", "c"]);
+
+    // Nothing to outdent
+    t.cancel_selection();
+    t.move_cursor(CursorMove::Bottom);
+    assert!(!t.outdent_lines());
+}
+
+#[test]
+fn indent_lines_with_hard_tab() {
+    let mut t = TextArea::from(["a", "b"]);
+    t.set_hard_tab_indent(true);
+    t.select_all();
+    t.indent_lines();
+    assert_eq!(t.lines(), ["\ta", "\tb"]);
+    t.outdent_lines();
+    assert_eq!(t.lines(), ["a", "b"]);
+}
+
+#[test]
+fn outdent_with_shift_tab() {
+    let shift_tab = Input {
+        key: Key::Tab,
+        shift: true,
+        ..Default::default()
+    };
+    let mut t = TextArea::from(["        a"]);
+    assert!(t.input(shift_tab.clone()));
+    assert_eq!(t.lines(), ["    a"]);
+
+    // Tab still inserts spaces
+    let tab = Input {
+        key: Key::Tab,
+        ..Default::default()
+    };
+    t.input(tab);
+    assert_eq!(t.lines(), ["        a"]);
+}
+
+#[test]
+fn auto_indent_at_each_cursor() {
+    let mut t = textarea(&["  a", "  b"], AutoIndent::Keep);
+    t.move_cursor(CursorMove::End);
+    t.add_cursor((1, 3));
+    t.insert_newline();
+    assert_eq!(t.lines(), ["  a", "  ", "  b", "  "]);
+}
+
+#[test]
+fn validate_indentation() {
+    // The indentation and the line of the closing bracket are validated with the newline
+    let mut t = textarea(&["f() {}"], AutoIndent::Smart);
+    t.add_validator(MaxLines(2));
+    t.move_cursor(CursorMove::Jump(0, 5));
+    assert!(t.input(Input {
+        key: Key::Enter,
+        ..Default::default()
+    }));
+    assert_eq!(t.lines(), ["f() {", "    }"]);
+    assert_eq!(t.cursor(), (1, 4));
+    t.insert_newline();
+    assert_eq!(t.lines(), ["f() {", "    }"]);
+    assert!(t.validation_error().is_some());
+
+    let mut t = textarea(&["    ab"], AutoIndent::Keep);
+    t.add_validator(MaxLength(6));
+    t.move_cursor(CursorMove::End);
+    t.insert_newline();
+    assert_eq!(t.lines(), ["    ab", "    "]);
+
+    // Lines are indented only when all indentations are accepted
+    let mut t = textarea(&["ab", "abcdef"], AutoIndent::None);
+    t.add_validator(MaxLength(6));
+    t.select_all();
+    assert!(!t.indent_lines());
+    assert_eq!(t.lines(), ["ab", "abcdef"]);
+    assert!(t.validation_error().is_some());
+}
@@ -10,7 +10,7 @@ fn test_input_all_combinations_sanity() {
             for alt in [true, false] {
                 for shift in [true, false] {
                     inputs.push(Input {
-                        key,
+                        key: key.clone(),
                         ctrl,
                         alt,
                         shift,
@@ -50,6 +50,7 @@ fn test_input_all_combinations_sanity() {
         Copy,
         Cut,
         Paste,
+        Pasted("a\nb".to_string()),
     ] {
         push_all_modifiers_combination(&mut inputs, k);
     }
@@ -115,3 +116,33 @@ fn test_ctrl_u_after_select_all_then_delete_str_max_on_empty_buffer() {
     assert_eq!(t.lines(), [""]);
     assert_eq!(t.cursor(), (0, 0));
 }
+
+fn pasted(text: &str) -> Input {
+    Input {
+        key: Key::Pasted(text.to_string()),
+        ..Input::default()
+    }
+}
+
+#[test]
+fn test_insert_pasted_text_as_single_edit() {
+    let mut t = TextArea::from(["abc"]);
+    t.move_cursor(tui_textarea::CursorMove::End);
+    assert!(t.input(pasted("d\r\ne\rf\tg")));
+    assert_eq!(t.lines(), ["abcd", "e", "f\tg"]);
+    assert_eq!(t.cursor(), (2, 3));
+
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["abc"]);
+    assert!(t.redo());
+    assert_eq!(t.lines(), ["abcd", "e", "f\tg"]);
+
+    // Selection is replaced with the pasted text in one step
+    t.select_all();
+    assert!(t.input_without_shortcuts(pasted("x")));
+    assert_eq!(t.lines(), ["x"]);
+    assert!(t.undo());
+    assert_eq!(t.lines(), ["abcd", "e", "f\tg"]);
+
+    assert!(!t.input(pasted("")));
+}
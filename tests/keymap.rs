@@ -0,0 +1,152 @@
+use tui_textarea::{Action, CursorMove, Input, Key, KeyMap, TextArea};
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    }
+}
+
+fn ctrl(c: char) -> Input {
+    Input {
+        key: Key::Char(c),
+        ctrl: true,
+        alt: false,
+        shift: false,
+    }
+}
+
+fn shift(key: Key) -> Input {
+    Input {
+        key,
+        ctrl: false,
+        alt: false,
+        shift: true,
+    }
+}
+
+#[test]
+fn standard_key_map() {
+    let mut t = TextArea::from(["hello world"]);
+    t.set_key_map(KeyMap::standard());
+
+    // Shift+arrows select text
+    t.input(shift(Key::Right));
+    t.input(shift(Key::Right));
+    assert_eq!(t.selection_range(), Some(((0, 0), (0, 2))));
+    t.input(ctrl('c'));
+    t.input(key(Key::End));
+    assert!(!t.is_selecting());
+    assert!(t.input(ctrl('v')));
+    assert_eq!(t.lines(), ["hello worldhe"]);
+
+    // Ctrl+Z undoes and Ctrl+Y redoes
+    assert!(t.input(ctrl('z')));
+    assert_eq!(t.lines(), ["hello world"]);
+    assert!(t.input(ctrl('y')));
+    assert_eq!(t.lines(), ["hello worldhe"]);
+
+    // Emacs bindings are not available
+    t.input(ctrl('a'));
+    assert!(t.is_selecting());
+    assert!(!t.input(ctrl('k')));
+    assert!(t.input(ctrl('x')));
+    assert!(t.is_empty());
+
+    // Characters are inserted
+    assert!(t.input(key(Key::Char('a'))));
+    assert_eq!(t.lines(), ["a"]);
+
+    // Esc cancels selection
+    t.input(Input {
+        key: Key::Home,
+        ctrl: false,
+        alt: false,
+        shift: true,
+    });
+    assert!(t.is_selecting());
+    t.input(key(Key::Esc));
+    assert!(!t.is_selecting());
+}
+
+#[test]
+fn key_sequence() {
+    let mut t = TextArea::from(["abc"]);
+    t.key_map_mut()
+        .bind([ctrl('x'), ctrl('e')], Action::Move(CursorMove::End));
+
+    assert!(!t.input(ctrl('x')));
+    assert_eq!(t.key_map().pending(), [ctrl('x')]);
+    // Invalid inputs such as key release events don't interrupt the sequence
+    assert!(!t.input(key(Key::Null)));
+    assert!(!t.input(ctrl('e')));
+    assert_eq!(t.cursor(), (0, 3));
+    assert!(t.key_map().pending().is_empty());
+
+    // Ctrl+X is now a prefix key and no longer cuts the text
+    t.select_all();
+    assert!(!t.input(ctrl('x')));
+    assert!(!t.input(key(Key::Char('z'))));
+    assert_eq!(t.lines(), ["abc"]);
+
+    // Characters are inserted again after the sequence is discarded
+    t.cancel_selection();
+    assert!(t.input(key(Key::Char('z'))));
+    assert_eq!(t.lines(), ["abcz"]);
+}
+
+#[test]
+fn rebind_and_unbind_default_key_map() {
+    let mut t = TextArea::from(["abc"]);
+    t.move_cursor(CursorMove::End);
+
+    let prev = t
+        .key_map_mut()
+        .bind([ctrl('h')], Action::Move(CursorMove::Head));
+    assert_eq!(prev, Some(Action::DeleteChar));
+    assert!(!t.input(ctrl('h')));
+    assert_eq!(t.cursor(), (0, 0));
+
+    assert_eq!(
+        t.key_map_mut().unbind([ctrl('e')]),
+        Some(Action::Move(CursorMove::End))
+    );
+    assert!(!t.input(ctrl('e')));
+    assert_eq!(t.cursor(), (0, 0));
+
+    // Other bindings are not affected
+    assert!(t.input(ctrl('d')));
+    assert_eq!(t.lines(), ["bc"]);
+}
+
+#[test]
+fn resolve_custom_action() {
+    let mut t = TextArea::from(["abc"]);
+    t.key_map_mut()
+        .bind([ctrl('s')], Action::Custom("save".to_string()));
+
+    let mut saved = false;
+    for input in [ctrl('s'), ctrl('e')] {
+        match t.key_map_mut().resolve(input) {
+            Some(Action::Custom(name)) if name == "save" => saved = true,
+            Some(action) => {
+                t.perform(action);
+            }
+            None => {}
+        }
+    }
+    assert!(saved);
+    assert_eq!(t.cursor(), (0, 3));
+}
+
+#[test]
+fn empty_key_map() {
+    let mut t = TextArea::default();
+    t.set_key_map(KeyMap::new());
+    assert!(t.input(key(Key::Char('a'))));
+    assert!(!t.input(key(Key::Enter)));
+    assert!(!t.input(key(Key::Backspace)));
+    assert_eq!(t.lines(), ["a"]);
+}
@@ -0,0 +1,241 @@
+use tui_textarea::{
+    Action, CursorMove, DigitsOnly, Input, Insertion, Key, KeyMap, MaxLines, TextArea, Validation,
+};
+
+fn select_rows<'a>(lines: &[&str], start: (u16, u16), end: (u16, u16)) -> TextArea<'a> {
+    let mut textarea = TextArea::from(lines.iter().copied());
+    textarea.move_cursor(CursorMove::Jump(start.0, start.1));
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::Jump(end.0, end.1));
+    textarea
+}
+
+// Each line operation is undone as one step
+fn assert_undo_once(textarea: &mut TextArea<'_>, before: &[&str]) {
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), before);
+    assert!(!textarea.undo());
+}
+
+#[test]
+fn select_lines() {
+    let mut textarea = select_rows(&["abc", "def", "ghi"], (0, 1), (1, 1));
+    textarea.select_lines();
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 0))));
+    textarea.select_lines();
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 3))));
+    textarea.select_lines();
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 3))));
+
+    textarea.cut();
+    assert_eq!(textarea.lines(), [""]);
+    assert_eq!(textarea.yank_text(), "abc\ndef\nghi");
+
+    let mut textarea = TextArea::from(["abc", "def"]);
+    textarea.perform(Action::SelectLines);
+    textarea.cut();
+    assert_eq!(textarea.lines(), ["def"]);
+    assert_eq!(textarea.yank_text(), "abc\n");
+}
+
+#[test]
+fn duplicate_lines() {
+    let mut textarea = select_rows(&["a", "b", "c"], (0, 0), (2, 0));
+    assert!(textarea.duplicate_lines());
+    assert_eq!(textarea.lines(), ["a", "b", "a", "b", "c"]);
+    assert_eq!(textarea.selection_range(), Some(((2, 0), (4, 0))));
+    assert_undo_once(&mut textarea, &["a", "b", "c"]);
+
+    let mut textarea = TextArea::from(["a", "b"]);
+    textarea.move_cursor(CursorMove::Bottom);
+    assert!(textarea.duplicate_lines());
+    assert_eq!(textarea.lines(), ["a", "b", "b"]);
+    assert_eq!(textarea.cursor(), (2, 0));
+}
+
+#[test]
+fn delete_lines() {
+    let mut textarea = select_rows(&["a", "b", "c", "d"], (1, 0), (2, 1));
+    assert!(textarea.delete_lines());
+    assert_eq!(textarea.lines(), ["a", "d"]);
+    assert_eq!(textarea.cursor(), (1, 1));
+    assert!(!textarea.is_selecting());
+    assert_undo_once(&mut textarea, &["a", "b", "c", "d"]);
+
+    // The last line
+    let mut textarea = TextArea::from(["a", "b"]);
+    textarea.move_cursor(CursorMove::Bottom);
+    assert!(textarea.delete_lines());
+    assert_eq!(textarea.lines(), ["a"]);
+    assert_eq!(textarea.cursor(), (0, 0));
+
+    // The only line
+    let mut textarea = TextArea::from(["abc"]);
+    assert!(textarea.delete_lines());
+    assert_eq!(textarea.lines(), [""]);
+    assert!(!textarea.delete_lines());
+}
+
+#[test]
+fn move_lines() {
+    let mut textarea = select_rows(&["a", "b", "c", "d"], (1, 0), (3, 0));
+    assert!(textarea.move_lines_up());
+    assert_eq!(textarea.lines(), ["b", "c", "a", "d"]);
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (2, 0))));
+    assert!(!textarea.move_lines_up());
+    assert_undo_once(&mut textarea, &["a", "b", "c", "d"]);
+
+    // The line moved below is not added to the selection made by `select_lines`
+    let mut textarea = TextArea::from(["a", "b", "c", "d"]);
+    textarea.move_cursor(CursorMove::Down);
+    textarea.select_lines();
+    assert!(textarea.move_lines_up());
+    assert_eq!(textarea.lines(), ["b", "a", "c", "d"]);
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (1, 0))));
+    assert!(textarea.cut());
+    assert_eq!(textarea.lines(), ["a", "c", "d"]);
+
+    let mut textarea = select_rows(&["a", "b", "c", "d"], (0, 0), (1, 1));
+    assert!(textarea.move_lines_down());
+    assert_eq!(textarea.lines(), ["c", "a", "b", "d"]);
+    assert_eq!(textarea.selection_range(), Some(((1, 0), (2, 1))));
+    assert!(textarea.move_lines_down());
+    assert_eq!(textarea.lines(), ["c", "d", "a", "b"]);
+    assert!(!textarea.move_lines_down());
+    assert_eq!(textarea.cursor(), (3, 1));
+
+    // The selection ending at the head of the last line stays at the end of the text
+    let mut textarea = select_rows(&["a", "b"], (0, 0), (1, 0));
+    assert!(textarea.move_lines_down());
+    assert_eq!(textarea.lines(), ["b", "a"]);
+    assert_eq!(textarea.selection_range(), Some(((1, 0), (1, 1))));
+}
+
+#[test]
+fn move_lines_with_keys() {
+    let alt = |key| Input {
+        key,
+        ctrl: false,
+        alt: true,
+        shift: false,
+    };
+    for key_map in [KeyMap::emacs(), KeyMap::standard()] {
+        let mut textarea = TextArea::from(["a", "b"]);
+        textarea.set_key_map(key_map);
+        assert!(textarea.input(alt(Key::Down)));
+        assert_eq!(textarea.lines(), ["b", "a"]);
+        assert!(textarea.input(alt(Key::Up)));
+        assert_eq!(textarea.lines(), ["a", "b"]);
+    }
+}
+
+#[test]
+fn join_lines() {
+    let mut textarea = TextArea::from(["a", "  b", "", "c ", "d", "\te"]);
+    assert!(textarea.join_lines());
+    assert_eq!(textarea.lines(), ["a b", "", "c ", "d", "\te"]);
+    assert_eq!(textarea.cursor(), (0, 1));
+    assert_undo_once(&mut textarea, &["a", "  b", "", "c ", "d", "\te"]);
+
+    // Blank lines and trailing spaces don't add a space
+    let mut textarea = select_rows(&["a", "  b", "", "c ", "d", "\te"], (1, 0), (5, 1));
+    assert!(textarea.join_lines());
+    assert_eq!(textarea.lines(), ["a", "  b c d e"]);
+    assert_undo_once(&mut textarea, &["a", "  b", "", "c ", "d", "\te"]);
+
+    let mut textarea = TextArea::from(["a"]);
+    assert!(!textarea.join_lines());
+}
+
+#[test]
+fn sort_lines() {
+    let mut textarea = select_rows(&["d", "c", "b", "a"], (1, 0), (3, 1));
+    assert!(textarea.sort_lines());
+    assert_eq!(textarea.lines(), ["d", "a", "b", "c"]);
+    assert_eq!(textarea.selection_range(), Some(((1, 0), (3, 1))));
+    assert!(!textarea.sort_lines());
+    assert_undo_once(&mut textarea, &["d", "c", "b", "a"]);
+}
+
+#[test]
+fn toggle_comment() {
+    let mut textarea = select_rows(&["fn f() {", "", "    g();", "}"], (0, 0), (2, 1));
+    assert!(textarea.toggle_comment());
+    assert_eq!(textarea.lines(), ["// fn f() {", "", "//     g();", "}"]);
+    assert_eq!(textarea.selection_range(), Some(((0, 3), (2, 4))));
+    assert_undo_once(&mut textarea, &["fn f() {", "", "    g();", "}"]);
+
+    // Comments are inserted at the smallest indentation
+    let mut textarea = TextArea::from(["    a", "  b"]);
+    textarea.select_all();
+    textarea.perform(Action::ToggleComment);
+    assert_eq!(textarea.lines(), ["  //   a", "  // b"]);
+    textarea.perform(Action::ToggleComment);
+    assert_eq!(textarea.lines(), ["    a", "  b"]);
+
+    // Lines are commented unless all lines are commented
+    let mut textarea = TextArea::from(["# a", "b", "#c"]);
+    textarea.set_comment_prefix("#");
+    textarea.select_all();
+    assert!(textarea.toggle_comment());
+    assert_eq!(textarea.lines(), ["# # a", "# b", "# #c"]);
+    assert!(textarea.toggle_comment());
+    assert_eq!(textarea.lines(), ["# a", "b", "#c"]);
+
+    // A space after the prefix is removed if any
+    let mut textarea = TextArea::from(["#a", "# b"]);
+    textarea.set_comment_prefix("#");
+    textarea.select_all();
+    assert!(textarea.toggle_comment());
+    assert_eq!(textarea.lines(), ["a", "b"]);
+
+    textarea.set_comment_prefix("");
+    assert!(!textarea.toggle_comment());
+}
+
+#[test]
+fn validate_line_operations() {
+    let mut textarea = TextArea::from(["a", "b"]);
+    textarea.add_validator(MaxLines(2));
+    assert!(!textarea.duplicate_lines());
+    assert_eq!(textarea.lines(), ["a", "b"]);
+    assert_eq!(
+        textarea.validation_error(),
+        Some("Text must be at most 2 lines")
+    );
+
+    // Moving or joining lines doesn't increase the number of lines
+    assert!(textarea.move_lines_down());
+    assert_eq!(textarea.lines(), ["b", "a"]);
+    textarea.move_cursor(CursorMove::Top);
+    assert!(textarea.join_lines());
+    assert_eq!(textarea.lines(), ["b a"]);
+    assert_eq!(textarea.validation_error(), None);
+
+    let mut textarea = TextArea::from(["1", "2"]);
+    textarea.add_validator(DigitsOnly);
+    textarea.select_all();
+    assert!(!textarea.toggle_comment());
+    assert_eq!(textarea.lines(), ["1", "2"]);
+    assert!(textarea.validation_error().is_some());
+
+    // The text is not changed when the validators transform the text of the line operation
+    assert!(!textarea.join_lines());
+    assert_eq!(textarea.lines(), ["1", "2"]);
+
+    let mut textarea = TextArea::from(["b", "a"]);
+    textarea.add_validator(|i: &Insertion<'_>| {
+        if i.text.starts_with('a') {
+            Validation::Reject("Locked".to_string())
+        } else {
+            Validation::Accept
+        }
+    });
+    textarea.select_all();
+    assert!(!textarea.sort_lines());
+    assert_eq!(textarea.validation_error(), Some("Locked"));
+    textarea.cancel_selection();
+    assert!(!textarea.move_lines_up());
+    assert_eq!(textarea.lines(), ["b", "a"]);
+    assert_eq!(textarea.cursor(), (1, 1));
+}
@@ -0,0 +1,253 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, Widget as _};
+use tui_textarea::{CursorMove, Input, Key, TextArea, WrapMode};
+
+fn render(textarea: &TextArea<'_>, x: u16, y: u16, width: u16, height: u16) {
+    let area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+}
+
+fn mouse(key: Key, shift: bool) -> Input {
+    Input {
+        key,
+        ctrl: false,
+        alt: false,
+        shift,
+    }
+}
+
+fn click(textarea: &mut TextArea<'_>, column: u16, row: u16) {
+    textarea.input(mouse(Key::MouseDown(column, row), false));
+    textarea.input(mouse(Key::MouseUp(column, row), false));
+}
+
+#[test]
+fn screen_to_cursor_before_render() {
+    let textarea = TextArea::from(["abc"]);
+    assert_eq!(textarea.screen_to_cursor(0, 0), None);
+}
+
+#[test]
+fn screen_to_cursor_with_block() {
+    let mut textarea = TextArea::from(["abc", "defgh", ""]);
+    textarea.set_block(Block::default().borders(Borders::ALL));
+    render(&textarea, 2, 3, 10, 6);
+
+    for (pos, want) in [
+        ((3, 4), Some((0, 0))),
+        ((5, 4), Some((0, 2))),
+        ((9, 4), Some((0, 3))),
+        ((4, 5), Some((1, 1))),
+        ((3, 6), Some((2, 0))),
+        ((6, 7), Some((2, 0))),
+        // Borders and outside of the widget
+        ((2, 4), None),
+        ((3, 3), None),
+        ((11, 4), None),
+        ((0, 0), None),
+    ] {
+        assert_eq!(textarea.screen_to_cursor(pos.0, pos.1), want, "{pos:?}");
+    }
+}
+
+#[test]
+fn screen_to_cursor_with_line_number() {
+    let mut textarea: TextArea = (0..12).map(|i| format!("line{i}")).collect();
+    textarea.set_line_number_style(Style::default());
+    render(&textarea, 0, 0, 20, 5);
+
+    // Line number gutter is " 1 ", " 2 ", ... with 2 digits
+    assert_eq!(textarea.screen_to_cursor(0, 1), Some((1, 0)));
+    assert_eq!(textarea.screen_to_cursor(3, 1), Some((1, 0)));
+    assert_eq!(textarea.screen_to_cursor(4, 1), Some((1, 0)));
+    assert_eq!(textarea.screen_to_cursor(6, 1), Some((1, 2)));
+}
+
+#[test]
+fn screen_to_cursor_with_wide_chars_and_tabs() {
+    let mut textarea = TextArea::from(["あいう", "\tx"]);
+    render(&textarea, 0, 0, 20, 5);
+
+    for (pos, want) in [
+        ((0, 0), (0, 0)),
+        ((1, 0), (0, 0)),
+        ((2, 0), (0, 1)),
+        ((5, 0), (0, 2)),
+        ((6, 0), (0, 3)),
+        ((3, 1), (1, 0)),
+        ((4, 1), (1, 1)),
+        ((5, 1), (1, 2)),
+    ] {
+        assert_eq!(
+            textarea.screen_to_cursor(pos.0, pos.1),
+            Some(want),
+            "{pos:?}"
+        );
+    }
+
+    textarea.set_mask_char('*');
+    assert_eq!(textarea.screen_to_cursor(2, 0), Some((0, 2)));
+}
+
+#[test]
+fn screen_to_cursor_with_scroll() {
+    let mut textarea: TextArea = (0..20).map(|i| format!("{i}abcdefghij")).collect();
+    textarea.move_cursor(CursorMove::Jump(15, 10));
+    render(&textarea, 0, 0, 5, 4);
+
+    assert_eq!(textarea.screen_to_cursor(0, 0), Some((12, 6)));
+    assert_eq!(textarea.screen_to_cursor(4, 3), Some((15, 10)));
+}
+
+#[test]
+fn screen_to_cursor_with_wrap() {
+    let mut textarea = TextArea::from(["abcdefgh", "ij"]);
+    textarea.set_wrap_mode(WrapMode::Glyph);
+    render(&textarea, 0, 0, 3, 6);
+
+    for (pos, want) in [
+        ((0, 0), (0, 0)),
+        ((2, 0), (0, 2)),
+        ((0, 1), (0, 3)),
+        ((1, 2), (0, 7)),
+        ((2, 2), (0, 8)),
+        ((1, 3), (1, 1)),
+        ((2, 4), (1, 2)),
+    ] {
+        assert_eq!(
+            textarea.screen_to_cursor(pos.0, pos.1),
+            Some(want),
+            "{pos:?}"
+        );
+    }
+}
+
+#[test]
+fn click_moves_cursor() {
+    let mut textarea = TextArea::from(["hello", "world"]);
+    render(&textarea, 0, 0, 10, 4);
+
+    click(&mut textarea, 3, 1);
+    assert_eq!(textarea.cursor(), (1, 3));
+    assert!(!textarea.is_selecting());
+
+    // Clicking outside of the textarea does nothing
+    click(&mut textarea, 20, 20);
+    assert_eq!(textarea.cursor(), (1, 3));
+}
+
+#[test]
+fn click_cancels_selection() {
+    let mut textarea = TextArea::from(["hello", "world"]);
+    render(&textarea, 0, 0, 10, 4);
+
+    textarea.select_all();
+    click(&mut textarea, 1, 0);
+    assert!(!textarea.is_selecting());
+    assert_eq!(textarea.cursor(), (0, 1));
+}
+
+#[test]
+fn drag_selects_text() {
+    let mut textarea = TextArea::from(["hello", "world"]);
+    render(&textarea, 0, 0, 10, 4);
+
+    textarea.input(mouse(Key::MouseDown(1, 0), false));
+    textarea.input(mouse(Key::MouseDrag(2, 1), false));
+    assert_eq!(textarea.selection_range(), Some(((0, 1), (1, 2))));
+    textarea.input(mouse(Key::MouseDrag(0, 0), false));
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (0, 1))));
+    textarea.input(mouse(Key::MouseUp(0, 0), false));
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (0, 1))));
+
+    textarea.copy();
+    assert_eq!(textarea.yank_text(), "h");
+
+    // Moving mouse after releasing the button does nothing
+    textarea.input(mouse(Key::MouseDrag(4, 1), false));
+    assert!(!textarea.is_selecting());
+}
+
+#[test]
+fn drag_outside_of_textarea() {
+    let mut textarea: TextArea = (0..10).map(|i| i.to_string()).collect();
+    render(&textarea, 0, 2, 10, 4);
+
+    textarea.mouse_down(0, 3, false);
+    textarea.mouse_drag(0, 20);
+    assert_eq!(textarea.cursor(), (4, 0));
+    textarea.mouse_drag(5, 0);
+    assert_eq!(textarea.cursor(), (0, 1));
+    textarea.mouse_up();
+    assert_eq!(textarea.selection_range(), Some(((0, 1), (1, 0))));
+}
+
+#[test]
+fn repeated_mouse_down_drags() {
+    let mut textarea = TextArea::from(["hello world"]);
+    render(&textarea, 0, 0, 20, 4);
+
+    // termwiz reports moving the mouse with the left button held as pressing it
+    textarea.input(mouse(Key::MouseDown(0, 0), false));
+    textarea.input(mouse(Key::MouseDown(4, 0), false));
+    textarea.input(mouse(Key::MouseUp(4, 0), false));
+    assert_eq!(textarea.selection_range(), Some(((0, 0), (0, 4))));
+}
+
+#[test]
+fn double_click_selects_word() {
+    let mut textarea = TextArea::from(["let foo_bar = 42;"]);
+    render(&textarea, 0, 0, 20, 4);
+
+    click(&mut textarea, 6, 0);
+    click(&mut textarea, 6, 0);
+    assert_eq!(textarea.selection_range(), Some(((0, 4), (0, 11))));
+    textarea.copy();
+    assert_eq!(textarea.yank_text(), "foo_bar");
+}
+
+#[test]
+fn triple_click_selects_line() {
+    let mut textarea = TextArea::from(["hello world", "goodbye"]);
+    render(&textarea, 0, 0, 20, 4);
+
+    click(&mut textarea, 2, 1);
+    click(&mut textarea, 2, 1);
+    click(&mut textarea, 2, 1);
+    assert_eq!(textarea.selection_range(), Some(((1, 0), (1, 7))));
+
+    // 4th click starts over from single click
+    click(&mut textarea, 2, 1);
+    assert!(!textarea.is_selecting());
+    assert_eq!(textarea.cursor(), (1, 2));
+}
+
+#[test]
+fn shift_click_extends_selection() {
+    let mut textarea = TextArea::from(["hello", "world"]);
+    render(&textarea, 0, 0, 10, 4);
+
+    textarea.move_cursor(CursorMove::Forward);
+    textarea.input(mouse(Key::MouseDown(3, 1), true));
+    textarea.input(mouse(Key::MouseUp(3, 1), true));
+    assert_eq!(textarea.selection_range(), Some(((0, 1), (1, 3))));
+}
+
+#[test]
+fn input_without_shortcuts_handles_mouse() {
+    let mut textarea = TextArea::from(["hello", "world"]);
+    render(&textarea, 0, 0, 10, 4);
+
+    textarea.input_without_shortcuts(mouse(Key::MouseDown(4, 1), false));
+    textarea.input_without_shortcuts(mouse(Key::MouseDrag(1, 1), false));
+    textarea.input_without_shortcuts(mouse(Key::MouseUp(1, 1), false));
+    assert_eq!(textarea.selection_range(), Some(((1, 1), (1, 4))));
+}
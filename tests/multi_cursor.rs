@@ -0,0 +1,259 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::Widget as _;
+use tui_textarea::{Action, CursorMove, Input, Key, KeyMap, TextArea};
+
+fn input(key: Key, ctrl: bool, alt: bool) -> Input {
+    Input {
+        key,
+        ctrl,
+        alt,
+        shift: false,
+    }
+}
+
+fn textarea_with_cursors<'a>(lines: &[&str], cursors: &[(usize, usize)]) -> TextArea<'a> {
+    let mut textarea = TextArea::from(lines.iter().copied());
+    let (first, rest) = cursors.split_first().unwrap();
+    textarea.move_cursor(CursorMove::Jump(first.0 as u16, first.1 as u16));
+    for &cursor in rest {
+        textarea.add_cursor(cursor);
+    }
+    assert_eq!(textarea.cursors(), cursors);
+    textarea
+}
+
+#[test]
+fn add_cursor_above_and_below() {
+    let mut textarea = TextArea::from(["abcd", "a", "abcd", "abcd"]);
+    textarea.move_cursor(CursorMove::Jump(1, 1));
+
+    assert!(textarea.add_cursor_below());
+    assert_eq!(textarea.cursor(), (2, 1)); // New cursor becomes the primary cursor
+    assert!(textarea.add_cursor_below());
+    assert!(!textarea.add_cursor_below());
+    assert!(textarea.add_cursor_above());
+    assert!(!textarea.add_cursor_above());
+    assert_eq!(textarea.cursor(), (0, 1));
+    assert_eq!(textarea.cursors(), [(0, 1), (1, 1), (2, 1), (3, 1)]);
+
+    // Adding a cursor at the existing cursor does nothing
+    textarea.add_cursor((2, 1));
+    assert_eq!(textarea.cursors(), [(0, 1), (1, 1), (2, 1), (3, 1)]);
+
+    textarea.clear_extra_cursors();
+    assert_eq!(textarea.cursors(), [(2, 1)]);
+}
+
+#[test]
+fn edit_at_each_cursor() {
+    type Case = (
+        &'static str,
+        fn(&mut TextArea<'_>) -> bool,
+        &'static [&'static str],
+        &'static [(usize, usize)],
+    );
+
+    #[rustfmt::skip]
+    let tests: &[Case] = &[
+        ("insert_char", |t| { t.insert_char('x'); true }, &["axb", "cxd", "exf"], &[(0, 2), (1, 2), (2, 2)]),
+        ("insert_str", |t| t.insert_str("xy"), &["axyb", "cxyd", "exyf"], &[(0, 3), (1, 3), (2, 3)]),
+        ("insert_newline", |t| { t.insert_newline(); true }, &["a", "b", "c", "d", "e", "f"], &[(1, 0), (3, 0), (5, 0)]),
+        ("insert_chunk", |t| t.insert_str("x\ny"), &["ax", "yb", "cx", "yd", "ex", "yf"], &[(1, 1), (3, 1), (5, 1)]),
+        ("insert_tab", |t| t.insert_tab(), &["a   b", "c   d", "e   f"], &[(0, 4), (1, 4), (2, 4)]),
+        ("delete_char", |t| t.delete_char(), &["b", "d", "f"], &[(0, 0), (1, 0), (2, 0)]),
+        ("delete_next_char", |t| t.delete_next_char(), &["a", "c", "e"], &[(0, 1), (1, 1), (2, 1)]),
+        ("delete_line_by_head", |t| t.delete_line_by_head(), &["b", "d", "f"], &[(0, 0), (1, 0), (2, 0)]),
+        ("delete_line_by_end", |t| t.delete_line_by_end(), &["a", "c", "e"], &[(0, 1), (1, 1), (2, 1)]),
+        ("delete_str", |t| t.delete_str(2), &["ace"], &[(0, 1), (0, 2), (0, 3)]),
+    ];
+
+    for (what, f, lines, cursors) in tests {
+        let mut textarea = textarea_with_cursors(&["ab", "cd", "ef"], &[(0, 1), (1, 1), (2, 1)]);
+        assert!(f(&mut textarea), "{what}");
+        assert_eq!(textarea.lines(), *lines, "{what}");
+        assert_eq!(textarea.cursors(), *cursors, "{what}");
+
+        // Edits at all cursors are undone as one step
+        assert!(textarea.undo(), "{what}");
+        assert_eq!(textarea.lines(), ["ab", "cd", "ef"], "{what}");
+        assert!(!textarea.undo(), "{what}");
+        assert_eq!(textarea.cursors().len(), 1, "{what}");
+        assert!(textarea.redo(), "{what}");
+        assert_eq!(textarea.lines(), *lines, "{what}");
+    }
+}
+
+#[test]
+fn edit_at_cursors_in_same_line() {
+    let mut textarea = textarea_with_cursors(&["aaa", "bbb"], &[(0, 0), (0, 2), (1, 0)]);
+
+    textarea.insert_str("xy");
+    assert_eq!(textarea.lines(), ["xyaaxya", "xybbb"]);
+    assert_eq!(textarea.cursors(), [(0, 2), (0, 6), (1, 2)]);
+
+    // Deleting a newline moves the cursors in the joined line
+    textarea.move_cursor(CursorMove::Head);
+    assert_eq!(textarea.cursors(), [(0, 0), (1, 0)]);
+    textarea.delete_char();
+    assert_eq!(textarea.lines(), ["xyaaxyaxybbb"]);
+    assert_eq!(textarea.cursors(), [(0, 0), (0, 7)]);
+
+    textarea.delete_next_char();
+    assert_eq!(textarea.lines(), ["yaaxyaybbb"]);
+    assert_eq!(textarea.cursors(), [(0, 0), (0, 6)]);
+
+    // The next edit is undone separately from the edits at multiple cursors
+    textarea.clear_extra_cursors();
+    textarea.insert_char('z');
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["yaaxyaybbb"]);
+}
+
+#[test]
+fn undo_grouping_at_cursors() {
+    let mut textarea = textarea_with_cursors(&["ab", "cd"], &[(0, 1), (0, 2), (1, 1)]);
+    for c in "xy z".chars() {
+        textarea.insert_char(c);
+    }
+    assert_eq!(textarea.lines(), ["axy zbxy z", "cxy zd"]);
+
+    // Typed characters are undone by words as with a single cursor
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["axy bxy ", "cxy d"]);
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["ab", "cd"]);
+    assert!(!textarea.undo());
+    assert!(textarea.redo());
+    assert_eq!(textarea.lines(), ["axy bxy ", "cxy d"]);
+
+    // Deleted characters are grouped as well
+    let mut textarea = textarea_with_cursors(&["abc", "def"], &[(0, 3), (1, 3)]);
+    textarea.delete_char();
+    textarea.delete_char();
+    assert_eq!(textarea.lines(), ["a", "d"]);
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["abc", "def"]);
+
+    // Typing at a different number of cursors starts a new group
+    let mut textarea = textarea_with_cursors(&["a", "b"], &[(0, 1), (1, 1)]);
+    textarea.insert_char('x');
+    textarea.clear_extra_cursors();
+    textarea.insert_char('y');
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["ax", "bx"]);
+}
+
+#[test]
+fn merge_cursors_at_same_position() {
+    let mut textarea = textarea_with_cursors(&["ab", "cd"], &[(0, 1), (1, 1)]);
+
+    textarea.move_cursor(CursorMove::Top);
+    assert_eq!(textarea.cursors(), [(0, 1)]);
+
+    let mut textarea = textarea_with_cursors(&["abc"], &[(0, 1), (0, 2)]);
+    textarea.delete_char();
+    assert_eq!(textarea.lines(), ["c"]);
+    assert_eq!(textarea.cursors(), [(0, 0)]);
+}
+
+#[test]
+fn select_and_replace_at_each_cursor() {
+    let mut textarea = textarea_with_cursors(&["foo bar", "foo baz"], &[(0, 0), (1, 0)]);
+
+    textarea.perform(Action::Select(CursorMove::WordForward));
+    assert_eq!(textarea.selection_range(), Some(((1, 0), (1, 4))));
+    textarea.insert_char('x');
+    assert_eq!(textarea.lines(), ["xbar", "xbaz"]);
+    assert!(!textarea.is_selecting());
+
+    // Paste the yanked text at each cursor
+    textarea.move_cursor(CursorMove::End);
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::Back);
+    textarea.copy();
+    textarea.cancel_selection();
+    textarea.paste();
+    assert_eq!(textarea.lines(), ["xbazr", "xbazz"]); // "z" was copied at the primary cursor
+}
+
+#[test]
+fn add_next_occurrence() {
+    let mut textarea = TextArea::from(["foo bar foo", "bar", "foo"]);
+    textarea.move_cursor(CursorMove::Jump(0, 8));
+
+    // Select the word under the cursor
+    assert!(textarea.add_next_occurrence());
+    assert_eq!(textarea.selection_range(), Some(((0, 8), (0, 11))));
+    assert_eq!(textarea.cursors(), [(0, 11)]);
+
+    // Search wraps around the text
+    assert!(textarea.add_next_occurrence());
+    assert!(textarea.add_next_occurrence());
+    assert_eq!(textarea.cursors(), [(0, 3), (0, 11), (2, 3)]);
+    assert_eq!(textarea.cursor(), (0, 3));
+    assert!(!textarea.add_next_occurrence());
+
+    textarea.insert_str("x");
+    assert_eq!(textarea.lines(), ["x bar x", "bar", "x"]);
+
+    // Multi-line text
+    let mut textarea = TextArea::from(["a", "b", "a", "b"]);
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::Down);
+    textarea.move_cursor(CursorMove::End);
+    assert!(textarea.add_next_occurrence());
+    assert_eq!(textarea.selection_range(), Some(((2, 0), (3, 1))));
+    textarea.delete_char();
+    assert_eq!(textarea.lines(), ["", ""]);
+
+    // Nothing to select
+    let mut textarea = TextArea::default();
+    assert!(!textarea.add_next_occurrence());
+}
+
+#[test]
+fn edit_at_primary_cursor_removes_extra_cursors() {
+    let mut textarea = textarea_with_cursors(&["ab", "cd"], &[(0, 1), (1, 1)]);
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::End);
+    assert!(textarea.cut());
+    assert_eq!(textarea.lines(), ["ab", "c"]);
+    assert_eq!(textarea.cursors(), [(1, 1)]);
+}
+
+#[test]
+fn render_cursors() {
+    let textarea = textarea_with_cursors(&["abc", "def"], &[(0, 1), (1, 3)]);
+    let area = Rect::new(0, 0, 6, 2);
+    let mut buf = Buffer::empty(area);
+    (&textarea).render(area, &mut buf);
+
+    let reversed = |x, y| buf[(x, y)].modifier.contains(Modifier::REVERSED);
+    assert!(reversed(1, 0));
+    assert!(reversed(3, 1));
+    assert!(!reversed(0, 0));
+    assert!(!reversed(2, 0));
+    assert!(!reversed(1, 1));
+}
+
+#[test]
+fn standard_key_map() {
+    let mut textarea = TextArea::from(["foo", "foo", "bar"]);
+    textarea.set_key_map(KeyMap::standard());
+
+    textarea.input(input(Key::Down, true, true));
+    assert_eq!(textarea.cursors(), [(0, 0), (1, 0)]);
+    textarea.input(input(Key::Esc, false, false));
+    assert_eq!(textarea.cursors(), [(1, 0)]);
+
+    textarea.input(input(Key::Char('d'), true, false));
+    textarea.input(input(Key::Char('d'), true, false));
+    assert_eq!(textarea.cursors(), [(0, 3), (1, 3)]);
+    textarea.input(input(Key::Char('x'), false, false));
+    assert_eq!(textarea.lines(), ["x", "x", "bar"]);
+
+    textarea.input(input(Key::Up, true, true));
+    assert_eq!(textarea.cursors(), [(0, 1), (1, 1)]);
+}
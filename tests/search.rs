@@ -130,3 +130,285 @@ fn set_search_pattern() {
     assert!(!textarea.search_back(true));
     assert!(!textarea.search_back(false));
 }
+
+#[test]
+fn enumerate_matches() {
+    let mut textarea = TextArea::from(["あfoo fo", "", "ふfooo"]);
+    assert!(textarea.search_matches().is_empty());
+    assert_eq!(textarea.search_match_index(), None);
+
+    textarea.set_search_pattern("fo+").unwrap();
+    assert_eq!(
+        textarea.search_matches(),
+        [((0, 1), (0, 4)), ((0, 5), (0, 7)), ((2, 1), (2, 5))],
+    );
+    assert_eq!(textarea.search_match_count(), 3);
+    assert_eq!(textarea.search_match_index(), None);
+
+    // Cursor inside the match
+    textarea.move_cursor(CursorMove::Jump(2, 3));
+    assert_eq!(textarea.search_match_index(), Some(2));
+    textarea.move_cursor(CursorMove::Jump(0, 4));
+    assert_eq!(textarea.search_match_index(), None);
+
+    assert!(textarea.search_nth(1));
+    assert_eq!(textarea.cursor(), (0, 5));
+    assert_eq!(textarea.search_match_index(), Some(1));
+    assert!(!textarea.search_nth(3));
+    assert_eq!(textarea.cursor(), (0, 5));
+}
+
+#[test]
+fn wrap_around_indicator() {
+    let mut textarea = TextArea::from(["foo", "bar foo"]);
+    textarea.set_search_pattern("foo").unwrap();
+
+    let expected = [((1, 4), false), ((0, 0), true), ((1, 4), false)];
+    for (pos, wrapped) in expected {
+        assert!(textarea.search_forward(false));
+        assert_eq!(textarea.cursor(), pos);
+        assert_eq!(textarea.search_wrapped(), wrapped, "{pos:?}");
+    }
+
+    let expected = [((0, 0), false), ((1, 4), true)];
+    for (pos, wrapped) in expected {
+        assert!(textarea.search_back(false));
+        assert_eq!(textarea.cursor(), pos);
+        assert_eq!(textarea.search_wrapped(), wrapped, "{pos:?}");
+    }
+
+    // Only match in the text is found again by wrapping around
+    let mut textarea = TextArea::from(["foo"]);
+    textarea.set_search_pattern("foo").unwrap();
+    assert!(textarea.search_forward(false));
+    assert!(textarea.search_wrapped());
+    assert!(textarea.search_forward(true));
+    assert!(!textarea.search_wrapped());
+
+    textarea.set_search_pattern("bar").unwrap();
+    assert!(!textarea.search_forward(false));
+    assert!(!textarea.search_wrapped());
+}
+
+#[test]
+fn search_options() {
+    use tui_textarea::SearchOptions;
+
+    let mut textarea = TextArea::from(["Foo foo.", "foobar foo"]);
+    assert_eq!(textarea.search_options(), SearchOptions::default());
+
+    textarea.set_search_pattern("foo").unwrap();
+    assert_eq!(textarea.search_match_count(), 3);
+
+    let opts = SearchOptions::default().ignore_case(true).whole_word(true);
+    textarea.set_search_options(opts).unwrap();
+    assert_eq!(textarea.search_options(), opts);
+    assert_eq!(
+        textarea.search_matches(),
+        [((0, 0), (0, 3)), ((0, 4), (0, 7)), ((1, 7), (1, 10))],
+    );
+
+    // Options are applied to patterns set later
+    textarea.set_search_pattern("o.").unwrap();
+    assert_eq!(textarea.search_match_count(), 0);
+    let opts = SearchOptions::default().literal(true);
+    textarea.set_search_options(opts).unwrap();
+    assert_eq!(textarea.search_matches(), [((0, 6), (0, 8))]);
+
+    // Options making the pattern invalid are not applied
+    textarea.set_search_pattern("(o").unwrap();
+    assert!(
+        textarea
+            .set_search_options(SearchOptions::default())
+            .is_err()
+    );
+    assert_eq!(textarea.search_options(), opts);
+    assert_eq!(textarea.search_match_count(), 0);
+}
+
+#[test]
+fn replace_next_match() {
+    let mut textarea = TextArea::from(["foo bar", "baz foo"]);
+    textarea.set_search_pattern("(?<word>foo)").unwrap();
+    textarea.move_cursor(CursorMove::Jump(0, 1));
+
+    // Next match is searched from the cursor and wraps around the text
+    assert!(textarea.replace_next("<${word}>"));
+    assert_eq!(textarea.lines(), ["foo bar", "baz <foo>"]);
+    assert_eq!(textarea.cursor(), (1, 9));
+    assert!(textarea.replace_next("$$"));
+    assert_eq!(textarea.lines(), ["$ bar", "baz <foo>"]);
+    assert_eq!(textarea.cursor(), (0, 1));
+
+    // Match at the cursor is replaced
+    textarea.move_cursor(CursorMove::Jump(1, 5));
+    assert!(textarea.replace_next("x\ny"));
+    assert_eq!(textarea.lines(), ["$ bar", "baz <x", "y>"]);
+    assert_eq!(textarea.cursor(), (2, 1));
+
+    assert!(!textarea.replace_next("foo"));
+
+    // Each replacement is undone separately
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["$ bar", "baz <foo>"]);
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["foo bar", "baz <foo>"]);
+
+    // Nothing is replaced without search pattern
+    textarea.set_search_pattern("").unwrap();
+    assert!(!textarea.replace_next("x"));
+}
+
+#[test]
+fn replace_next_finds_match_from_cursor() {
+    // The match is searched from the cursor as `search_forward` does, even when it overlaps a preceding match
+    let mut textarea = TextArea::from(["aaa"]);
+    textarea.set_search_pattern("(a)a").unwrap();
+    textarea.move_cursor(CursorMove::Jump(0, 1));
+    assert!(textarea.replace_next("<$1>"));
+    assert_eq!(textarea.lines(), ["a<a>"]);
+    assert_eq!(textarea.cursor(), (0, 4));
+    assert!(!textarea.search_wrapped());
+
+    let mut textarea = TextArea::from(["ab", "cd"]);
+    textarea.set_search_pattern("a(b)").unwrap();
+    textarea.move_cursor(CursorMove::Jump(1, 1));
+    assert!(textarea.replace_next("$1"));
+    assert_eq!(textarea.lines(), ["b", "cd"]);
+    assert!(textarea.search_wrapped());
+}
+
+#[test]
+fn replace_all_matches() {
+    let mut textarea = TextArea::from(["a1 b2", "c3", "d"]);
+    textarea.set_search_pattern(r"(\w)(\d)").unwrap();
+    assert_eq!(textarea.replace_all("$2$1", false), 3);
+    assert_eq!(textarea.lines(), ["1a 2b", "3c", "d"]);
+
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["a1 b2", "c3", "d"]);
+    assert!(!textarea.undo());
+
+    // Empty matches insert the replacement
+    textarea.set_search_pattern("^").unwrap();
+    assert_eq!(textarea.replace_all("// ", false), 3);
+    assert_eq!(textarea.lines(), ["// a1 b2", "// c3", "// d"]);
+
+    // Only matches entirely in the selection are replaced
+    textarea.set_search_pattern("[a-z]").unwrap();
+    textarea.move_cursor(CursorMove::Jump(0, 4));
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::Jump(1, 4));
+    assert_eq!(textarea.replace_all("_", true), 2);
+    assert_eq!(textarea.lines(), ["// a1 _2", "// _3", "// d"]);
+    assert_eq!(textarea.selection_range(), None);
+
+    // Nothing is replaced when nothing is selected
+    assert_eq!(textarea.replace_all("_", true), 0);
+    textarea.set_search_pattern("x").unwrap();
+    assert_eq!(textarea.replace_all("_", false), 0);
+    assert_eq!(textarea.lines(), ["// a1 _2", "// _3", "// d"]);
+}
+
+#[test]
+fn multi_line_search() {
+    use tui_textarea::SearchOptions;
+
+    let mut textarea = TextArea::from(["foo", "bar baz", "", "foo", "bar"]);
+    textarea
+        .set_search_options(SearchOptions::default().multi_line(true))
+        .unwrap();
+    textarea.set_search_pattern(r"o\nb").unwrap();
+    assert_eq!(
+        textarea.search_matches(),
+        [((0, 2), (1, 1)), ((3, 2), (4, 1))],
+    );
+
+    assert!(textarea.search_forward(false));
+    assert_eq!(textarea.cursor(), (0, 2));
+    assert!(textarea.search_forward(true));
+    assert_eq!(textarea.cursor(), (0, 2));
+    assert!(textarea.search_forward(false));
+    assert_eq!(textarea.cursor(), (3, 2));
+    assert!(!textarea.search_wrapped());
+    assert!(textarea.search_forward(false));
+    assert_eq!(textarea.cursor(), (0, 2));
+    assert!(textarea.search_wrapped());
+    assert!(textarea.search_back(false));
+    assert_eq!(textarea.cursor(), (3, 2));
+    assert!(textarea.search_wrapped());
+
+    // Cursor in the middle of a match spanning lines
+    textarea.move_cursor(CursorMove::Jump(4, 0));
+    assert_eq!(textarea.search_match_index(), Some(1));
+
+    // `^` and `$` match at each line and empty lines are matched
+    textarea.set_search_pattern("^$").unwrap();
+    assert_eq!(textarea.search_matches(), [((2, 0), (2, 0))]);
+
+    // Matches are updated after edits
+    textarea.set_search_pattern(r"\w+\n\w+").unwrap();
+    assert_eq!(textarea.search_match_count(), 2);
+    textarea.move_cursor(CursorMove::Jump(2, 0));
+    textarea.insert_str("qux");
+    assert_eq!(
+        textarea.search_matches(),
+        [((0, 0), (1, 3)), ((1, 4), (2, 3)), ((3, 0), (4, 3))],
+    );
+    textarea.undo();
+    assert_eq!(textarea.search_match_count(), 2);
+    textarea.set_lines(vec!["a".into(), "b".into()], (0, 0));
+    assert_eq!(textarea.search_matches(), [((0, 0), (1, 1))]);
+}
+
+#[test]
+fn multi_line_replace() {
+    use tui_textarea::SearchOptions;
+
+    let mut textarea = TextArea::from(["foo,", "bar.", "baz,", "qux"]);
+    textarea
+        .set_search_options(SearchOptions::default().multi_line(true))
+        .unwrap();
+    textarea.set_search_pattern(r"(\w+),\n(\w+)").unwrap();
+    assert_eq!(textarea.replace_all("$1 & $2", false), 2);
+    assert_eq!(textarea.lines(), ["foo & bar.", "baz & qux"]);
+    assert!(textarea.undo());
+    assert_eq!(textarea.lines(), ["foo,", "bar.", "baz,", "qux"]);
+
+    assert!(textarea.replace_next("$2\n$1"));
+    assert_eq!(textarea.lines(), ["bar", "foo.", "baz,", "qux"]);
+    assert_eq!(textarea.cursor(), (1, 3));
+}
+
+#[test]
+fn highlight_multi_line_matches() {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+    use ratatui::widgets::Widget as _;
+    use tui_textarea::SearchOptions;
+
+    let mut textarea = TextArea::from(["ab", "cd", "ef"]);
+    textarea.set_cursor_style(Style::default());
+    textarea.set_cursor_line_style(Style::default());
+    textarea
+        .set_search_options(SearchOptions::default().multi_line(true))
+        .unwrap();
+    textarea.set_search_pattern(r"b\ncd\ne").unwrap();
+
+    let area = Rect::new(0, 0, 4, 3);
+    let mut buf = Buffer::empty(area);
+    (&textarea).render(area, &mut buf);
+
+    let highlighted: Vec<Vec<bool>> = (0..3)
+        .map(|y| (0..4).map(|x| buf[(x, y)].bg == Color::Blue).collect())
+        .collect();
+    assert_eq!(
+        highlighted,
+        [
+            [false, true, true, false],
+            [true, true, true, false],
+            [true, false, false, false],
+        ],
+    );
+}
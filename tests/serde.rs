@@ -1,6 +1,10 @@
 #![cfg(feature = "serde")]
 
-use tui_textarea::{CursorMove, Input, Key, Scrolling};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, Input, Key, Scrolling, TextArea, TextAreaSnapshot, WrapMode};
 
 #[test]
 fn test_serde_key() {
@@ -45,3 +49,159 @@ fn test_serde_cursor_move() {
     let d: CursorMove = serde_json::from_str(&s).unwrap();
     assert_eq!(d, c);
 }
+
+fn round_trip(textarea: &TextArea<'_>) -> TextArea<'static> {
+    let json = serde_json::to_string(&textarea.snapshot()).unwrap();
+    let snapshot: TextAreaSnapshot = serde_json::from_str(&json).unwrap();
+    TextArea::from_snapshot(snapshot)
+}
+
+#[test]
+fn test_serde_snapshot_undo_redo() {
+    let mut t = TextArea::default();
+    for c in "hello world".chars() {
+        t.insert_char(c);
+    }
+    t.insert_newline();
+    t.insert_str("foo\nbar");
+    t.undo();
+
+    let mut r = round_trip(&t);
+    assert_eq!(r.lines(), t.lines());
+    assert_eq!(r.cursor(), t.cursor());
+
+    // Redo and undo are continued after restoring
+    assert!(r.redo());
+    assert_eq!(r.lines(), ["hello world", "foo", "bar"]);
+    assert!(r.undo());
+    assert!(r.undo());
+    assert_eq!(r.lines(), ["hello world"]);
+    assert!(r.undo());
+    assert_eq!(r.lines(), ["hello "]);
+    assert!(r.undo());
+    assert_eq!(r.lines(), [""]);
+    assert!(!r.undo());
+}
+
+#[test]
+fn test_serde_snapshot_typing_after_restore() {
+    let mut t = TextArea::default();
+    t.insert_char('a');
+    t.insert_char('b');
+    let mut r = round_trip(&t);
+
+    // Typing after restoring starts a new undo step
+    r.insert_char('c');
+    r.undo();
+    assert_eq!(r.lines(), ["ab"]);
+    r.undo();
+    assert_eq!(r.lines(), [""]);
+}
+
+#[test]
+fn test_serde_snapshot_selection_and_yank() {
+    let mut t = TextArea::from(["abc", "def"]);
+    t.set_yank_text("x\ny");
+    t.move_cursor(CursorMove::Forward);
+    t.start_selection();
+    t.move_cursor(CursorMove::Down);
+
+    let mut r = round_trip(&t);
+    assert_eq!(r.selection_range(), Some(((0, 1), (1, 1))));
+    assert_eq!(r.yank_text(), "x\ny");
+    r.cancel_selection();
+    r.paste();
+    assert_eq!(r.lines(), ["abc", "dx", "yef"]);
+}
+
+#[test]
+fn test_serde_snapshot_config() {
+    let mut t = TextArea::default();
+    t.set_tab_length(2);
+    t.set_hard_tab_indent(true);
+    t.set_wrap_mode(WrapMode::Word);
+    t.set_placeholder_text("Enter text");
+    t.set_placeholder_style(Style::default().fg(Color::Green));
+    t.set_style(Style::default().bg(Color::Black));
+    t.set_cursor_line_style(Style::default());
+    t.set_line_number_style(Style::default().fg(Color::Yellow));
+    t.set_selection_style(Style::default().bg(Color::Red));
+    t.set_max_histories(3);
+
+    let mut r = round_trip(&t);
+    assert_eq!(r.tab_length(), 2);
+    assert!(r.hard_tab_indent());
+    assert_eq!(r.wrap_mode(), WrapMode::Word);
+    assert_eq!(r.placeholder_text(), "Enter text");
+    assert_eq!(
+        r.placeholder_style(),
+        Some(Style::default().fg(Color::Green)),
+    );
+    assert_eq!(r.style(), Style::default().bg(Color::Black));
+    assert_eq!(r.cursor_line_style(), Style::default());
+    assert_eq!(
+        r.line_number_style(),
+        Some(Style::default().fg(Color::Yellow)),
+    );
+    assert_eq!(r.selection_style(), Style::default().bg(Color::Red));
+    assert_eq!(r.max_histories(), 3);
+}
+
+#[test]
+fn test_serde_snapshot_scroll_position() {
+    let mut t: TextArea = (0..10).map(|i| i.to_string()).collect();
+    let area = Rect::new(0, 0, 5, 2);
+    let mut buf = Buffer::empty(area);
+    t.render(area, &mut buf);
+    t.scroll((5, 0));
+
+    let r = round_trip(&t);
+    let mut buf = Buffer::empty(area);
+    r.render(area, &mut buf);
+    assert_eq!(buf[(0, 0)].symbol(), "5");
+    assert_eq!(r.cursor(), (5, 0));
+}
+
+#[test]
+fn test_serde_snapshot_clamps_cursor() {
+    let mut snapshot = serde_json::to_value(TextArea::from(["ab"]).snapshot()).unwrap();
+    snapshot["cursor"] = serde_json::json!([3, 10]);
+    let snapshot: TextAreaSnapshot = serde_json::from_value(snapshot).unwrap();
+    assert_eq!(snapshot.cursor(), (3, 10));
+    let t: TextArea = TextArea::from_snapshot(snapshot);
+    assert_eq!(t.cursor(), (0, 2));
+}
+
+#[test]
+fn test_serde_snapshot_drops_broken_history() {
+    let mut t = TextArea::default();
+    t.insert_str("hello");
+    t.insert_newline();
+    let snapshot = serde_json::to_value(t.snapshot()).unwrap();
+
+    let broken = [
+        ("/history/current", serde_json::json!(42)),
+        ("/history/root", serde_json::json!(1)),
+        ("/history/next_seq", serde_json::json!(1)),
+        ("/history/nodes/1/edits/0/before/row", serde_json::json!(3)),
+        (
+            "/history/nodes/2/edits/0/before/offset",
+            serde_json::json!(9),
+        ),
+        ("/history/nodes/1/parent", serde_json::json!(2)),
+    ];
+    for (path, value) in broken {
+        let mut snapshot = snapshot.clone();
+        *snapshot.pointer_mut(path).unwrap() = value;
+        let snapshot: TextAreaSnapshot = serde_json::from_value(snapshot).unwrap();
+        let mut r: TextArea = TextArea::from_snapshot(snapshot);
+        assert_eq!(r.lines(), ["hello", ""], "{path}");
+        assert!(!r.undo(), "{path}");
+        assert!(!r.redo(), "{path}");
+
+        // New edits are recorded in the fresh history
+        r.insert_char('a');
+        assert!(r.undo(), "{path}");
+        assert_eq!(r.lines(), ["hello", ""], "{path}");
+    }
+}
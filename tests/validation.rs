@@ -0,0 +1,182 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::{Block, Borders, Widget as _};
+use std::sync::{Arc, Mutex};
+use tui_textarea::{
+    Action, CursorMove, DigitsOnly, Input, Insertion, Key, MaxLength, MaxLines, TextArea,
+    Validation,
+};
+
+#[test]
+fn validate_each_insertion() {
+    let seen = Arc::new(Mutex::new(vec![]));
+    let mut t = TextArea::from(["ab", "cd"]);
+    {
+        let seen = Arc::clone(&seen);
+        t.add_validator(move |i: &Insertion<'_>| {
+            let i = (
+                i.text.to_string(),
+                i.before.to_string(),
+                i.after.to_string(),
+                i.line_count,
+            );
+            seen.lock().unwrap().push(i);
+            Validation::Accept
+        });
+    }
+
+    t.move_cursor(CursorMove::Forward);
+    t.insert_char('x');
+    t.insert_str("y\r\nz");
+    t.insert_newline();
+    t.insert_tab();
+    t.set_yank_text("w");
+    t.paste();
+
+    // Selection is excluded from the context
+    t.move_cursor(CursorMove::Top);
+    t.move_cursor(CursorMove::Head);
+    t.start_selection();
+    t.move_cursor(CursorMove::Bottom);
+    t.insert_char('v');
+
+    let s = |s: &str| s.to_string();
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [
+            (s("x"), s("a"), s("b"), 2),
+            (s("y\nz"), s("ax"), s("b"), 2),
+            (s("\n"), s("z"), s("b"), 3),
+            (s("    "), s(""), s("b"), 4),
+            (s("w"), s("    "), s("b"), 4),
+            (s("v"), s(""), s("cd"), 1),
+        ],
+    );
+}
+
+#[test]
+fn reject_and_transform_insertion() {
+    let mut t = TextArea::default();
+    t.add_validator(MaxLines(1));
+    t.add_validator(MaxLength(3));
+    t.add_validator(DigitsOnly);
+
+    t.insert_str("1a\n2");
+    assert_eq!(t.lines(), ["1"]);
+    assert_eq!(t.validation_error(), None);
+
+    // Newline is rejected without modifying the text
+    t.insert_newline();
+    assert_eq!(t.lines(), ["1"]);
+    assert!(t.validation_error().is_some());
+    assert!(!t.input(Input {
+        key: Key::Enter,
+        ..Input::default()
+    }));
+    assert!(!t.insert_tab());
+
+    t.insert_char('2');
+    t.insert_char('3');
+    assert_eq!(t.validation_error(), None);
+    t.insert_char('4');
+    assert_eq!(t.lines(), ["123"]);
+    assert_eq!(
+        t.validation_error(),
+        Some("Line must be at most 3 characters")
+    );
+
+    // Replacing the selection is allowed
+    t.select_all();
+    t.set_yank_text("4567");
+    assert!(t.paste());
+    assert_eq!(t.lines(), ["456"]);
+
+    // Edits at each cursor are validated separately
+    let mut t = TextArea::from(["ab", "abc"]);
+    t.add_validator(MaxLength(3));
+    t.move_cursor(CursorMove::Jump(0, 2));
+    t.add_cursor_below();
+    t.insert_char('x');
+    assert_eq!(t.lines(), ["abx", "abc"]);
+
+    // Transformed text is undone as one step
+    let mut t = TextArea::default();
+    t.add_validator(DigitsOnly);
+    t.insert_str("1-2");
+    assert!(t.undo());
+    assert_eq!(t.lines(), [""]);
+
+    t.clear_validators();
+    t.insert_str("a\nb");
+    assert_eq!(t.lines(), ["a", "b"]);
+}
+
+#[test]
+fn insertion_replaced_with_empty_text_does_not_modify() {
+    let mut t = TextArea::from(["", "c"]);
+    t.set_max_histories(0);
+    t.add_validator(|i: &Insertion<'_>| Validation::Replace(i.text.replace([' ', '\n'], "")));
+
+    assert!(!t.perform(Action::InsertChar(' ')));
+    assert!(!t.perform(Action::InsertNewline));
+    assert!(!t.input(Input {
+        key: Key::Char(' '),
+        ..Input::default()
+    }));
+    assert_eq!(t.validation_error(), None);
+    assert_eq!(t.lines(), ["", "c"]);
+
+    assert!(t.perform(Action::InsertChar('a')));
+    assert!(t.input(Input {
+        key: Key::Char('b'),
+        ..Input::default()
+    }));
+    assert_eq!(t.lines(), ["ab", "c"]);
+
+    // Same at each cursor
+    t.add_cursor_below();
+    assert!(!t.perform(Action::InsertChar(' ')));
+    assert!(t.perform(Action::InsertChar('d')));
+    assert_eq!(t.lines(), ["abd", "cd"]);
+}
+
+#[test]
+fn render_error_style_on_block() {
+    let mut t = TextArea::default();
+    t.set_block(Block::default().borders(Borders::ALL));
+    t.add_validator(DigitsOnly);
+
+    let render = |t: &TextArea<'_>| {
+        let area = Rect::new(0, 0, 5, 3);
+        let mut buf = Buffer::empty(area);
+        t.render(area, &mut buf);
+        buf[(0, 0)].fg
+    };
+
+    assert_eq!(render(&t), Color::Reset);
+    t.insert_char('a');
+    assert_eq!(render(&t), Color::Red);
+    t.set_error_style(ratatui::style::Style::default().fg(Color::Yellow));
+    assert_eq!(render(&t), Color::Yellow);
+    t.insert_char('1');
+    assert_eq!(render(&t), Color::Reset);
+
+    t.set_validation_error("Required");
+    assert_eq!(render(&t), Color::Yellow);
+    t.clear_validation_error();
+    assert_eq!(render(&t), Color::Reset);
+}
+
+#[cfg(feature = "search")]
+#[test]
+fn regex_filter() {
+    use tui_textarea::RegexFilter;
+
+    let mut t = TextArea::default();
+    t.add_validator(RegexFilter::new(r"[\w.@]").unwrap());
+    t.insert_str("foo bar@example.com");
+    assert_eq!(t.lines(), ["foobar@example.com"]);
+    t.insert_char(' ');
+    assert!(t.validation_error().is_some());
+}
@@ -0,0 +1,359 @@
+#![cfg(feature = "vim")]
+
+use tui_textarea::{
+    CursorMove, Input, Key, TextArea, Vim, VimEvent, VimMode, VimOperator, VimRegister,
+};
+
+fn textarea(lines: &[&str], cursor: (u16, u16)) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().copied());
+    t.move_cursor(CursorMove::Jump(cursor.0, cursor.1));
+    t
+}
+
+fn key(key: Key) -> Input {
+    Input {
+        key,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    }
+}
+
+// `<` is not used by the tests so it is used as Esc
+fn keys(vim: &mut Vim, t: &mut TextArea<'_>, keys: &str) -> VimEvent {
+    let mut event = VimEvent::Nop;
+    for c in keys.chars() {
+        let key = if c == '<' { Key::Esc } else { Key::Char(c) };
+        event = match vim.input(t, self::key(key)) {
+            VimEvent::Nop => event,
+            e => e,
+        };
+    }
+    event
+}
+
+// Initial lines, initial cursor, inputs, expected lines, expected cursor
+type Case = (
+    &'static [&'static str],
+    (u16, u16),
+    &'static str,
+    &'static [&'static str],
+    (usize, usize),
+);
+
+#[test]
+fn normal_mode_commands() {
+    #[rustfmt::skip]
+    let tests: &[Case] = &[
+        // Motions
+        (&["abc def ghi"], (0, 0), "2w", &["abc def ghi"], (0, 8)),
+        (&["abc def ghi"], (0, 0), "$", &["abc def ghi"], (0, 10)),
+        (&["abc def ghi"], (0, 10), "0", &["abc def ghi"], (0, 0)),
+        (&["  abc"], (0, 4), "^", &["  abc"], (0, 2)),
+        (&["a", "b", "c", "d"], (0, 0), "3G", &["a", "b", "c", "d"], (2, 0)),
+        (&["a", "b", "c", "d"], (0, 0), "G", &["a", "b", "c", "d"], (3, 0)),
+        (&["a", "b", "c", "d"], (3, 0), "gg", &["a", "b", "c", "d"], (0, 0)),
+        (&["a,b,c"], (0, 0), "2f,", &["a,b,c"], (0, 3)),
+        (&["a,b,c"], (0, 0), "t,", &["a,b,c"], (0, 0)),
+        (&["abc"], (0, 0), "10l", &["abc"], (0, 2)),
+        // Operators with motions and counts
+        (&["abc def ghi"], (0, 0), "dw", &["def ghi"], (0, 0)),
+        (&["abc def ghi"], (0, 0), "3dw", &[""], (0, 0)),
+        (&["a b c d e f g"], (0, 0), "2d2w", &["e f g"], (0, 0)),
+        (&["abc def", "ghi"], (0, 4), "dw", &["abc ", "ghi"], (0, 3)),
+        (&["abc def ghi"], (0, 4), "de", &["abc  ghi"], (0, 4)),
+        (&["abc def ghi"], (0, 4), "d$", &["abc "], (0, 3)),
+        (&["abc def ghi"], (0, 4), "D", &["abc "], (0, 3)),
+        (&["abc def ghi"], (0, 4), "d0", &["def ghi"], (0, 0)),
+        (&["abc,def"], (0, 0), "df,", &["def"], (0, 0)),
+        (&["abc,def"], (0, 0), "dt,", &[",def"], (0, 0)),
+        (&["abc,def"], (0, 0), "dfx", &["abc,def"], (0, 0)),
+        (&["abc"], (0, 1), "x", &["ac"], (0, 1)),
+        (&["abc"], (0, 2), "x", &["ab"], (0, 1)),
+        (&["abc"], (0, 0), "2x", &["c"], (0, 0)),
+        (&["abc"], (0, 2), "X", &["ac"], (0, 1)),
+        (&["a", "b", "c", "d"], (1, 0), "dd", &["a", "c", "d"], (1, 0)),
+        (&["a", "b", "c", "d"], (1, 0), "2dd", &["a", "d"], (1, 0)),
+        (&["a", "b", "c", "d"], (3, 0), "dd", &["a", "b", "c"], (2, 0)),
+        (&["a", "b"], (0, 0), "5dd", &[""], (0, 0)),
+        (&["a", "b", "c", "d"], (1, 0), "dj", &["a", "d"], (1, 0)),
+        (&["a", "b", "c", "d"], (2, 0), "dk", &["a", "d"], (1, 0)),
+        (&["a", "b", "c", "d"], (1, 0), "dG", &["a"], (0, 0)),
+        (&["a", "b", "c", "d"], (3, 0), "dj", &["a", "b", "c", "d"], (3, 0)),
+        // Text objects
+        (&["foo bar baz"], (0, 5), "diw", &["foo  baz"], (0, 4)),
+        (&["foo bar baz"], (0, 5), "daw", &["foo baz"], (0, 4)),
+        (&["say \"hi there\" ok"], (0, 7), "di\"", &["say \"\" ok"], (0, 5)),
+        (&["say \"hi there\" ok"], (0, 7), "da\"", &["say ok"], (0, 4)),
+        (&["f(a, (b), c)"], (0, 3), "di(", &["f()"], (0, 2)),
+        (&["f(a, (b), c)"], (0, 6), "dab", &["f(a, , c)"], (0, 5)),
+        (&["x = {", "  y", "}"], (1, 2), "di{", &["x = {}"], (0, 5)),
+        (&["a", "b", "", "c"], (0, 0), "dip", &["", "c"], (0, 0)),
+        (&["a", "b", "", "c"], (0, 0), "dap", &["c"], (0, 0)),
+        (&["abc"], (0, 1), "dix", &["abc"], (0, 1)),
+        // Change operator returns to normal mode with Esc
+        (&["abc def"], (0, 0), "cwxyz<", &["xyz def"], (0, 2)),
+        (&["abc def"], (0, 0), "ciwx<", &["x def"], (0, 0)),
+        (&["abc def"], (0, 4), "Cx<", &["abc x"], (0, 4)),
+        (&["abc"], (0, 0), "sx<", &["xbc"], (0, 0)),
+        (&[""], (0, 0), "sx<", &["x"], (0, 0)),
+        (&["a", "bcd", "e"], (1, 1), "ccx<", &["a", "x", "e"], (1, 0)),
+        (&["a", "bcd", "e"], (1, 1), "Sx<", &["a", "x", "e"], (1, 0)),
+        // Insert commands
+        (&["abc"], (0, 1), "ix<", &["axbc"], (0, 1)),
+        (&["abc"], (0, 1), "ax<", &["abxc"], (0, 2)),
+        (&["  abc"], (0, 4), "Ix<", &["  xabc"], (0, 2)),
+        (&["abc"], (0, 0), "Ax<", &["abcx"], (0, 3)),
+        (&["a", "b"], (0, 0), "ox<", &["a", "x", "b"], (1, 0)),
+        (&["a", "b"], (1, 0), "Ox<", &["a", "x", "b"], (1, 0)),
+        // Put
+        (&["abc"], (0, 0), "yyp", &["abc", "abc"], (1, 0)),
+        (&["abc"], (0, 0), "yy2P", &["abc", "abc", "abc"], (0, 0)),
+        (&["abc def"], (0, 0), "dwP", &["abc def"], (0, 3)),
+        (&["abc def"], (0, 0), "dwp", &["dabc ef"], (0, 4)),
+        (&["abc def"], (0, 0), "yw$p", &["abc defabc "], (0, 10)),
+        (&["a", "b", "c"], (0, 0), "ddp", &["b", "a", "c"], (1, 0)),
+        (&["abc"], (0, 0), "p", &["abc"], (0, 0)),
+        // Replace and join
+        (&["abc"], (0, 0), "rx", &["xbc"], (0, 0)),
+        (&["abc"], (0, 0), "2rx", &["xxc"], (0, 1)),
+        (&["abc"], (0, 1), "5rx", &["abc"], (0, 1)),
+        (&["a", "  b", "c"], (0, 0), "J", &["a b", "c"], (0, 1)),
+        (&["a", "b", "c"], (0, 0), "3J", &["a b c"], (0, 3)),
+        (&["a", ""], (0, 0), "J", &["a"], (0, 0)),
+        // Undo and redo
+        (&["abc def"], (0, 0), "dwdwu", &["def"], (0, 2)),
+        (&["abc def"], (0, 0), "dwdw2u", &["abc def"], (0, 4)),
+        // Visual mode
+        (&["abc def"], (0, 0), "vlld", &[" def"], (0, 0)),
+        (&["abc def"], (0, 2), "vhhy$p", &["abc defabc"], (0, 9)),
+        (&["abc def"], (0, 0), "veex", &[""], (0, 0)),
+        (&["abc def"], (0, 5), "viwd", &["abc "], (0, 3)),
+        (&["abc def"], (0, 0), "vecx<", &["x def"], (0, 0)),
+        (&["a", "b", "c", "d"], (1, 0), "Vjd", &["a", "d"], (1, 0)),
+        (&["a", "b", "c", "d"], (2, 0), "Vkd", &["a", "d"], (1, 0)),
+        (&["a", "b", "c"], (0, 0), "Vyjp", &["a", "b", "a", "c"], (2, 0)),
+        (&["abc"], (0, 0), "vl<x", &["ac"], (0, 1)),
+    ];
+
+    for (i, (lines, cursor, input, want_lines, want_cursor)) in tests.iter().enumerate() {
+        let mut t = textarea(lines, *cursor);
+        let mut vim = Vim::new();
+        keys(&mut vim, &mut t, input);
+        assert_eq!(t.lines(), *want_lines, "#{i}: {input:?} on {lines:?}");
+        assert_eq!(t.cursor(), *want_cursor, "#{i}: {input:?} on {lines:?}");
+        assert_eq!(vim.mode(), VimMode::Normal, "#{i}: {input:?} on {lines:?}");
+        assert!(!t.is_selecting(), "#{i}: {input:?} on {lines:?}");
+    }
+}
+
+#[test]
+fn modes() {
+    let mut t = textarea(&["abc", "def"], (0, 0));
+    let mut vim = Vim::new();
+
+    let tests = [
+        ('d', VimMode::OperatorPending(VimOperator::Delete)),
+        ('2', VimMode::OperatorPending(VimOperator::Delete)),
+        ('<', VimMode::Normal),
+        ('y', VimMode::OperatorPending(VimOperator::Yank)),
+        ('z', VimMode::Normal),
+        ('c', VimMode::OperatorPending(VimOperator::Change)),
+        ('c', VimMode::Insert),
+        ('<', VimMode::Normal),
+        ('v', VimMode::Visual),
+        ('V', VimMode::VisualLine),
+        ('v', VimMode::Visual),
+        ('v', VimMode::Normal),
+        ('V', VimMode::VisualLine),
+        ('<', VimMode::Normal),
+        (':', VimMode::Command),
+        ('<', VimMode::Normal),
+    ];
+    for (i, (c, mode)) in tests.into_iter().enumerate() {
+        keys(&mut vim, &mut t, &c.to_string());
+        assert_eq!(vim.mode(), mode, "#{i}: {c:?}");
+    }
+}
+
+#[test]
+fn visual_line_selection() {
+    let mut t = textarea(&["abc", "de", "fghi"], (0, 1));
+    let mut vim = Vim::new();
+
+    keys(&mut vim, &mut t, "V");
+    assert_eq!(t.selection_range(), Some(((0, 0), (0, 3))));
+    keys(&mut vim, &mut t, "j");
+    assert_eq!(t.selection_range(), Some(((0, 0), (1, 2))));
+    keys(&mut vim, &mut t, "o");
+    assert_eq!(t.selection_range(), Some(((0, 0), (1, 2))));
+    assert_eq!(t.cursor(), (0, 0));
+
+    keys(&mut vim, &mut t, "<Gkv");
+    assert_eq!(vim.mode(), VimMode::Visual);
+    keys(&mut vim, &mut t, "j");
+    assert_eq!(t.selection_range(), Some(((1, 0), (2, 0))));
+}
+
+#[test]
+fn registers() {
+    let mut t = textarea(&["abc def", "ghi"], (0, 0));
+    let mut vim = Vim::new();
+
+    keys(&mut vim, &mut t, "\"ayw");
+    assert_eq!(
+        vim.register('a'),
+        Some(&VimRegister {
+            text: "abc ".into(),
+            linewise: false,
+        }),
+    );
+    assert_eq!(vim.register('0'), None);
+    keys(&mut vim, &mut t, "\"Ayy");
+    assert_eq!(
+        vim.register('a'),
+        Some(&VimRegister {
+            text: "abc \nabc def".into(),
+            linewise: true,
+        }),
+    );
+
+    // Yank register keeps the last yanked text after deletion
+    keys(&mut vim, &mut t, "yyjdd");
+    assert_eq!(vim.register('0').unwrap().text, "abc def");
+    assert_eq!(vim.register('"').unwrap().text, "ghi");
+
+    // Black hole register does not overwrite the unnamed register
+    keys(&mut vim, &mut t, "\"_x");
+    assert_eq!(t.lines(), ["bc def"]);
+    assert_eq!(vim.register('"').unwrap().text, "ghi");
+
+    keys(&mut vim, &mut t, "\"0p");
+    assert_eq!(t.lines(), ["bc def", "abc def"]);
+    keys(&mut vim, &mut t, "\"aP");
+    assert_eq!(t.lines(), ["bc def", "abc ", "abc def", "abc def"]);
+    keys(&mut vim, &mut t, "\"zp");
+    assert_eq!(t.lines(), ["bc def", "abc ", "abc def", "abc def"]);
+}
+
+#[test]
+fn dot_repeat() {
+    #[rustfmt::skip]
+    let tests: &[(&[&str], &str, &[&str])] = &[
+        (&["a b c d e"], "dw.", &["c d e"]),
+        (&["a b c d e"], "dw2.", &["d e"]),
+        (&["a b c d e"], "2dw.", &["e"]),
+        (&["a", "b", "c"], "dd.", &["c"]),
+        (&["abc abc"], "cwxy<w.", &["xy xy"]),
+        (&["a", "b"], "Ax<j.", &["ax", "bx"]),
+        (&["a"], "ox<.", &["a", "x", "x"]),
+        (&["abc"], "x..", &[""]),
+        (&["abcd"], "rxl.", &["xxcd"]),
+        (&["abc"], "yyp.", &["abc", "abc", "abc"]),
+        // Motions, yanks, and visual mode changes don't update the last change
+        (&["a b c d e"], "dwwyw.", &["b d e"]),
+        (&["abcd"], "xvld.", &[""]),
+        (&["abcd"], ".", &["abcd"]),
+    ];
+
+    for (i, (lines, input, want)) in tests.iter().enumerate() {
+        let mut t = textarea(lines, (0, 0));
+        let mut vim = Vim::new();
+        keys(&mut vim, &mut t, input);
+        assert_eq!(t.lines(), *want, "#{i}: {input:?} on {lines:?}");
+    }
+}
+
+#[test]
+fn command_line() {
+    let mut t = textarea(&["abc"], (0, 0));
+    let mut vim = Vim::new();
+
+    assert_eq!(keys(&mut vim, &mut t, ":wq"), VimEvent::Nop);
+    assert_eq!(vim.command_line(), Some("wq"));
+    vim.input(&mut t, key(Key::Backspace));
+    assert_eq!(vim.command_line(), Some("w"));
+    assert_eq!(
+        vim.input(&mut t, key(Key::Enter)),
+        VimEvent::Command("w".to_string()),
+    );
+    assert_eq!(vim.mode(), VimMode::Normal);
+    assert_eq!(vim.command_line(), None);
+
+    // Esc and Backspace on empty command line cancel the command line
+    keys(&mut vim, &mut t, ":q<");
+    assert_eq!(vim.mode(), VimMode::Normal);
+    keys(&mut vim, &mut t, ":");
+    vim.input(&mut t, key(Key::Backspace));
+    assert_eq!(vim.mode(), VimMode::Normal);
+
+    // Command line does not modify the text
+    assert_eq!(t.lines(), ["abc"]);
+}
+
+#[test]
+fn insert_mode_uses_textarea_input() {
+    let mut t = textarea(&["abc"], (0, 0));
+    let mut vim = Vim::new();
+
+    assert_eq!(keys(&mut vim, &mut t, "A"), VimEvent::Nop);
+    assert_eq!(vim.input(&mut t, key(Key::Backspace)), VimEvent::Modified);
+    assert_eq!(vim.input(&mut t, key(Key::Enter)), VimEvent::Modified);
+    assert_eq!(vim.input(&mut t, key(Key::Char('x'))), VimEvent::Modified);
+    assert_eq!(t.lines(), ["ab", "x"]);
+    assert_eq!(vim.input(&mut t, key(Key::Esc)), VimEvent::Nop);
+    assert_eq!(vim.mode(), VimMode::Normal);
+    assert_eq!(t.cursor(), (1, 0));
+}
+
+#[test]
+fn modified_event() {
+    let mut t = textarea(&["abc def"], (0, 0));
+    let mut vim = Vim::new();
+
+    assert_eq!(keys(&mut vim, &mut t, "w"), VimEvent::Nop);
+    assert_eq!(keys(&mut vim, &mut t, "yw"), VimEvent::Nop);
+    assert_eq!(keys(&mut vim, &mut t, "dw"), VimEvent::Modified);
+    assert_eq!(keys(&mut vim, &mut t, "u"), VimEvent::Modified);
+    assert_eq!(keys(&mut vim, &mut t, "u"), VimEvent::Nop);
+    assert_eq!(keys(&mut vim, &mut t, "."), VimEvent::Modified);
+}
+
+#[test]
+fn undo_insert_session_at_once() {
+    let mut t = textarea(&["abc"], (0, 0));
+    let mut vim = Vim::new();
+
+    keys(&mut vim, &mut t, "ohello world<");
+    assert_eq!(t.lines(), ["abc", "hello world"]);
+    assert_eq!(keys(&mut vim, &mut t, "u"), VimEvent::Modified);
+    assert_eq!(t.lines(), ["abc"]);
+
+    keys(&mut vim, &mut t, "0cwfoo bar<");
+    assert_eq!(t.lines(), ["foo bar"]);
+    keys(&mut vim, &mut t, "u");
+    assert_eq!(t.lines(), ["abc"]); // Deletion by `c` is undone with the insertion
+    assert_eq!(keys(&mut vim, &mut t, "u"), VimEvent::Nop);
+}
+
+#[test]
+fn undo_tree_with_g_minus_and_g_plus() {
+    let mut t = textarea(&["abc"], (0, 0));
+    t.set_undo_tree(true);
+    let mut vim = Vim::new();
+
+    keys(&mut vim, &mut t, "x");
+    keys(&mut vim, &mut t, "u");
+    keys(&mut vim, &mut t, "Afoo<");
+    assert_eq!(t.lines(), ["abcfoo"]);
+
+    // `g-` goes back to the undone branch
+    assert_eq!(keys(&mut vim, &mut t, "g-"), VimEvent::Modified);
+    assert_eq!(t.lines(), ["bc"]);
+    keys(&mut vim, &mut t, "g-");
+    assert_eq!(t.lines(), ["abc"]);
+    assert_eq!(keys(&mut vim, &mut t, "g-"), VimEvent::Nop);
+    assert_eq!(keys(&mut vim, &mut t, "2g+"), VimEvent::Modified);
+    assert_eq!(t.lines(), ["abcfoo"]);
+    assert_eq!(vim.mode(), VimMode::Normal);
+}
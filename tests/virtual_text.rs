@@ -0,0 +1,121 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget as _;
+use tui_textarea::{CursorMove, TextArea, VirtualText, WrapMode};
+
+fn render(textarea: &TextArea<'_>, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    textarea.render(area, &mut buf);
+    buf
+}
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    (0..area.height)
+        .map(|y| {
+            let row: String = (0..area.width).map(|x| buf[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+fn textarea(lines: &[&str]) -> TextArea<'static> {
+    let mut t = TextArea::from(lines.iter().map(|l| l.to_string()));
+    t.set_cursor_style(Style::default());
+    t.set_cursor_line_style(Style::default());
+    t
+}
+
+const HINT: Style = Style::new().fg(Color::DarkGray);
+
+#[test]
+fn render_virtual_texts() {
+    let mut t = textarea(&["let x = 1;", "x"]);
+    t.set_virtual_texts([
+        VirtualText::new((0, 5), ": i32", HINT),
+        VirtualText::new((1, 1), " // end", HINT),
+    ]);
+    let buf = render(&t, 20, 2);
+    assert_eq!(rows(&buf), ["let x: i32 = 1;", "x // end"]);
+    assert_eq!(buf[(5, 0)].fg, Color::DarkGray);
+    assert_eq!(buf[(11, 0)].fg, Color::Reset);
+
+    // Virtual texts are not a part of the text
+    assert_eq!(t.lines(), ["let x = 1;", "x"]);
+}
+
+#[test]
+fn cursor_skips_virtual_text() {
+    let mut t = textarea(&["abcd"]);
+    t.add_virtual_text(VirtualText::new((0, 2), "<>", HINT));
+    t.move_cursor(CursorMove::Jump(0, 1));
+    t.move_cursor(CursorMove::Forward);
+    assert_eq!(t.cursor(), (0, 2));
+    t.move_cursor(CursorMove::Forward);
+    assert_eq!(t.cursor(), (0, 3));
+    t.move_cursor(CursorMove::End);
+    assert_eq!(t.cursor(), (0, 4));
+
+    t.delete_char();
+    assert_eq!(t.lines(), ["abc"]);
+}
+
+#[test]
+fn ghost_text_after_cursor() {
+    let mut t = TextArea::from(["hel"]);
+    t.move_cursor(CursorMove::End);
+    t.add_virtual_text(VirtualText::new((0, 3), "lo", HINT));
+    let buf = render(&t, 10, 1);
+    // Ghost text is rendered after the cursor at the end of line
+    assert_eq!(rows(&buf), ["hel lo"]);
+}
+
+#[test]
+fn wrap_with_virtual_text() {
+    let mut t = textarea(&["abcdef"]);
+    t.set_wrap_mode(WrapMode::Glyph);
+    t.add_virtual_text(VirtualText::new((0, 1), "__", HINT));
+    t.move_cursor(CursorMove::Bottom);
+    assert_eq!(rows(&render(&t, 4, 3)), ["a__b", "cdef", ""]);
+
+    // Virtual text at the wrapping point is rendered at the start of the next row
+    t.set_virtual_texts([VirtualText::new((0, 4), "__", HINT)]);
+    assert_eq!(rows(&render(&t, 4, 3)), ["abcd", "__ef", ""]);
+}
+
+#[test]
+fn click_on_virtual_text() {
+    let mut t = textarea(&["abcd"]);
+    t.add_virtual_text(VirtualText::new((0, 2), "<>", HINT));
+    render(&t, 10, 1);
+    assert_eq!(t.screen_to_cursor(1, 0), Some((0, 1)));
+    // Clicking the virtual text places the cursor at the character after it
+    assert_eq!(t.screen_to_cursor(2, 0), Some((0, 2)));
+    assert_eq!(t.screen_to_cursor(3, 0), Some((0, 2)));
+    assert_eq!(t.screen_to_cursor(4, 0), Some((0, 2)));
+    assert_eq!(t.screen_to_cursor(5, 0), Some((0, 3)));
+}
+
+#[test]
+fn horizontal_scroll_with_virtual_text() {
+    let mut t = textarea(&["abcdef"]);
+    t.add_virtual_text(VirtualText::new((0, 0), "12345", HINT));
+    t.move_cursor(CursorMove::End);
+    // Cursor is rendered at the 12th column so the view is scrolled
+    assert_eq!(rows(&render(&t, 8, 1)), ["5abcdef"]);
+}
+
+#[test]
+fn virtual_texts_follow_edits_above() {
+    let mut t = textarea(&["a", "b"]);
+    t.add_virtual_text(VirtualText::new((1, 1), "!", HINT));
+    t.insert_newline();
+    assert_eq!(t.virtual_texts()[0].pos, (2, 1));
+    t.undo();
+    assert_eq!(t.virtual_texts()[0].pos, (1, 1));
+
+    t.clear_virtual_texts();
+    assert!(t.virtual_texts().is_empty());
+}